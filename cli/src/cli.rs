@@ -162,26 +162,57 @@ where
     }
 
     fn execute(&mut self, sql: impl AsRef<str>) -> Result<()> {
-        match block_on(self.glue.execute(sql)) {
-            Ok(payloads) => self.print.payloads(&payloads)?,
-            Err(e) => {
-                println!("[error] {}\n", e);
-            }
-        };
+        if let Err(e) = self.stream(sql)? {
+            println!("[error] {}\n", e);
+        }
 
         Ok(())
     }
 
+    /// Prints the result of each statement, rows of a query are printed while they are fetched.
+    ///
+    /// The transaction of a query is committed once its rows are printed,
+    /// it is rolled back when printing stops early.
+    fn stream(&mut self, sql: impl AsRef<str>) -> Result<gluesql_core::prelude::Result<()>> {
+        let result = self.print_statements(sql);
+        let finished = match &result {
+            Ok(Ok(())) => block_on(self.glue.finish_stream()),
+            Ok(Err(_)) | Err(_) => block_on(self.glue.rollback_stream()),
+        };
+
+        result.map(|result| result.and(finished))
+    }
+
+    fn print_statements(
+        &mut self,
+        sql: impl AsRef<str>,
+    ) -> Result<gluesql_core::prelude::Result<()>> {
+        let statements = match block_on(self.glue.plan(sql)) {
+            Ok(statements) => statements,
+            Err(e) => return Ok(Err(e)),
+        };
+
+        for statement in statements.iter() {
+            let payload = match block_on(self.glue.execute_stream(statement)) {
+                Ok(payload) => payload,
+                Err(e) => return Ok(Err(e)),
+            };
+
+            if let Err(e) = self.print.payload_stream(payload)? {
+                return Ok(Err(e));
+            }
+        }
+
+        Ok(Ok(()))
+    }
+
     pub fn load<P: AsRef<Path>>(&mut self, filename: P) -> Result<()> {
         let mut sqls = String::new();
         File::open(filename)?.read_to_string(&mut sqls)?;
         for sql in sqls.split(';').filter(|sql| !sql.trim().is_empty()) {
-            match block_on(self.glue.execute(sql)) {
-                Ok(payloads) => self.print.payloads(&payloads)?,
-                Err(e) => {
-                    println!("[error] {}\n", e);
-                    break;
-                }
+            if let Err(e) = self.stream(sql)? {
+                println!("[error] {}\n", e);
+                break;
             }
         }
 
@@ -14,9 +14,14 @@ use {
         stream::{StreamExt, TryStreamExt},
     },
     gluesql_core::{
-        ast::{Expr, SetExpr, Statement, ToSql, Values},
-        data::Value,
-        store::{DataRow, GStore, GStoreMut, Store, Transaction},
+        ast::{
+            AlterColumnOperation, AlterTableOperation, AstLiteral, Expr, Function, SequenceOptions,
+            SetExpr, Statement, ToSql, Values,
+        },
+        ast_builder::{table, Build},
+        data::{Schema, Sequence, Value, View},
+        executor::{identity_sequence_name, select_stream, PayloadStream},
+        store::{GStore, GStoreMut},
     },
     gluesql_csv_storage::CsvStorage,
     gluesql_file_storage::FileStorage,
@@ -143,57 +148,178 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
-pub fn dump_database(storage: &mut SledStorage, dump_path: PathBuf) -> Result<()> {
+/// Writes the sequences, tables with their rows and views of the storage as SQL statements.
+///
+/// Identity columns and triggers are added after the rows of their table, so the rows are
+/// inserted with their values as they are, and every sequence is set to its current value.
+pub fn dump_database<T: GStore + GStoreMut>(storage: &mut T, dump_path: PathBuf) -> Result<()> {
     let file = File::create(dump_path)?;
 
     block_on(async {
         storage.begin(true).await?;
         let schemas = storage.fetch_all_schemas().await?;
+        let sequences = storage.fetch_all_sequences().await?;
+
+        // sequences of identity columns are created along with their columns
+        let identity_sequence_names = schemas
+            .iter()
+            .flat_map(|schema| {
+                schema
+                    .column_defs
+                    .iter()
+                    .flatten()
+                    .filter(|column_def| column_def.identity.is_some())
+                    .map(|column_def| identity_sequence_name(&schema.table_name, &column_def.name))
+            })
+            .collect::<Vec<_>>();
+        let created_sequences = sequences
+            .iter()
+            .filter(|Sequence { sequence_name, .. }| {
+                !identity_sequence_names.contains(sequence_name)
+            })
+            .collect::<Vec<_>>();
+
+        for sequence in &created_sequences {
+            let create_sequence = Statement::CreateSequence {
+                if_not_exists: false,
+                name: sequence.sequence_name.clone(),
+                options: SequenceOptions {
+                    increment: Some(sequence.increment),
+                    start: Some(sequence.start),
+                },
+            }
+            .to_sql();
+
+            writeln!(&file, "{}", create_sequence)?;
+        }
+
+        if !created_sequences.is_empty() {
+            writeln!(&file)?;
+        }
+
         for schema in schemas {
-            writeln!(&file, "{}", schema.to_ddl())?;
+            let mut table_schema = Schema {
+                triggers: Vec::new(),
+                ..schema.clone()
+            };
+            let identities = table_schema
+                .column_defs
+                .iter_mut()
+                .flatten()
+                .filter_map(|column_def| {
+                    let identity = column_def.identity.take()?;
 
-            let mut rows_list = storage
-                .scan_data(&schema.table_name)
-                .await?
-                .map_ok(|(_, row)| row)
-                .chunks(100);
+                    Some(AlterTableOperation::AlterColumn {
+                        column_name: column_def.name.clone(),
+                        operation: AlterColumnOperation::AddIdentity { identity },
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            writeln!(&file, "{}", table_schema.to_ddl())?;
+
+            let query = match table(&schema.table_name).select().build()? {
+                Statement::Query(query) => query,
+                _ => unreachable!("select node always builds a query"),
+            };
+            let rows = match select_stream(&*storage, &query, None).await? {
+                PayloadStream::Select { rows, .. } => rows,
+                PayloadStream::SelectMap(rows) => {
+                    rows.map_ok(|values| vec![Value::Map(values)]).boxed_local()
+                }
+                PayloadStream::Payload(_) => unreachable!("query always streams rows"),
+            };
+            let mut rows_list = rows.chunks(100);
 
             while let Some(rows) = rows_list.next().await {
                 let exprs_list = rows
                     .into_iter()
-                    .map(|result| {
-                        result.map(|data_row| {
-                            let values = match data_row {
-                                DataRow::Vec(values) => values,
-                                DataRow::Map(values) => vec![Value::Map(values)],
-                            };
-
-                            values
-                                .into_iter()
-                                .map(|value| Ok(Expr::try_from(value)?))
-                                .collect::<Result<Vec<_>>>()
-                        })?
+                    .map(|values| {
+                        values?
+                            .into_iter()
+                            .map(|value| Ok(Expr::try_from(value)?))
+                            .collect::<Result<Vec<_>>>()
                     })
-                    .collect::<Result<Vec<_>, _>>()?;
+                    .collect::<Result<Vec<_>>>()?;
 
                 let insert_statement = Statement::Insert {
                     table_name: schema.table_name.clone(),
                     columns: Vec::new(),
                     source: gluesql_core::ast::Query {
+                        with: None,
                         body: SetExpr::Values(Values(exprs_list)),
                         order_by: Vec::new(),
                         limit: None,
                         offset: None,
                     },
+                    on_conflict: None,
+                    returning: Vec::new(),
                 }
                 .to_sql();
 
                 writeln!(&file, "{}", insert_statement)?;
             }
 
+            if !identities.is_empty() {
+                let add_identities = Statement::AlterTable {
+                    name: schema.table_name.clone(),
+                    operations: identities,
+                }
+                .to_sql();
+
+                writeln!(&file, "{}", add_identities)?;
+            }
+
+            for trigger in schema.triggers {
+                let create_trigger = Statement::CreateTrigger {
+                    table_name: schema.table_name.clone(),
+                    trigger,
+                }
+                .to_sql();
+
+                writeln!(&file, "{}", create_trigger)?;
+            }
+
             writeln!(&file)?;
         }
 
+        for Sequence {
+            sequence_name,
+            current,
+            ..
+        } in sequences
+        {
+            let Some(current) = current else {
+                continue;
+            };
+
+            let set_value = Expr::Function(Box::new(Function::Setval {
+                name: Expr::Literal(AstLiteral::QuotedString(sequence_name)),
+                value: Expr::Literal(AstLiteral::Number(current.into())),
+            }));
+
+            writeln!(&file, "SELECT {};", set_value.to_sql())?;
+        }
+
+        for View {
+            view_name,
+            columns,
+            query,
+        } in storage.fetch_all_views().await?
+        {
+            let create_view = Statement::CreateView {
+                or_replace: false,
+                name: view_name,
+                columns,
+                query: Box::new(query),
+            }
+            .to_sql();
+
+            writeln!(&file, "{}", create_view)?;
+        }
+
+        storage.commit().await?;
+
         Ok(())
     })
 }
@@ -1,11 +1,13 @@
 use {
     crate::command::{SetOption, ShowOption},
-    gluesql_core::prelude::{Payload, PayloadVariable},
+    futures::executor::{block_on, block_on_stream},
+    gluesql_core::prelude::{Payload, PayloadStream, PayloadVariable, Result},
     std::{
         collections::{HashMap, HashSet},
         fmt::Display,
         fs::File,
         io::{Result as IOResult, Write},
+        iter::once,
         path::Path,
     },
     strum_macros::Display,
@@ -97,6 +99,29 @@ impl<'a, W: Write> Print<W> {
         payloads.iter().try_for_each(|p| self.payload(p))
     }
 
+    /// Prints rows of a query as soon as they are fetched, except for the tabular output
+    /// which needs every row to align its columns.
+    pub fn payload_stream(&mut self, payload: PayloadStream<'_>) -> IOResult<Result<()>> {
+        match payload {
+            PayloadStream::Select { labels, rows } if !self.option.tabular => {
+                self.write_header(labels.iter().map(String::as_str))?;
+
+                for row in block_on_stream(rows) {
+                    match row {
+                        Ok(row) => self.write_rows(once(row.iter().map(String::from)))?,
+                        Err(error) => return Ok(Err(error)),
+                    }
+                }
+
+                Ok(Ok(()))
+            }
+            payload => match block_on(payload.collect()) {
+                Ok(payload) => self.payload(&payload).map(Ok),
+                Err(error) => Ok(Err(error)),
+            },
+        }
+    }
+
     pub fn payload(&mut self, payload: &Payload) -> IOResult<()> {
         #[derive(Display)]
         #[strum(serialize_all = "snake_case")]
@@ -117,8 +142,15 @@ impl<'a, W: Write> Print<W> {
             Payload::AlterTable => self.writeln("Table altered")?,
             Payload::CreateIndex => self.writeln("Index created")?,
             Payload::DropIndex => self.writeln("Index dropped")?,
+            Payload::CreateView => self.writeln("View created")?,
+            Payload::DropView => self.writeln("View dropped")?,
+            Payload::CreateSequence => self.writeln("Sequence created")?,
+            Payload::DropSequence => self.writeln("Sequence dropped")?,
+            Payload::CreateTrigger => self.writeln("Trigger created")?,
+            Payload::DropTrigger => self.writeln("Trigger dropped")?,
             Payload::Commit => self.writeln("Commit completed")?,
             Payload::Rollback => self.writeln("Rollback completed")?,
+            Payload::Explain(plan) => self.writeln(plan)?,
             Payload::StartTransaction => self.writeln("Transaction started")?,
             Payload::Insert(n) => affected(*n, Row, "inserted")?,
             Payload::Delete(n) => affected(*n, Row, "deleted")?,
@@ -367,6 +399,7 @@ mod tests {
     fn print_payload() {
         use gluesql_core::{
             ast::DataType,
+            executor::ExplainNode,
             prelude::{Payload, PayloadVariable, Value},
         };
 
@@ -393,10 +426,32 @@ mod tests {
         test!(Payload::AlterTable, "Table altered");
         test!(Payload::CreateIndex, "Index created");
         test!(Payload::DropIndex, "Index dropped");
+        test!(Payload::CreateView, "View created");
+        test!(Payload::DropView, "View dropped");
+        test!(Payload::CreateSequence, "Sequence created");
+        test!(Payload::DropSequence, "Sequence dropped");
+        test!(Payload::CreateTrigger, "Trigger created");
+        test!(Payload::DropTrigger, "Trigger dropped");
         test!(Payload::DropFunction, "Function dropped");
         test!(Payload::Commit, "Commit completed");
         test!(Payload::Rollback, "Rollback completed");
         test!(Payload::StartTransaction, "Transaction started");
+        test!(
+            Payload::Explain(ExplainNode {
+                operator: "Filter".to_owned(),
+                detail: Some(r#""id" > 1"#.to_owned()),
+                analyzed: None,
+                children: vec![ExplainNode {
+                    operator: "SeqScan".to_owned(),
+                    detail: Some("Item".to_owned()),
+                    analyzed: None,
+                    children: Vec::new(),
+                }],
+            }),
+            r#"
+Filter: "id" > 1
+  -> SeqScan: Item"#
+        );
         test!(Payload::Insert(0), "0 row inserted");
         test!(Payload::Insert(1), "1 row inserted");
         test!(Payload::Insert(7), "7 rows inserted");
@@ -770,6 +825,64 @@ heading ON"
         );
     }
 
+    #[test]
+    fn print_payload_stream() {
+        use {
+            futures::stream::{self, StreamExt},
+            gluesql_core::prelude::{Error, Payload, PayloadStream, Value},
+        };
+
+        let mut print = Print::new(Vec::new(), None, Default::default());
+        print.set_option(SetOption::Tabular(false));
+
+        let payload = PayloadStream::Select {
+            labels: vec!["id".to_owned(), "name".to_owned()],
+            rows: stream::iter([
+                Ok(vec![Value::I64(1), Value::Str("foo".to_owned())]),
+                Ok(vec![Value::I64(2), Value::Str("bar".to_owned())]),
+                Err(Error::StorageMsg("broken row".to_owned())),
+                Ok(vec![Value::I64(3), Value::Str("baz".to_owned())]),
+            ])
+            .boxed_local(),
+        };
+        let actual = print.payload_stream(payload).unwrap();
+        assert_eq!(actual, Err(Error::StorageMsg("broken row".to_owned())));
+        assert_eq!(
+            String::from_utf8(print.output.clone()).unwrap(),
+            "id|name\n1|foo\n2|bar\n"
+        );
+        print.output.clear();
+
+        print.set_option(SetOption::Tabular(true));
+        let payload = PayloadStream::Select {
+            labels: vec!["id".to_owned()],
+            rows: stream::iter([Ok(vec![Value::I64(1)]), Ok(vec![Value::I64(2)])]).boxed_local(),
+        };
+        assert_eq!(print.payload_stream(payload).unwrap(), Ok(()));
+        assert_eq!(
+            String::from_utf8(print.output.clone())
+                .unwrap()
+                .as_str()
+                .trim_matches('\n'),
+            "
+| id |
+|----|
+| 1  |
+| 2  |"
+                .trim_matches('\n')
+        );
+        print.output.clear();
+
+        let payload = PayloadStream::Payload(Payload::Insert(2));
+        assert_eq!(print.payload_stream(payload).unwrap(), Ok(()));
+        assert_eq!(
+            String::from_utf8(print.output.clone())
+                .unwrap()
+                .trim_matches('\n'),
+            "2 rows inserted"
+        );
+    }
+
     #[test]
     fn print_spool() {
         use std::fs;
@@ -1,6 +1,7 @@
 use {
     gluesql_cli::dump_database,
     gluesql_core::prelude::Glue,
+    gluesql_memory_storage::MemoryStorage,
     gluesql_sled_storage::{sled, SledStorage},
     std::{fs::File, io::Read, path::PathBuf},
 };
@@ -62,6 +63,12 @@ async fn dump_and_import() {
             ('{"a": {"red": "apple", "blue": 1}, "b": 10}'),
             ('{"a": 100, "c": true}');
         "#,
+        "CREATE SEQUENCE Seq INCREMENT BY 5 START WITH 10;",
+        "SELECT NEXTVAL('Seq') AS n;",
+        "CREATE SEQUENCE Unused;",
+        "CREATE TABLE Qux (id INTEGER GENERATED ALWAYS AS IDENTITY, code SERIAL, name TEXT);",
+        "INSERT INTO Qux (name) VALUES ('a'), ('b');",
+        "INSERT INTO Qux (code, name) VALUES (100, 'c');",
     ];
 
     for sql in sqls {
@@ -101,4 +108,58 @@ async fn dump_and_import() {
     let source_data = source_glue.execute(sql).await.unwrap();
     let target_data = target_glue.execute(sql).await.unwrap();
     assert_eq!(source_data, target_data);
+
+    // identity values are kept and sequences continue from their current values
+    let sql = "SELECT * FROM Qux;";
+    let source_data = source_glue.execute(sql).await.unwrap();
+    let target_data = target_glue.execute(sql).await.unwrap();
+    assert_eq!(source_data, target_data);
+
+    let sql = "INSERT INTO Qux (name) VALUES ('d') RETURNING id, code;";
+    let source_data = source_glue.execute(sql).await.unwrap();
+    let target_data = target_glue.execute(sql).await.unwrap();
+    assert_eq!(source_data, target_data);
+
+    let sql = "SELECT NEXTVAL('Seq') AS n, NEXTVAL('Unused') AS m;";
+    let source_data = source_glue.execute(sql).await.unwrap();
+    let target_data = target_glue.execute(sql).await.unwrap();
+    assert_eq!(source_data, target_data);
+
+    let sql = "INSERT INTO Qux (id, name) VALUES (10, 'e');";
+    assert!(target_glue.execute(sql).await.is_err());
+}
+
+#[tokio::test]
+async fn dump_views() {
+    let dump_path = PathBuf::from("tmp/dump_views.sql");
+    let mut source_glue = Glue::new(MemoryStorage::default());
+
+    let sqls = vec![
+        "CREATE TABLE Item (id INTEGER, name TEXT);",
+        "INSERT INTO Item VALUES (1, 'a'), (2, 'b'), (3, 'c');",
+        "CREATE VIEW ItemName (item_name) AS SELECT name FROM Item WHERE id > 1;",
+    ];
+
+    for sql in sqls {
+        source_glue.execute(sql).await.unwrap();
+    }
+
+    dump_database(&mut source_glue.storage, dump_path.clone()).unwrap();
+
+    let mut target_glue = Glue::new(MemoryStorage::default());
+
+    let mut sqls = String::new();
+    File::open(dump_path)
+        .unwrap()
+        .read_to_string(&mut sqls)
+        .unwrap();
+
+    for sql in sqls.split(';').filter(|sql| !sql.trim().is_empty()) {
+        target_glue.execute(sql).await.unwrap();
+    }
+
+    let sql = "SELECT * FROM ItemName;";
+    let source_data = source_glue.execute(sql).await.unwrap();
+    let target_data = target_glue.execute(sql).await.unwrap();
+    assert_eq!(source_data, target_data);
 }
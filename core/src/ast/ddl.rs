@@ -20,6 +20,27 @@ pub enum AlterTableOperation {
     },
     /// `RENAME TO <table_name>`
     RenameTable { table_name: String },
+    /// `ALTER [ COLUMN ] <column_name> <operation>`
+    AlterColumn {
+        column_name: String,
+        operation: AlterColumnOperation,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AlterColumnOperation {
+    /// `[ SET DATA ] TYPE <data_type>`
+    SetDataType { data_type: DataType },
+    /// `SET DEFAULT <expr>`
+    SetDefault { default: Expr },
+    /// `DROP DEFAULT`
+    DropDefault,
+    /// `SET NOT NULL`
+    SetNotNull,
+    /// `DROP NOT NULL`
+    DropNotNull,
+    /// `ADD GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( <options> ) ]`
+    AddIdentity { identity: ColumnIdentityOption },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -31,6 +52,10 @@ pub struct ColumnDef {
     pub default: Option<Expr>,
     /// `{ PRIMARY KEY | UNIQUE }`
     pub unique: Option<ColumnUniqueOption>,
+    /// `CHECK (<expr>)`
+    pub check: Option<Expr>,
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`
+    pub identity: Option<ColumnIdentityOption>,
     pub comment: Option<String>,
 }
 
@@ -39,6 +64,22 @@ pub struct ColumnUniqueOption {
     pub is_primary: bool,
 }
 
+/// Values of an identity column are drawn from the sequence `<table>_<column>_seq`,
+/// which is created and dropped along with the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ColumnIdentityOption {
+    /// `ALWAYS` rejects values given by `INSERT`, `BY DEFAULT` uses them instead
+    pub always: bool,
+    pub options: SequenceOptions,
+}
+
+/// `[ INCREMENT BY <increment> ] [ START WITH <start> ]`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SequenceOptions {
+    pub increment: Option<i64>,
+    pub start: Option<i64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct OperateFunctionArg {
     pub name: String,
@@ -67,6 +108,25 @@ impl ToSql for AlterTableOperation {
             AlterTableOperation::RenameTable { table_name } => {
                 format!(r#"RENAME TO "{table_name}""#)
             }
+            AlterTableOperation::AlterColumn {
+                column_name,
+                operation,
+            } => format!(r#"ALTER COLUMN "{column_name}" {}"#, operation.to_sql()),
+        }
+    }
+}
+
+impl ToSql for AlterColumnOperation {
+    fn to_sql(&self) -> String {
+        match self {
+            AlterColumnOperation::SetDataType { data_type } => format!("SET DATA TYPE {data_type}"),
+            AlterColumnOperation::SetDefault { default } => {
+                format!("SET DEFAULT {}", default.to_sql())
+            }
+            AlterColumnOperation::DropDefault => "DROP DEFAULT".to_owned(),
+            AlterColumnOperation::SetNotNull => "SET NOT NULL".to_owned(),
+            AlterColumnOperation::DropNotNull => "DROP NOT NULL".to_owned(),
+            AlterColumnOperation::AddIdentity { identity } => format!("ADD {}", identity.to_sql()),
         }
     }
 }
@@ -79,6 +139,8 @@ impl ToSql for ColumnDef {
             nullable,
             default,
             unique,
+            check,
+            identity,
             comment,
         } = self;
         {
@@ -90,12 +152,16 @@ impl ToSql for ColumnDef {
             let default = default
                 .as_ref()
                 .map(|expr| format!("DEFAULT {}", expr.to_sql()));
+            let identity = identity.as_ref().map(ToSql::to_sql);
             let unique = unique.as_ref().map(ToSql::to_sql);
+            let check = check
+                .as_ref()
+                .map(|expr| format!("CHECK ({})", expr.to_sql()));
             let comment = comment
                 .as_ref()
                 .map(|comment| format!("COMMENT '{}'", comment));
 
-            [Some(column_def), default, unique, comment]
+            [Some(column_def), default, identity, unique, check, comment]
                 .into_iter()
                 .flatten()
                 .collect::<Vec<_>>()
@@ -115,6 +181,35 @@ impl ToSql for ColumnUniqueOption {
     }
 }
 
+impl ToSql for ColumnIdentityOption {
+    fn to_sql(&self) -> String {
+        let ColumnIdentityOption { always, options } = self;
+        let generated = match always {
+            true => "GENERATED ALWAYS AS IDENTITY",
+            false => "GENERATED BY DEFAULT AS IDENTITY",
+        };
+
+        match options.to_sql().as_str() {
+            "" => generated.to_owned(),
+            options => format!("{generated} ({options})"),
+        }
+    }
+}
+
+impl ToSql for SequenceOptions {
+    fn to_sql(&self) -> String {
+        let SequenceOptions { increment, start } = self;
+        let increment = increment.map(|increment| format!("INCREMENT BY {increment}"));
+        let start = start.map(|start| format!("START WITH {start}"));
+
+        [increment, start]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 impl ToSql for OperateFunctionArg {
     fn to_sql(&self) -> String {
         let OperateFunctionArg {
@@ -133,7 +228,8 @@ impl ToSql for OperateFunctionArg {
 #[cfg(test)]
 mod tests {
     use crate::ast::{
-        AstLiteral, ColumnDef, ColumnUniqueOption, DataType, Expr, OperateFunctionArg, ToSql,
+        AstLiteral, BinaryOperator, ColumnDef, ColumnUniqueOption, DataType, Expr,
+        OperateFunctionArg, ToSql,
     };
 
     #[test]
@@ -146,6 +242,8 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: false }),
+                check: None,
+                identity: None,
                 comment: None,
             }
             .to_sql()
@@ -159,6 +257,8 @@ mod tests {
                 nullable: true,
                 default: None,
                 unique: None,
+                check: None,
+                identity: None,
                 comment: None,
             }
             .to_sql()
@@ -172,6 +272,8 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
+                check: None,
+                identity: None,
                 comment: None,
             }
             .to_sql()
@@ -185,6 +287,8 @@ mod tests {
                 nullable: false,
                 default: Some(Expr::Literal(AstLiteral::Boolean(false))),
                 unique: None,
+                check: None,
+                identity: None,
                 comment: None,
             }
             .to_sql()
@@ -198,6 +302,8 @@ mod tests {
                 nullable: false,
                 default: Some(Expr::Literal(AstLiteral::Boolean(false))),
                 unique: Some(ColumnUniqueOption { is_primary: false }),
+                check: None,
+                identity: None,
                 comment: None,
             }
             .to_sql()
@@ -211,10 +317,31 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: None,
+                check: None,
+                identity: None,
                 comment: Some("this is comment".to_owned()),
             }
             .to_sql()
         );
+
+        assert_eq!(
+            r#""price" INT NOT NULL CHECK ("price" > 0)"#,
+            ColumnDef {
+                name: "price".to_owned(),
+                data_type: DataType::Int,
+                nullable: false,
+                default: None,
+                unique: None,
+                check: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("price".to_owned())),
+                    op: BinaryOperator::Gt,
+                    right: Box::new(Expr::Literal(AstLiteral::Number(0.into()))),
+                }),
+                identity: None,
+                comment: None,
+            }
+            .to_sql()
+        );
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use {
     super::{
         Aggregate, AstLiteral, BinaryOperator, DataType, DateTimeField, Function, Query, ToSql,
-        ToSqlUnquoted, UnaryOperator,
+        ToSqlUnquoted, UnaryOperator, Window,
     },
     serde::{Deserialize, Serialize},
 };
@@ -58,6 +58,7 @@ pub enum Expr {
     },
     Function(Box<Function>),
     Aggregate(Box<Aggregate>),
+    Window(Box<Window>),
     Exists {
         subquery: Box<Query>,
         negated: bool,
@@ -80,6 +81,8 @@ pub enum Expr {
     Array {
         elem: Vec<Expr>,
     },
+    /// Placeholder of a prepared statement, `$1` refers to the first parameter
+    Parameter(usize),
 }
 
 impl ToSql for Expr {
@@ -216,6 +219,7 @@ impl Expr {
                 }
             }
             Expr::Aggregate(a) => a.to_sql(),
+            Expr::Window(window) => window.to_sql(),
             Expr::Function(func) => func.to_sql(),
             Expr::InSubquery {
                 expr,
@@ -267,6 +271,7 @@ impl Expr {
                     None => format!("INTERVAL {expr} {leading_field}"),
                 }
             }
+            Expr::Parameter(index) => format!("${index}"),
         }
     }
 }
@@ -442,7 +447,9 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -470,7 +477,9 @@ mod tests {
             Expr::InSubquery {
                 expr: Box::new(Expr::Identifier("id".to_owned())),
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -497,7 +506,9 @@ mod tests {
             r#"EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -524,7 +535,9 @@ mod tests {
             r#"NOT EXISTS(SELECT * FROM "FOO")"#,
             Expr::Exists {
                 subquery: Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![SelectItem::Wildcard],
                         from: TableWithJoins {
                             relation: TableFactor::Table {
@@ -550,7 +563,9 @@ mod tests {
         assert_eq!(
             r#"(SELECT * FROM "FOO")"#,
             Expr::Subquery(Box::new(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -704,5 +719,7 @@ mod tests {
             }
             .to_sql()
         );
+
+        assert_eq!("$2", Expr::Parameter(2).to_sql());
     }
 }
@@ -1,5 +1,5 @@
 use {
-    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr},
+    super::{ast_literal::TrimWhereField, DataType, DateTimeField, Expr, OrderByExpr},
     crate::ast::ToSql,
     serde::{Deserialize, Serialize},
     strum_macros::Display,
@@ -134,6 +134,12 @@ pub enum Function {
         selector: Expr,
     },
     GenerateUuid(),
+    Nextval(Expr),
+    Currval(Expr),
+    Setval {
+        name: Expr,
+        value: Expr,
+    },
     Greatest(Vec<Expr>),
     Format {
         expr: Expr,
@@ -372,6 +378,11 @@ impl ToSql for Function {
                 format!("UNWRAP({}, {})", expr.to_sql(), selector.to_sql())
             }
             Function::GenerateUuid() => "GENERATE_UUID()".to_owned(),
+            Function::Nextval(e) => format!("NEXTVAL({})", e.to_sql()),
+            Function::Currval(e) => format!("CURRVAL({})", e.to_sql()),
+            Function::Setval { name, value } => {
+                format!("SETVAL({}, {})", name.to_sql(), value.to_sql())
+            }
             Function::Greatest(items) => {
                 let items = items
                     .iter()
@@ -498,25 +509,44 @@ impl ToSql for Function {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Aggregate {
-    Count(CountArgExpr),
-    Sum(Expr),
-    Max(Expr),
-    Min(Expr),
-    Avg(Expr),
-    Variance(Expr),
-    Stdev(Expr),
+    Count { expr: CountArgExpr, distinct: bool },
+    Sum { expr: Expr, distinct: bool },
+    Max { expr: Expr, distinct: bool },
+    Min { expr: Expr, distinct: bool },
+    Avg { expr: Expr, distinct: bool },
+    Variance { expr: Expr, distinct: bool },
+    Stdev { expr: Expr, distinct: bool },
+}
+
+impl Aggregate {
+    pub fn is_distinct(&self) -> bool {
+        match self {
+            Aggregate::Count { distinct, .. }
+            | Aggregate::Sum { distinct, .. }
+            | Aggregate::Max { distinct, .. }
+            | Aggregate::Min { distinct, .. }
+            | Aggregate::Avg { distinct, .. }
+            | Aggregate::Variance { distinct, .. }
+            | Aggregate::Stdev { distinct, .. } => *distinct,
+        }
+    }
 }
 
 impl ToSql for Aggregate {
     fn to_sql(&self) -> String {
-        match self {
-            Aggregate::Count(cae) => format!("COUNT({})", cae.to_sql()),
-            Aggregate::Sum(e) => format!("SUM({})", e.to_sql()),
-            Aggregate::Max(e) => format!("MAX({})", e.to_sql()),
-            Aggregate::Min(e) => format!("MIN({})", e.to_sql()),
-            Aggregate::Avg(e) => format!("AVG({})", e.to_sql()),
-            Aggregate::Variance(e) => format!("VARIANCE({})", e.to_sql()),
-            Aggregate::Stdev(e) => format!("STDEV({})", e.to_sql()),
+        let (name, expr) = match self {
+            Aggregate::Count { expr, .. } => ("COUNT", expr.to_sql()),
+            Aggregate::Sum { expr, .. } => ("SUM", expr.to_sql()),
+            Aggregate::Max { expr, .. } => ("MAX", expr.to_sql()),
+            Aggregate::Min { expr, .. } => ("MIN", expr.to_sql()),
+            Aggregate::Avg { expr, .. } => ("AVG", expr.to_sql()),
+            Aggregate::Variance { expr, .. } => ("VARIANCE", expr.to_sql()),
+            Aggregate::Stdev { expr, .. } => ("STDEV", expr.to_sql()),
+        };
+
+        match self.is_distinct() {
+            true => format!("{name}(DISTINCT {expr})"),
+            false => format!("{name}({expr})"),
         }
     }
 }
@@ -536,12 +566,98 @@ impl ToSql for CountArgExpr {
     }
 }
 
+/// Window function call, `function OVER (PARTITION BY .. ORDER BY ..)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Window {
+    pub function: WindowFunction,
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderByExpr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+    Lead {
+        expr: Expr,
+        offset: Option<Expr>,
+        default: Option<Expr>,
+    },
+    Aggregate(Aggregate),
+}
+
+impl ToSql for Window {
+    fn to_sql(&self) -> String {
+        let Window {
+            function,
+            partition_by,
+            order_by,
+        } = self;
+
+        let partition_by = (!partition_by.is_empty()).then(|| {
+            let exprs = partition_by.iter().map(ToSql::to_sql).collect::<Vec<_>>();
+
+            format!("PARTITION BY {}", exprs.join(", "))
+        });
+        let order_by = (!order_by.is_empty()).then(|| {
+            let exprs = order_by.iter().map(ToSql::to_sql).collect::<Vec<_>>();
+
+            format!("ORDER BY {}", exprs.join(", "))
+        });
+        let spec = [partition_by, order_by]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        format!("{} OVER ({spec})", function.to_sql())
+    }
+}
+
+impl ToSql for WindowFunction {
+    fn to_sql(&self) -> String {
+        let offset_args =
+            |name: &str, expr: &Expr, offset: &Option<Expr>, default: &Option<Expr>| {
+                let args = [Some(expr), offset.as_ref(), default.as_ref()]
+                    .into_iter()
+                    .flatten()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>();
+
+                format!("{name}({})", args.join(", "))
+            };
+
+        match self {
+            WindowFunction::RowNumber => "ROW_NUMBER()".to_owned(),
+            WindowFunction::Rank => "RANK()".to_owned(),
+            WindowFunction::DenseRank => "DENSE_RANK()".to_owned(),
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            } => offset_args("LAG", expr, offset, default),
+            WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => offset_args("LEAD", expr, offset, default),
+            WindowFunction::Aggregate(aggregate) => aggregate.to_sql(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
         crate::ast::{
-            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function, ToSql,
-            TrimWhereField,
+            Aggregate, AstLiteral, CountArgExpr, DataType, DateTimeField, Expr, Function,
+            OrderByExpr, ToSql, TrimWhereField, Window, WindowFunction,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -1065,6 +1181,28 @@ mod tests {
             "GENERATE_UUID()",
             &Expr::Function(Box::new(Function::GenerateUuid())).to_sql()
         );
+        assert_eq!(
+            "NEXTVAL('seq')",
+            &Expr::Function(Box::new(Function::Nextval(Expr::Literal(
+                AstLiteral::QuotedString("seq".to_owned())
+            ))))
+            .to_sql()
+        );
+        assert_eq!(
+            "CURRVAL('seq')",
+            &Expr::Function(Box::new(Function::Currval(Expr::Literal(
+                AstLiteral::QuotedString("seq".to_owned())
+            ))))
+            .to_sql()
+        );
+        assert_eq!(
+            "SETVAL('seq', 10)",
+            &Expr::Function(Box::new(Function::Setval {
+                name: Expr::Literal(AstLiteral::QuotedString("seq".to_owned())),
+                value: Expr::Literal(AstLiteral::Number(BigDecimal::from_str("10").unwrap()))
+            }))
+            .to_sql()
+        );
         assert_eq!(
             "ADD_MONTH('2023-06-15',1)",
             &Expr::Function(Box::new(Function::AddMonth {
@@ -1370,45 +1508,157 @@ mod tests {
 
     #[test]
     fn to_sql_aggregate() {
+        let ident = |name: &str| Expr::Identifier(name.to_owned());
+
         assert_eq!(
             r#"MAX("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Max(Expr::Identifier("id".to_owned())))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate::Max {
+                expr: ident("id"),
+                distinct: false,
+            }))
+            .to_sql()
         );
 
         assert_eq!(
             "COUNT(*)",
-            Expr::Aggregate(Box::new(Aggregate::Count(CountArgExpr::Wildcard))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate::Count {
+                expr: CountArgExpr::Wildcard,
+                distinct: false,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"COUNT(DISTINCT "id")"#,
+            Expr::Aggregate(Box::new(Aggregate::Count {
+                expr: CountArgExpr::Expr(ident("id")),
+                distinct: true,
+            }))
+            .to_sql()
         );
 
         assert_eq!(
             r#"MIN("id")"#,
-            Expr::Aggregate(Box::new(Aggregate::Min(Expr::Identifier("id".to_owned())))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate::Min {
+                expr: ident("id"),
+                distinct: false,
+            }))
+            .to_sql()
         );
 
         assert_eq!(
             r#"SUM("price")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Sum(Expr::Identifier(
-                "price".to_owned()
-            ))))
+            Expr::Aggregate(Box::new(Aggregate::Sum {
+                expr: ident("price"),
+                distinct: false,
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"SUM(DISTINCT "price")"#,
+            Expr::Aggregate(Box::new(Aggregate::Sum {
+                expr: ident("price"),
+                distinct: true,
+            }))
             .to_sql()
         );
 
         assert_eq!(
             r#"AVG("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Avg(Expr::Identifier("pay".to_owned())))).to_sql()
+            Expr::Aggregate(Box::new(Aggregate::Avg {
+                expr: ident("pay"),
+                distinct: false,
+            }))
+            .to_sql()
         );
         assert_eq!(
             r#"VARIANCE("pay")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Variance(Expr::Identifier(
-                "pay".to_owned()
-            ))))
+            Expr::Aggregate(Box::new(Aggregate::Variance {
+                expr: ident("pay"),
+                distinct: false,
+            }))
             .to_sql()
         );
         assert_eq!(
             r#"STDEV("total")"#,
-            &Expr::Aggregate(Box::new(Aggregate::Stdev(Expr::Identifier(
-                "total".to_owned()
-            ))))
+            Expr::Aggregate(Box::new(Aggregate::Stdev {
+                expr: ident("total"),
+                distinct: false,
+            }))
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_window() {
+        let ident = |name: &str| Expr::Identifier(name.to_owned());
+        let number = |n: &str| Expr::Literal(AstLiteral::Number(BigDecimal::from_str(n).unwrap()));
+
+        assert_eq!(
+            "ROW_NUMBER() OVER ()",
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::RowNumber,
+                partition_by: Vec::new(),
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"RANK() OVER (PARTITION BY "region", "city" ORDER BY "amount" DESC)"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Rank,
+                partition_by: vec![ident("region"), ident("city")],
+                order_by: vec![OrderByExpr {
+                    expr: ident("amount"),
+                    asc: Some(false),
+                }],
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LAG("amount", 2, 0) OVER (ORDER BY "id")"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Lag {
+                    expr: ident("amount"),
+                    offset: Some(number("2")),
+                    default: Some(number("0")),
+                },
+                partition_by: Vec::new(),
+                order_by: vec![OrderByExpr {
+                    expr: ident("id"),
+                    asc: None,
+                }],
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"LEAD("amount") OVER (PARTITION BY "region")"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Lead {
+                    expr: ident("amount"),
+                    offset: None,
+                    default: None,
+                },
+                partition_by: vec![ident("region")],
+                order_by: Vec::new(),
+            }))
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"SUM("amount") OVER (PARTITION BY "region")"#,
+            Expr::Window(Box::new(Window {
+                function: WindowFunction::Aggregate(Aggregate::Sum {
+                    expr: ident("amount"),
+                    distinct: false,
+                }),
+                partition_by: vec![ident("region")],
+                order_by: Vec::new(),
+            }))
             .to_sql()
         );
     }
@@ -11,7 +11,7 @@ pub use {
     data_type::DataType,
     ddl::*,
     expr::Expr,
-    function::{Aggregate, CountArgExpr, Function},
+    function::{Aggregate, CountArgExpr, Function, Window, WindowFunction},
     operator::*,
     query::*,
 };
@@ -39,10 +39,53 @@ pub struct ForeignKey {
     pub on_update: ReferentialAction,
 }
 
+/// `[ CONSTRAINT <name> ] CHECK (<expr>)`
+#[derive(PartialEq, Debug, Clone, Eq, Hash, Serialize, Deserialize)]
+pub struct Check {
+    pub name: Option<String>,
+    pub expr: Expr,
+}
+
+/// `CREATE TRIGGER <name> { BEFORE | AFTER } <event> [ OR <event> ]... ON <table>
+/// FOR EACH ROW [ WHEN (<condition>) ] <body>`
+///
+/// The body is run once for each row with `NEW.<column>` and `OLD.<column>` bound to the values of the row.
+#[derive(PartialEq, Debug, Clone, Eq, Hash, Serialize, Deserialize)]
+pub struct Trigger {
+    pub name: String,
+    pub timing: TriggerTiming,
+    pub events: Vec<TriggerEvent>,
+    pub condition: Option<Expr>,
+    pub body: Vec<Statement>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum TriggerTiming {
+    Before,
+    After,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum TriggerEvent {
+    Insert,
+    Update,
+    Delete,
+}
+
 #[derive(PartialEq, Debug, Clone, Eq, Hash, Serialize, Deserialize, Display)]
 pub enum ReferentialAction {
     #[strum(to_string = "NO ACTION")]
     NoAction,
+    #[strum(to_string = "RESTRICT")]
+    Restrict,
+    #[strum(to_string = "CASCADE")]
+    Cascade,
+    #[strum(to_string = "SET NULL")]
+    SetNull,
+    #[strum(to_string = "SET DEFAULT")]
+    SetDefault,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -60,6 +103,10 @@ pub enum Statement {
         columns: Vec<String>,
         /// A SQL query that specifies what to insert
         source: Query,
+        /// ON CONFLICT
+        on_conflict: Option<OnConflict>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// UPDATE
     Update {
@@ -67,15 +114,23 @@ pub enum Statement {
         table_name: String,
         /// Column assignments
         assignments: Vec<Assignment>,
+        /// FROM
+        from: Option<TableWithJoins>,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// DELETE
     Delete {
         /// FROM
         table_name: String,
+        /// USING
+        using: Option<TableWithJoins>,
         /// WHERE
         selection: Option<Expr>,
+        /// RETURNING
+        returning: Vec<SelectItem>,
     },
     /// CREATE TABLE
     CreateTable {
@@ -84,9 +139,12 @@ pub enum Statement {
         name: String,
         /// Optional schema
         columns: Option<Vec<ColumnDef>>,
+        /// Columns of a table-level `PRIMARY KEY (..)` constraint in the order of the key
+        primary_key: Vec<String>,
         source: Option<Box<Query>>,
         engine: Option<String>,
         foreign_keys: Vec<ForeignKey>,
+        checks: Vec<Check>,
         comment: Option<String>,
     },
     /// CREATE FUNCTION
@@ -101,7 +159,7 @@ pub enum Statement {
     AlterTable {
         /// Table name
         name: String,
-        operation: AlterTableOperation,
+        operations: Vec<AlterTableOperation>,
     },
     /// DROP TABLE
     DropTable {
@@ -123,13 +181,50 @@ pub enum Statement {
     CreateIndex {
         name: String,
         table_name: String,
-        column: OrderByExpr,
+        columns: Vec<OrderByExpr>,
     },
     /// DROP INDEX
     DropIndex {
         name: String,
         table_name: String,
     },
+    /// CREATE VIEW
+    CreateView {
+        or_replace: bool,
+        name: String,
+        /// Optional column names
+        columns: Vec<String>,
+        query: Box<Query>,
+    },
+    /// DROP VIEW
+    DropView {
+        /// An optional `IF EXISTS` clause. (Non-standard.)
+        if_exists: bool,
+        /// One or more views to drop.
+        names: Vec<String>,
+    },
+    /// CREATE SEQUENCE
+    CreateSequence {
+        if_not_exists: bool,
+        name: String,
+        options: SequenceOptions,
+    },
+    /// DROP SEQUENCE
+    DropSequence {
+        if_exists: bool,
+        names: Vec<String>,
+    },
+    /// CREATE TRIGGER
+    CreateTrigger {
+        table_name: String,
+        trigger: Trigger,
+    },
+    /// DROP TRIGGER
+    DropTrigger {
+        if_exists: bool,
+        name: String,
+        table_name: String,
+    },
     /// START TRANSACTION, BEGIN
     StartTransaction,
     /// COMMIT
@@ -139,6 +234,12 @@ pub enum Statement {
     /// SHOW VARIABLE
     ShowVariable(Variable),
     ShowIndexes(String),
+    /// EXPLAIN [ANALYZE]
+    Explain {
+        /// Executes the statement and reports rows and elapsed time of each operator
+        analyze: bool,
+        statement: Box<Statement>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -147,6 +248,23 @@ pub struct Assignment {
     pub value: Expr,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OnConflict {
+    /// Conflict target columns, empty for any unique constraint
+    pub columns: Vec<String>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnConflictAction {
+    DoNothing,
+    DoUpdate {
+        assignments: Vec<Assignment>,
+        /// WHERE
+        selection: Option<Expr>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Variable {
     Tables,
@@ -164,48 +282,82 @@ impl ToSql for Statement {
                 table_name,
                 columns,
                 source,
+                on_conflict,
+                returning,
             } => {
                 let columns = match columns.is_empty() {
                     true => "".to_owned(),
                     false => format!("({}) ", columns.join(", ")),
                 };
+                let on_conflict = match on_conflict {
+                    Some(on_conflict) => format!(" {}", on_conflict.to_sql()),
+                    None => "".to_owned(),
+                };
+                let returning = returning_to_sql(returning);
 
-                format!("INSERT INTO {table_name} {columns}{};", source.to_sql())
+                format!(
+                    "INSERT INTO {table_name} {columns}{}{on_conflict}{returning};",
+                    source.to_sql()
+                )
             }
             Statement::Update {
                 table_name,
                 assignments,
+                from,
                 selection,
+                returning,
             } => {
                 let assignments = assignments
                     .iter()
                     .map(ToSql::to_sql)
                     .collect::<Vec<_>>()
                     .join(", ");
+                let from = from
+                    .as_ref()
+                    .map(|from| format!(" FROM {}", from.to_sql()))
+                    .unwrap_or_default();
+                let returning = returning_to_sql(returning);
                 match selection {
                     Some(expr) => {
                         format!(
-                            r#"UPDATE "{table_name}" SET {assignments} WHERE {};"#,
+                            r#"UPDATE "{table_name}" SET {assignments}{from} WHERE {}{returning};"#,
                             expr.to_sql()
                         )
                     }
-                    None => format!(r#"UPDATE "{table_name}" SET {assignments};"#),
+                    None => {
+                        format!(r#"UPDATE "{table_name}" SET {assignments}{from}{returning};"#)
+                    }
                 }
             }
             Statement::Delete {
                 table_name,
+                using,
                 selection,
-            } => match selection {
-                Some(expr) => format!(r#"DELETE FROM "{table_name}" WHERE {};"#, expr.to_sql()),
-                None => format!(r#"DELETE FROM "{table_name}";"#),
-            },
+                returning,
+            } => {
+                let using = using
+                    .as_ref()
+                    .map(|using| format!(" USING {}", using.to_sql()))
+                    .unwrap_or_default();
+                let returning = returning_to_sql(returning);
+
+                match selection {
+                    Some(expr) => format!(
+                        r#"DELETE FROM "{table_name}"{using} WHERE {}{returning};"#,
+                        expr.to_sql()
+                    ),
+                    None => format!(r#"DELETE FROM "{table_name}"{using}{returning};"#),
+                }
+            }
             Statement::CreateTable {
                 if_not_exists,
                 name,
                 columns,
+                primary_key,
                 source,
                 engine,
                 foreign_keys,
+                checks,
                 comment,
             } => {
                 let if_not_exists = if_not_exists.then_some("IF NOT EXISTS");
@@ -213,11 +365,49 @@ impl ToSql for Statement {
                     (Some(query), _) => Some(format!("AS {}", query.to_sql())),
                     (None, None) => None,
                     (None, Some(columns)) => {
+                        // a primary key of several columns is written as a constraint
+                        // in the order of the key
+                        let key_columns = match primary_key.is_empty() {
+                            false => primary_key.iter().map(String::as_str).collect(),
+                            true => columns
+                                .iter()
+                                .filter(|ColumnDef { unique, .. }| {
+                                    unique == &Some(ColumnUniqueOption { is_primary: true })
+                                })
+                                .map(|ColumnDef { name, .. }| name.as_str())
+                                .collect::<Vec<_>>(),
+                        };
+                        let key_columns = match key_columns.len() {
+                            2.. => key_columns,
+                            _ => Vec::new(),
+                        };
+                        let columns = columns.iter().map(|column_def| match column_def {
+                            ColumnDef {
+                                name,
+                                unique: Some(ColumnUniqueOption { is_primary: true }),
+                                ..
+                            } if key_columns.contains(&name.as_str()) => ColumnDef {
+                                unique: None,
+                                ..column_def.clone()
+                            }
+                            .to_sql(),
+                            _ => column_def.to_sql(),
+                        });
+                        let primary_key = (!key_columns.is_empty()).then(|| {
+                            let key_columns = key_columns
+                                .iter()
+                                .map(|name| format!(r#""{name}""#))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+
+                            format!("PRIMARY KEY ({key_columns})")
+                        });
                         let foreign_keys = foreign_keys.iter().map(ToSql::to_sql);
+                        let checks = checks.iter().map(ToSql::to_sql);
                         let body = columns
-                            .iter()
-                            .map(ToSql::to_sql)
+                            .chain(primary_key)
                             .chain(foreign_keys)
+                            .chain(checks)
                             .collect::<Vec<_>>()
                             .join(", ");
 
@@ -259,8 +449,14 @@ impl ToSql for Statement {
                 let return_ = format!(" RETURN {}", return_.to_sql());
                 format!("CREATE{or_replace} FUNCTION {name}({args}){return_};")
             }
-            Statement::AlterTable { name, operation } => {
-                format!(r#"ALTER TABLE "{name}" {};"#, operation.to_sql())
+            Statement::AlterTable { name, operations } => {
+                let operations = operations
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(r#"ALTER TABLE "{name}" {operations};"#)
             }
             Statement::DropTable {
                 if_exists,
@@ -292,16 +488,114 @@ impl ToSql for Statement {
             Statement::CreateIndex {
                 name,
                 table_name,
-                column,
+                columns,
             } => {
-                format!(
-                    r#"CREATE INDEX "{name}" ON "{table_name}" ({});"#,
-                    column.to_sql()
-                )
+                let columns = columns
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({columns});"#)
             }
             Statement::DropIndex { name, table_name } => {
                 format!("DROP INDEX {table_name}.{name};")
             }
+            Statement::CreateView {
+                or_replace,
+                name,
+                columns,
+                query,
+            } => {
+                let or_replace = or_replace.then_some(" OR REPLACE").unwrap_or_default();
+                let columns = match columns.is_empty() {
+                    true => "".to_owned(),
+                    false => {
+                        let columns = columns
+                            .iter()
+                            .map(|column| format!(r#""{column}""#))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        format!(" ({columns})")
+                    }
+                };
+
+                format!(
+                    r#"CREATE{or_replace} VIEW "{name}"{columns} AS {};"#,
+                    query.to_sql()
+                )
+            }
+            Statement::DropView { if_exists, names } => {
+                let if_exists = if_exists.then_some(" IF EXISTS").unwrap_or_default();
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("DROP VIEW{if_exists} {names};")
+            }
+            Statement::CreateSequence {
+                if_not_exists,
+                name,
+                options,
+            } => {
+                let if_not_exists = if_not_exists
+                    .then_some(" IF NOT EXISTS")
+                    .unwrap_or_default();
+                let options = match options.to_sql().as_str() {
+                    "" => "".to_owned(),
+                    options => format!(" {options}"),
+                };
+
+                format!(r#"CREATE SEQUENCE{if_not_exists} "{name}"{options};"#)
+            }
+            Statement::DropSequence { if_exists, names } => {
+                let if_exists = if_exists.then_some(" IF EXISTS").unwrap_or_default();
+                let names = names
+                    .iter()
+                    .map(|name| format!(r#""{name}""#))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("DROP SEQUENCE{if_exists} {names};")
+            }
+            Statement::CreateTrigger {
+                table_name,
+                trigger:
+                    Trigger {
+                        name,
+                        timing,
+                        events,
+                        condition,
+                        body,
+                    },
+            } => {
+                let events = events
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" OR ");
+                let condition = condition
+                    .as_ref()
+                    .map(|expr| format!(" WHEN ({})", expr.to_sql()))
+                    .unwrap_or_default();
+                let body = body.iter().map(ToSql::to_sql).collect::<Vec<_>>().join(" ");
+
+                format!(
+                    r#"CREATE TRIGGER "{name}" {timing} {events} ON "{table_name}" FOR EACH ROW{condition} BEGIN {body} END;"#
+                )
+            }
+            Statement::DropTrigger {
+                if_exists,
+                name,
+                table_name,
+            } => {
+                let if_exists = if_exists.then_some(" IF EXISTS").unwrap_or_default();
+
+                format!(r#"DROP TRIGGER{if_exists} "{name}" ON "{table_name}";"#)
+            }
             Statement::StartTransaction => "START TRANSACTION;".to_owned(),
             Statement::Commit => "COMMIT;".to_owned(),
             Statement::Rollback => "ROLLBACK;".to_owned(),
@@ -313,17 +607,68 @@ impl ToSql for Statement {
             Statement::ShowIndexes(object_name) => {
                 format!(r#"SHOW INDEXES FROM "{object_name}";"#)
             }
+            Statement::Explain { analyze, statement } => {
+                let analyze = analyze.then_some(" ANALYZE").unwrap_or_default();
+
+                format!("EXPLAIN{analyze} {}", statement.to_sql())
+            }
             _ => "(..statement..)".to_owned(),
         }
     }
 }
 
+fn returning_to_sql(returning: &[SelectItem]) -> String {
+    match returning.is_empty() {
+        true => "".to_owned(),
+        false => {
+            let returning = returning
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!(" RETURNING {returning}")
+        }
+    }
+}
+
 impl ToSql for Assignment {
     fn to_sql(&self) -> String {
         format!(r#""{}" = {}"#, self.id, self.value.to_sql())
     }
 }
 
+impl ToSql for OnConflict {
+    fn to_sql(&self) -> String {
+        let OnConflict { columns, action } = self;
+
+        let columns = match columns.is_empty() {
+            true => "".to_owned(),
+            false => format!("({}) ", columns.join(", ")),
+        };
+        let action = match action {
+            OnConflictAction::DoNothing => "DO NOTHING".to_owned(),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                let assignments = assignments
+                    .iter()
+                    .map(ToSql::to_sql)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match selection {
+                    Some(expr) => format!("DO UPDATE SET {assignments} WHERE {}", expr.to_sql()),
+                    None => format!("DO UPDATE SET {assignments}"),
+                }
+            }
+        };
+
+        format!("ON CONFLICT {columns}{action}")
+    }
+}
+
 impl ToSql for ForeignKey {
     fn to_sql(&self) -> String {
         let ForeignKey {
@@ -347,6 +692,17 @@ impl ToSql for ForeignKey {
     }
 }
 
+impl ToSql for Check {
+    fn to_sql(&self) -> String {
+        let Check { name, expr } = self;
+
+        match name {
+            Some(name) => format!(r#"CONSTRAINT "{name}" CHECK ({})"#, expr.to_sql()),
+            None => format!("CHECK ({})", expr.to_sql()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Array {
     pub elem: Vec<Expr>,
@@ -357,9 +713,11 @@ pub struct Array {
 mod tests {
     use {
         crate::ast::{
-            AlterTableOperation, Assignment, AstLiteral, BinaryOperator, ColumnDef, DataType, Expr,
-            ForeignKey, OperateFunctionArg, OrderByExpr, Query, ReferentialAction, Select,
-            SelectItem, SetExpr, Statement, TableFactor, TableWithJoins, ToSql, Values, Variable,
+            AlterColumnOperation, AlterTableOperation, Assignment, AstLiteral, BinaryOperator,
+            ColumnDef, ColumnIdentityOption, DataType, Expr, ForeignKey, OnConflict,
+            OnConflictAction, OperateFunctionArg, OrderByExpr, Query, ReferentialAction, Select,
+            SelectItem, SequenceOptions, SetExpr, Statement, TableFactor, TableWithJoins, ToSql,
+            Trigger, TriggerEvent, TriggerTiming, Values, Variable,
         },
         bigdecimal::BigDecimal,
         std::str::FromStr,
@@ -384,6 +742,7 @@ mod tests {
                 table_name: "Test".into(),
                 columns: vec!["id".to_owned(), "num".to_owned(), "name".to_owned()],
                 source: Query {
+                    with: None,
                     body: SetExpr::Values(Values(vec![vec![
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
                         Expr::Literal(AstLiteral::Number(BigDecimal::from_str("2").unwrap())),
@@ -392,7 +751,64 @@ mod tests {
                     order_by: vec![],
                     limit: None,
                     offset: None
-                }
+                },
+                on_conflict: None,
+                returning: Vec::new(),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_insert_on_conflict() {
+        let source = Query {
+            with: None,
+            body: SetExpr::Values(Values(vec![vec![
+                Expr::Literal(AstLiteral::Number(BigDecimal::from_str("1").unwrap())),
+                Expr::Literal(AstLiteral::QuotedString("Hello".to_owned())),
+            ]])),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        };
+
+        assert_eq!(
+            "INSERT INTO Test VALUES (1, 'Hello') ON CONFLICT DO NOTHING;",
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: Vec::new(),
+                source: source.clone(),
+                on_conflict: Some(OnConflict {
+                    columns: Vec::new(),
+                    action: OnConflictAction::DoNothing,
+                }),
+                returning: Vec::new(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"INSERT INTO Test VALUES (1, 'Hello') ON CONFLICT (id) DO UPDATE SET "name" = "excluded"."name" WHERE "name" IS NULL;"#,
+            Statement::Insert {
+                table_name: "Test".into(),
+                columns: Vec::new(),
+                source,
+                on_conflict: Some(OnConflict {
+                    columns: vec!["id".to_owned()],
+                    action: OnConflictAction::DoUpdate {
+                        assignments: vec![Assignment {
+                            id: "name".to_owned(),
+                            value: Expr::CompoundIdentifier {
+                                alias: "excluded".to_owned(),
+                                ident: "name".to_owned(),
+                            },
+                        }],
+                        selection: Some(Expr::IsNull(Box::new(Expr::Identifier(
+                            "name".to_owned()
+                        )))),
+                    },
+                }),
+                returning: Vec::new(),
             }
             .to_sql()
         );
@@ -416,7 +832,9 @@ mod tests {
                         value: Expr::Literal(AstLiteral::QuotedString("blue".to_owned()))
                     }
                 ],
-                selection: None
+                from: None,
+                selection: None,
+                returning: Vec::new(),
             }
             .to_sql()
         );
@@ -429,14 +847,75 @@ mod tests {
                     id: "name".to_owned(),
                     value: Expr::Literal(AstLiteral::QuotedString("first".to_owned()))
                 }],
+                from: None,
                 selection: Some(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier("a".to_owned())),
                     op: BinaryOperator::Gt,
                     right: Box::new(Expr::Identifier("b".to_owned()))
-                })
+                }),
+                returning: Vec::new(),
             }
             .to_sql()
-        )
+        );
+
+        assert_eq!(
+            r#"UPDATE "Foo" SET "name" = 'first' RETURNING "id", "name" AS "label";"#,
+            Statement::Update {
+                table_name: "Foo".into(),
+                assignments: vec![Assignment {
+                    id: "name".to_owned(),
+                    value: Expr::Literal(AstLiteral::QuotedString("first".to_owned()))
+                }],
+                from: None,
+                selection: None,
+                returning: vec![
+                    SelectItem::Expr {
+                        expr: Expr::Identifier("id".to_owned()),
+                        label: "".to_owned(),
+                    },
+                    SelectItem::Expr {
+                        expr: Expr::Identifier("name".to_owned()),
+                        label: "label".to_owned(),
+                    },
+                ],
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"UPDATE "Foo" SET "name" = "Bar"."name" FROM "Bar" WHERE "Foo"."id" = "Bar"."id";"#,
+            Statement::Update {
+                table_name: "Foo".into(),
+                assignments: vec![Assignment {
+                    id: "name".to_owned(),
+                    value: Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "name".to_owned(),
+                    }
+                }],
+                from: Some(TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                }),
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "Foo".to_owned(),
+                        ident: "id".to_owned(),
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "id".to_owned(),
+                    }),
+                }),
+                returning: Vec::new(),
+            }
+            .to_sql()
+        );
     }
 
     #[test]
@@ -445,7 +924,9 @@ mod tests {
             r#"DELETE FROM "Foo";"#,
             Statement::Delete {
                 table_name: "Foo".into(),
-                selection: None
+                using: None,
+                selection: None,
+                returning: Vec::new(),
             }
             .to_sql()
         );
@@ -454,11 +935,52 @@ mod tests {
             r#"DELETE FROM "Foo" WHERE "item" = 'glue';"#,
             Statement::Delete {
                 table_name: "Foo".into(),
+                using: None,
                 selection: Some(Expr::BinaryOp {
                     left: Box::new(Expr::Identifier("item".to_owned())),
                     op: BinaryOperator::Eq,
                     right: Box::new(Expr::Literal(AstLiteral::QuotedString("glue".to_owned())))
-                })
+                }),
+                returning: Vec::new(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" RETURNING *;"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                using: None,
+                selection: None,
+                returning: vec![SelectItem::Wildcard],
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DELETE FROM "Foo" USING "Bar" WHERE "Foo"."id" = "Bar"."id";"#,
+            Statement::Delete {
+                table_name: "Foo".into(),
+                using: Some(TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                }),
+                selection: Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier {
+                        alias: "Foo".to_owned(),
+                        ident: "id".to_owned(),
+                    }),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::CompoundIdentifier {
+                        alias: "Bar".to_owned(),
+                        ident: "id".to_owned(),
+                    }),
+                }),
+                returning: Vec::new(),
             }
             .to_sql()
         );
@@ -474,7 +996,9 @@ mod tests {
                 columns: None,
                 source: None,
                 engine: None,
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -488,7 +1012,9 @@ mod tests {
                 columns: None,
                 source: None,
                 engine: None,
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -505,11 +1031,15 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },]),
                 source: None,
                 engine: None,
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: Some("this is comment".to_owned()),
             }
             .to_sql()
@@ -527,6 +1057,8 @@ mod tests {
                         nullable: false,
                         default: None,
                         unique: None,
+                        check: None,
+                        identity: None,
                         comment: None,
                     },
                     ColumnDef {
@@ -535,6 +1067,8 @@ mod tests {
                         nullable: true,
                         default: None,
                         unique: None,
+                        check: None,
+                        identity: None,
                         comment: None,
                     },
                     ColumnDef {
@@ -543,12 +1077,16 @@ mod tests {
                         nullable: false,
                         default: None,
                         unique: None,
+                        check: None,
+                        identity: None,
                         comment: None,
                     }
                 ]),
                 source: None,
                 engine: None,
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -564,7 +1102,9 @@ mod tests {
                 name: "Foo".into(),
                 columns: None,
                 source: Some(Box::new(Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(Select {
+                        distinct: false,
                         projection: vec![
                             SelectItem::Expr {
                                 expr: Expr::Identifier("id".to_owned()),
@@ -592,7 +1132,9 @@ mod tests {
                     offset: None
                 })),
                 engine: None,
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -605,6 +1147,7 @@ mod tests {
                 name: "Foo".into(),
                 columns: None,
                 source: Some(Box::new(Query {
+                    with: None,
                     body: SetExpr::Values(Values(vec![vec![Expr::Literal(AstLiteral::Boolean(
                         true
                     ))]])),
@@ -613,7 +1156,9 @@ mod tests {
                     offset: None
                 })),
                 engine: None,
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -630,7 +1175,9 @@ mod tests {
                 columns: None,
                 source: None,
                 engine: Some("MEMORY".to_owned()),
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -647,11 +1194,15 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },]),
                 source: None,
                 engine: Some("SLED".to_owned()),
+                primary_key: Vec::new(),
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
                 comment: None,
             }
             .to_sql()
@@ -694,7 +1245,7 @@ mod tests {
             r#"ALTER TABLE "Foo" ADD COLUMN "amount" INT NOT NULL DEFAULT 10;"#,
             Statement::AlterTable {
                 name: "Foo".into(),
-                operation: AlterTableOperation::AddColumn {
+                operations: vec![AlterTableOperation::AddColumn {
                     column_def: ColumnDef {
                         name: "amount".to_owned(),
                         data_type: DataType::Int,
@@ -703,9 +1254,11 @@ mod tests {
                             BigDecimal::from_str("10").unwrap()
                         ))),
                         unique: None,
+                        check: None,
+                        identity: None,
                         comment: None,
                     }
-                }
+                }]
             }
             .to_sql()
         );
@@ -714,10 +1267,10 @@ mod tests {
             r#"ALTER TABLE "Foo" DROP COLUMN "something";"#,
             Statement::AlterTable {
                 name: "Foo".into(),
-                operation: AlterTableOperation::DropColumn {
+                operations: vec![AlterTableOperation::DropColumn {
                     column_name: "something".to_owned(),
                     if_exists: false
-                }
+                }]
             }
             .to_sql()
         );
@@ -726,10 +1279,10 @@ mod tests {
             r#"ALTER TABLE "Foo" DROP COLUMN IF EXISTS "something";"#,
             Statement::AlterTable {
                 name: "Foo".into(),
-                operation: AlterTableOperation::DropColumn {
+                operations: vec![AlterTableOperation::DropColumn {
                     column_name: "something".to_owned(),
                     if_exists: true
-                }
+                }]
             }
             .to_sql()
         );
@@ -738,10 +1291,10 @@ mod tests {
             r#"ALTER TABLE "Bar" RENAME COLUMN "id" TO "new_id";"#,
             Statement::AlterTable {
                 name: "Bar".into(),
-                operation: AlterTableOperation::RenameColumn {
+                operations: vec![AlterTableOperation::RenameColumn {
                     old_column_name: "id".to_owned(),
                     new_column_name: "new_id".to_owned()
-                }
+                }]
             }
             .to_sql()
         );
@@ -750,9 +1303,63 @@ mod tests {
             r#"ALTER TABLE "Foo" RENAME TO "Bar";"#,
             Statement::AlterTable {
                 name: "Foo".to_owned(),
-                operation: AlterTableOperation::RenameTable {
+                operations: vec![AlterTableOperation::RenameTable {
                     table_name: "Bar".to_owned(),
-                }
+                }]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" ALTER COLUMN "id" SET DATA TYPE INT, ALTER COLUMN "id" SET NOT NULL, ALTER COLUMN "name" SET DEFAULT 'glue', ALTER COLUMN "name" DROP DEFAULT, ALTER COLUMN "name" DROP NOT NULL;"#,
+            Statement::AlterTable {
+                name: "Foo".to_owned(),
+                operations: vec![
+                    AlterTableOperation::AlterColumn {
+                        column_name: "id".to_owned(),
+                        operation: AlterColumnOperation::SetDataType {
+                            data_type: DataType::Int,
+                        },
+                    },
+                    AlterTableOperation::AlterColumn {
+                        column_name: "id".to_owned(),
+                        operation: AlterColumnOperation::SetNotNull,
+                    },
+                    AlterTableOperation::AlterColumn {
+                        column_name: "name".to_owned(),
+                        operation: AlterColumnOperation::SetDefault {
+                            default: Expr::Literal(AstLiteral::QuotedString("glue".to_owned())),
+                        },
+                    },
+                    AlterTableOperation::AlterColumn {
+                        column_name: "name".to_owned(),
+                        operation: AlterColumnOperation::DropDefault,
+                    },
+                    AlterTableOperation::AlterColumn {
+                        column_name: "name".to_owned(),
+                        operation: AlterColumnOperation::DropNotNull,
+                    },
+                ],
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"ALTER TABLE "Foo" ALTER COLUMN "id" ADD GENERATED ALWAYS AS IDENTITY (INCREMENT BY 2 START WITH 10);"#,
+            Statement::AlterTable {
+                name: "Foo".to_owned(),
+                operations: vec![AlterTableOperation::AlterColumn {
+                    column_name: "id".to_owned(),
+                    operation: AlterColumnOperation::AddIdentity {
+                        identity: ColumnIdentityOption {
+                            always: true,
+                            options: SequenceOptions {
+                                increment: Some(2),
+                                start: Some(10),
+                            },
+                        },
+                    },
+                }],
             }
             .to_sql()
         );
@@ -828,10 +1435,29 @@ mod tests {
             Statement::CreateIndex {
                 name: "idx_name".into(),
                 table_name: "Test".into(),
-                column: OrderByExpr {
+                columns: vec![OrderByExpr {
                     expr: Expr::Identifier("LastName".to_owned()),
                     asc: None
-                }
+                }]
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE INDEX "idx_tenant" ON "Test" ("tenant_id", "created_at" DESC);"#,
+            Statement::CreateIndex {
+                name: "idx_tenant".into(),
+                table_name: "Test".into(),
+                columns: vec![
+                    OrderByExpr {
+                        expr: Expr::Identifier("tenant_id".to_owned()),
+                        asc: None
+                    },
+                    OrderByExpr {
+                        expr: Expr::Identifier("created_at".to_owned()),
+                        asc: Some(false)
+                    }
+                ]
             }
             .to_sql()
         );
@@ -849,6 +1475,184 @@ mod tests {
         )
     }
 
+    #[test]
+    fn to_sql_create_view() {
+        let query = Query {
+            with: None,
+            body: SetExpr::Select(Box::new(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Bar".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: vec![],
+                },
+                selection: None,
+                group_by: vec![],
+                having: None,
+            })),
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        };
+
+        assert_eq!(
+            r#"CREATE VIEW "Foo" AS SELECT * FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: false,
+                name: "Foo".into(),
+                columns: Vec::new(),
+                query: Box::new(query.clone()),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE OR REPLACE VIEW "Foo" ("a", "b") AS SELECT * FROM "Bar";"#,
+            Statement::CreateView {
+                or_replace: true,
+                name: "Foo".into(),
+                columns: vec!["a".to_owned(), "b".to_owned()],
+                query: Box::new(query),
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_view() {
+        assert_eq!(
+            r#"DROP VIEW "Foo";"#,
+            Statement::DropView {
+                if_exists: false,
+                names: vec!["Foo".into()],
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP VIEW IF EXISTS "Foo", "Bar";"#,
+            Statement::DropView {
+                if_exists: true,
+                names: vec!["Foo".into(), "Bar".into()],
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_sequence() {
+        assert_eq!(
+            r#"CREATE SEQUENCE "Foo";"#,
+            Statement::CreateSequence {
+                if_not_exists: false,
+                name: "Foo".into(),
+                options: SequenceOptions::default(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"CREATE SEQUENCE IF NOT EXISTS "Foo" INCREMENT BY -1 START WITH 100;"#,
+            Statement::CreateSequence {
+                if_not_exists: true,
+                name: "Foo".into(),
+                options: SequenceOptions {
+                    increment: Some(-1),
+                    start: Some(100),
+                },
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_sequence() {
+        assert_eq!(
+            r#"DROP SEQUENCE "Foo";"#,
+            Statement::DropSequence {
+                if_exists: false,
+                names: vec!["Foo".into()],
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP SEQUENCE IF EXISTS "Foo", "Bar";"#,
+            Statement::DropSequence {
+                if_exists: true,
+                names: vec!["Foo".into(), "Bar".into()],
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_create_trigger() {
+        assert_eq!(
+            r#"CREATE TRIGGER "Audit" AFTER INSERT OR UPDATE ON "Foo" FOR EACH ROW WHEN ("NEW"."id" > 1) BEGIN DELETE FROM "Bar"; DELETE FROM "Baz"; END;"#,
+            Statement::CreateTrigger {
+                table_name: "Foo".into(),
+                trigger: Trigger {
+                    name: "Audit".into(),
+                    timing: TriggerTiming::After,
+                    events: vec![TriggerEvent::Insert, TriggerEvent::Update],
+                    condition: Some(Expr::BinaryOp {
+                        left: Box::new(Expr::CompoundIdentifier {
+                            alias: "NEW".into(),
+                            ident: "id".into(),
+                        }),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::Literal(AstLiteral::Number(
+                            BigDecimal::from_str("1").unwrap()
+                        ))),
+                    }),
+                    body: vec![
+                        Statement::Delete {
+                            table_name: "Bar".into(),
+                            using: None,
+                            selection: None,
+                            returning: Vec::new(),
+                        },
+                        Statement::Delete {
+                            table_name: "Baz".into(),
+                            using: None,
+                            selection: None,
+                            returning: Vec::new(),
+                        },
+                    ],
+                },
+            }
+            .to_sql()
+        );
+    }
+
+    #[test]
+    fn to_sql_drop_trigger() {
+        assert_eq!(
+            r#"DROP TRIGGER "Audit" ON "Foo";"#,
+            Statement::DropTrigger {
+                if_exists: false,
+                name: "Audit".into(),
+                table_name: "Foo".into(),
+            }
+            .to_sql()
+        );
+
+        assert_eq!(
+            r#"DROP TRIGGER IF EXISTS "Audit" ON "Foo";"#,
+            Statement::DropTrigger {
+                if_exists: true,
+                name: "Audit".into(),
+                table_name: "Foo".into(),
+            }
+            .to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_transaction() {
         assert_eq!("START TRANSACTION;", Statement::StartTransaction.to_sql());
@@ -880,6 +1684,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn to_sql_explain() {
+        assert_eq!(
+            r#"EXPLAIN ANALYZE SHOW INDEXES FROM "Test";"#,
+            Statement::Explain {
+                analyze: true,
+                statement: Box::new(Statement::ShowIndexes("Test".into())),
+            }
+            .to_sql()
+        );
+    }
+
     #[test]
     fn to_sql_assignment() {
         assert_eq!(
@@ -905,6 +1721,19 @@ mod tests {
                 on_update: ReferentialAction::NoAction,
             }
             .to_sql()
-        )
+        );
+
+        assert_eq!(
+            r#"CONSTRAINT "fk_id" FOREIGN KEY ("id") REFERENCES "Test" ("id") ON DELETE CASCADE ON UPDATE SET NULL"#,
+            ForeignKey {
+                name: "fk_id".into(),
+                referencing_column_name: "id".into(),
+                referenced_table_name: "Test".into(),
+                referenced_column_name: "id".into(),
+                on_delete: ReferentialAction::Cascade,
+                on_update: ReferentialAction::SetNull,
+            }
+            .to_sql()
+        );
     }
 }
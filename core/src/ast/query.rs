@@ -8,20 +8,51 @@ use {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Query {
+    pub with: Option<With>,
     pub body: SetExpr,
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Expr>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct With {
+    pub recursive: bool,
+    pub cte_tables: Vec<Cte>,
+}
+
+/// Common table expression, `name (columns) AS (query)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cte {
+    pub alias: TableAlias,
+    pub query: Query,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SetExpr {
     Select(Box<Select>),
     Values(Values),
+    /// UNION, EXCEPT, INTERSECT
+    SetOperation {
+        op: SetOperator,
+        /// ALL keeps duplicated rows
+        all: bool,
+        left: Box<SetExpr>,
+        right: Box<SetExpr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetOperator {
+    Union,
+    Except,
+    Intersect,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Select {
+    pub distinct: bool,
     pub projection: Vec<SelectItem>,
     pub from: TableWithJoins,
     /// WHERE
@@ -49,10 +80,33 @@ pub struct TableWithJoins {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum IndexItem {
     PrimaryKey(Expr),
+    /// Point lookup of a table keyed by several primary key columns, one value per key column
+    /// in the order of the key
+    CompositePrimaryKey(Vec<Expr>),
+    /// Range scan of a table keyed by a single primary key column
+    PrimaryKeyRange {
+        /// `>` or `>=` with the lower bound of the key
+        lower: Option<(IndexOperator, Expr)>,
+        /// `<` or `<=` with the upper bound of the key
+        upper: Option<(IndexOperator, Expr)>,
+        /// Planned from ORDER BY on the key column, descending when `Some(false)`
+        asc: Option<bool>,
+    },
     NonClustered {
         name: String,
         asc: Option<bool>,
+        /// Equality values for the leading expressions of a composite index
+        prefix: Vec<Expr>,
         cmp_expr: Option<(IndexOperator, Expr)>,
+        /// `<` or `<=` with the upper bound when `cmp_expr` is the lower bound of a range
+        upper_cmp_expr: Option<(IndexOperator, Expr)>,
+    },
+    /// Full scan with the conditions and columns pushed down to the storage
+    Scan {
+        /// Conjunctions of the WHERE clause which compare a column with a constant
+        predicates: Vec<Expr>,
+        /// Columns required by the rest of the query, `None` when every column is required
+        columns: Option<Vec<String>>,
     },
 }
 
@@ -114,6 +168,8 @@ pub enum JoinExecutor {
 pub enum JoinOperator {
     Inner(JoinConstraint),
     LeftOuter(JoinConstraint),
+    RightOuter(JoinConstraint),
+    FullOuter(JoinConstraint),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -151,6 +207,7 @@ impl Query {
         };
 
         let Query {
+            with,
             body,
             order_by,
             limit,
@@ -179,16 +236,82 @@ impl Query {
             _ => "".to_owned(),
         };
 
-        let string = [order_by, limit, offset]
+        let with = match with {
+            Some(with) => with.to_sql_with(quoted),
+            None => "".to_owned(),
+        };
+
+        [with, body.to_sql_with(quoted), order_by, limit, offset]
             .iter()
             .filter(|sql| !sql.is_empty())
-            .join(" ");
+            .join(" ")
+    }
+}
+
+impl ToSql for With {
+    fn to_sql(&self) -> String {
+        self.to_sql_with(true)
+    }
+}
+
+impl ToSqlUnquoted for With {
+    fn to_sql_unquoted(&self) -> String {
+        self.to_sql_with(false)
+    }
+}
+
+impl With {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        let With {
+            recursive,
+            cte_tables,
+        } = self;
+
+        let recursive = if *recursive { "RECURSIVE " } else { "" };
+        let cte_tables = cte_tables
+            .iter()
+            .map(|cte| cte.to_sql_with(quoted))
+            .join(", ");
+
+        format!("WITH {recursive}{cte_tables}")
+    }
+}
 
-        if string.is_empty() {
-            body.to_sql_with(quoted)
+impl ToSql for Cte {
+    fn to_sql(&self) -> String {
+        self.to_sql_with(true)
+    }
+}
+
+impl ToSqlUnquoted for Cte {
+    fn to_sql_unquoted(&self) -> String {
+        self.to_sql_with(false)
+    }
+}
+
+impl Cte {
+    fn to_sql_with(&self, quoted: bool) -> String {
+        let Cte {
+            alias: TableAlias { name, columns },
+            query,
+        } = self;
+
+        let quote = |ident: &String| match quoted {
+            true => format!(r#""{ident}""#),
+            false => ident.to_owned(),
+        };
+
+        let columns = if columns.is_empty() {
+            "".to_owned()
         } else {
-            format!("{} {}", body.to_sql_with(quoted), string)
-        }
+            format!("({})", columns.iter().map(quote).join(", "))
+        };
+
+        format!(
+            "{}{columns} AS ({})",
+            quote(name),
+            query.to_sql_with(quoted)
+        )
     }
 }
 
@@ -211,6 +334,35 @@ impl SetExpr {
             (SetExpr::Select(select), false) => select.to_sql_unquoted(),
             (SetExpr::Values(values), true) => format!("VALUES {}", values.to_sql()),
             (SetExpr::Values(values), false) => format!("VALUES {}", values.to_sql_unquoted()),
+            (
+                SetExpr::SetOperation {
+                    op,
+                    all,
+                    left,
+                    right,
+                },
+                _,
+            ) => {
+                let left_sql = left.to_sql_with(quoted);
+                let left = match left.as_ref() {
+                    SetExpr::SetOperation { op: left_op, .. }
+                        if *op == SetOperator::Intersect && *left_op != SetOperator::Intersect =>
+                    {
+                        format!("({left_sql})")
+                    }
+                    _ => left_sql,
+                };
+
+                let right_sql = right.to_sql_with(quoted);
+                let right = match right.as_ref() {
+                    SetExpr::SetOperation { .. } => format!("({right_sql})"),
+                    _ => right_sql,
+                };
+
+                let all = if *all { " ALL" } else { "" };
+
+                format!("{left} {op}{all} {right}")
+            }
         }
     }
 }
@@ -235,6 +387,7 @@ impl Select {
         };
 
         let Select {
+            distinct,
             projection,
             from,
             selection,
@@ -245,6 +398,10 @@ impl Select {
             .iter()
             .map(|item| item.to_sql_with(quoted))
             .join(", ");
+        let projection = match distinct {
+            true => format!("DISTINCT {projection}"),
+            false => projection,
+        };
 
         let selection = match selection {
             Some(expr) => format!("WHERE {}", to_sql(expr)),
@@ -437,6 +594,8 @@ impl Join {
         let (join_operator, join_constraint) = match join_operator {
             JoinOperator::Inner(join_constraint) => ("INNER JOIN", join_constraint),
             JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER JOIN", join_constraint),
+            JoinOperator::RightOuter(join_constraint) => ("RIGHT OUTER JOIN", join_constraint),
+            JoinOperator::FullOuter(join_constraint) => ("FULL OUTER JOIN", join_constraint),
         };
 
         let (join_constraint, join_executor) = match quoted {
@@ -587,9 +746,10 @@ mod tests {
     use {
         crate::{
             ast::{
-                AstLiteral, BinaryOperator, Dictionary, Expr, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr, TableAlias,
-                TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                AstLiteral, BinaryOperator, Cte, Dictionary, Expr, Join, JoinConstraint,
+                JoinExecutor, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr,
+                SetOperator, TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted, Values,
+                With,
             },
             parse_sql::parse_expr,
             translate::translate_expr,
@@ -613,7 +773,9 @@ mod tests {
         let actual =
             r#"SELECT * FROM "FOO" AS "F" ORDER BY "name" ASC LIMIT 10 OFFSET 3"#.to_owned();
         let expected = Query {
+            with: None,
             body: SetExpr::Select(Box::new(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -650,7 +812,9 @@ mod tests {
         }];
         let actual = "SELECT * FROM FOO AS F ORDER BY name ASC LIMIT 10 OFFSET 3".to_owned();
         let expected = Query {
+            with: None,
             body: SetExpr::Select(Box::new(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -683,6 +847,7 @@ mod tests {
     fn to_sql_set_expr() {
         let actual = r#"SELECT * FROM "FOO" AS "F" INNER JOIN "PlayerItem""#.to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -731,6 +896,7 @@ mod tests {
     fn to_sql_unquoted_set_expr() {
         let actual = "SELECT * FROM FOO AS F INNER JOIN PlayerItem".to_owned();
         let expected = SetExpr::Select(Box::new(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -789,11 +955,116 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_sql_set_operation() {
+        let values = |n: &str| {
+            Box::new(SetExpr::Values(Values(vec![vec![Expr::Literal(
+                AstLiteral::Number(BigDecimal::from_str(n).unwrap()),
+            )]])))
+        };
+
+        let actual = "VALUES (1) UNION ALL VALUES (2)".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            left: values("1"),
+            right: values("2"),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = "(VALUES (1) UNION VALUES (2)) INTERSECT VALUES (3)".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Intersect,
+            all: false,
+            left: Box::new(SetExpr::SetOperation {
+                op: SetOperator::Union,
+                all: false,
+                left: values("1"),
+                right: values("2"),
+            }),
+            right: values("3"),
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = "VALUES (1) EXCEPT (VALUES (2) EXCEPT ALL VALUES (3))".to_owned();
+        let expected = SetExpr::SetOperation {
+            op: SetOperator::Except,
+            all: false,
+            left: values("1"),
+            right: Box::new(SetExpr::SetOperation {
+                op: SetOperator::Except,
+                all: true,
+                left: values("2"),
+                right: values("3"),
+            }),
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_sql_with() {
+        let cte = |name: &str, columns: Vec<&str>, sql: &str| Cte {
+            alias: TableAlias {
+                name: name.to_owned(),
+                columns: columns.into_iter().map(ToOwned::to_owned).collect(),
+            },
+            query: Query {
+                with: None,
+                body: SetExpr::Values(Values(vec![vec![expr(sql)]])),
+                order_by: Vec::new(),
+                limit: None,
+                offset: None,
+            },
+        };
+        let query = |with| Query {
+            with: Some(with),
+            body: SetExpr::Select(Box::new(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Foo".to_owned(),
+                        alias: None,
+                        index: None,
+                    },
+                    joins: Vec::new(),
+                },
+                selection: None,
+                group_by: Vec::new(),
+                having: None,
+            })),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+
+        let actual = r#"WITH "Foo" AS (VALUES (1)) SELECT * FROM "Foo""#.to_owned();
+        let expected = query(With {
+            recursive: false,
+            cte_tables: vec![cte("Foo", Vec::new(), "1")],
+        })
+        .to_sql();
+        assert_eq!(actual, expected);
+
+        let actual = "WITH RECURSIVE Foo(n) AS (VALUES (1)), Bar AS (VALUES (2)) SELECT * FROM Foo"
+            .to_owned();
+        let expected = query(With {
+            recursive: true,
+            cte_tables: vec![cte("Foo", vec!["n"], "1"), cte("Bar", Vec::new(), "2")],
+        })
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn to_sql_select() {
         let actual =
             r#"SELECT * FROM "FOO" AS "F" GROUP BY "name" HAVING "name" = 'glue'"#.to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -817,8 +1088,31 @@ mod tests {
         .to_sql();
         assert_eq!(actual, expected);
 
+        let actual = r#"SELECT DISTINCT "name" AS "name" FROM "FOO""#.to_owned();
+        let expected = Select {
+            distinct: true,
+            projection: vec![SelectItem::Expr {
+                expr: Expr::Identifier("name".to_owned()),
+                label: "name".to_owned(),
+            }],
+            from: TableWithJoins {
+                relation: TableFactor::Table {
+                    name: "FOO".to_owned(),
+                    alias: None,
+                    index: None,
+                },
+                joins: Vec::new(),
+            },
+            selection: None,
+            group_by: Vec::new(),
+            having: None,
+        }
+        .to_sql();
+        assert_eq!(actual, expected);
+
         let actual = r#"SELECT * FROM "FOO" WHERE "name" = 'glue'"#.to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -844,6 +1138,7 @@ mod tests {
     fn to_sql_unquoted_select() {
         let actual = "SELECT * FROM FOO AS F GROUP BY name HAVING name = 'glue'".to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -869,6 +1164,7 @@ mod tests {
 
         let actual = "SELECT * FROM FOO WHERE name = 'glue'".to_owned();
         let expected = Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -977,7 +1273,9 @@ mod tests {
         let actual = r#"(SELECT * FROM "FOO") AS "F""#;
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -1043,7 +1341,9 @@ mod tests {
         let actual = "(SELECT * FROM FOO) AS F";
         let expected = TableFactor::Derived {
             subquery: Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -1243,6 +1543,38 @@ mod tests {
         .to_sql_unquoted();
         assert_eq!(actual, expected);
 
+        let actual = "RIGHT OUTER JOIN PlayerItem ON PlayerItem.user_id = Player.id";
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::RightOuter(JoinConstraint::On(expr(
+                "PlayerItem.user_id = Player.id",
+            ))),
+            join_executor: JoinExecutor::NestedLoop,
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
+        let actual = "FULL OUTER JOIN PlayerItem ON PlayerItem.user_id = Player.id";
+        let expected = Join {
+            relation: TableFactor::Table {
+                name: "PlayerItem".to_owned(),
+                alias: None,
+                index: None,
+            },
+            join_operator: JoinOperator::FullOuter(JoinConstraint::None),
+            join_executor: JoinExecutor::Hash {
+                key_expr: expr("PlayerItem.user_id"),
+                value_expr: expr("Player.id"),
+                where_clause: None,
+            },
+        }
+        .to_sql_unquoted();
+        assert_eq!(actual, expected);
+
         let actual = "LEFT OUTER JOIN PlayerItem ON PlayerItem.user_id = Player.id";
         let expected = Join {
             relation: TableFactor::Table {
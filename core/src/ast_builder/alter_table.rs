@@ -69,7 +69,7 @@ impl Build for AddColumnNode {
         };
         Ok(Statement::AlterTable {
             name: table_name,
-            operation,
+            operations: vec![operation],
         })
     }
 }
@@ -89,7 +89,7 @@ impl Build for DropColumnNode {
         };
         Ok(Statement::AlterTable {
             name: table_name,
-            operation,
+            operations: vec![operation],
         })
     }
 }
@@ -109,7 +109,7 @@ impl Build for RenameColumnNode {
         };
         Ok(Statement::AlterTable {
             name: table_name,
-            operation,
+            operations: vec![operation],
         })
     }
 }
@@ -127,7 +127,7 @@ impl Build for RenameTableNode {
         };
         Ok(Statement::AlterTable {
             name: old_table_name,
-            operation,
+            operations: vec![operation],
         })
     }
 }
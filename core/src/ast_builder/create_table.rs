@@ -50,9 +50,11 @@ impl Build for CreateTableNode {
             name: table_name,
             if_not_exists: self.if_not_exists,
             columns,
+            primary_key: Vec::new(),
             source: None,
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
             comment: None,
         })
     }
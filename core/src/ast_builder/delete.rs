@@ -34,7 +34,9 @@ impl<'a> Build for DeleteNode<'a> {
 
         Ok(Statement::Delete {
             table_name,
+            using: None,
             selection,
+            returning: Vec::new(),
         })
     }
 }
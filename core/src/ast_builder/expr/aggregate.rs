@@ -10,13 +10,34 @@ use {
 
 #[derive(Clone, Debug)]
 pub enum AggregateNode<'a> {
-    Count(CountArgExprNode<'a>),
-    Sum(ExprNode<'a>),
-    Min(ExprNode<'a>),
-    Max(ExprNode<'a>),
-    Avg(ExprNode<'a>),
-    Variance(ExprNode<'a>),
-    Stdev(ExprNode<'a>),
+    Count {
+        expr: CountArgExprNode<'a>,
+        distinct: bool,
+    },
+    Sum {
+        expr: ExprNode<'a>,
+        distinct: bool,
+    },
+    Min {
+        expr: ExprNode<'a>,
+        distinct: bool,
+    },
+    Max {
+        expr: ExprNode<'a>,
+        distinct: bool,
+    },
+    Avg {
+        expr: ExprNode<'a>,
+        distinct: bool,
+    },
+    Variance {
+        expr: ExprNode<'a>,
+        distinct: bool,
+    },
+    Stdev {
+        expr: ExprNode<'a>,
+        distinct: bool,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -58,15 +79,27 @@ impl<'a> TryFrom<AggregateNode<'a>> for Aggregate {
 
     fn try_from(aggr_node: AggregateNode<'a>) -> Result<Self> {
         match aggr_node {
-            AggregateNode::Count(count_arg_expr_node) => {
-                count_arg_expr_node.try_into().map(Aggregate::Count)
-            }
-            AggregateNode::Sum(expr_node) => expr_node.try_into().map(Aggregate::Sum),
-            AggregateNode::Min(expr_node) => expr_node.try_into().map(Aggregate::Min),
-            AggregateNode::Max(expr_node) => expr_node.try_into().map(Aggregate::Max),
-            AggregateNode::Avg(expr_node) => expr_node.try_into().map(Aggregate::Avg),
-            AggregateNode::Variance(expr_node) => expr_node.try_into().map(Aggregate::Variance),
-            AggregateNode::Stdev(expr_node) => expr_node.try_into().map(Aggregate::Stdev),
+            AggregateNode::Count { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Count { expr, distinct }),
+            AggregateNode::Sum { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Sum { expr, distinct }),
+            AggregateNode::Min { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Min { expr, distinct }),
+            AggregateNode::Max { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Max { expr, distinct }),
+            AggregateNode::Avg { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Avg { expr, distinct }),
+            AggregateNode::Variance { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Variance { expr, distinct }),
+            AggregateNode::Stdev { expr, distinct } => expr
+                .try_into()
+                .map(|expr| Aggregate::Stdev { expr, distinct }),
         }
     }
 }
@@ -76,62 +109,163 @@ impl<'a> ExprNode<'a> {
         count(self)
     }
 
+    pub fn count_distinct(self) -> Self {
+        count_distinct(self)
+    }
+
     pub fn sum(self) -> Self {
         sum(self)
     }
 
+    pub fn sum_distinct(self) -> Self {
+        sum_distinct(self)
+    }
+
     pub fn min(self) -> Self {
         min(self)
     }
 
+    pub fn min_distinct(self) -> Self {
+        min_distinct(self)
+    }
+
     pub fn max(self) -> Self {
         max(self)
     }
 
+    pub fn max_distinct(self) -> Self {
+        max_distinct(self)
+    }
+
     pub fn avg(self) -> Self {
         avg(self)
     }
 
+    pub fn avg_distinct(self) -> Self {
+        avg_distinct(self)
+    }
+
     pub fn variance(self) -> Self {
         variance(self)
     }
 
+    pub fn variance_distinct(self) -> Self {
+        variance_distinct(self)
+    }
+
     pub fn stdev(self) -> Self {
         stdev(self)
     }
+
+    pub fn stdev_distinct(self) -> Self {
+        stdev_distinct(self)
+    }
 }
 
 pub fn count<'a, T: Into<CountArgExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Count(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Count {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn count_distinct<'a, T: Into<CountArgExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Count {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 pub fn sum<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Sum(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Sum {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn sum_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Sum {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 pub fn min<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Min(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Min {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn min_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Min {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 pub fn max<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Max(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Max {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn max_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Max {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 pub fn avg<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Avg(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Avg {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn avg_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Avg {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 pub fn variance<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Variance(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Variance {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn variance_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Variance {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 pub fn stdev<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
-    ExprNode::Aggregate(Box::new(AggregateNode::Stdev(expr.into())))
+    ExprNode::Aggregate(Box::new(AggregateNode::Stdev {
+        expr: expr.into(),
+        distinct: false,
+    }))
+}
+
+pub fn stdev_distinct<'a, T: Into<ExprNode<'a>>>(expr: T) -> ExprNode<'a> {
+    ExprNode::Aggregate(Box::new(AggregateNode::Stdev {
+        expr: expr.into(),
+        distinct: true,
+    }))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ast_builder::{avg, col, count, max, min, stdev, sum, test_expr, variance};
+    use crate::ast_builder::{
+        avg, avg_distinct, col, count, count_distinct, max, max_distinct, min, min_distinct, stdev,
+        stdev_distinct, sum, sum_distinct, test_expr, variance, variance_distinct,
+    };
 
     #[test]
     fn aggregate() {
@@ -194,4 +328,63 @@ mod tests {
         let expected = "STDEV(scatterplot)";
         test_expr(actual, expected);
     }
+
+    #[test]
+    fn aggregate_distinct() {
+        let actual = col("id").count_distinct();
+        let expected = "COUNT(DISTINCT id)";
+        test_expr(actual, expected);
+
+        let actual = count_distinct("id");
+        let expected = "COUNT(DISTINCT id)";
+        test_expr(actual, expected);
+
+        let actual = col("amount").sum_distinct();
+        let expected = "SUM(DISTINCT amount)";
+        test_expr(actual, expected);
+
+        let actual = sum_distinct("amount");
+        let expected = "SUM(DISTINCT amount)";
+        test_expr(actual, expected);
+
+        let actual = col("budget").min_distinct();
+        let expected = "MIN(DISTINCT budget)";
+        test_expr(actual, expected);
+
+        let actual = min_distinct("budget");
+        let expected = "MIN(DISTINCT budget)";
+        test_expr(actual, expected);
+
+        let actual = col("score").max_distinct();
+        let expected = "MAX(DISTINCT score)";
+        test_expr(actual, expected);
+
+        let actual = max_distinct("score");
+        let expected = "MAX(DISTINCT score)";
+        test_expr(actual, expected);
+
+        let actual = col("grade").avg_distinct();
+        let expected = "AVG(DISTINCT grade)";
+        test_expr(actual, expected);
+
+        let actual = avg_distinct("grade");
+        let expected = "AVG(DISTINCT grade)";
+        test_expr(actual, expected);
+
+        let actual = col("statistic").variance_distinct();
+        let expected = "VARIANCE(DISTINCT statistic)";
+        test_expr(actual, expected);
+
+        let actual = variance_distinct("statistic");
+        let expected = "VARIANCE(DISTINCT statistic)";
+        test_expr(actual, expected);
+
+        let actual = col("scatterplot").stdev_distinct();
+        let expected = "STDEV(DISTINCT scatterplot)";
+        test_expr(actual, expected);
+
+        let actual = stdev_distinct("scatterplot");
+        let expected = "STDEV(DISTINCT scatterplot)";
+        test_expr(actual, expected);
+    }
 }
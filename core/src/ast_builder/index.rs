@@ -1,5 +1,5 @@
 use {
-    super::{Build, OrderByExprNode},
+    super::{Build, OrderByExprList},
     crate::{ast::Statement, result::Result},
 };
 
@@ -7,15 +7,15 @@ use {
 pub struct CreateIndexNode<'a> {
     name: String,
     table_name: String,
-    column: OrderByExprNode<'a>,
+    columns: OrderByExprList<'a>,
 }
 
 impl<'a> CreateIndexNode<'a> {
-    pub fn new(table_name: String, name: String, column: OrderByExprNode<'a>) -> Self {
+    pub fn new(table_name: String, name: String, columns: OrderByExprList<'a>) -> Self {
         Self {
             table_name,
             name,
-            column,
+            columns,
         }
     }
 }
@@ -24,12 +24,12 @@ impl<'a> Build for CreateIndexNode<'a> {
     fn build(self) -> Result<Statement> {
         let table_name = self.table_name;
         let name = self.name;
-        let column = self.column.try_into()?;
+        let columns = self.columns.try_into()?;
 
         Ok(Statement::CreateIndex {
             name,
             table_name,
-            column,
+            columns,
         })
     }
 }
@@ -57,7 +57,7 @@ impl Build for DropIndexNode {
 
 #[cfg(test)]
 mod tests {
-    use crate::ast_builder::{table, test, Build};
+    use crate::ast_builder::{col, table, test, Build};
 
     #[test]
     fn create_index() {
@@ -68,6 +68,24 @@ mod tests {
         let actual = table("Foo").create_index("nameIndex", "name desc").build();
         let expected = "CREATE INDEX nameIndex ON Foo (name Desc)";
         test(actual, expected);
+
+        let actual = table("Foo")
+            .create_index("tenantIndex", "tenant_id, created_at desc")
+            .build();
+        let expected = "CREATE INDEX tenantIndex ON Foo (tenant_id, created_at DESC)";
+        test(actual, expected);
+
+        let actual = table("Foo")
+            .create_index("tenantIndex", vec!["tenant_id", "created_at"])
+            .build();
+        let expected = "CREATE INDEX tenantIndex ON Foo (tenant_id, created_at)";
+        test(actual, expected);
+
+        let actual = table("Foo")
+            .create_index("tenantIndex", col("created_at"))
+            .build();
+        let expected = "CREATE INDEX tenantIndex ON Foo (created_at)";
+        test(actual, expected);
     }
 
     #[test]
@@ -62,10 +62,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: Some(true),
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::Eq,
                 Expr::Literal(AstLiteral::Number(1.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -77,10 +79,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: Some(false),
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::Eq,
                 Expr::Literal(AstLiteral::Number(2.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -89,10 +93,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::Eq,
                 Expr::Literal(AstLiteral::Number(3.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
     }
@@ -59,7 +59,9 @@ impl<'a> Prebuild<IndexItem> for IndexItemNode<'a> {
                 Ok(IndexItem::NonClustered {
                     name,
                     asc,
+                    prefix: Vec::new(),
                     cmp_expr: cmp_expr_result,
+                    upper_cmp_expr: None,
                 })
             }
             IndexItemNode::PrimaryKey(expr) => Ok(IndexItem::PrimaryKey(expr.try_into()?)),
@@ -52,10 +52,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::Gt,
                 Expr::Literal(AstLiteral::Number(1.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -64,10 +66,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::Lt,
                 Expr::Literal(AstLiteral::Number(1.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -76,10 +80,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::GtEq,
                 Expr::Literal(AstLiteral::Number(1.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -88,10 +94,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::LtEq,
                 Expr::Literal(AstLiteral::Number(1.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -100,10 +108,12 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: Some((
                 IndexOperator::Eq,
                 Expr::Literal(AstLiteral::Number(1.into())),
             )),
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
 
@@ -112,7 +122,9 @@ mod tests {
         let expected = IndexItem::NonClustered {
             name: "idx".to_owned(),
             asc: None,
+            prefix: Vec::new(),
             cmp_expr: None,
+            upper_cmp_expr: None,
         };
         assert_eq!(actual, expected);
     }
@@ -29,7 +29,10 @@ mod update;
 
 /// Available aggregate or normal SQL functions
 pub use expr::{
-    aggregate::{avg, count, max, min, stdev, sum, variance, AggregateNode},
+    aggregate::{
+        avg, avg_distinct, count, count_distinct, max, max_distinct, min, min_distinct, stdev,
+        stdev_distinct, sum, sum_distinct, variance, variance_distinct, AggregateNode,
+    },
     function,
 };
 /// Available expression builder functions
@@ -66,6 +69,7 @@ pub use {
     select::{
         select, values, FilterNode, GroupByNode, HashJoinNode, HavingNode, JoinConstraintNode,
         JoinNode, LimitNode, OffsetLimitNode, OffsetNode, OrderByNode, ProjectNode, SelectNode,
+        SetOperationNode,
     },
     select_item::SelectItemNode,
     select_item_list::SelectItemList,
@@ -1,6 +1,6 @@
 use {
     super::{
-        select::{Prebuild, ValuesNode},
+        select::{Prebuild, SetOperationNode, ValuesNode},
         table_factor::TableType,
         ExprList, FilterNode, GroupByNode, HashJoinNode, HavingNode, JoinConstraintNode, JoinNode,
         LimitNode, OffsetLimitNode, OffsetNode, OrderByNode, ProjectNode, SelectNode,
@@ -31,6 +31,7 @@ pub enum QueryNode<'a> {
     FilterNode(FilterNode<'a>),
     ProjectNode(ProjectNode<'a>),
     OrderByNode(OrderByNode<'a>),
+    SetOperationNode(SetOperationNode<'a>),
 }
 
 impl<'a> QueryNode<'a> {
@@ -80,6 +81,8 @@ impl_from_select_nodes!(OffsetNode);
 impl_from_select_nodes!(OffsetLimitNode);
 impl_from_select_nodes!(ProjectNode);
 impl_from_select_nodes!(OrderByNode);
+impl_from_select_nodes!(ValuesNode);
+impl_from_select_nodes!(SetOperationNode);
 
 impl<'a> TryFrom<QueryNode<'a>> for Query {
     type Error = Error;
@@ -96,6 +99,7 @@ impl<'a> TryFrom<QueryNode<'a>> for Query {
                     .collect::<Result<Vec<_>>>()?;
 
                 Ok(Query {
+                    with: None,
                     body: SetExpr::Values(Values(values)),
                     order_by: Vec::new(),
                     limit: None,
@@ -115,6 +119,7 @@ impl<'a> TryFrom<QueryNode<'a>> for Query {
             QueryNode::OffsetLimitNode(node) => node.prebuild(),
             QueryNode::ProjectNode(node) => node.prebuild(),
             QueryNode::OrderByNode(node) => node.prebuild(),
+            QueryNode::SetOperationNode(node) => node.prebuild(),
         }
     }
 }
@@ -174,6 +179,7 @@ mod test {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -189,6 +195,7 @@ mod test {
             };
 
             Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
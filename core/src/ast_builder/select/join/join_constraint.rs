@@ -78,6 +78,32 @@ impl<'a> JoinConstraintNode<'a> {
         )
     }
 
+    pub fn right_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Right)
+    }
+
+    pub fn right_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Right,
+        )
+    }
+
+    pub fn full_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Full)
+    }
+
+    pub fn full_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Full,
+        )
+    }
+
     pub fn project<T: Into<SelectItemList<'a>>>(self, select_items: T) -> ProjectNode<'a> {
         ProjectNode::new(self, select_items)
     }
@@ -125,6 +151,12 @@ impl<'a> Prebuild<Select> for JoinConstraintNode<'a> {
                 JoinOperatorType::Left => {
                     JoinOperator::LeftOuter(JoinConstraint::On(self.expr.try_into()?))
                 }
+                JoinOperatorType::Right => {
+                    JoinOperator::RightOuter(JoinConstraint::On(self.expr.try_into()?))
+                }
+                JoinOperatorType::Full => {
+                    JoinOperator::FullOuter(JoinConstraint::On(self.expr.try_into()?))
+                }
             },
             join_executor,
         });
@@ -184,6 +216,24 @@ mod tests {
         let expected = "SELECT * FROM Foo LEFT OUTER JOIN Bar b ON Foo.id = b.id";
         test(actual, expected);
 
+        // join node -> join constraint node -> build
+        let actual = table("Foo")
+            .select()
+            .right_join("Bar")
+            .on("Foo.id = Bar.id")
+            .build();
+        let expected = "SELECT * FROM Foo RIGHT OUTER JOIN Bar ON Foo.id = Bar.id";
+        test(actual, expected);
+
+        // join node -> join constraint node -> build
+        let actual = table("Foo")
+            .select()
+            .full_join_as("Bar", "b")
+            .on("Foo.id = b.id")
+            .build();
+        let expected = "SELECT * FROM Foo FULL OUTER JOIN Bar b ON Foo.id = b.id";
+        test(actual, expected);
+
         // hash join node -> join constraint node -> build
         let actual = table("Player")
             .select()
@@ -208,6 +258,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -223,6 +274,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
@@ -119,6 +119,32 @@ impl<'a> JoinNode<'a> {
         )
     }
 
+    pub fn right_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Right)
+    }
+
+    pub fn right_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Right,
+        )
+    }
+
+    pub fn full_join(self, table_name: &str) -> JoinNode<'a> {
+        JoinNode::new(self, table_name.to_owned(), None, JoinOperatorType::Full)
+    }
+
+    pub fn full_join_as(self, table_name: &str, alias: &str) -> JoinNode<'a> {
+        JoinNode::new(
+            self,
+            table_name.to_owned(),
+            Some(alias.to_owned()),
+            JoinOperatorType::Full,
+        )
+    }
+
     pub fn hash_executor<T: Into<ExprNode<'a>>, U: Into<ExprNode<'a>>>(
         self,
         key_expr: T,
@@ -366,6 +392,46 @@ mod tests {
         test(actual, expected);
     }
 
+    #[test]
+    fn right_and_full_join() {
+        // select node -> right join node -> join constraint node
+        let actual = table("player")
+            .select()
+            .right_join("item")
+            .on("player.id = item.id")
+            .project(vec!["player.id", "item.id"])
+            .build();
+        let expected = "
+            SELECT player.id, item.id
+            FROM player
+            RIGHT JOIN item
+            ON player.id = item.id
+        ";
+        test(actual, expected);
+
+        // select node -> full join node -> join constraint node -> right join node
+        let actual = table("Item")
+            .select()
+            .full_join_as("Player", "p1")
+            .on("p1.id = Item.player_id")
+            .right_join_as("Player", "p2")
+            .on("p2.id = Item.player_id")
+            .filter("p1.id IS NULL")
+            .build();
+        let expected = "
+            SELECT * FROM Item
+            FULL JOIN Player p1 ON p1.id = Item.player_id
+            RIGHT JOIN Player p2 ON p2.id = Item.player_id
+            WHERE p1.id IS NULL;
+        ";
+        test(actual, expected);
+
+        // select node -> full join node -> build
+        let actual = table("Item").select().full_join("Player").build();
+        let expected = "SELECT * FROM Item FULL JOIN Player";
+        test(actual, expected);
+    }
+
     #[test]
     fn join_join() {
         // join - join
@@ -590,6 +656,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -605,6 +672,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
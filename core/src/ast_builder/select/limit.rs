@@ -1,5 +1,5 @@
 use {
-    super::{values::ValuesNode, Prebuild},
+    super::{values::ValuesNode, Prebuild, SetOperationNode},
     crate::{
         ast::Query,
         ast_builder::{
@@ -22,6 +22,7 @@ pub enum PrevNode<'a> {
     Filter(FilterNode<'a>),
     OrderBy(OrderByNode<'a>),
     ProjectNode(Box<ProjectNode<'a>>),
+    SetOperation(Box<SetOperationNode<'a>>),
 }
 
 impl<'a> Prebuild<Query> for PrevNode<'a> {
@@ -37,6 +38,7 @@ impl<'a> Prebuild<Query> for PrevNode<'a> {
             Self::Filter(node) => node.prebuild(),
             Self::OrderBy(node) => node.prebuild(),
             Self::ProjectNode(node) => node.prebuild(),
+            Self::SetOperation(node) => node.prebuild(),
         }
     }
 }
@@ -53,6 +55,12 @@ impl<'a> From<ValuesNode<'a>> for PrevNode<'a> {
     }
 }
 
+impl<'a> From<SetOperationNode<'a>> for PrevNode<'a> {
+    fn from(node: SetOperationNode<'a>) -> Self {
+        PrevNode::SetOperation(Box::new(node))
+    }
+}
+
 impl<'a> From<GroupByNode<'a>> for PrevNode<'a> {
     fn from(node: GroupByNode<'a>) -> Self {
         PrevNode::GroupBy(node)
@@ -254,6 +262,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("*").try_into().unwrap(),
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -269,6 +278,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: Some(num(100).try_into().unwrap()),
@@ -8,6 +8,7 @@ mod offset_limit;
 mod order_by;
 mod project;
 mod root;
+mod set_operation;
 mod values;
 
 use {
@@ -28,6 +29,7 @@ pub use {
     order_by::OrderByNode,
     project::ProjectNode,
     root::{select, SelectNode},
+    set_operation::SetOperationNode,
     values::{values, ValuesNode},
 };
 
@@ -40,6 +42,7 @@ impl<T: Prebuild<Select>> Prebuild<Query> for T {
         let select = self.prebuild()?;
         let body = SetExpr::Select(Box::new(select));
         let query = Query {
+            with: None,
             body,
             order_by: Vec::new(),
             limit: None,
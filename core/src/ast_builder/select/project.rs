@@ -1,7 +1,7 @@
 use {
-    super::Prebuild,
+    super::{Prebuild, SetOperationNode},
     crate::{
-        ast::Select,
+        ast::{Select, SetOperator},
         ast_builder::{
             ExprNode, FilterNode, GroupByNode, HashJoinNode, HavingNode, JoinConstraintNode,
             JoinNode, LimitNode, OffsetNode, OrderByExprList, OrderByNode, QueryNode,
@@ -82,6 +82,7 @@ impl<'a> From<FilterNode<'a>> for PrevNode<'a> {
 pub struct ProjectNode<'a> {
     prev_node: PrevNode<'a>,
     select_items_list: Vec<SelectItemList<'a>>,
+    distinct: bool,
 }
 
 impl<'a> ProjectNode<'a> {
@@ -92,9 +93,16 @@ impl<'a> ProjectNode<'a> {
         Self {
             prev_node: prev_node.into(),
             select_items_list: vec![select_items.into()],
+            distinct: false,
         }
     }
 
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+
+        self
+    }
+
     pub fn project<T: Into<SelectItemList<'a>>>(mut self, select_items: T) -> Self {
         self.select_items_list.push(select_items.into());
 
@@ -116,11 +124,36 @@ impl<'a> ProjectNode<'a> {
     pub fn limit<T: Into<ExprNode<'a>>>(self, expr: T) -> LimitNode<'a> {
         LimitNode::new(self, expr)
     }
+
+    pub fn union<T: Into<QueryNode<'a>>>(self, query: T) -> SetOperationNode<'a> {
+        SetOperationNode::new(self, SetOperator::Union, false, query)
+    }
+
+    pub fn union_all<T: Into<QueryNode<'a>>>(self, query: T) -> SetOperationNode<'a> {
+        SetOperationNode::new(self, SetOperator::Union, true, query)
+    }
+
+    pub fn intersect<T: Into<QueryNode<'a>>>(self, query: T) -> SetOperationNode<'a> {
+        SetOperationNode::new(self, SetOperator::Intersect, false, query)
+    }
+
+    pub fn intersect_all<T: Into<QueryNode<'a>>>(self, query: T) -> SetOperationNode<'a> {
+        SetOperationNode::new(self, SetOperator::Intersect, true, query)
+    }
+
+    pub fn except<T: Into<QueryNode<'a>>>(self, query: T) -> SetOperationNode<'a> {
+        SetOperationNode::new(self, SetOperator::Except, false, query)
+    }
+
+    pub fn except_all<T: Into<QueryNode<'a>>>(self, query: T) -> SetOperationNode<'a> {
+        SetOperationNode::new(self, SetOperator::Except, true, query)
+    }
 }
 
 impl<'a> Prebuild<Select> for ProjectNode<'a> {
     fn prebuild(self) -> Result<Select> {
         let mut query: Select = self.prev_node.prebuild()?;
+        query.distinct = self.distinct;
         query.projection = self
             .select_items_list
             .into_iter()
@@ -185,6 +218,22 @@ mod tests {
             .build();
         let expected = "SELECT 1 + 1 as col1, col2 FROM Aliased";
         test(actual, expected);
+
+        // project node -> distinct -> build
+        let actual = table("Foo").select().project("city").distinct().build();
+        let expected = "SELECT DISTINCT city FROM Foo";
+        test(actual, expected);
+
+        // project node -> distinct -> project node -> order by node -> build
+        let actual = table("Foo")
+            .select()
+            .project("city")
+            .distinct()
+            .project("name")
+            .order_by("city")
+            .build();
+        let expected = "SELECT DISTINCT city, name FROM Foo ORDER BY city";
+        test(actual, expected);
     }
 
     #[test]
@@ -248,6 +297,7 @@ mod tests {
                 },
             };
             let select = Select {
+                distinct: false,
                 projection: SelectItemList::from("Player.name, PlayerItem.name")
                     .try_into()
                     .unwrap(),
@@ -265,6 +315,7 @@ mod tests {
             };
 
             Ok(Statement::Query(Query {
+                with: None,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vec::new(),
                 limit: None,
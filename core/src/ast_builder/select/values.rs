@@ -41,6 +41,7 @@ impl<'a> Prebuild<Query> for ValuesNode<'a> {
         let body = SetExpr::Values(Values(values));
 
         Ok(Query {
+            with: None,
             body,
             order_by: Vec::new(),
             limit: None,
@@ -91,7 +91,9 @@ impl<'a> Build for UpdateSetNode<'a> {
         Ok(Statement::Update {
             table_name,
             assignments,
+            from: None,
             selection,
+            returning: Vec::new(),
         })
     }
 }
@@ -50,6 +50,8 @@ pub enum Key {
     Interval(Interval),
     Uuid(u128),
     Inet(IpAddr),
+    /// Tuple of column keys used by composite primary keys
+    List(Vec<Key>),
     None,
 }
 
@@ -78,6 +80,7 @@ impl Ord for Key {
             (Key::Interval(l), Key::Interval(r)) => l.cmp(r),
             (Key::Uuid(l), Key::Uuid(r)) => l.cmp(r),
             (Key::Inet(l), Key::Inet(r)) => l.cmp(r),
+            (Key::List(l), Key::List(r)) => l.cmp(r),
             (Key::None, Key::None) => Ordering::Equal,
             (Key::None, _) => Ordering::Greater,
             (_, Key::None) => Ordering::Less,
@@ -169,6 +172,7 @@ impl From<Key> for Value {
             Key::Time(v) => Value::Time(v),
             Key::Interval(v) => Value::Interval(v),
             Key::Uuid(v) => Value::Uuid(v),
+            Key::List(v) => Value::List(v.into_iter().map(Value::from).collect()),
             Key::None => Value::Null,
         }
     }
@@ -337,10 +341,35 @@ impl Key {
                 .chain(v.to_be_bytes().iter())
                 .copied()
                 .collect::<Vec<_>>(),
+            Key::List(keys) => {
+                let mut bytes = vec![VALUE];
+
+                for key in keys {
+                    for byte in key.to_cmp_be_bytes()? {
+                        match byte {
+                            0x00 => bytes.extend([0x00, 0xFF]),
+                            _ => bytes.push(byte),
+                        }
+                    }
+
+                    bytes.extend([0x00, 0x00]);
+                }
+
+                bytes
+            }
             Key::None => vec![NONE],
         })
     }
 
+    /// Builds the key of a row from the keys of its primary key columns,
+    /// a table with several primary key columns is keyed by their tuple.
+    pub fn from_primary_keys(keys: Vec<Key>) -> Key {
+        match <[Key; 1]>::try_from(keys) {
+            Ok([key]) => key,
+            Err(keys) => Key::List(keys),
+        }
+    }
+
     fn to_order(&self) -> u8 {
         match self {
             Key::I8(_) => 1,
@@ -365,7 +394,8 @@ impl Key {
             Key::Interval(_) => 20,
             Key::Uuid(_) => 21,
             Key::Inet(_) => 22,
-            Key::None => 23,
+            Key::List(_) => 23,
+            Key::None => 24,
         }
     }
 }
@@ -554,6 +584,14 @@ mod tests {
         assert!(Key::Inet(inet("127.0.0.1")) > Key::Inet(inet("0.0.0.1")));
         assert!(Key::Inet(inet("192.168.1.19")) < Key::None);
 
+        assert!(
+            Key::List(vec![Key::I64(1), Key::Str("b".to_owned())])
+                > Key::List(vec![Key::I64(1), Key::Str("a".to_owned())])
+        );
+        assert!(Key::List(vec![Key::I64(1)]) < Key::List(vec![Key::I64(1), Key::I64(0)]));
+        assert!(Key::List(vec![Key::I64(2)]) > Key::List(vec![Key::I64(1), Key::I64(9)]));
+        assert!(Key::List(vec![Key::I64(2)]) < Key::None);
+
         assert_eq!(Key::None.partial_cmp(&Key::None), Some(Ordering::Equal));
         assert!(Key::None > Key::I8(100));
     }
@@ -818,6 +856,17 @@ mod tests {
         assert_eq!(cmp(&n2, &n1), Ordering::Greater);
         assert_eq!(cmp(&n1, &null), Ordering::Less);
 
+        let n1 = List(vec![I64(1), Str("a".to_owned())]).to_cmp_be_bytes();
+        let n2 = List(vec![I64(1), Str("ab".to_owned())]).to_cmp_be_bytes();
+        let n3 = List(vec![I64(2), Str("a".to_owned())]).to_cmp_be_bytes();
+        let n4 = List(vec![I64(1)]).to_cmp_be_bytes();
+
+        assert_eq!(cmp(&n1, &n1), Ordering::Equal);
+        assert_eq!(cmp(&n1, &n2), Ordering::Less);
+        assert_eq!(cmp(&n2, &n3), Ordering::Less);
+        assert_eq!(cmp(&n4, &n1), Ordering::Less);
+        assert_eq!(cmp(&n1, &null), Ordering::Less);
+
         assert_eq!(
             F64(12.34.into()).to_cmp_be_bytes(),
             Err(KeyError::FloatToCmpBigEndianNotSupported.into())
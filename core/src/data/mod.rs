@@ -5,8 +5,10 @@ mod key;
 mod literal;
 mod point;
 mod row;
+mod sequence;
 mod string_ext;
 mod table;
+mod view;
 
 pub mod schema;
 pub mod value;
@@ -19,8 +21,10 @@ pub use {
     literal::{Literal, LiteralError},
     point::Point,
     row::{Row, RowError},
-    schema::{Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
+    schema::{primary_key_indexes, Schema, SchemaIndex, SchemaIndexOrd, SchemaParseError},
+    sequence::Sequence,
     string_ext::{StringExt, StringExtError},
     table::{get_alias, get_index, TableError},
     value::{ConvertError, HashMapJsonExt, NumericBinaryOperator, Value, ValueError},
+    view::View,
 };
@@ -1,6 +1,9 @@
 use {
     crate::{
-        ast::{ColumnDef, Expr, ForeignKey, OrderByExpr, Statement, ToSql},
+        ast::{
+            Check, ColumnDef, ColumnUniqueOption, Expr, ForeignKey, Function, OrderByExpr,
+            Statement, ToSql, Trigger,
+        },
         prelude::{parse, translate},
         result::Result,
     },
@@ -22,18 +25,73 @@ pub enum SchemaIndexOrd {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SchemaIndex {
     pub name: String,
-    pub expr: Expr,
+    pub exprs: Vec<Expr>,
     pub order: SchemaIndexOrd,
     pub created: NaiveDateTime,
 }
 
+impl SchemaIndex {
+    /// Checks whether any of the index expressions refers to `column_name`.
+    pub fn contains_column(&self, column_name: &str) -> bool {
+        self.exprs.iter().any(|expr| find_column(expr, column_name))
+    }
+
+    /// Renames the column in the index expressions, index data keyed by them stays valid.
+    pub fn rename_column(&mut self, old_column_name: &str, new_column_name: &str) {
+        for expr in self.exprs.iter_mut() {
+            rename_column(expr, old_column_name, new_column_name);
+        }
+    }
+}
+
+fn find_column(expr: &Expr, column_name: &str) -> bool {
+    let find = |expr| find_column(expr, column_name);
+
+    match expr {
+        Expr::Identifier(ident) => ident == column_name,
+        Expr::Nested(expr) => find(expr),
+        Expr::BinaryOp { left, right, .. } => find(left) || find(right),
+        Expr::UnaryOp { expr, .. } => find(expr),
+        Expr::Function(func) => match func.as_ref() {
+            Function::Cast { expr, .. } => find(expr),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn rename_column(expr: &mut Expr, old_column_name: &str, new_column_name: &str) {
+    let rename = |expr: &mut Expr| rename_column(expr, old_column_name, new_column_name);
+
+    match expr {
+        Expr::Identifier(ident) if *ident == old_column_name => {
+            new_column_name.clone_into(ident);
+        }
+        Expr::Nested(expr) | Expr::UnaryOp { expr, .. } => rename(expr),
+        Expr::BinaryOp { left, right, .. } => {
+            rename(left);
+            rename(right);
+        }
+        Expr::Function(func) => {
+            if let Function::Cast { expr, .. } = func.as_mut() {
+                rename(expr);
+            }
+        }
+        _ => {}
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Schema {
     pub table_name: String,
     pub column_defs: Option<Vec<ColumnDef>>,
+    /// Columns of a table-level `PRIMARY KEY (..)` constraint in the order of the key
+    pub primary_key: Vec<String>,
     pub indexes: Vec<SchemaIndex>,
     pub engine: Option<String>,
     pub foreign_keys: Vec<ForeignKey>,
+    pub checks: Vec<Check>,
+    pub triggers: Vec<Trigger>,
     pub comment: Option<String>,
 }
 
@@ -42,9 +100,12 @@ impl Schema {
         let Schema {
             table_name,
             column_defs,
+            primary_key,
             indexes,
             engine,
             foreign_keys,
+            checks,
+            triggers,
             comment,
         } = self;
 
@@ -52,21 +113,36 @@ impl Schema {
             if_not_exists: false,
             name: table_name.to_owned(),
             columns: column_defs.to_owned(),
+            primary_key: primary_key.to_owned(),
             engine: engine.to_owned(),
             comment: comment.to_owned(),
             source: None,
             foreign_keys: foreign_keys.to_owned(),
+            checks: checks.to_owned(),
         }
         .to_sql();
 
-        let create_indexes = indexes.iter().map(|SchemaIndex { name, expr, .. }| {
-            let expr = expr.to_sql();
+        let create_indexes = indexes.iter().map(|SchemaIndex { name, exprs, .. }| {
+            let exprs = exprs
+                .iter()
+                .map(ToSql::to_sql)
+                .collect::<Vec<_>>()
+                .join(", ");
 
-            format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({expr});"#)
+            format!(r#"CREATE INDEX "{name}" ON "{table_name}" ({exprs});"#)
+        });
+
+        let create_triggers = triggers.iter().map(|trigger| {
+            Statement::CreateTrigger {
+                table_name: table_name.to_owned(),
+                trigger: trigger.to_owned(),
+            }
+            .to_sql()
         });
 
         iter::once(create_table)
             .chain(create_indexes)
+            .chain(create_triggers)
             .collect::<Vec<_>>()
             .join("\n")
     }
@@ -75,34 +151,32 @@ impl Schema {
         let created = Utc::now().naive_utc();
         let statements = parse(ddl)?;
 
-        let indexes = statements
-            .iter()
-            .skip(1)
-            .map(|create_index| {
-                let create_index = translate(create_index)?;
-                match create_index {
-                    Statement::CreateIndex {
+        let mut indexes = Vec::new();
+        let mut triggers = Vec::new();
+        for statement in statements.iter().skip(1) {
+            match translate(statement)? {
+                Statement::CreateIndex { name, columns, .. } => {
+                    let order = columns
+                        .first()
+                        .and_then(|OrderByExpr { asc, .. }| *asc)
+                        .and_then(|bool| bool.then_some(SchemaIndexOrd::Asc))
+                        .unwrap_or(SchemaIndexOrd::Both);
+                    let exprs = columns
+                        .into_iter()
+                        .map(|OrderByExpr { expr, .. }| expr)
+                        .collect();
+
+                    indexes.push(SchemaIndex {
                         name,
-                        column: OrderByExpr { expr, asc },
-                        ..
-                    } => {
-                        let order = asc
-                            .and_then(|bool| bool.then_some(SchemaIndexOrd::Asc))
-                            .unwrap_or(SchemaIndexOrd::Both);
-
-                        let index = SchemaIndex {
-                            name,
-                            expr,
-                            order,
-                            created,
-                        };
-
-                        Ok(index)
-                    }
-                    _ => Err(SchemaParseError::CannotParseDDL.into()),
+                        exprs,
+                        order,
+                        created,
+                    });
                 }
-            })
-            .collect::<Result<Vec<_>>>()?;
+                Statement::CreateTrigger { trigger, .. } => triggers.push(trigger),
+                _ => return Err(SchemaParseError::CannotParseDDL.into()),
+            }
+        }
 
         let create_table = statements.first().ok_or(SchemaParseError::CannotParseDDL)?;
         let create_table = translate(create_table)?;
@@ -111,16 +185,21 @@ impl Schema {
             Statement::CreateTable {
                 name,
                 columns,
+                primary_key,
                 engine,
                 foreign_keys,
+                checks,
                 comment,
                 ..
             } => Ok(Schema {
                 table_name: name,
                 column_defs: columns,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             }),
             _ => Err(SchemaParseError::CannotParseDDL.into()),
@@ -128,6 +207,32 @@ impl Schema {
     }
 }
 
+/// Returns the indexes of the primary key columns in the order of the key.
+///
+/// `primary_key` holds the columns of a table-level `PRIMARY KEY (..)` constraint,
+/// without it the columns declared as `PRIMARY KEY` are taken in the order of `column_defs`.
+pub fn primary_key_indexes(column_defs: &[ColumnDef], primary_key: &[String]) -> Vec<usize> {
+    if primary_key.is_empty() {
+        return column_defs
+            .iter()
+            .enumerate()
+            .filter(|(_, ColumnDef { unique, .. })| {
+                unique == &Some(ColumnUniqueOption { is_primary: true })
+            })
+            .map(|(i, _)| i)
+            .collect();
+    }
+
+    primary_key
+        .iter()
+        .filter_map(|column_name| {
+            column_defs
+                .iter()
+                .position(|ColumnDef { name, .. }| name == column_name)
+        })
+        .collect()
+}
+
 #[derive(ThisError, Debug, PartialEq, Serialize)]
 pub enum SchemaParseError {
     #[error("cannot parse ddl")]
@@ -139,10 +244,13 @@ mod tests {
     use {
         super::SchemaParseError,
         crate::{
-            ast::{AstLiteral, ColumnDef, ColumnUniqueOption, Expr},
+            ast::{
+                AstLiteral, BinaryOperator, Check, ColumnDef, ColumnUniqueOption, Expr, Statement,
+                Trigger, TriggerEvent, TriggerTiming,
+            },
             chrono::Utc,
-            data::{Schema, SchemaIndex, SchemaIndexOrd},
-            prelude::DataType,
+            data::{primary_key_indexes, Schema, SchemaIndex, SchemaIndexOrd},
+            prelude::{parse, translate, DataType},
         },
     };
 
@@ -150,25 +258,34 @@ mod tests {
         let Schema {
             table_name,
             column_defs,
+            primary_key,
             indexes,
             engine,
             foreign_keys,
+            checks,
+            triggers,
             comment,
         } = actual;
 
         let Schema {
             table_name: table_name_e,
             column_defs: column_defs_e,
+            primary_key: primary_key_e,
             indexes: indexes_e,
             engine: engine_e,
             foreign_keys: foreign_keys_e,
+            checks: checks_e,
+            triggers: triggers_e,
             comment: comment_e,
         } = expected;
 
         assert_eq!(table_name, table_name_e);
         assert_eq!(column_defs, column_defs_e);
+        assert_eq!(primary_key, primary_key_e);
         assert_eq!(engine, engine_e);
         assert_eq!(foreign_keys, foreign_keys_e);
+        assert_eq!(checks, checks_e);
+        assert_eq!(triggers, triggers_e);
         assert_eq!(comment, comment_e);
         indexes
             .into_iter()
@@ -178,17 +295,17 @@ mod tests {
 
     fn assert_index(actual: SchemaIndex, expected: SchemaIndex) {
         let SchemaIndex {
-            name, expr, order, ..
+            name, exprs, order, ..
         } = actual;
         let SchemaIndex {
             name: name_e,
-            expr: expr_e,
+            exprs: exprs_e,
             order: order_e,
             ..
         } = expected;
 
         assert_eq!(name, name_e);
-        assert_eq!(expr, expr_e);
+        assert_eq!(exprs, exprs_e);
         assert_eq!(order, order_e);
     }
 
@@ -203,6 +320,8 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },
                 ColumnDef {
@@ -211,12 +330,17 @@ mod tests {
                     nullable: true,
                     default: Some(Expr::Literal(AstLiteral::QuotedString("glue".to_owned()))),
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },
             ]),
+            primary_key: Vec::new(),
             indexes: Vec::new(),
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
             comment: None,
         };
 
@@ -229,9 +353,12 @@ mod tests {
         let schema = Schema {
             table_name: "Test".to_owned(),
             column_defs: None,
+            primary_key: Vec::new(),
             indexes: Vec::new(),
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
             comment: None,
         };
         let ddl = r#"CREATE TABLE "Test";"#;
@@ -251,11 +378,16 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: Some(ColumnUniqueOption { is_primary: true }),
+                check: None,
+                identity: None,
                 comment: None,
             }]),
+            primary_key: Vec::new(),
             indexes: Vec::new(),
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
             comment: None,
         };
 
@@ -264,6 +396,122 @@ mod tests {
 
         let actual = Schema::from_ddl(ddl).unwrap();
         assert_schema(actual, schema);
+
+        let column_def = |name: &str| ColumnDef {
+            name: name.to_owned(),
+            data_type: DataType::Int,
+            nullable: false,
+            default: None,
+            unique: Some(ColumnUniqueOption { is_primary: true }),
+            check: None,
+            identity: None,
+            comment: None,
+        };
+        let schema = Schema {
+            table_name: "Pair".to_owned(),
+            column_defs: Some(vec![column_def("a"), column_def("b")]),
+            primary_key: vec!["b".to_owned(), "a".to_owned()],
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
+            comment: None,
+        };
+
+        let ddl =
+            r#"CREATE TABLE "Pair" ("a" INT NOT NULL, "b" INT NOT NULL, PRIMARY KEY ("b", "a"));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
+    #[test]
+    fn primary_key_order() {
+        let column_def = |name: &str, is_primary| ColumnDef {
+            name: name.to_owned(),
+            data_type: DataType::Int,
+            nullable: !is_primary,
+            default: None,
+            unique: is_primary.then_some(ColumnUniqueOption { is_primary: true }),
+            check: None,
+            identity: None,
+            comment: None,
+        };
+        let column_defs = [
+            column_def("a", true),
+            column_def("b", false),
+            column_def("c", true),
+        ];
+
+        assert_eq!(primary_key_indexes(&column_defs, &[]), vec![0, 2]);
+        assert_eq!(
+            primary_key_indexes(&column_defs, &["c".to_owned(), "a".to_owned()]),
+            vec![2, 0]
+        );
+    }
+
+    #[test]
+    fn table_check() {
+        let schema = Schema {
+            table_name: "Item".to_owned(),
+            column_defs: Some(vec![
+                ColumnDef {
+                    name: "price".to_owned(),
+                    data_type: DataType::Int,
+                    nullable: false,
+                    default: None,
+                    unique: None,
+                    check: Some(Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("price".to_owned())),
+                        op: BinaryOperator::Gt,
+                        right: Box::new(Expr::Literal(AstLiteral::Number(0.into()))),
+                    }),
+                    identity: None,
+                    comment: None,
+                },
+                ColumnDef {
+                    name: "status".to_owned(),
+                    data_type: DataType::Text,
+                    nullable: true,
+                    default: None,
+                    unique: None,
+                    check: None,
+                    identity: None,
+                    comment: None,
+                },
+            ]),
+            primary_key: Vec::new(),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: vec![
+                Check {
+                    name: Some("valid_status".to_owned()),
+                    expr: Expr::InList {
+                        expr: Box::new(Expr::Identifier("status".to_owned())),
+                        list: vec![
+                            Expr::Literal(AstLiteral::QuotedString("open".to_owned())),
+                            Expr::Literal(AstLiteral::QuotedString("closed".to_owned())),
+                        ],
+                        negated: false,
+                    },
+                },
+                Check {
+                    name: None,
+                    expr: Expr::IsNotNull(Box::new(Expr::Identifier("status".to_owned()))),
+                },
+            ],
+            triggers: Vec::new(),
+            comment: None,
+        };
+
+        let ddl = r#"CREATE TABLE "Item" ("price" INT NOT NULL CHECK ("price" > 0), "status" TEXT NULL, CONSTRAINT "valid_status" CHECK ("status" IN ('open', 'closed')), CHECK ("status" IS NOT NULL));"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
     }
 
     #[test]
@@ -285,6 +533,8 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },
                 ColumnDef {
@@ -293,30 +543,45 @@ mod tests {
                     nullable: false,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },
             ]),
+            primary_key: Vec::new(),
             indexes: vec![
                 SchemaIndex {
                     name: "User_id".to_owned(),
-                    expr: Expr::Identifier("id".to_owned()),
+                    exprs: vec![Expr::Identifier("id".to_owned())],
                     order: SchemaIndexOrd::Both,
                     created: Utc::now().naive_utc(),
                 },
                 SchemaIndex {
                     name: "User_name".to_owned(),
-                    expr: Expr::Identifier("name".to_owned()),
+                    exprs: vec![Expr::Identifier("name".to_owned())],
+                    order: SchemaIndexOrd::Both,
+                    created: Utc::now().naive_utc(),
+                },
+                SchemaIndex {
+                    name: "User_name_id".to_owned(),
+                    exprs: vec![
+                        Expr::Identifier("name".to_owned()),
+                        Expr::Identifier("id".to_owned()),
+                    ],
                     order: SchemaIndexOrd::Both,
                     created: Utc::now().naive_utc(),
                 },
             ],
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
             comment: None,
         };
         let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);
 CREATE INDEX "User_id" ON "User" ("id");
-CREATE INDEX "User_name" ON "User" ("name");"#;
+CREATE INDEX "User_name" ON "User" ("name");
+CREATE INDEX "User_name_id" ON "User" ("name", "id");"#;
         assert_eq!(schema.to_ddl(), ddl);
 
         let actual = Schema::from_ddl(ddl).unwrap();
@@ -328,6 +593,54 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
         assert_eq!(actual, Err(SchemaParseError::CannotParseDDL.into()));
     }
 
+    #[test]
+    fn table_with_trigger() {
+        let schema = Schema {
+            table_name: "User".to_owned(),
+            column_defs: Some(vec![ColumnDef {
+                name: "id".to_owned(),
+                data_type: DataType::Int,
+                nullable: false,
+                default: None,
+                unique: None,
+                check: None,
+                identity: None,
+                comment: None,
+            }]),
+            primary_key: Vec::new(),
+            indexes: Vec::new(),
+            engine: None,
+            foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: vec![Trigger {
+                name: "Audit".to_owned(),
+                timing: TriggerTiming::After,
+                events: vec![TriggerEvent::Insert, TriggerEvent::Delete],
+                condition: None,
+                body: vec![Statement::Delete {
+                    table_name: "Log".to_owned(),
+                    using: None,
+                    selection: Some(Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("id".to_owned())),
+                        op: BinaryOperator::Eq,
+                        right: Box::new(Expr::CompoundIdentifier {
+                            alias: "OLD".to_owned(),
+                            ident: "id".to_owned(),
+                        }),
+                    }),
+                    returning: Vec::new(),
+                }],
+            }],
+            comment: None,
+        };
+        let ddl = r#"CREATE TABLE "User" ("id" INT NOT NULL);
+CREATE TRIGGER "Audit" AFTER INSERT OR DELETE ON "User" FOR EACH ROW BEGIN DELETE FROM "Log" WHERE "id" = "OLD"."id"; END;"#;
+        assert_eq!(schema.to_ddl(), ddl);
+
+        let actual = Schema::from_ddl(ddl).unwrap();
+        assert_schema(actual, schema);
+    }
+
     #[test]
     fn non_word_identifier() {
         let schema = Schema {
@@ -339,6 +652,8 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     nullable: true,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },
                 ColumnDef {
@@ -347,17 +662,22 @@ CREATE TABLE "User" ("id" INT NOT NULL, "name" TEXT NOT NULL);"#;
                     nullable: true,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 },
             ]),
+            primary_key: Vec::new(),
             indexes: vec![SchemaIndex {
                 name: ".".to_owned(),
-                expr: Expr::Identifier(";".to_owned()),
+                exprs: vec![Expr::Identifier(";".to_owned())],
                 order: SchemaIndexOrd::Both,
                 created: Utc::now().naive_utc(),
             }],
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
             comment: None,
         };
         let ddl = r#"CREATE TABLE "1" ("2" INT NULL, ";" INT NULL);
@@ -367,4 +687,28 @@ CREATE INDEX "." ON "1" (";");"#;
         let actual = Schema::from_ddl(ddl).unwrap();
         assert_schema(actual, schema);
     }
+
+    #[test]
+    fn index_column() {
+        let sql = "CREATE INDEX idx ON Foo (CAST(a AS INT) + -b)";
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let Ok(Statement::CreateIndex { columns, .. }) = translate(&parsed) else {
+            panic!("CREATE INDEX expected: {sql}");
+        };
+        let mut index = SchemaIndex {
+            name: "idx".to_owned(),
+            exprs: columns.into_iter().map(|column| column.expr).collect(),
+            order: SchemaIndexOrd::Both,
+            created: Utc::now().naive_utc(),
+        };
+
+        assert!(index.contains_column("a"));
+        assert!(index.contains_column("b"));
+        assert!(!index.contains_column("c"));
+
+        index.rename_column("b", "c");
+        assert!(!index.contains_column("b"));
+        assert!(index.contains_column("c"));
+        assert!(index.contains_column("a"));
+    }
 }
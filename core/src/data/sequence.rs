@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sequence {
+    pub sequence_name: String,
+    pub start: i64,
+    pub increment: i64,
+    /// The value most recently returned by `NEXTVAL`, `None` until it is called
+    pub current: Option<i64>,
+}
+
+impl Sequence {
+    /// Returns the value following `current`, `None` when it overflows.
+    pub fn next_value(&self) -> Option<i64> {
+        match self.current {
+            Some(current) => current.checked_add(self.increment),
+            None => Some(self.start),
+        }
+    }
+}
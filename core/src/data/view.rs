@@ -0,0 +1,12 @@
+use {
+    crate::ast::Query,
+    serde::{Deserialize, Serialize},
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    pub view_name: String,
+    /// Optional column names which rename the labels of the query
+    pub columns: Vec<String>,
+    pub query: Query,
+}
@@ -19,7 +19,7 @@ use {
     std::{convert::identity, rc::Rc},
 };
 
-pub use error::AggregateError;
+pub use {error::AggregateError, state::AggrValue};
 
 pub struct Aggregator<'a, T: GStore> {
     storage: &'a T,
@@ -173,18 +173,23 @@ impl<'a, T: GStore> Aggregator<'a, T> {
     }
 
     fn check_aggregate(&self) -> bool {
-        if !self.group_by.is_empty() {
-            return true;
-        }
+        check_aggregate(self.fields, self.group_by)
+    }
+}
 
-        self.fields
-            .iter()
-            .map(|field| match field {
-                SelectItem::Expr { expr, .. } => check(expr),
-                _ => false,
-            })
-            .any(identity)
+/// Returns whether the select has `GROUP BY` or aggregate functions in its projection.
+pub fn check_aggregate(fields: &[SelectItem], group_by: &[Expr]) -> bool {
+    if !group_by.is_empty() {
+        return true;
     }
+
+    fields
+        .iter()
+        .map(|field| match field {
+            SelectItem::Expr { expr, .. } => check(expr),
+            _ => false,
+        })
+        .any(identity)
 }
 
 #[async_recursion(?Send)]
@@ -233,6 +238,11 @@ where
                 .await
         }
         Expr::Aggregate(aggr) => state.accumulate(filter_context, aggr.as_ref()).await,
+        Expr::Window(window) => {
+            stream::iter(window.as_exprs().map(Ok))
+                .try_fold(state, aggr)
+                .await
+        }
         _ => Ok(state),
     }
 }
@@ -261,6 +271,7 @@ fn check(expr: &Expr) -> bool {
                     .unwrap_or(false)
         }
         Expr::Aggregate(_) => true,
+        Expr::Window(window) => window.as_exprs().any(check),
         _ => false,
     }
 }
@@ -17,7 +17,8 @@ type Group = Rc<Vec<Key>>;
 type ValuesMap<'a> = HashMap<&'a Aggregate, Value>;
 type Context<'a> = Rc<RowContext<'a>>;
 
-enum AggrValue {
+#[derive(Clone)]
+pub enum AggrValue {
     Count {
         wildcard: bool,
         count: i64,
@@ -42,31 +43,37 @@ enum AggrValue {
 }
 
 impl AggrValue {
-    fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
+    pub fn new(aggr: &Aggregate, value: &Value) -> Result<Self> {
         let value = value.clone();
 
         Ok(match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => AggrValue::Count {
+            Aggregate::Count {
+                expr: CountArgExpr::Wildcard,
+                ..
+            } => AggrValue::Count {
                 wildcard: true,
                 count: 1,
             },
-            Aggregate::Count(CountArgExpr::Expr(_)) => AggrValue::Count {
+            Aggregate::Count {
+                expr: CountArgExpr::Expr(_),
+                ..
+            } => AggrValue::Count {
                 wildcard: false,
                 count: i64::from(!value.is_null()),
             },
-            Aggregate::Sum(_) => AggrValue::Sum(value),
-            Aggregate::Min(_) => AggrValue::Min(value),
-            Aggregate::Max(_) => AggrValue::Max(value),
-            Aggregate::Avg(_) => AggrValue::Avg {
+            Aggregate::Sum { .. } => AggrValue::Sum(value),
+            Aggregate::Min { .. } => AggrValue::Min(value),
+            Aggregate::Max { .. } => AggrValue::Max(value),
+            Aggregate::Avg { .. } => AggrValue::Avg {
                 sum: value,
                 count: 1,
             },
-            Aggregate::Variance(_) => AggrValue::Variance {
+            Aggregate::Variance { .. } => AggrValue::Variance {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
             },
-            Aggregate::Stdev(_) => AggrValue::Stdev {
+            Aggregate::Stdev { .. } => AggrValue::Stdev {
                 sum_square: value.multiply(&value)?,
                 sum: value,
                 count: 1,
@@ -74,7 +81,7 @@ impl AggrValue {
         })
     }
 
-    fn accumulate(&self, new_value: &Value) -> Result<Option<Self>> {
+    pub fn accumulate(&self, new_value: &Value) -> Result<Option<Self>> {
         match self {
             Self::Count { wildcard, count } => {
                 let wildcard = *wildcard;
@@ -122,7 +129,7 @@ impl AggrValue {
         }
     }
 
-    async fn export(self) -> Result<Value> {
+    pub async fn export(self) -> Result<Value> {
         let variance = |sum_square: Value, sum: Value, count: i64| async move {
             let count = Value::I64(count);
             let sum_expr1 = sum_square.multiply(&count)?;
@@ -159,6 +166,8 @@ pub struct State<'a, T: GStore> {
     index: usize,
     group: Group,
     values: IndexMap<(Group, &'a Aggregate), (usize, AggrValue)>,
+    /// Values already accumulated by DISTINCT aggregates
+    distinct_values: HashSet<(Group, &'a Aggregate, Key)>,
     groups: HashSet<Group>,
     contexts: Vector<Rc<RowContext<'a>>>,
 }
@@ -170,6 +179,7 @@ impl<'a, T: GStore> State<'a, T> {
             index: 0,
             group: Rc::new(vec![Key::None]),
             values: IndexMap::new(),
+            distinct_values: HashSet::new(),
             groups: HashSet::new(),
             contexts: Vector::new(),
         }
@@ -246,27 +256,39 @@ impl<'a, T: GStore> State<'a, T> {
         filter_context: Option<Rc<RowContext<'a>>>,
         aggr: &'a Aggregate,
     ) -> Result<State<'a, T>> {
-        let value = match aggr {
-            Aggregate::Count(CountArgExpr::Wildcard) => Value::Null,
-            Aggregate::Count(CountArgExpr::Expr(expr))
-            | Aggregate::Sum(expr)
-            | Aggregate::Min(expr)
-            | Aggregate::Max(expr)
-            | Aggregate::Avg(expr)
-            | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => evaluate(self.storage, filter_context, None, expr)
+        let value = match aggr.as_expr() {
+            None => Value::Null,
+            Some(expr) => evaluate(self.storage, filter_context, None, expr)
                 .await?
                 .try_into()?,
         };
-        let aggr_value = match self.get(aggr) {
-            Some((index, _)) if self.index <= *index => None,
+
+        let state = match aggr.is_distinct() {
+            true => {
+                let key = (Rc::clone(&self.group), aggr, Key::try_from(&value)?);
+
+                if self.distinct_values.contains(&key) {
+                    return Ok(self);
+                }
+
+                let distinct_values = self.distinct_values.update(key);
+
+                Self {
+                    distinct_values,
+                    ..self
+                }
+            }
+            false => self,
+        };
+        let aggr_value = match state.get(aggr) {
+            Some((index, _)) if state.index <= *index => None,
             Some((_, aggr_value)) => aggr_value.accumulate(&value)?,
             None => Some(AggrValue::new(aggr, &value)?),
         };
 
         match aggr_value {
-            Some(aggr_value) => Ok(self.update(aggr, aggr_value)),
-            None => Ok(self),
+            Some(aggr_value) => Ok(state.update(aggr, aggr_value)),
+            None => Ok(state),
         }
     }
 }
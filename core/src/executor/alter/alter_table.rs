@@ -1,14 +1,39 @@
 use {
-    super::{validate, AlterError, Referencing},
+    super::{create_sequence, validate, AlterError, Referencing},
     crate::{
-        ast::{AlterTableOperation, Expr, Function},
-        data::{Schema, SchemaIndex},
+        ast::{
+            AlterColumnOperation, AlterTableOperation, ColumnDef, ColumnUniqueOption, OrderByExpr,
+        },
+        data::{Schema, SchemaIndex, SchemaIndexOrd, Sequence},
+        executor::sequence::identity_sequence_name,
         result::Result,
-        store::{GStore, GStoreMut},
+        store::{AlterTableError, GStore, GStoreMut},
     },
 };
 
+/// Applies the operations in order, operations after `RENAME TO` run against the new table name.
 pub async fn alter_table<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    operations: &[AlterTableOperation],
+) -> Result<()> {
+    let mut table_name = table_name.to_owned();
+
+    for operation in operations {
+        alter_table_operation(storage, &table_name, operation).await?;
+
+        if let AlterTableOperation::RenameTable {
+            table_name: new_table_name,
+        } = operation
+        {
+            new_table_name.clone_into(&mut table_name);
+        }
+    }
+
+    Ok(())
+}
+
+async fn alter_table_operation<T: GStore + GStoreMut>(
     storage: &mut T,
     table_name: &str,
     operation: &AlterTableOperation,
@@ -17,7 +42,11 @@ pub async fn alter_table<T: GStore + GStoreMut>(
         old_column_name: column_name,
         ..
     }
-    | AlterTableOperation::DropColumn { column_name, .. } = operation
+    | AlterTableOperation::DropColumn { column_name, .. }
+    | AlterTableOperation::AlterColumn {
+        column_name,
+        operation: AlterColumnOperation::SetDataType { .. },
+    } = operation
     {
         if let Some(schema) = storage.fetch_schema(table_name).await? {
             let referencing_foreign_key = schema
@@ -51,16 +80,50 @@ pub async fn alter_table<T: GStore + GStoreMut>(
     match operation {
         AlterTableOperation::RenameTable {
             table_name: new_table_name,
-        } => storage.rename_schema(table_name, new_table_name).await,
+        } => {
+            let identity_columns = identity_columns(storage, table_name).await?;
+
+            storage.rename_schema(table_name, new_table_name).await?;
+
+            for column_name in identity_columns {
+                rename_sequence(
+                    storage,
+                    &identity_sequence_name(table_name, &column_name),
+                    &identity_sequence_name(new_table_name, &column_name),
+                )
+                .await?;
+            }
+
+            Ok(())
+        }
         AlterTableOperation::RenameColumn {
             old_column_name,
             new_column_name,
         } => {
+            let identity_columns = identity_columns(storage, table_name).await?;
+
             storage
                 .rename_column(table_name, old_column_name, new_column_name)
-                .await
+                .await?;
+
+            if identity_columns.contains(old_column_name) {
+                rename_sequence(
+                    storage,
+                    &identity_sequence_name(table_name, old_column_name),
+                    &identity_sequence_name(table_name, new_column_name),
+                )
+                .await?;
+            }
+
+            Ok(())
         }
         AlterTableOperation::AddColumn { column_def } => {
+            if column_def.identity.is_some() {
+                return Err(
+                    AlterError::AddIdentityColumnNotSupported(column_def.name.to_owned()).into(),
+                );
+            }
+
             validate(column_def).await?;
 
             storage.add_column(table_name, column_def).await
@@ -69,8 +132,12 @@ pub async fn alter_table<T: GStore + GStoreMut>(
             column_name,
             if_exists,
         } => {
-            let indexes = match storage.fetch_schema(table_name).await? {
-                Some(Schema { indexes, .. }) => indexes,
+            let (indexes, column_defs) = match storage.fetch_schema(table_name).await? {
+                Some(Schema {
+                    indexes,
+                    column_defs,
+                    ..
+                }) => (indexes, column_defs),
                 None => {
                     return Err(AlterError::TableNotFound(table_name.to_owned()).into());
                 }
@@ -78,7 +145,7 @@ pub async fn alter_table<T: GStore + GStoreMut>(
 
             let indexes = indexes
                 .iter()
-                .filter(|SchemaIndex { expr, .. }| find_column(expr, column_name))
+                .filter(|index| index.contains_column(column_name))
                 .map(|SchemaIndex { name, .. }| name);
 
             for index_name in indexes {
@@ -87,23 +154,160 @@ pub async fn alter_table<T: GStore + GStoreMut>(
 
             storage
                 .drop_column(table_name, column_name, *if_exists)
-                .await
+                .await?;
+
+            let identity = column_defs
+                .iter()
+                .flatten()
+                .any(|ColumnDef { name, identity, .. }| name == column_name && identity.is_some());
+            if identity {
+                storage
+                    .delete_sequence(&identity_sequence_name(table_name, column_name))
+                    .await?;
+            }
+
+            Ok(())
         }
+        AlterTableOperation::AlterColumn {
+            column_name,
+            operation,
+        } => alter_column(storage, table_name, column_name, operation).await,
     }
 }
 
-fn find_column(expr: &Expr, column_name: &str) -> bool {
-    let find = |expr| find_column(expr, column_name);
-
-    match expr {
-        Expr::Identifier(ident) => ident == column_name,
-        Expr::Nested(expr) => find(expr),
-        Expr::BinaryOp { left, right, .. } => find(left) || find(right),
-        Expr::UnaryOp { expr, .. } => find(expr),
-        Expr::Function(func) => match func.as_ref() {
-            Function::Cast { expr, .. } => find(expr),
-            _ => false,
-        },
-        _ => false,
+async fn alter_column<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    column_name: &str,
+    operation: &AlterColumnOperation,
+) -> Result<()> {
+    let Schema {
+        column_defs,
+        indexes,
+        ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+
+    let mut column_def = column_defs
+        .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()))?
+        .into_iter()
+        .find(|ColumnDef { name, .. }| name == column_name)
+        .ok_or_else(|| AlterTableError::AlteringColumnNotFound(column_name.to_owned()))?;
+
+    if column_def.unique == Some(ColumnUniqueOption { is_primary: true })
+        && matches!(
+            operation,
+            AlterColumnOperation::SetDataType { .. } | AlterColumnOperation::DropNotNull
+        )
+    {
+        return Err(AlterError::CannotAlterPrimaryKeyColumn(column_name.to_owned()).into());
+    }
+
+    match operation {
+        AlterColumnOperation::SetDataType { data_type } => {
+            column_def.data_type = data_type.clone();
+        }
+        AlterColumnOperation::SetDefault { default } => {
+            column_def.default = Some(default.clone());
+        }
+        AlterColumnOperation::DropDefault => {
+            column_def.default = None;
+        }
+        AlterColumnOperation::SetNotNull => {
+            column_def.nullable = false;
+        }
+        AlterColumnOperation::DropNotNull => {
+            column_def.nullable = true;
+        }
+        AlterColumnOperation::AddIdentity { identity } => {
+            if column_def.identity.is_some() {
+                return Err(AlterError::IdentityColumnAlreadyExists(column_name.to_owned()).into());
+            }
+
+            column_def.nullable = false;
+            column_def.identity = Some(*identity);
+        }
     }
+
+    validate(&column_def).await?;
+
+    // the sequence starts from its START value, the existing values of the column are kept
+    if let AlterColumnOperation::AddIdentity { identity } = operation {
+        create_sequence(
+            storage,
+            &identity_sequence_name(table_name, column_name),
+            &identity.options,
+            false,
+        )
+        .await?;
+    }
+
+    if !matches!(operation, AlterColumnOperation::SetDataType { .. }) {
+        return storage.alter_column(table_name, &column_def).await;
+    }
+
+    // indexes on the column are rebuilt, their keys are computed from the converted values
+    let indexes = indexes
+        .into_iter()
+        .filter(|index| index.contains_column(column_name))
+        .collect::<Vec<_>>();
+
+    for SchemaIndex { name, .. } in &indexes {
+        storage.drop_index(table_name, name).await?;
+    }
+
+    storage.alter_column(table_name, &column_def).await?;
+
+    for SchemaIndex {
+        name, exprs, order, ..
+    } in indexes
+    {
+        let asc = match order {
+            SchemaIndexOrd::Asc => Some(true),
+            SchemaIndexOrd::Desc => Some(false),
+            SchemaIndexOrd::Both => None,
+        };
+        let columns = exprs
+            .into_iter()
+            .map(|expr| OrderByExpr { expr, asc })
+            .collect::<Vec<_>>();
+
+        storage.create_index(table_name, &name, &columns).await?;
+    }
+
+    Ok(())
+}
+
+async fn identity_columns<T: GStore>(storage: &T, table_name: &str) -> Result<Vec<String>> {
+    let column_defs = storage
+        .fetch_schema(table_name)
+        .await?
+        .and_then(|Schema { column_defs, .. }| column_defs)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|ColumnDef { identity, .. }| identity.is_some())
+        .map(|ColumnDef { name, .. }| name)
+        .collect();
+
+    Ok(column_defs)
+}
+
+async fn rename_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_name: &str,
+    new_sequence_name: &str,
+) -> Result<()> {
+    let Some(sequence) = storage.fetch_sequence(sequence_name).await? else {
+        return Ok(());
+    };
+
+    storage.delete_sequence(sequence_name).await?;
+    storage
+        .insert_sequence(Sequence {
+            sequence_name: new_sequence_name.to_owned(),
+            ..sequence
+        })
+        .await
 }
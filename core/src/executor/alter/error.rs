@@ -18,6 +18,33 @@ pub enum AlterError {
     #[error("function does not exist: {0}")]
     FunctionNotFound(String),
 
+    #[error("view already exists: {0}")]
+    ViewAlreadyExists(String),
+
+    #[error("view does not exist: {0}")]
+    ViewNotFound(String),
+
+    #[error("sequence already exists: {0}")]
+    SequenceAlreadyExists(String),
+
+    #[error("sequence does not exist: {0}")]
+    SequenceNotFound(String),
+
+    #[error("INCREMENT of sequence '{0}' must not be zero")]
+    ZeroSequenceIncrement(String),
+
+    #[error("trigger '{trigger_name}' already exists on table '{table_name}'")]
+    TriggerAlreadyExists {
+        table_name: String,
+        trigger_name: String,
+    },
+
+    #[error("trigger '{trigger_name}' does not exist on table '{table_name}'")]
+    TriggerNotFound {
+        table_name: String,
+        trigger_name: String,
+    },
+
     // CREATE INDEX, DROP TABLE
     #[error("table does not exist: {0}")]
     TableNotFound(String),
@@ -29,6 +56,21 @@ pub enum AlterError {
     #[error("column '{0}' of data type '{1:?}' is unsupported for unique constraint")]
     UnsupportedDataTypeForUniqueColumn(String, DataType),
 
+    #[error("column '{0}' of data type '{1:?}' is unsupported for identity column")]
+    UnsupportedDataTypeForIdentityColumn(String, DataType),
+
+    #[error("both default and identity specified for column: {0}")]
+    IdentityColumnWithDefault(String),
+
+    #[error("adding identity column is not supported: {0}")]
+    AddIdentityColumnNotSupported(String),
+
+    #[error("column is already an identity column: {0}")]
+    IdentityColumnAlreadyExists(String),
+
+    #[error("cannot change data type or nullability of primary key column: {0}")]
+    CannotAlterPrimaryKeyColumn(String),
+
     // validate index expr
     #[error("unsupported index expr: {0:#?}")]
     UnsupportedIndexExpr(Expr),
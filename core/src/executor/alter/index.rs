@@ -12,27 +12,28 @@ pub async fn create_index<T: GStore + GStoreMut>(
     storage: &mut T,
     table_name: &str,
     index_name: &str,
-    column: &OrderByExpr,
+    columns: &[OrderByExpr],
 ) -> Result<()> {
-    let expr = &column.expr;
     let Schema { column_defs, .. } = storage
         .fetch_schema(table_name)
         .await?
         .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
-    let columns = column_defs
+    let column_names = column_defs
         .unwrap_or_default()
         .into_iter()
         .map(|ColumnDef { name, .. }| name)
         .collect::<Vec<_>>();
 
-    let (valid, has_ident) = validate_index_expr(&columns, expr);
-    if !valid {
-        return Err(AlterError::UnsupportedIndexExpr(expr.clone()).into());
-    } else if !has_ident {
-        return Err(AlterError::IdentifierNotFound(expr.clone()).into());
+    for OrderByExpr { expr, .. } in columns {
+        let (valid, has_ident) = validate_index_expr(&column_names, expr);
+        if !valid {
+            return Err(AlterError::UnsupportedIndexExpr(expr.clone()).into());
+        } else if !has_ident {
+            return Err(AlterError::IdentifierNotFound(expr.clone()).into());
+        }
     }
 
-    storage.create_index(table_name, index_name, column).await
+    storage.create_index(table_name, index_name, columns).await
 }
 
 fn validate_index_expr(columns: &[String], expr: &Expr) -> (bool, bool) {
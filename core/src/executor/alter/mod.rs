@@ -2,8 +2,11 @@ mod alter_table;
 mod error;
 mod function;
 mod index;
+mod sequence;
 mod table;
+mod trigger;
 mod validate;
+mod view;
 
 use validate::{validate, validate_arg_names, validate_column_names, validate_default_args};
 
@@ -12,5 +15,8 @@ pub use {
     error::AlterError,
     function::{delete_function, insert_function},
     index::create_index,
+    sequence::{create_sequence, drop_sequence},
     table::{create_table, drop_table, CreateTableOptions, Referencing},
+    trigger::{create_trigger, drop_trigger},
+    view::{create_view, drop_view},
 };
@@ -0,0 +1,58 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::SequenceOptions,
+        data::Sequence,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+};
+
+pub async fn create_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_name: &str,
+    options: &SequenceOptions,
+    if_not_exists: bool,
+) -> Result<()> {
+    if storage.fetch_sequence(sequence_name).await?.is_some() {
+        return match if_not_exists {
+            true => Ok(()),
+            false => Err(AlterError::SequenceAlreadyExists(sequence_name.to_owned()).into()),
+        };
+    }
+
+    let SequenceOptions { increment, start } = *options;
+    let increment = increment.unwrap_or(1);
+    if increment == 0 {
+        return Err(AlterError::ZeroSequenceIncrement(sequence_name.to_owned()).into());
+    }
+
+    // a descending sequence starts from -1 by default
+    let start = start.unwrap_or(increment.signum());
+    let sequence = Sequence {
+        sequence_name: sequence_name.to_owned(),
+        start,
+        increment,
+        current: None,
+    };
+
+    storage.insert_sequence(sequence).await
+}
+
+pub async fn drop_sequence<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for sequence_name in sequence_names {
+        let sequence = storage.fetch_sequence(sequence_name).await?;
+
+        if !if_exists {
+            sequence.ok_or_else(|| AlterError::SequenceNotFound(sequence_name.to_owned()))?;
+        }
+
+        storage.delete_sequence(sequence_name).await?;
+    }
+
+    Ok(())
+}
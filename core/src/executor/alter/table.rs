@@ -1,28 +1,35 @@
 use {
-    super::{validate, validate_column_names, AlterError},
+    super::{create_sequence, validate, validate_column_names, AlterError},
     crate::{
         ast::{
-            ColumnDef, ColumnUniqueOption, ForeignKey, Query, SetExpr, TableFactor, ToSql, Values,
+            Check, ColumnDef, ColumnIdentityOption, ColumnUniqueOption, ForeignKey, Query, SetExpr,
+            TableFactor, ToSql, Values,
         },
         data::{Schema, TableError},
-        executor::{evaluate_stateless, select::select},
+        executor::{
+            context::RowContext, evaluate::evaluate_stateless_with, select::select,
+            sequence::identity_sequence_name,
+        },
         prelude::{DataType, Value},
         result::{Error, Result},
         store::{GStore, GStoreMut},
     },
     futures::stream::TryStreamExt,
     serde::Serialize,
-    std::fmt,
+    std::{fmt, rc::Rc},
 };
 
 pub struct CreateTableOptions<'a> {
     pub target_table_name: &'a str,
     pub column_defs: Option<&'a [ColumnDef]>,
+    pub primary_key: &'a Vec<String>,
     pub if_not_exists: bool,
     pub source: &'a Option<Box<Query>>,
     pub engine: &'a Option<String>,
     pub foreign_keys: &'a Vec<ForeignKey>,
+    pub checks: &'a Vec<Check>,
     pub comment: &'a Option<String>,
+    pub context: Option<Rc<RowContext<'a>>>,
 }
 
 pub async fn create_table<T: GStore + GStoreMut>(
@@ -30,25 +37,40 @@ pub async fn create_table<T: GStore + GStoreMut>(
     CreateTableOptions {
         target_table_name,
         column_defs,
+        primary_key,
         if_not_exists,
         source,
         engine,
         foreign_keys,
+        checks,
         comment,
+        context,
     }: CreateTableOptions<'_>,
 ) -> Result<()> {
+    let mut target_primary_key = primary_key.to_owned();
     let target_columns_defs = match source.as_deref() {
-        Some(Query { body, .. }) => match body {
+        Some(Query { body, .. }) => match leftmost_set_expr(body) {
             SetExpr::Select(select_query) => match &select_query.from.relation {
                 TableFactor::Table { name, .. } => {
                     let schema = storage.fetch_schema(name).await?;
                     let Schema {
                         column_defs: source_column_defs,
+                        primary_key: source_primary_key,
                         ..
                     } = schema
                         .ok_or_else(|| AlterError::CtasSourceTableNotFound(name.to_owned()))?;
-
-                    source_column_defs
+                    target_primary_key = source_primary_key;
+
+                    // the copied values are not generated by the new table
+                    source_column_defs.map(|column_defs| {
+                        column_defs
+                            .into_iter()
+                            .map(|column_def| ColumnDef {
+                                identity: None,
+                                ..column_def
+                            })
+                            .collect()
+                    })
                 }
                 TableFactor::Series { .. } => {
                     let column_def = ColumnDef {
@@ -57,6 +79,8 @@ pub async fn create_table<T: GStore + GStoreMut>(
                         nullable: false,
                         default: None,
                         unique: None,
+                        check: None,
+                        identity: None,
                         comment: None,
                     };
 
@@ -76,7 +100,7 @@ pub async fn create_table<T: GStore + GStoreMut>(
                             continue;
                         }
 
-                        column_types[i] = evaluate_stateless(None, expr)
+                        column_types[i] = evaluate_stateless_with(context.clone(), expr)
                             .await
                             .and_then(Value::try_from)
                             .map(|value| value.get_type())?;
@@ -100,12 +124,17 @@ pub async fn create_table<T: GStore + GStoreMut>(
                         nullable: true,
                         default: None,
                         unique: None,
+                        check: None,
+                        identity: None,
                         comment: None,
                     })
                     .collect::<Vec<_>>();
 
                 Some(column_defs)
             }
+            SetExpr::SetOperation { .. } => {
+                return Err(Error::Table(TableError::Unreachable));
+            }
         },
         None if column_defs.is_some() => column_defs.map(<[ColumnDef]>::to_vec),
         None => None,
@@ -140,6 +169,16 @@ pub async fn create_table<T: GStore + GStoreMut>(
             referenced_schema.column_defs
         };
 
+        // a column of a composite primary key does not identify a row by itself
+        let composite_primary_key = column_defs
+            .iter()
+            .flatten()
+            .filter(|ColumnDef { unique, .. }| {
+                unique == &Some(ColumnUniqueOption { is_primary: true })
+            })
+            .count()
+            > 1;
+
         let referenced_column_def = column_defs
             .and_then(|column_defs| {
                 column_defs
@@ -170,7 +209,9 @@ pub async fn create_table<T: GStore + GStoreMut>(
             .into());
         }
 
-        if referenced_column_def.unique != Some(ColumnUniqueOption { is_primary: true }) {
+        if referenced_column_def.unique != Some(ColumnUniqueOption { is_primary: true })
+            || composite_primary_key
+        {
             return Err(AlterError::ReferencingNonPKColumn {
                 referenced_table: referenced_table_name.to_owned(),
                 referenced_column: referenced_column_name.to_owned(),
@@ -179,13 +220,40 @@ pub async fn create_table<T: GStore + GStoreMut>(
         }
     }
 
+    if storage.fetch_view(target_table_name).await?.is_some() {
+        return Err(AlterError::ViewAlreadyExists(target_table_name.to_owned()).into());
+    }
+
     if storage.fetch_schema(target_table_name).await?.is_none() {
+        let identities = target_columns_defs
+            .iter()
+            .flatten()
+            .filter_map(|ColumnDef { name, identity, .. }| {
+                identity.map(|ColumnIdentityOption { options, .. }| {
+                    (identity_sequence_name(target_table_name, name), options)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (sequence_name, _) in &identities {
+            if storage.fetch_sequence(sequence_name).await?.is_some() {
+                return Err(AlterError::SequenceAlreadyExists(sequence_name.to_owned()).into());
+            }
+        }
+
+        for (sequence_name, options) in &identities {
+            create_sequence(storage, sequence_name, options, false).await?;
+        }
+
         let schema = Schema {
             table_name: target_table_name.to_owned(),
             column_defs: target_columns_defs,
+            primary_key: target_primary_key,
             indexes: vec![],
             engine: engine.clone(),
             foreign_keys: foreign_keys.clone(),
+            checks: checks.clone(),
+            triggers: Vec::new(),
             comment: comment.clone(),
         };
 
@@ -196,7 +264,7 @@ pub async fn create_table<T: GStore + GStoreMut>(
 
     match source {
         Some(query) => {
-            let rows = select(storage, query, None)
+            let rows = select(storage, query, context)
                 .await?
                 .map_ok(Into::into)
                 .try_collect()
@@ -211,6 +279,14 @@ pub async fn create_table<T: GStore + GStoreMut>(
     }
 }
 
+/// Column definitions of a set operation follow its leftmost operand.
+fn leftmost_set_expr(set_expr: &SetExpr) -> &SetExpr {
+    match set_expr {
+        SetExpr::SetOperation { left, .. } => leftmost_set_expr(left),
+        _ => set_expr,
+    }
+}
+
 pub async fn drop_table<T: GStore + GStoreMut>(
     storage: &mut T,
     table_names: &[String],
@@ -220,17 +296,15 @@ pub async fn drop_table<T: GStore + GStoreMut>(
     let mut n = 0;
 
     for table_name in table_names {
-        let schema = storage.fetch_schema(table_name).await?;
-
-        match (schema, if_exists) {
+        let schema = match (storage.fetch_schema(table_name).await?, if_exists) {
             (None, true) => {
                 continue;
             }
             (None, false) => {
                 return Err(AlterError::TableNotFound(table_name.to_owned()).into());
             }
-            _ => {}
-        }
+            (Some(schema), _) => schema,
+        };
 
         let referencings = storage.fetch_referencings(table_name).await?;
 
@@ -258,6 +332,17 @@ pub async fn drop_table<T: GStore + GStoreMut>(
         }
         storage.delete_schema(table_name).await?;
 
+        let identity_columns = schema
+            .column_defs
+            .iter()
+            .flatten()
+            .filter(|ColumnDef { identity, .. }| identity.is_some());
+        for ColumnDef { name, .. } in identity_columns {
+            storage
+                .delete_sequence(&identity_sequence_name(table_name, name))
+                .await?;
+        }
+
         n += 1;
     }
 
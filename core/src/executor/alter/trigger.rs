@@ -0,0 +1,62 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::Trigger,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+};
+
+pub async fn create_trigger<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    trigger: &Trigger,
+) -> Result<()> {
+    let schema = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+
+    if schema
+        .triggers
+        .iter()
+        .any(|Trigger { name, .. }| name == &trigger.name)
+    {
+        return Err(AlterError::TriggerAlreadyExists {
+            table_name: table_name.to_owned(),
+            trigger_name: trigger.name.to_owned(),
+        }
+        .into());
+    }
+
+    storage.create_trigger(table_name, trigger).await
+}
+
+pub async fn drop_trigger<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
+    trigger_name: &str,
+    if_exists: bool,
+) -> Result<()> {
+    let schema = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| AlterError::TableNotFound(table_name.to_owned()))?;
+
+    if !schema
+        .triggers
+        .iter()
+        .any(|Trigger { name, .. }| name == trigger_name)
+    {
+        return match if_exists {
+            true => Ok(()),
+            false => Err(AlterError::TriggerNotFound {
+                table_name: table_name.to_owned(),
+                trigger_name: trigger_name.to_owned(),
+            }
+            .into()),
+        };
+    }
+
+    storage.drop_trigger(table_name, trigger_name).await
+}
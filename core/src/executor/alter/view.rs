@@ -0,0 +1,63 @@
+use {
+    super::AlterError,
+    crate::{
+        ast::Query,
+        ast_builder::{table, Build},
+        data::View,
+        plan::expand_views,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    std::collections::HashMap,
+};
+
+pub async fn create_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_name: &str,
+    columns: &[String],
+    query: &Query,
+    or_replace: bool,
+) -> Result<()> {
+    if storage.fetch_schema(view_name).await?.is_some() {
+        return Err(AlterError::TableAlreadyExists(view_name.to_owned()).into());
+    }
+
+    if storage.fetch_view(view_name).await?.is_some() && !or_replace {
+        return Err(AlterError::ViewAlreadyExists(view_name.to_owned()).into());
+    }
+
+    let view = View {
+        view_name: view_name.to_owned(),
+        columns: columns.to_owned(),
+        query: query.to_owned(),
+    };
+
+    let mut views = storage
+        .fetch_all_views()
+        .await?
+        .into_iter()
+        .map(|view| (view.view_name.clone(), view))
+        .collect::<HashMap<_, _>>();
+    views.insert(view_name.to_owned(), view.clone());
+    expand_views(&views, table(view_name).select().build()?)?;
+
+    storage.insert_view(view).await
+}
+
+pub async fn drop_view<T: GStore + GStoreMut>(
+    storage: &mut T,
+    view_names: &[String],
+    if_exists: bool,
+) -> Result<()> {
+    for view_name in view_names {
+        let view = storage.fetch_view(view_name).await?;
+
+        if !if_exists {
+            view.ok_or_else(|| AlterError::ViewNotFound(view_name.to_owned()))?;
+        }
+
+        storage.delete_view(view_name).await?;
+    }
+
+    Ok(())
+}
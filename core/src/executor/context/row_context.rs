@@ -1,8 +1,18 @@
 use {
-    crate::data::{Row, Value},
+    crate::{
+        ast::Window,
+        data::{Row, Value},
+    },
     std::{borrow::Cow, collections::HashMap, fmt::Debug, rc::Rc},
 };
 
+/// Materialized rows of a common table expression
+#[derive(Debug)]
+pub struct CteTable {
+    pub labels: Option<Vec<String>>,
+    pub rows: Vec<Row>,
+}
+
 #[derive(Debug)]
 pub enum RowContext<'a> {
     Data {
@@ -19,6 +29,25 @@ pub enum RowContext<'a> {
         left: Rc<RowContext<'a>>,
         right: Rc<RowContext<'a>>,
     },
+    Cte {
+        name: &'a str,
+        table: Rc<CteTable>,
+        next: Option<Rc<RowContext<'a>>>,
+    },
+    /// Window function results computed for the row in `next`
+    Window {
+        values: HashMap<&'a Window, Value>,
+        next: Rc<RowContext<'a>>,
+    },
+    /// Values bound to the parameters of a prepared statement, `$1` is `params[0]`
+    Params(&'a [Value]),
+    /// Rows of `NEW` and `OLD` in the body of a trigger fired for a row,
+    /// `depth` is the number of triggers the body is nested in
+    Trigger {
+        new: Option<&'a Row>,
+        old: Option<&'a Row>,
+        depth: usize,
+    },
 }
 
 impl<'a> RowContext<'a> {
@@ -34,6 +63,97 @@ impl<'a> RowContext<'a> {
         Self::Bridge { left, right }
     }
 
+    pub fn new_cte(name: &'a str, table: CteTable, next: Option<Rc<RowContext<'a>>>) -> Self {
+        Self::Cte {
+            name,
+            table: Rc::new(table),
+            next,
+        }
+    }
+
+    pub fn new_window(values: HashMap<&'a Window, Value>, next: Rc<RowContext<'a>>) -> Self {
+        Self::Window { values, next }
+    }
+
+    pub fn get_param(&self, index: usize) -> Option<&Value> {
+        match self {
+            Self::Params(params) => index.checked_sub(1).and_then(|i| params.get(i)),
+            Self::Data { next, .. } | Self::Cte { next, .. } => {
+                next.as_ref().and_then(|next| next.get_param(index))
+            }
+            Self::Bridge { left, right } => {
+                left.get_param(index).or_else(|| right.get_param(index))
+            }
+            Self::Window { next, .. } => next.get_param(index),
+            Self::RefVecData { .. } | Self::RefMapData(_) | Self::Trigger { .. } => None,
+        }
+    }
+
+    /// Row of `NEW` or `OLD` in a trigger, `Some(None)` if the row does not exist for the event.
+    pub fn get_trigger_row(&self, alias: &str) -> Option<Option<&Row>> {
+        match self {
+            Self::Trigger { new, .. } if alias.eq_ignore_ascii_case("NEW") => Some(*new),
+            Self::Trigger { old, .. } if alias.eq_ignore_ascii_case("OLD") => Some(*old),
+            Self::Data { next, .. } | Self::Cte { next, .. } => {
+                next.as_ref().and_then(|next| next.get_trigger_row(alias))
+            }
+            Self::Bridge { left, right } => left
+                .get_trigger_row(alias)
+                .or_else(|| right.get_trigger_row(alias)),
+            Self::Window { next, .. } => next.get_trigger_row(alias),
+            Self::RefVecData { .. }
+            | Self::RefMapData(_)
+            | Self::Params(_)
+            | Self::Trigger { .. } => None,
+        }
+    }
+
+    /// Number of triggers the statement runs in, `0` outside of a trigger body.
+    pub fn trigger_depth(&self) -> usize {
+        match self {
+            Self::Trigger { depth, .. } => *depth,
+            Self::Data { next, .. } | Self::Cte { next, .. } => {
+                next.as_ref().map_or(0, |next| next.trigger_depth())
+            }
+            Self::Bridge { left, right } => left.trigger_depth().max(right.trigger_depth()),
+            Self::Window { next, .. } => next.trigger_depth(),
+            Self::RefVecData { .. } | Self::RefMapData(_) | Self::Params(_) => 0,
+        }
+    }
+
+    pub fn get_window_value(&self, target: &Window) -> Option<&Value> {
+        match self {
+            Self::Window { values, next } => {
+                values.get(target).or_else(|| next.get_window_value(target))
+            }
+            Self::Data { next, .. } | Self::Cte { next, .. } => {
+                next.as_ref().and_then(|next| next.get_window_value(target))
+            }
+            Self::Bridge { left, right } => left
+                .get_window_value(target)
+                .or_else(|| right.get_window_value(target)),
+            Self::RefVecData { .. }
+            | Self::RefMapData(_)
+            | Self::Params(_)
+            | Self::Trigger { .. } => None,
+        }
+    }
+
+    pub fn get_cte(&self, target: &str) -> Option<Rc<CteTable>> {
+        match self {
+            Self::Cte { name, table, .. } if *name == target => Some(Rc::clone(table)),
+            Self::Data { next, .. } | Self::Cte { next, .. } => {
+                next.as_ref().and_then(|next| next.get_cte(target))
+            }
+            Self::Bridge { left, right } => left.get_cte(target).or_else(|| right.get_cte(target)),
+            Self::Window { next, .. } => next.get_cte(target),
+            Self::RefVecData { .. }
+            | Self::RefMapData(_)
+            | Self::Params(_)
+            | Self::Trigger { .. } => None,
+        }
+    }
+
     pub fn get_value(&'a self, target: &str) -> Option<&'a Value> {
         match self {
             Self::Data {
@@ -52,6 +172,9 @@ impl<'a> RowContext<'a> {
                 .position(|column| column == target)
                 .and_then(|index| values.get(index)),
             Self::RefMapData(values) => values.get(target),
+            Self::Cte { next, .. } => next.as_ref().and_then(|next| next.get_value(target)),
+            Self::Window { next, .. } => next.get_value(target),
+            Self::Params(_) | Self::Trigger { .. } => None,
         }
     }
 
@@ -78,6 +201,10 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_value(target_table_alias, target)
                 .or_else(|| right.get_alias_value(target_table_alias, target)),
+            Self::Cte {
+                next: Some(next), ..
+            } => next.get_alias_value(target_table_alias, target),
+            Self::Window { next, .. } => next.get_alias_value(target_table_alias, target),
             _ => None,
         }
     }
@@ -94,6 +221,10 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => left
                 .get_alias_entries(alias)
                 .or_else(|| right.get_alias_entries(alias)),
+            Self::Cte {
+                next: Some(next), ..
+            } => next.get_alias_entries(alias),
+            Self::Window { next, .. } => next.get_alias_entries(alias),
             _ => None,
         }
     }
@@ -115,6 +246,10 @@ impl<'a> RowContext<'a> {
             Self::Bridge { left, right } => {
                 [left.get_all_entries(), right.get_all_entries()].concat()
             }
+            Self::Cte {
+                next: Some(next), ..
+            } => next.get_all_entries(),
+            Self::Window { next, .. } => next.get_all_entries(),
             _ => vec![],
         }
     }
@@ -1,14 +1,26 @@
 use {
     super::{
-        fetch::{fetch, fetch_columns},
-        Payload, Referencing,
+        context::RowContext,
+        fetch::fetch,
+        join::join_targets,
+        referential::Changes,
+        returning::returning,
+        trigger::{fire_action_triggers, fire_triggers, TriggerRow},
+        update::{referential_value, set_referencing_column},
+        FetchError, Payload, Referencing,
     },
     crate::{
-        ast::{BinaryOperator, Expr, ForeignKey, ReferentialAction},
-        result::{Error, Result},
+        ast::{
+            Expr, ForeignKey, ReferentialAction, SelectItem, TableWithJoins, TriggerEvent,
+            TriggerTiming,
+        },
+        data::{Key, Row, Schema},
+        plan::plan_target_join,
+        result::Result,
         store::{GStore, GStoreMut},
     },
-    futures::stream::{StreamExt, TryStreamExt},
+    async_recursion::async_recursion,
+    futures::stream::TryStreamExt,
     serde::Serialize,
     std::rc::Rc,
     thiserror::Error as ThisError,
@@ -26,59 +38,202 @@ pub enum DeleteError {
 pub async fn delete<T: GStore + GStoreMut>(
     storage: &mut T,
     table_name: &str,
+    using: Option<&TableWithJoins>,
     selection: &Option<Expr>,
+    select_items: &[SelectItem],
+    context: Option<Rc<RowContext<'_>>>,
 ) -> Result<Payload> {
-    let columns = fetch_columns(storage, table_name).await?.map(Rc::from);
-    let referencings = storage.fetch_referencings(table_name).await?;
-    let keys = fetch(storage, table_name, columns, selection.as_ref())
+    let Schema {
+        column_defs,
+        triggers,
+        ..
+    } = storage
+        .fetch_schema(table_name)
         .await?
-        .into_stream()
-        .then(|item| async {
-            let (key, row) = item?;
-
-            for Referencing {
-                table_name: referencing_table_name,
-                foreign_key:
-                    ForeignKey {
-                        referencing_column_name,
-                        referenced_column_name,
-                        on_delete,
-                        ..
-                    },
-            } in &referencings
-            {
-                let value = row
-                    .get_value(referenced_column_name)
-                    .ok_or(DeleteError::ValueNotFound(referenced_column_name.clone()))?
-                    .clone();
-
-                let expr = &Expr::BinaryOp {
-                    left: Box::new(Expr::Identifier(referencing_column_name.clone())),
-                    op: BinaryOperator::Eq,
-                    right: Box::new(Expr::try_from(value)?),
-                };
-
-                let columns = Some(Rc::from(Vec::new()));
-                let referencing_rows =
-                    fetch(storage, referencing_table_name, columns, Some(expr)).await?;
-
-                let referencing_row_exists = Box::pin(referencing_rows).next().await.is_some();
-                if referencing_row_exists && on_delete == &ReferentialAction::NoAction {
-                    return Err(DeleteError::ReferencingColumnExists(format!(
-                        "{referencing_table_name}.{referencing_column_name}"
-                    ))
-                    .into());
-                }
+        .ok_or_else(|| FetchError::TableNotFound(table_name.to_owned()))?;
+    let columns = column_defs.map(|column_defs| {
+        column_defs
+            .into_iter()
+            .map(|column_def| column_def.name)
+            .collect::<Rc<[String]>>()
+    });
+    let rows = match using {
+        Some(using) => {
+            let storage = &*storage;
+            let target_join =
+                plan_target_join(storage, table_name, using, selection.as_ref()).await?;
+            let (keys, rows): (Vec<Key>, Vec<Row>) =
+                fetch(storage, table_name, columns, None, None)
+                    .await?
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .unzip();
+            let joined = join_targets(
+                storage,
+                rows.clone(),
+                using,
+                &target_join,
+                context.as_ref().map(Rc::clone),
+            )
+            .await?;
+
+            keys.into_iter()
+                .zip(rows)
+                .zip(joined)
+                .filter_map(|(item, joined)| joined.is_some().then_some(item))
+                .collect::<Vec<_>>()
+        }
+        None => {
+            let context = context.as_ref().map(Rc::clone);
+
+            fetch(storage, table_name, columns, selection.as_ref(), context)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?
+        }
+    };
+
+    let depth = context.as_deref().map_or(0, RowContext::trigger_depth);
+    let mut changes = Changes::default();
+    let rows = changes.delete(table_name, rows);
+    let trigger_rows = match triggers.is_empty() {
+        true => Vec::new(),
+        false => rows
+            .iter()
+            .map(|(_, row)| TriggerRow {
+                old: Some(row),
+                new: None,
+            })
+            .collect(),
+    };
+
+    fire_triggers(
+        storage,
+        &triggers,
+        TriggerTiming::Before,
+        TriggerEvent::Delete,
+        &trigger_rows,
+        depth,
+    )
+    .await?;
+
+    resolve_delete(&*storage, &mut changes, table_name, &rows).await?;
+    let actions = changes.take_actions();
+    fire_action_triggers(storage, TriggerTiming::Before, &actions, depth).await?;
+    changes.apply(storage).await?;
+    fire_action_triggers(storage, TriggerTiming::After, &actions, depth).await?;
+
+    fire_triggers(
+        storage,
+        &triggers,
+        TriggerTiming::After,
+        TriggerEvent::Delete,
+        &trigger_rows,
+        depth,
+    )
+    .await?;
+
+    let num_keys = rows.len();
+    match select_items.is_empty() {
+        true => Ok(Payload::Delete(num_keys)),
+        false => {
+            let rows = rows.into_iter().map(|(_, row)| row).collect();
+
+            returning(storage, table_name, select_items, rows, context).await
+        }
+    }
+}
+
+/// Resolves the `ON DELETE` actions of the rows referencing the deleted `rows`.
+#[async_recursion(?Send)]
+pub async fn resolve_delete<T: GStore>(
+    storage: &T,
+    changes: &mut Changes,
+    table_name: &str,
+    rows: &[(Key, Row)],
+) -> Result<()> {
+    let referencings = storage.fetch_referencings(table_name).await?;
+
+    for Referencing {
+        table_name: referencing_table_name,
+        foreign_key,
+    } in &referencings
+    {
+        let ForeignKey {
+            referencing_column_name,
+            referenced_column_name,
+            on_delete,
+            ..
+        } = foreign_key;
+
+        let mut values = Vec::new();
+        for (_, row) in rows {
+            let value = row
+                .get_value(referenced_column_name)
+                .ok_or_else(|| DeleteError::ValueNotFound(referenced_column_name.clone()))?;
+
+            if !value.is_null() {
+                values.push(value.clone());
             }
+        }
+        if values.is_empty() {
+            continue;
+        }
+
+        let referencing_rows = changes
+            .fetch_rows(
+                storage,
+                referencing_table_name,
+                referencing_column_name,
+                &values,
+            )
+            .await?;
+        if referencing_rows.is_empty() {
+            continue;
+        }
 
-            Ok::<_, Error>(key)
-        })
-        .try_collect::<Vec<_>>()
-        .await?;
-    let num_keys = keys.len();
+        match on_delete {
+            ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                return Err(DeleteError::ReferencingColumnExists(format!(
+                    "{referencing_table_name}.{referencing_column_name}"
+                ))
+                .into());
+            }
+            ReferentialAction::Cascade => {
+                let rows = changes.delete(referencing_table_name, referencing_rows);
+                changes.record_action(
+                    referencing_table_name,
+                    rows.iter().map(|(_, row)| (row.clone(), None)),
+                );
+
+                resolve_delete(storage, changes, referencing_table_name, &rows).await?;
+            }
+            ReferentialAction::SetNull | ReferentialAction::SetDefault => {
+                let value = referential_value(
+                    storage,
+                    changes,
+                    referencing_table_name,
+                    foreign_key,
+                    on_delete,
+                )
+                .await?;
+                let rows = referencing_rows
+                    .into_iter()
+                    .map(|(key, row)| (key, row, value.clone()))
+                    .collect();
+
+                set_referencing_column(
+                    storage,
+                    changes,
+                    referencing_table_name,
+                    referencing_column_name,
+                    rows,
+                )
+                .await?;
+            }
+        }
+    }
 
-    storage
-        .delete_data(table_name, keys)
-        .await
-        .map(|_| Payload::Delete(num_keys))
+    Ok(())
 }
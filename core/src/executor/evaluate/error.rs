@@ -86,6 +86,9 @@ pub enum EvaluateError {
     #[error("unreachable empty aggregate value: {0:?}")]
     UnreachableEmptyAggregateValue(Aggregate),
 
+    #[error("window function is only allowed in SELECT and ORDER BY: {0}")]
+    WindowFunctionNotAllowed(String),
+
     #[error("incompatible bit operation between {0} and {1}")]
     IncompatibleBitOperation(String, String),
 
@@ -164,6 +167,9 @@ pub enum EvaluateError {
 
     #[error("function requires at least one argument: {0}")]
     FunctionRequiresAtLeastOneArgument(String),
+
+    #[error("parameter is not bound: ${0}")]
+    UnboundParameter(usize),
 }
 
 fn error_serialize<S>(error: &chrono::format::ParseError, serializer: S) -> Result<S::Ok, S::Error>
@@ -5,9 +5,14 @@ mod function;
 
 use {
     self::function::BreakCase,
-    super::{context::RowContext, select::select},
+    super::{
+        context::RowContext,
+        select::select,
+        sequence::{current_value, SequenceError},
+        trigger::TriggerError,
+    },
     crate::{
-        ast::{Aggregate, Expr, Function},
+        ast::{Aggregate, Expr, Function, ToSql},
         data::{CustomFunction, Interval, Literal, Row, Value},
         mock::MockStorage,
         result::{Error, Result},
@@ -25,6 +30,8 @@ use {
 
 pub use {error::EvaluateError, evaluated::Evaluated};
 
+pub(crate) use expr::{between, binary_op};
+
 #[async_recursion(?Send)]
 pub async fn evaluate<'a, 'b: 'a, 'c: 'a, T>(
     storage: &'a T,
@@ -42,7 +49,15 @@ pub async fn evaluate_stateless<'a, 'b: 'a>(
     context: Option<RowContext<'b>>,
     expr: &'a Expr,
 ) -> Result<Evaluated<'a>> {
-    let context = context.map(Rc::new);
+    evaluate_stateless_with(context.map(Rc::new), expr).await
+}
+
+/// Same as [`evaluate_stateless`] with a shared context, e.g. the parameters of a prepared
+/// statement.
+pub(crate) async fn evaluate_stateless_with<'a, 'b: 'a>(
+    context: Option<Rc<RowContext<'b>>>,
+    expr: &'a Expr,
+) -> Result<Evaluated<'a>> {
     let storage: Option<&MockStorage> = None;
 
     evaluate_inner(storage, context, None, expr).await
@@ -85,13 +100,20 @@ where
             let context = context
                 .ok_or_else(|| EvaluateError::ContextRequiredForIdentEvaluation(expr.clone()))?;
 
+            // `NEW` and `OLD` of a trigger are looked up after the rows of the statement
             match context.get_alias_value(alias, ident) {
                 Some(value) => Ok(value.clone()),
-                None => Err(EvaluateError::CompoundIdentifierNotFound {
-                    table_alias: alias.to_owned(),
-                    column_name: ident.to_owned(),
-                }
-                .into()),
+                None => match context.get_trigger_row(alias) {
+                    Some(Some(row)) => row.get_value(ident).cloned().ok_or_else(|| {
+                        TriggerError::RowColumnNotFound(format!("{alias}.{ident}")).into()
+                    }),
+                    Some(None) => Ok(Value::Null),
+                    None => Err(EvaluateError::CompoundIdentifierNotFound {
+                        table_alias: alias.to_owned(),
+                        column_name: ident.to_owned(),
+                    }
+                    .into()),
+                },
             }
             .map(Evaluated::Value)
         }
@@ -152,6 +174,13 @@ where
             Some(value) => Ok(Evaluated::Value(value.clone())),
             None => Err(EvaluateError::UnreachableEmptyAggregateValue(*aggr.clone()).into()),
         },
+        Expr::Window(window) => match context
+            .as_ref()
+            .and_then(|context| context.get_window_value(window))
+        {
+            Some(value) => Ok(Evaluated::Value(value.clone())),
+            None => Err(EvaluateError::WindowFunctionNotAllowed(window.to_sql()).into()),
+        },
         Expr::Function(func) => {
             let context = context.as_ref().map(Rc::clone);
             let aggregated = aggregated.as_ref().map(Rc::clone);
@@ -321,6 +350,12 @@ where
                 .map(Value::Interval)
                 .map(Evaluated::Value)
         }
+        Expr::Parameter(index) => context
+            .as_ref()
+            .and_then(|context| context.get_param(*index))
+            .cloned()
+            .map(Evaluated::Value)
+            .ok_or_else(|| EvaluateError::UnboundParameter(*index).into()),
     }
 }
 
@@ -580,6 +615,22 @@ async fn evaluate_function<'a, 'b: 'a, 'c: 'a, T: GStore>(
             f::unwrap(name, expr, selector)
         }
         Function::GenerateUuid() => return Ok(f::generate_uuid()),
+        Function::Nextval(_) | Function::Setval { .. } => {
+            return Err(SequenceError::NextvalNotSupported.into())
+        }
+        Function::Currval(expr) => {
+            let storage = storage.ok_or_else(|| {
+                EvaluateError::UnsupportedStatelessExpr(Expr::Function(Box::new(func.clone())))
+            })?;
+            let sequence_name = match Value::try_from(eval(expr).await?)? {
+                Value::Str(sequence_name) => sequence_name,
+                _ => return Err(EvaluateError::FunctionRequiresStringValue(name).into()),
+            };
+
+            return current_value(storage, &sequence_name)
+                .await
+                .map(|value| Evaluated::Value(Value::I64(value)));
+        }
         Function::Greatest(exprs) => {
             let exprs = stream::iter(exprs).then(eval).try_collect().await?;
             return f::greatest(name, exprs);
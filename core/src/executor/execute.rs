@@ -1,22 +1,33 @@
 use {
     super::{
         alter::{
-            alter_table, create_index, create_table, delete_function, drop_table, insert_function,
+            alter_table, create_index, create_sequence, create_table, create_trigger, create_view,
+            delete_function, drop_sequence, drop_table, drop_trigger, drop_view, insert_function,
             CreateTableOptions,
         },
+        context::RowContext,
         delete::delete,
+        explain::{explain, explain_analyze, ExplainNode},
         fetch::fetch,
         insert::insert,
+        join::join_targets,
+        referential::Changes,
+        returning::returning,
         select::{select, select_with_labels},
-        update::Update,
-        validate::{validate_unique, ColumnValidation},
+        sequence::resolve_query_sequences,
+        stream::select_stream,
+        trigger::{fire_action_triggers, fire_triggers, TriggerRow},
+        update::{resolve_update, Update},
+        validate::{validate_check, validate_unique, ColumnValidation},
     },
     crate::{
         ast::{
             AstLiteral, BinaryOperator, DataType, Dictionary, Expr, Query, SelectItem, SetExpr,
-            Statement, TableAlias, TableFactor, TableWithJoins, Variable,
+            Statement, TableAlias, TableFactor, TableWithJoins, TriggerEvent, TriggerTiming,
+            Variable,
         },
         data::{Key, Row, Schema, Value},
+        plan::plan_target_join,
         result::Result,
         store::{GStore, GStoreMut},
     },
@@ -30,6 +41,9 @@ use {
 pub enum ExecuteError {
     #[error("table not found: {0}")]
     TableNotFound(String),
+
+    #[error("prepared statement requires a single statement, found: {0}")]
+    PrepareRequiresSingleStatement(usize),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -49,10 +63,17 @@ pub enum Payload {
     AlterTable,
     CreateIndex,
     DropIndex,
+    CreateView,
+    DropView,
+    CreateSequence,
+    DropSequence,
+    CreateTrigger,
+    DropTrigger,
     StartTransaction,
     Commit,
     Rollback,
     ShowVariable(PayloadVariable),
+    Explain(ExplainNode),
 }
 
 impl Payload {
@@ -98,16 +119,35 @@ pub enum PayloadVariable {
 pub async fn execute<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
+) -> Result<Payload> {
+    execute_with_context(storage, statement, None).await
+}
+
+/// Executes the prepared `statement`, `$1` is bound to `params[0]` when it is evaluated.
+pub async fn execute_with_params<T: GStore + GStoreMut>(
+    storage: &mut T,
+    statement: &Statement,
+    params: &[Value],
+) -> Result<Payload> {
+    let context = Rc::new(RowContext::Params(params));
+
+    execute_with_context(storage, statement, Some(context)).await
+}
+
+async fn execute_with_context<T: GStore + GStoreMut>(
+    storage: &mut T,
+    statement: &Statement,
+    context: Option<Rc<RowContext<'_>>>,
 ) -> Result<Payload> {
     if matches!(
         statement,
         Statement::StartTransaction | Statement::Rollback | Statement::Commit
     ) {
-        return execute_inner(storage, statement).await;
+        return execute_inner(storage, statement, context).await;
     }
 
     let autocommit = storage.begin(true).await?;
-    let result = execute_inner(storage, statement).await;
+    let result = execute_inner(storage, statement, context).await;
 
     if !autocommit {
         return result;
@@ -123,9 +163,12 @@ pub async fn execute<T: GStore + GStoreMut>(
     }
 }
 
-async fn execute_inner<T: GStore + GStoreMut>(
+/// `context` is the root of every row context evaluated by the statement,
+/// it carries the parameters of a prepared statement.
+pub async fn execute_inner<T: GStore + GStoreMut>(
     storage: &mut T,
     statement: &Statement,
+    context: Option<Rc<RowContext<'_>>>,
 ) -> Result<Payload> {
     match statement {
         //- Modification
@@ -133,20 +176,25 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::CreateTable {
             name,
             columns,
+            primary_key,
             if_not_exists,
             source,
             engine,
             foreign_keys,
+            checks,
             comment,
         } => {
             let options = CreateTableOptions {
                 target_table_name: name,
                 column_defs: columns.as_ref().map(Vec::as_slice),
+                primary_key,
                 if_not_exists: *if_not_exists,
                 source,
                 engine,
                 foreign_keys,
+                checks,
                 comment,
+                context,
             };
 
             create_table(storage, options)
@@ -161,20 +209,57 @@ async fn execute_inner<T: GStore + GStoreMut>(
         } => drop_table(storage, names, *if_exists, *cascade)
             .await
             .map(Payload::DropTable),
-        Statement::AlterTable { name, operation } => alter_table(storage, name, operation)
+        Statement::AlterTable { name, operations } => alter_table(storage, name, operations)
             .await
             .map(|_| Payload::AlterTable),
         Statement::CreateIndex {
             name,
             table_name,
-            column,
-        } => create_index(storage, table_name, name, column)
+            columns,
+        } => create_index(storage, table_name, name, columns)
             .await
             .map(|_| Payload::CreateIndex),
         Statement::DropIndex { name, table_name } => storage
             .drop_index(table_name, name)
             .await
             .map(|_| Payload::DropIndex),
+        //-- Views
+        Statement::CreateView {
+            or_replace,
+            name,
+            columns,
+            query,
+        } => create_view(storage, name, columns, query, *or_replace)
+            .await
+            .map(|_| Payload::CreateView),
+        Statement::DropView { if_exists, names } => drop_view(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropView),
+        //-- Sequences
+        Statement::CreateSequence {
+            if_not_exists,
+            name,
+            options,
+        } => create_sequence(storage, name, options, *if_not_exists)
+            .await
+            .map(|_| Payload::CreateSequence),
+        Statement::DropSequence { if_exists, names } => drop_sequence(storage, names, *if_exists)
+            .await
+            .map(|_| Payload::DropSequence),
+        //-- Triggers
+        Statement::CreateTrigger {
+            table_name,
+            trigger,
+        } => create_trigger(storage, table_name, trigger)
+            .await
+            .map(|_| Payload::CreateTrigger),
+        Statement::DropTrigger {
+            if_exists,
+            name,
+            table_name,
+        } => drop_trigger(storage, table_name, name, *if_exists)
+            .await
+            .map(|_| Payload::DropTrigger),
         //- Transaction
         Statement::StartTransaction => storage
             .begin(false)
@@ -187,17 +272,33 @@ async fn execute_inner<T: GStore + GStoreMut>(
             table_name,
             columns,
             source,
-        } => insert(storage, table_name, columns, source)
+            on_conflict,
+            returning: select_items,
+        } => {
+            insert(
+                storage,
+                table_name,
+                columns,
+                source,
+                on_conflict.as_ref(),
+                select_items,
+                context,
+            )
             .await
-            .map(Payload::Insert),
+        }
         Statement::Update {
             table_name,
-            selection,
             assignments,
+            from,
+            selection,
+            returning: select_items,
         } => {
             let Schema {
                 column_defs,
+                primary_key,
                 foreign_keys,
+                checks,
+                triggers,
                 ..
             } = storage
                 .fetch_schema(table_name)
@@ -215,70 +316,193 @@ async fn execute_inner<T: GStore + GStoreMut>(
                 .map(|assignment| assignment.id.to_owned())
                 .collect();
 
-            let update = Update::new(storage, table_name, assignments, column_defs.as_deref())?;
+            let update = Update::new(
+                storage,
+                table_name,
+                assignments,
+                column_defs.as_deref(),
+                context.as_ref().map(Rc::clone),
+            )?;
 
             let foreign_keys = Rc::new(foreign_keys);
 
-            let rows = fetch(storage, table_name, all_columns, selection.as_ref())
-                .await?
-                .and_then(|item| {
-                    let update = &update;
-                    let (key, row) = item;
+            let fetch_selection = match from {
+                Some(_) => None,
+                None => selection.as_ref(),
+            };
 
-                    let foreign_keys = Rc::clone(&foreign_keys);
-                    async move {
-                        let row = update.apply(row, foreign_keys.as_ref()).await?;
+            let target_join = match from {
+                Some(from) => {
+                    let target_join =
+                        plan_target_join(&*storage, table_name, from, selection.as_ref()).await?;
 
-                        Ok((key, row))
+                    Some((from, target_join))
+                }
+                None => None,
+            };
+
+            let rows = fetch(
+                storage,
+                table_name,
+                all_columns,
+                fetch_selection,
+                context.clone(),
+            )
+            .await?;
+            let rows = match &target_join {
+                Some((from, target_join)) => {
+                    let (keys, rows): (Vec<Key>, Vec<Row>) =
+                        rows.try_collect::<Vec<_>>().await?.into_iter().unzip();
+                    let joined =
+                        join_targets(&*storage, rows.clone(), from, target_join, context.clone())
+                            .await?;
+
+                    let mut updated = Vec::new();
+                    for ((key, row), joined) in keys.into_iter().zip(rows).zip(joined) {
+                        if let Some(joined) = joined {
+                            let new_row = update
+                                .apply_with_context(row.clone(), Some(joined), &foreign_keys)
+                                .await?;
+
+                            updated.push((key, row, new_row));
+                        }
                     }
-                })
-                .try_collect::<Vec<(Key, Row)>>()
-                .await?;
 
-            if let Some(column_defs) = column_defs {
+                    updated
+                }
+                None => {
+                    rows.and_then(|(key, row)| {
+                        let update = &update;
+                        let foreign_keys = Rc::clone(&foreign_keys);
+
+                        async move {
+                            let new_row = update.apply(row.clone(), foreign_keys.as_ref()).await?;
+
+                            Ok((key, row, new_row))
+                        }
+                    })
+                    .try_collect::<Vec<(Key, Row, Row)>>()
+                    .await?
+                }
+            };
+
+            if let Some(column_defs) = column_defs.as_deref() {
                 let column_validation =
-                    ColumnValidation::SpecifiedColumns(&column_defs, columns_to_update);
-                let rows = rows.iter().filter_map(|(_, row)| match row {
+                    ColumnValidation::SpecifiedColumns(column_defs, columns_to_update);
+                let rows = rows.iter().filter_map(|(_, _, row)| match row {
                     Row::Vec { values, .. } => Some(values.as_slice()),
                     Row::Map(_) => None,
                 });
 
+                validate_check(storage, table_name, column_defs, &checks, rows.clone()).await?;
                 validate_unique(storage, table_name, column_validation, rows).await?;
             }
 
             let num_rows = rows.len();
-            let rows = rows
-                .into_iter()
-                .map(|(key, row)| (key, row.into()))
-                .collect();
-
-            storage
-                .insert_data(table_name, rows)
-                .await
-                .map(|_| Payload::Update(num_rows))
+            let returned_rows = match select_items.is_empty() {
+                true => Vec::new(),
+                false => rows.iter().map(|(_, _, row)| row.clone()).collect(),
+            };
+            let changed_rows = match triggers.is_empty() {
+                true => Vec::new(),
+                false => rows
+                    .iter()
+                    .map(|(_, old, new)| (old.clone(), new.clone()))
+                    .collect(),
+            };
+            let depth = context.as_deref().map_or(0, RowContext::trigger_depth);
+            let trigger_rows = changed_rows
+                .iter()
+                .map(|(old, new)| TriggerRow {
+                    old: Some(old),
+                    new: Some(new),
+                })
+                .collect::<Vec<_>>();
+
+            fire_triggers(
+                storage,
+                &triggers,
+                TriggerTiming::Before,
+                TriggerEvent::Update,
+                &trigger_rows,
+                depth,
+            )
+            .await?;
+
+            let mut changes = Changes::default();
+            resolve_update(
+                &*storage,
+                &mut changes,
+                table_name,
+                column_defs.as_deref(),
+                &primary_key,
+                rows,
+            )
+            .await?;
+            let actions = changes.take_actions();
+            fire_action_triggers(storage, TriggerTiming::Before, &actions, depth).await?;
+            changes.apply(storage).await?;
+            fire_action_triggers(storage, TriggerTiming::After, &actions, depth).await?;
+
+            fire_triggers(
+                storage,
+                &triggers,
+                TriggerTiming::After,
+                TriggerEvent::Update,
+                &trigger_rows,
+                depth,
+            )
+            .await?;
+
+            match select_items.is_empty() {
+                true => Ok(Payload::Update(num_rows)),
+                false => returning(storage, table_name, select_items, returned_rows, context).await,
+            }
         }
         Statement::Delete {
             table_name,
+            using,
             selection,
-        } => delete(storage, table_name, selection).await,
+            returning: select_items,
+        } => {
+            delete(
+                storage,
+                table_name,
+                using.as_ref(),
+                selection,
+                select_items,
+                context,
+            )
+            .await
+        }
 
-        //- Selection
-        Statement::Query(query) => {
-            let (labels, rows) = select_with_labels(storage, query, None).await?;
+        //- Explain
+        Statement::Explain {
+            analyze: false,
+            statement,
+        } => explain(&*storage, statement).await.map(Payload::Explain),
+        Statement::Explain {
+            analyze: true,
+            statement,
+        } => explain_analyze(storage, statement, context)
+            .await
+            .map(Payload::Explain),
 
-            match labels {
-                Some(labels) => rows
-                    .map(|row| row?.try_into_vec())
-                    .try_collect::<Vec<_>>()
+        //- Selection
+        Statement::Query(query) => match resolve_query_sequences(storage, query).await? {
+            Some(query) => {
+                select_stream(storage, &query, context)
+                    .await?
+                    .collect()
                     .await
-                    .map(|rows| Payload::Select { labels, rows }),
-                None => rows
-                    .map(|row| row?.try_into_map())
-                    .try_collect::<Vec<_>>()
+            }
+            None => {
+                select_stream(storage, query, context)
+                    .await?
+                    .collect()
                     .await
-                    .map(Payload::SelectMap),
             }
-        }
+        },
         Statement::ShowColumns { table_name } => {
             let Schema { column_defs, .. } = storage
                 .fetch_schema(table_name)
@@ -295,7 +519,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
         }
         Statement::ShowIndexes(table_name) => {
             let query = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(crate::ast::Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Dictionary {
@@ -338,7 +564,9 @@ async fn execute_inner<T: GStore + GStoreMut>(
         Statement::ShowVariable(variable) => match variable {
             Variable::Tables => {
                 let query = Query {
+                    with: None,
                     body: SetExpr::Select(Box::new(crate::ast::Select {
+                        distinct: false,
                         projection: vec![SelectItem::Expr {
                             expr: Expr::Identifier("TABLE_NAME".to_owned()),
                             label: "TABLE_NAME".to_owned(),
@@ -362,7 +590,7 @@ async fn execute_inner<T: GStore + GStoreMut>(
                     offset: None,
                 };
 
-                let table_names = select(storage, &query, None)
+                let mut table_names = select(storage, &query, None)
                     .await?
                     .map(|row| row?.try_into_vec())
                     .try_collect::<Vec<Vec<Value>>>()
@@ -371,6 +599,12 @@ async fn execute_inner<T: GStore + GStoreMut>(
                     .flat_map(|values| values.iter().map(|value| value.into()))
                     .collect::<Vec<_>>();
 
+                let views = storage.fetch_all_views().await?;
+                if !views.is_empty() {
+                    table_names.extend(views.into_iter().map(|view| view.view_name));
+                    table_names.sort();
+                }
+
                 Ok(Payload::ShowVariable(PayloadVariable::Tables(table_names)))
             }
             Variable::Functions => {
@@ -0,0 +1,605 @@
+use {
+    super::{
+        aggregate::check_aggregate,
+        context::RowContext,
+        execute::{execute_inner, Payload},
+        select::select_query,
+    },
+    crate::{
+        ast::{
+            BinaryOperator, Expr, IndexItem, Join, JoinConstraint, JoinExecutor, JoinOperator,
+            OrderByExpr, Query, Select, SetExpr, Statement, TableFactor, TableWithJoins, ToSql,
+            Values,
+        },
+        plan::plan_target_join,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    async_recursion::async_recursion,
+    chrono::{DateTime, Utc},
+    futures::{
+        future::Either,
+        stream::{Stream, TryStreamExt},
+    },
+    serde::{Deserialize, Serialize},
+    std::{
+        cell::Cell,
+        fmt,
+        pin::Pin,
+        rc::Rc,
+        task::{Context, Poll},
+        time::Duration,
+    },
+};
+
+/// Operator of the plan tree returned by `EXPLAIN`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ExplainNode {
+    pub operator: String,
+    pub detail: Option<String>,
+    /// Filled by `EXPLAIN ANALYZE` only
+    pub analyzed: Option<Analyzed>,
+    pub children: Vec<ExplainNode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub struct Analyzed {
+    pub rows: usize,
+    /// Time spent while the rows were pulled from the operator, including its children
+    pub elapsed: Duration,
+}
+
+impl ExplainNode {
+    fn new(operator: &str, detail: Option<String>, children: Vec<ExplainNode>) -> Self {
+        Self {
+            operator: operator.to_owned(),
+            detail,
+            analyzed: None,
+            children,
+        }
+    }
+
+    fn measured(mut self, probe: Option<&Probe>) -> Self {
+        self.analyzed = probe.map(Probe::analyzed);
+        self
+    }
+
+    fn write(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        if depth > 0 {
+            write!(f, "\n{}-> ", "  ".repeat(depth))?;
+        }
+
+        write!(f, "{}", self.operator)?;
+        if let Some(detail) = &self.detail {
+            write!(f, ": {detail}")?;
+        }
+        if let Some(Analyzed { rows, elapsed }) = &self.analyzed {
+            write!(f, " (rows={rows}, elapsed={elapsed:?})")?;
+        }
+
+        self.children
+            .iter()
+            .try_for_each(|child| child.write(f, depth + 1))
+    }
+}
+
+impl fmt::Display for ExplainNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// Counts rows and elapsed time of a single operator while `EXPLAIN ANALYZE` runs a query.
+#[derive(Default)]
+pub struct Probe {
+    rows: Cell<usize>,
+    elapsed: Cell<Duration>,
+}
+
+impl Probe {
+    fn record(&self, started: DateTime<Utc>, row: bool) {
+        let elapsed = (Utc::now() - started).to_std().unwrap_or_default();
+
+        self.elapsed.set(self.elapsed.get() + elapsed);
+        if row {
+            self.rows.set(self.rows.get() + 1);
+        }
+    }
+
+    fn analyzed(&self) -> Analyzed {
+        Analyzed {
+            rows: self.rows.get(),
+            elapsed: self.elapsed.get(),
+        }
+    }
+}
+
+/// Probes of the operators of a top level `SELECT`, in the order rows flow through them.
+pub struct QueryProbes {
+    pub scan: Probe,
+    pub joins: Vec<Probe>,
+    pub filter: Probe,
+    pub aggregate: Probe,
+    pub project: Probe,
+    pub distinct: Probe,
+    pub sort: Probe,
+}
+
+impl QueryProbes {
+    fn new(query: &Query) -> Self {
+        let num_joins = match &query.body {
+            SetExpr::Select(select) => select.from.joins.len(),
+            _ => 0,
+        };
+
+        Self {
+            scan: Probe::default(),
+            joins: (0..num_joins).map(|_| Probe::default()).collect(),
+            filter: Probe::default(),
+            aggregate: Probe::default(),
+            project: Probe::default(),
+            distinct: Probe::default(),
+            sort: Probe::default(),
+        }
+    }
+}
+
+struct Measured<'a, S> {
+    rows: Pin<Box<S>>,
+    probe: &'a Probe,
+}
+
+impl<S: Stream> Stream for Measured<'_, S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let started = Utc::now();
+        let polled = self.rows.as_mut().poll_next(cx);
+        self.probe
+            .record(started, matches!(polled, Poll::Ready(Some(_))));
+
+        polled
+    }
+}
+
+/// Wraps `rows` to be measured by `probe`, rows are passed through as they are without a probe.
+pub fn measure<'a, S>(rows: S, probe: Option<&'a Probe>) -> impl Stream<Item = S::Item> + 'a
+where
+    S: Stream + 'a,
+{
+    match probe {
+        Some(probe) => Either::Left(Measured {
+            rows: Box::pin(rows),
+            probe,
+        }),
+        None => Either::Right(rows),
+    }
+}
+
+pub async fn explain<T: GStore>(storage: &T, statement: &Statement) -> Result<ExplainNode> {
+    let node = match statement {
+        Statement::Query(query) => query_node(query, None),
+        Statement::Insert {
+            table_name, source, ..
+        } => ExplainNode::new(
+            "Insert",
+            Some(table_name.to_owned()),
+            vec![query_node(source, None)],
+        ),
+        Statement::Update {
+            table_name,
+            from,
+            selection,
+            ..
+        } => ExplainNode::new(
+            "Update",
+            Some(table_name.to_owned()),
+            vec![target_node(storage, table_name, from.as_ref(), selection.as_ref()).await?],
+        ),
+        Statement::Delete {
+            table_name,
+            using,
+            selection,
+            ..
+        } => ExplainNode::new(
+            "Delete",
+            Some(table_name.to_owned()),
+            vec![target_node(storage, table_name, using.as_ref(), selection.as_ref()).await?],
+        ),
+        _ => ExplainNode::new("Statement", Some(statement.to_sql()), Vec::new()),
+    };
+
+    Ok(node)
+}
+
+/// Executes the statement and returns its plan tree with the measured rows and elapsed time,
+/// the operators of a top level `SELECT` are measured one by one.
+#[async_recursion(?Send)]
+pub async fn explain_analyze<T: GStore + GStoreMut>(
+    storage: &mut T,
+    statement: &Statement,
+    context: Option<Rc<RowContext<'_>>>,
+) -> Result<ExplainNode> {
+    let started = Utc::now();
+
+    let (mut node, rows) = match statement {
+        Statement::Query(query) => {
+            let probes = QueryProbes::new(query);
+            let (_, rows) = select_query(&*storage, query, context, Some(&probes)).await?;
+            let rows = rows
+                .try_fold(0, |rows, _| async move { Ok(rows + 1) })
+                .await?;
+
+            (query_node(query, Some(&probes)), rows)
+        }
+        statement => {
+            let rows = match execute_inner(storage, statement, context).await? {
+                Payload::Insert(rows) | Payload::Update(rows) | Payload::Delete(rows) => rows,
+                Payload::Select { rows, .. } => rows.len(),
+                Payload::SelectMap(rows) => rows.len(),
+                _ => 0,
+            };
+
+            (explain(&*storage, statement).await?, rows)
+        }
+    };
+
+    node.analyzed = Some(Analyzed {
+        rows,
+        elapsed: (Utc::now() - started).to_std().unwrap_or_default(),
+    });
+
+    Ok(node)
+}
+
+fn query_node(query: &Query, probes: Option<&QueryProbes>) -> ExplainNode {
+    let Query {
+        with,
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+
+    let mut node = match body {
+        SetExpr::Select(select) => select_node(select, order_by, probes),
+        body => sort_node(set_expr_node(body), order_by, None),
+    };
+
+    if limit.is_some() || offset.is_some() {
+        let limit = limit
+            .iter()
+            .map(|limit| format!("LIMIT {}", limit.to_sql()));
+        let offset = offset
+            .iter()
+            .map(|offset| format!("OFFSET {}", offset.to_sql()));
+        let detail = limit.chain(offset).collect::<Vec<_>>().join(" ");
+
+        node = ExplainNode::new("Limit", Some(detail), vec![node]);
+    }
+
+    if let Some(with) = with {
+        node.children.extend(with.cte_tables.iter().map(|cte| {
+            ExplainNode::new(
+                "CTE",
+                Some(cte.alias.name.to_owned()),
+                vec![query_node(&cte.query, None)],
+            )
+        }));
+    }
+
+    node
+}
+
+fn set_expr_node(set_expr: &SetExpr) -> ExplainNode {
+    match set_expr {
+        SetExpr::Select(select) => select_node(select, &[], None),
+        SetExpr::Values(Values(values_list)) => ExplainNode::new(
+            "Values",
+            Some(format!("{} rows", values_list.len())),
+            Vec::new(),
+        ),
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let all = all.then_some(" ALL").unwrap_or_default();
+
+            ExplainNode::new(
+                "SetOperation",
+                Some(format!("{op}{all}")),
+                vec![set_expr_node(left), set_expr_node(right)],
+            )
+        }
+    }
+}
+
+fn select_node(
+    select: &Select,
+    order_by: &[OrderByExpr],
+    probes: Option<&QueryProbes>,
+) -> ExplainNode {
+    let Select {
+        distinct,
+        projection,
+        from,
+        selection,
+        group_by,
+        having,
+    } = select;
+
+    let node = table_factor_node(&from.relation).measured(probes.map(|probes| &probes.scan));
+    let node = from.joins.iter().enumerate().fold(node, |node, (i, join)| {
+        join_node(join, node).measured(probes.and_then(|probes| probes.joins.get(i)))
+    });
+    let node = match selection {
+        Some(selection) => {
+            filter_node(node, Some(selection)).measured(probes.map(|probes| &probes.filter))
+        }
+        None => node,
+    };
+    let node = match check_aggregate(projection, group_by) {
+        true => {
+            let group_by =
+                (!group_by.is_empty()).then(|| format!("GROUP BY {}", join_sql(group_by)));
+            let having = having
+                .as_ref()
+                .map(|having| format!("HAVING {}", having.to_sql()));
+            let detail = group_by.into_iter().chain(having).collect::<Vec<_>>();
+            let detail = (!detail.is_empty()).then(|| detail.join(" "));
+
+            ExplainNode::new("Aggregate", detail, vec![node])
+                .measured(probes.map(|probes| &probes.aggregate))
+        }
+        false => node,
+    };
+    let node = ExplainNode::new("Project", Some(join_sql(projection)), vec![node])
+        .measured(probes.map(|probes| &probes.project));
+    let node = match distinct {
+        true => ExplainNode::new("Distinct", None, vec![node])
+            .measured(probes.map(|probes| &probes.distinct)),
+        false => node,
+    };
+
+    sort_node(node, order_by, probes.map(|probes| &probes.sort))
+}
+
+fn sort_node(node: ExplainNode, order_by: &[OrderByExpr], probe: Option<&Probe>) -> ExplainNode {
+    match order_by.is_empty() {
+        true => node,
+        false => ExplainNode::new("Sort", Some(join_sql(order_by)), vec![node]).measured(probe),
+    }
+}
+
+fn filter_node(node: ExplainNode, selection: Option<&Expr>) -> ExplainNode {
+    match selection {
+        Some(selection) => ExplainNode::new("Filter", Some(selection.to_sql()), vec![node]),
+        None => node,
+    }
+}
+
+fn seq_scan_node(table_name: &str) -> ExplainNode {
+    ExplainNode::new("SeqScan", Some(table_name.to_owned()), Vec::new())
+}
+
+/// Scan of the `UPDATE` or `DELETE` target, which is joined after every relation of `from`
+/// with `selection` as its constraint.
+async fn target_node<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    from: Option<&TableWithJoins>,
+    selection: Option<&Expr>,
+) -> Result<ExplainNode> {
+    let from = match from {
+        Some(from) => from,
+        None => return Ok(filter_node(seq_scan_node(table_name), selection)),
+    };
+
+    let target_join = plan_target_join(storage, table_name, from, selection).await?;
+    let TableWithJoins { relation, joins } = from;
+    let node = joins
+        .iter()
+        .fold(table_factor_node(relation), |node, join| {
+            join_node(join, node)
+        });
+
+    Ok(join_node(&target_join, node))
+}
+
+fn table_factor_node(table_factor: &TableFactor) -> ExplainNode {
+    match table_factor {
+        TableFactor::Table { name, alias, index } => {
+            let table = match alias {
+                Some(alias) if &alias.name != name => format!("{name} AS {}", alias.name),
+                _ => name.to_owned(),
+            };
+
+            match index {
+                None => seq_scan_node(&table),
+                Some(IndexItem::PrimaryKey(expr)) => ExplainNode::new(
+                    "PrimaryKeyScan",
+                    Some(format!("{table} (key = {})", expr.to_sql())),
+                    Vec::new(),
+                ),
+                Some(IndexItem::CompositePrimaryKey(exprs)) => {
+                    let key = exprs
+                        .iter()
+                        .map(ToSql::to_sql)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    ExplainNode::new(
+                        "PrimaryKeyScan",
+                        Some(format!("{table} (key = ({key}))")),
+                        Vec::new(),
+                    )
+                }
+                Some(IndexItem::PrimaryKeyRange { lower, upper, asc }) => {
+                    let bounds = lower.iter().chain(upper).map(|(op, expr)| {
+                        let op = BinaryOperator::from(op.clone());
+
+                        format!("key {} {}", op.to_sql(), expr.to_sql())
+                    });
+                    let order = asc.map(|asc| match asc {
+                        true => "ASC".to_owned(),
+                        false => "DESC".to_owned(),
+                    });
+                    let conditions = bounds.chain(order).collect::<Vec<_>>();
+
+                    ExplainNode::new(
+                        "PrimaryKeyRangeScan",
+                        Some(format!("{table} ({})", conditions.join(", "))),
+                        Vec::new(),
+                    )
+                }
+                Some(IndexItem::NonClustered {
+                    name: index_name,
+                    asc,
+                    prefix,
+                    cmp_expr,
+                    upper_cmp_expr,
+                }) => {
+                    let prefix =
+                        (!prefix.is_empty()).then(|| format!("prefix = ({})", join_sql(prefix)));
+                    let cmp = cmp_expr.iter().chain(upper_cmp_expr).map(|(op, expr)| {
+                        let op = BinaryOperator::from(op.clone());
+
+                        format!("key {} {}", op.to_sql(), expr.to_sql())
+                    });
+                    let order = asc.map(|asc| match asc {
+                        true => "ASC".to_owned(),
+                        false => "DESC".to_owned(),
+                    });
+                    let conditions = prefix
+                        .into_iter()
+                        .chain(cmp)
+                        .chain(order)
+                        .collect::<Vec<_>>();
+                    let detail = match conditions.is_empty() {
+                        true => format!("{table} using {index_name}"),
+                        false => format!("{table} using {index_name} ({})", conditions.join(", ")),
+                    };
+
+                    ExplainNode::new("IndexScan", Some(detail), Vec::new())
+                }
+                Some(IndexItem::Scan {
+                    predicates,
+                    columns,
+                }) => {
+                    let filter = (!predicates.is_empty()).then(|| {
+                        let predicates = predicates.iter().map(ToSql::to_sql).collect::<Vec<_>>();
+
+                        format!("filter = {}", predicates.join(" AND "))
+                    });
+                    let columns = columns.as_ref().map(|columns| {
+                        let columns = columns
+                            .iter()
+                            .map(|column| format!(r#""{column}""#))
+                            .collect::<Vec<_>>();
+
+                        format!("columns = ({})", columns.join(", "))
+                    });
+                    let conditions = filter.into_iter().chain(columns).collect::<Vec<_>>();
+
+                    ExplainNode::new(
+                        "SeqScan",
+                        Some(format!("{table} ({})", conditions.join(", "))),
+                        Vec::new(),
+                    )
+                }
+            }
+        }
+        TableFactor::Derived { subquery, alias } => ExplainNode::new(
+            "SubqueryScan",
+            Some(alias.name.to_owned()),
+            vec![query_node(subquery, None)],
+        ),
+        TableFactor::Series { alias, size } => ExplainNode::new(
+            "SeriesScan",
+            Some(format!("{} (size = {})", alias.name, size.to_sql())),
+            Vec::new(),
+        ),
+        TableFactor::Dictionary { dict, .. } => {
+            ExplainNode::new("DictionaryScan", Some(dict.to_string()), Vec::new())
+        }
+    }
+}
+
+fn join_node(join: &Join, left: ExplainNode) -> ExplainNode {
+    let Join {
+        relation,
+        join_operator,
+        join_executor,
+    } = join;
+
+    let (join_operator, join_constraint) = match join_operator {
+        JoinOperator::Inner(join_constraint) => ("INNER", join_constraint),
+        JoinOperator::LeftOuter(join_constraint) => ("LEFT OUTER", join_constraint),
+        JoinOperator::RightOuter(join_constraint) => ("RIGHT OUTER", join_constraint),
+        JoinOperator::FullOuter(join_constraint) => ("FULL OUTER", join_constraint),
+    };
+    let mut detail = match join_constraint {
+        JoinConstraint::On(expr) => format!("{join_operator} ON {}", expr.to_sql()),
+        JoinConstraint::None => join_operator.to_owned(),
+    };
+
+    let operator = match join_executor {
+        JoinExecutor::NestedLoop => "NestedLoopJoin",
+        JoinExecutor::Hash {
+            key_expr,
+            value_expr,
+            where_clause,
+        } => {
+            detail = format!(
+                "{detail}, hash key {} = {}",
+                key_expr.to_sql(),
+                value_expr.to_sql()
+            );
+            if let Some(where_clause) = where_clause {
+                detail = format!("{detail}, hash filter {}", where_clause.to_sql());
+            }
+
+            "HashJoin"
+        }
+    };
+
+    ExplainNode::new(
+        operator,
+        Some(detail),
+        vec![left, table_factor_node(relation)],
+    )
+}
+
+fn join_sql<T: ToSql>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(ToSql::to_sql)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{Analyzed, ExplainNode},
+        std::time::Duration,
+    };
+
+    #[test]
+    fn display() {
+        let scan = ExplainNode::new("SeqScan", Some("Item".to_owned()), Vec::new());
+        let mut node = ExplainNode::new("Filter", Some(r#""id" > 1"#.to_owned()), vec![scan]);
+        node.analyzed = Some(Analyzed {
+            rows: 2,
+            elapsed: Duration::from_micros(15),
+        });
+        let node = ExplainNode::new("Project", Some(r#""id""#.to_owned()), vec![node]);
+
+        let expected = r#"Project: "id"
+  -> Filter: "id" > 1 (rows=2, elapsed=15µs)
+    -> SeqScan: Item"#;
+        assert_eq!(node.to_string(), expected);
+    }
+}
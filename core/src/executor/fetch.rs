@@ -1,15 +1,19 @@
 use {
-    super::{context::RowContext, evaluate::evaluate_stateless, filter::check_expr},
+    super::{
+        context::{CteTable, RowContext},
+        evaluate::evaluate_stateless_with,
+        filter::check_expr,
+    },
     crate::{
         ast::{
-            ColumnDef, ColumnUniqueOption, Dictionary, Expr, IndexItem, Join, Query, Select,
-            SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, ToSql, ToSqlUnquoted,
-            Values,
+            BinaryOperator, Cte, DataType, Dictionary, Expr, IndexItem, IndexOperator, Join, Query,
+            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, ToSql,
+            ToSqlUnquoted, Values, With,
         },
-        data::{get_alias, get_index, Key, Row, Value},
+        data::{get_alias, get_index, primary_key_indexes, Key, Row, Schema, Value},
         executor::{evaluate::evaluate, select::select},
         result::Result,
-        store::{DataRow, GStore},
+        store::{DataRow, GStore, KeyRange, ScanOperator, ScanPredicate},
     },
     async_recursion::async_recursion,
     futures::{
@@ -17,7 +21,7 @@ use {
         stream::{self, Stream, StreamExt, TryStreamExt},
     },
     serde::Serialize,
-    std::{borrow::Cow, collections::HashMap, fmt::Debug, iter, rc::Rc},
+    std::{borrow::Cow, collections::HashMap, fmt::Debug, iter, ops::Bound, rc::Rc},
     thiserror::Error as ThisError,
 };
 
@@ -34,6 +38,9 @@ pub enum FetchError {
 
     #[error("table '{0}' has {1} columns available but {2} column aliases specified")]
     TooManyColumnAliases(String, usize, usize),
+
+    #[error("unreachable scan predicate: {0}")]
+    UnreachableScanPredicate(String),
 }
 
 pub async fn fetch<'a, T: GStore>(
@@ -41,6 +48,7 @@ pub async fn fetch<'a, T: GStore>(
     table_name: &'a str,
     columns: Option<Rc<[String]>>,
     where_clause: Option<&'a Expr>,
+    context: Option<Rc<RowContext<'a>>>,
 ) -> Result<impl Stream<Item = Result<(Key, Row)>> + 'a> {
     let columns = columns.unwrap_or_else(|| Rc::from([]));
     let rows = storage
@@ -55,6 +63,8 @@ pub async fn fetch<'a, T: GStore>(
                 DataRow::Map(values) => Row::Map(values),
             };
 
+            let context = context.as_ref().map(Rc::clone);
+
             async move {
                 let expr = match where_clause {
                     None => {
@@ -63,7 +73,7 @@ pub async fn fetch<'a, T: GStore>(
                     Some(expr) => expr,
                 };
 
-                let context = RowContext::new(table_name, Cow::Borrowed(&row), None);
+                let context = RowContext::new(table_name, Cow::Borrowed(&row), context);
 
                 check_expr(storage, Some(Rc::new(context)), None, expr)
                     .await
@@ -74,6 +84,110 @@ pub async fn fetch<'a, T: GStore>(
     Ok(rows)
 }
 
+/// Converts the conjunctions planned into [`IndexItem::Scan`] into a predicate for the storage.
+async fn scan_predicate<'a>(
+    predicates: &'a [Expr],
+    filter_context: &Option<Rc<RowContext<'a>>>,
+) -> Result<Option<ScanPredicate>> {
+    let eval = |expr: &'a Expr| evaluate_stateless_with(filter_context.clone(), expr);
+    let column = |expr: &Expr| match expr {
+        Expr::Identifier(ident) | Expr::CompoundIdentifier { ident, .. } => Some(ident.to_owned()),
+        _ => None,
+    };
+    let unreachable = |expr: &Expr| FetchError::UnreachableScanPredicate(expr.to_sql());
+
+    let mut scan_predicates = Vec::with_capacity(predicates.len());
+    for expr in predicates {
+        let predicate = match expr {
+            Expr::BinaryOp { left, op, right } => {
+                let op = match op {
+                    BinaryOperator::Eq => ScanOperator::Eq,
+                    BinaryOperator::NotEq => ScanOperator::NotEq,
+                    BinaryOperator::Lt => ScanOperator::Lt,
+                    BinaryOperator::LtEq => ScanOperator::LtEq,
+                    BinaryOperator::Gt => ScanOperator::Gt,
+                    BinaryOperator::GtEq => ScanOperator::GtEq,
+                    _ => return Err(unreachable(expr).into()),
+                };
+                let (column, op, value) = match (column(left), column(right)) {
+                    (Some(column), _) => (column, op, right),
+                    (None, Some(column)) => (column, op.reverse(), left),
+                    (None, None) => return Err(unreachable(expr).into()),
+                };
+                let value: Value = eval(value).await?.try_into()?;
+
+                ScanPredicate::Compare { column, op, value }
+            }
+            Expr::IsNull(target) | Expr::IsNotNull(target) => ScanPredicate::IsNull {
+                column: column(target).ok_or_else(|| unreachable(expr))?,
+                negated: matches!(expr, Expr::IsNotNull(_)),
+            },
+            Expr::InList {
+                expr: target,
+                list,
+                negated,
+            } => {
+                let mut values: Vec<Value> = Vec::with_capacity(list.len());
+                for item in list {
+                    values.push(eval(item).await?.try_into()?);
+                }
+
+                ScanPredicate::InList {
+                    column: column(target).ok_or_else(|| unreachable(expr))?,
+                    list: values,
+                    negated: *negated,
+                }
+            }
+            Expr::Between {
+                expr: target,
+                negated: false,
+                low,
+                high,
+            } => {
+                let column = column(target).ok_or_else(|| unreachable(expr))?;
+                let low: Value = eval(low).await?.try_into()?;
+                let high: Value = eval(high).await?.try_into()?;
+
+                ScanPredicate::And(vec![
+                    ScanPredicate::Compare {
+                        column: column.clone(),
+                        op: ScanOperator::GtEq,
+                        value: low,
+                    },
+                    ScanPredicate::Compare {
+                        column,
+                        op: ScanOperator::LtEq,
+                        value: high,
+                    },
+                ])
+            }
+            _ => return Err(unreachable(expr).into()),
+        };
+
+        scan_predicates.push(predicate);
+    }
+
+    Ok(match scan_predicates.len() {
+        0 => None,
+        1 => scan_predicates.pop(),
+        _ => Some(ScanPredicate::And(scan_predicates)),
+    })
+}
+
+/// Comparisons with `NULL` are never true, and storages are not asked to scan an empty range.
+fn is_empty_range(range: &KeyRange) -> bool {
+    match range {
+        (Bound::Included(Key::None) | Bound::Excluded(Key::None), _)
+        | (_, Bound::Included(Key::None) | Bound::Excluded(Key::None)) => true,
+        (Bound::Included(lower), Bound::Included(upper)) => lower > upper,
+        (
+            Bound::Included(lower) | Bound::Excluded(lower),
+            Bound::Included(upper) | Bound::Excluded(upper),
+        ) => lower >= upper,
+        _ => false,
+    }
+}
+
 #[derive(futures_enum::Stream)]
 pub enum Rows<I1, I2, I3, I4> {
     Derived(I1),
@@ -88,7 +202,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
     filter_context: &Option<Rc<RowContext<'a>>>,
 ) -> Result<impl Stream<Item = Result<Row>> + 'a> {
     let columns = Rc::from(
-        fetch_relation_columns(storage, table_factor)
+        fetch_relation_columns(storage, table_factor, filter_context)
             .await?
             .unwrap_or_default(),
     );
@@ -112,30 +226,117 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
         TableFactor::Table { name, .. } => {
             let rows = {
                 #[derive(futures_enum::Stream)]
-                enum Rows<I1, I2, I3, I4> {
+                enum Rows<I1, I2, I3, I4, I5, I6> {
                     Indexed(I1),
                     PrimaryKey(I2),
                     PrimaryKeyEmpty(I3),
-                    FullScan(I4),
+                    PrimaryKeyRange(I4),
+                    FullScan(I5),
+                    Cte(I6),
                 }
 
+                let cte_table = filter_context
+                    .as_ref()
+                    .and_then(|context| context.get_cte(name));
+                let key_types = match get_index(table_factor) {
+                    Some(
+                        IndexItem::PrimaryKey(_)
+                        | IndexItem::CompositePrimaryKey(_)
+                        | IndexItem::PrimaryKeyRange { .. },
+                    ) => fetch_primary_key_types(storage, name).await?,
+                    _ => Vec::new(),
+                };
+                // bound parameters are cast to the type of the key column they are compared with,
+                // keys of different types do not match each other
+                let evaluate_key = |expr: &'a Expr, data_type: Option<DataType>| {
+                    let filter_context = filter_context.as_ref().map(Rc::clone);
+
+                    async move {
+                        let value = evaluate(storage, filter_context, None, expr)
+                            .await
+                            .and_then(Value::try_from)?;
+                        let value = match (expr, data_type) {
+                            (Expr::Parameter(_), Some(data_type)) => value.cast(&data_type)?,
+                            _ => value,
+                        };
+
+                        Key::try_from(value)
+                    }
+                };
+                let fetch_key_row = |key: Key| {
+                    let columns = Rc::clone(&columns);
+
+                    async move {
+                        storage.fetch_data(name, &key).await.map(|data_row| {
+                            data_row.map(|data_row| match data_row {
+                                DataRow::Vec(values) => Row::Vec { columns, values },
+                                DataRow::Map(values) => Row::Map(values),
+                            })
+                        })
+                    }
+                };
+
                 match get_index(table_factor) {
+                    _ if cte_table.is_some() => {
+                        let rows = cte_table
+                            .map(|table| table.rows.clone())
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(move |row| match row {
+                                Row::Vec { values, .. } => Ok(Row::Vec {
+                                    columns: Rc::clone(&columns),
+                                    values,
+                                }),
+                                Row::Map(values) => Ok(Row::Map(values)),
+                            });
+
+                        Rows::Cte(stream::iter(rows))
+                    }
                     Some(IndexItem::NonClustered {
                         name: index_name,
                         asc,
+                        prefix,
                         cmp_expr,
+                        upper_cmp_expr,
                     }) => {
                         let cmp_value = match cmp_expr {
                             Some((op, expr)) => {
-                                let evaluated = evaluate(storage, None, None, expr).await?;
+                                let filter_context = filter_context.clone();
+                                let evaluated =
+                                    evaluate(storage, filter_context, None, expr).await?;
 
                                 Some((op, evaluated.try_into()?))
                             }
                             None => None,
                         };
+                        let upper_cmp_value = match upper_cmp_expr {
+                            Some((op, expr)) => {
+                                let filter_context = filter_context.clone();
+                                let evaluated =
+                                    evaluate(storage, filter_context, None, expr).await?;
+
+                                Some((op, evaluated.try_into()?))
+                            }
+                            None => None,
+                        };
+
+                        let mut prefix_values = Vec::with_capacity(prefix.len());
+                        for expr in prefix {
+                            let filter_context = filter_context.clone();
+                            let evaluated = evaluate(storage, filter_context, None, expr).await?;
+
+                            prefix_values.push(evaluated.try_into()?);
+                        }
 
                         let rows = storage
-                            .scan_indexed_data(name, index_name, *asc, cmp_value)
+                            .scan_indexed_data(
+                                name,
+                                index_name,
+                                *asc,
+                                &prefix_values,
+                                cmp_value,
+                                upper_cmp_value,
+                            )
                             .await?
                             .map_ok(move |(_, data_row)| match data_row {
                                 DataRow::Vec(values) => Row::Vec {
@@ -148,37 +349,87 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                         Rows::Indexed(rows)
                     }
                     Some(IndexItem::PrimaryKey(expr)) => {
-                        let filter_context = filter_context.as_ref().map(Rc::clone);
-                        let key = evaluate(storage, filter_context, None, expr)
+                        let key = evaluate_key(expr, key_types.first().cloned()).await?;
+
+                        match fetch_key_row(key).await? {
+                            Some(row) => Rows::PrimaryKey(stream::once(future::ready(Ok(row)))),
+                            None => Rows::PrimaryKeyEmpty(stream::empty()),
+                        }
+                    }
+                    Some(IndexItem::CompositePrimaryKey(exprs)) => {
+                        let key = stream::iter(exprs.iter().enumerate())
+                            .then(|(i, expr)| evaluate_key(expr, key_types.get(i).cloned()))
+                            .try_collect::<Vec<_>>()
                             .await
-                            .and_then(Value::try_from)
-                            .and_then(Key::try_from)?;
+                            .map(Key::List)?;
 
-                        match storage.fetch_data(name, &key).await? {
-                            Some(data_row) => {
-                                let row = match data_row {
+                        match fetch_key_row(key).await? {
+                            Some(row) => Rows::PrimaryKey(stream::once(future::ready(Ok(row)))),
+                            None => Rows::PrimaryKeyEmpty(stream::empty()),
+                        }
+                    }
+                    Some(IndexItem::PrimaryKeyRange { lower, upper, asc }) => {
+                        let bound = |op: &IndexOperator, key| match op {
+                            IndexOperator::Gt | IndexOperator::Lt => Bound::Excluded(key),
+                            _ => Bound::Included(key),
+                        };
+                        let lower = match lower {
+                            Some((op, expr)) => {
+                                bound(op, evaluate_key(expr, key_types.first().cloned()).await?)
+                            }
+                            None => Bound::Unbounded,
+                        };
+                        let upper = match upper {
+                            Some((op, expr)) => {
+                                bound(op, evaluate_key(expr, key_types.first().cloned()).await?)
+                            }
+                            None => Bound::Unbounded,
+                        };
+                        let range = (lower, upper);
+
+                        if is_empty_range(&range) {
+                            Rows::PrimaryKeyEmpty(stream::empty())
+                        } else {
+                            let rows = storage
+                                .scan_data_range(name, range, asc.unwrap_or(true))
+                                .await?
+                                .map_ok(move |(_, data_row)| match data_row {
                                     DataRow::Vec(values) => Row::Vec {
                                         columns: Rc::clone(&columns),
                                         values,
                                     },
                                     DataRow::Map(values) => Row::Map(values),
-                                };
+                                });
 
-                                Rows::PrimaryKey(stream::once(future::ready(Ok(row))))
-                            }
-                            None => Rows::PrimaryKeyEmpty(stream::empty()),
+                            Rows::PrimaryKeyRange(rows)
                         }
                     }
-                    _ => {
-                        let rows = storage.scan_data(name).await?.map_ok(move |(_, data_row)| {
-                            match data_row {
+                    index => {
+                        let (predicate, scan_columns) = match index {
+                            Some(IndexItem::Scan {
+                                predicates,
+                                columns,
+                            }) => (
+                                scan_predicate(predicates, filter_context).await?,
+                                columns.as_deref(),
+                            ),
+                            _ => (None, None),
+                        };
+                        let columns = match scan_columns {
+                            Some(scan_columns) => Rc::from(scan_columns),
+                            None => columns,
+                        };
+
+                        let rows = storage
+                            .scan_data_filtered(name, predicate.as_ref(), scan_columns)
+                            .await?
+                            .map_ok(move |(_, data_row)| match data_row {
                                 DataRow::Vec(values) => Row::Vec {
                                     columns: Rc::clone(&columns),
                                     values,
                                 },
                                 DataRow::Map(values) => Row::Map(values),
-                            }
-                        });
+                            });
 
                         Rows::FullScan(rows)
                     }
@@ -188,7 +439,9 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
             Ok(Rows::Table(rows))
         }
         TableFactor::Series { size, .. } => {
-            let value: Value = evaluate_stateless(None, size).await?.try_into()?;
+            let value: Value = evaluate_stateless_with(filter_context.clone(), size)
+                .await?
+                .try_into()?;
             let size: i64 = value.try_into()?;
             let size = match size {
                 n if n >= 0 => size,
@@ -218,6 +471,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                 match dict {
                     Dictionary::GlueObjects => {
                         let schemas = storage.fetch_all_schemas().await?;
+                        let views = storage.fetch_all_views().await?;
                         let table_metas = storage
                             .scan_table_meta()
                             .await?
@@ -249,8 +503,14 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                                 .chain(index_rows)
                                 .map(|hash_map| Ok(Row::Map(hash_map)))
                         });
+                        let view_rows = views.into_iter().map(|view| {
+                            Ok(Row::Map(HashMap::from([
+                                ("OBJECT_NAME".to_owned(), Value::Str(view.view_name)),
+                                ("OBJECT_TYPE".to_owned(), Value::Str("VIEW".to_owned())),
+                            ])))
+                        });
 
-                        Rows::Objects(stream::iter(rows))
+                        Rows::Objects(stream::iter(rows.chain(view_rows)))
                     }
                     Dictionary::GlueTables => {
                         let schemas = storage.fetch_all_schemas().await?;
@@ -307,20 +567,20 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                         let schemas = storage.fetch_all_schemas().await?;
                         let rows = schemas.into_iter().flat_map(move |schema| {
                             let column_defs = schema.column_defs.unwrap_or_default();
-                            let primary_column = column_defs.iter().find_map(|column_def| {
-                                let ColumnDef { name, unique, .. } = column_def;
-
-                                (unique == &Some(ColumnUniqueOption { is_primary: true }))
-                                    .then_some(name)
-                            });
-
-                            let clustered = match primary_column {
-                                Some(column_name) => {
+                            let primary_columns =
+                                primary_key_indexes(&column_defs, &schema.primary_key)
+                                    .into_iter()
+                                    .map(|i| column_defs[i].name.as_str())
+                                    .collect::<Vec<_>>();
+
+                            let clustered = match primary_columns.is_empty() {
+                                false => {
+                                    let column_name = primary_columns.join(", ");
                                     let values = vec![
                                         Value::Str(schema.table_name.clone()),
                                         Value::Str("PRIMARY".to_owned()),
                                         Value::Str("BOTH".to_owned()),
-                                        Value::Str(column_name.to_owned()),
+                                        Value::Str(column_name),
                                         Value::Bool(true),
                                     ];
 
@@ -331,7 +591,7 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
 
                                     vec![Ok(row)]
                                 }
-                                None => Vec::new(),
+                                true => Vec::new(),
                             };
 
                             let columns = Rc::clone(&columns);
@@ -340,7 +600,14 @@ pub async fn fetch_relation_rows<'a, T: GStore>(
                                     Value::Str(schema.table_name.clone()),
                                     Value::Str(index.name),
                                     Value::Str(index.order.to_string()),
-                                    Value::Str(index.expr.to_sql_unquoted()),
+                                    Value::Str(
+                                        index
+                                            .exprs
+                                            .iter()
+                                            .map(ToSqlUnquoted::to_sql_unquoted)
+                                            .collect::<Vec<_>>()
+                                            .join(", "),
+                                    ),
                                     Value::Bool(false),
                                 ];
 
@@ -382,17 +649,43 @@ pub async fn fetch_columns<T: GStore>(
     Ok(columns)
 }
 
+async fn fetch_primary_key_types<T: GStore>(
+    storage: &T,
+    table_name: &str,
+) -> Result<Vec<DataType>> {
+    let data_types = match storage.fetch_schema(table_name).await? {
+        Some(Schema {
+            column_defs: Some(column_defs),
+            primary_key,
+            ..
+        }) => primary_key_indexes(&column_defs, &primary_key)
+            .into_iter()
+            .map(|i| column_defs[i].data_type.to_owned())
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Ok(data_types)
+}
+
 #[async_recursion(?Send)]
-pub async fn fetch_relation_columns<T>(
+pub async fn fetch_relation_columns<'a, T>(
     storage: &T,
-    table_factor: &TableFactor,
+    table_factor: &'a TableFactor,
+    filter_context: &Option<Rc<RowContext<'a>>>,
 ) -> Result<Option<Vec<String>>>
 where
     T: GStore,
 {
     match table_factor {
         TableFactor::Table { name, alias, .. } => {
-            let columns = fetch_columns(storage, name).await?;
+            let cte_table = filter_context
+                .as_ref()
+                .and_then(|context| context.get_cte(name));
+            let columns = match cte_table {
+                Some(table) => table.labels.clone(),
+                None => fetch_columns(storage, name).await?,
+            };
             match (columns, alias) {
                 (columns, None) => Ok(columns),
                 (None, Some(_)) => Ok(None),
@@ -439,79 +732,117 @@ where
                 "UNIQUENESS".to_owned(),
             ],
         })),
-        TableFactor::Derived {
-            subquery: Query { body, .. },
-            alias:
-                TableAlias {
-                    columns: alias_columns,
-                    name,
+        TableFactor::Derived { subquery, alias } => {
+            fetch_query_labels(storage, subquery, filter_context)
+                .await?
+                .map(|labels| alias_labels(alias, labels))
+                .transpose()
+        }
+    }
+}
+
+#[async_recursion(?Send)]
+async fn fetch_query_labels<'a, T>(
+    storage: &T,
+    query: &'a Query,
+    filter_context: &Option<Rc<RowContext<'a>>>,
+) -> Result<Option<Vec<String>>>
+where
+    T: GStore,
+{
+    let Query { with, body, .. } = query;
+    let mut filter_context = filter_context.as_ref().map(Rc::clone);
+
+    if let Some(With { cte_tables, .. }) = with {
+        for Cte { alias, query } in cte_tables {
+            let labels = fetch_query_labels(storage, query, &filter_context)
+                .await?
+                .map(|labels| alias_labels(alias, labels))
+                .transpose()?;
+            let table = CteTable {
+                labels,
+                rows: Vec::new(),
+            };
+
+            filter_context = Some(Rc::new(RowContext::new_cte(
+                &alias.name,
+                table,
+                filter_context,
+            )));
+        }
+    }
+
+    fetch_set_expr_labels(storage, body, &filter_context).await
+}
+
+/// Applies the column aliases of `alias` to the leading `labels`
+pub fn alias_labels(alias: &TableAlias, labels: Vec<String>) -> Result<Vec<String>> {
+    let TableAlias {
+        name,
+        columns: alias_columns,
+    } = alias;
+
+    if alias_columns.len() > labels.len() {
+        return Err(FetchError::TooManyColumnAliases(
+            name.to_owned(),
+            labels.len(),
+            alias_columns.len(),
+        )
+        .into());
+    }
+
+    Ok(alias_columns
+        .iter()
+        .cloned()
+        .chain(labels[alias_columns.len()..].to_vec())
+        .collect())
+}
+
+#[async_recursion(?Send)]
+async fn fetch_set_expr_labels<'a, T>(
+    storage: &T,
+    set_expr: &'a SetExpr,
+    filter_context: &Option<Rc<RowContext<'a>>>,
+) -> Result<Option<Vec<String>>>
+where
+    T: GStore,
+{
+    match set_expr {
+        SetExpr::Select(statement) => {
+            let Select {
+                from: TableWithJoins {
+                    relation, joins, ..
                 },
-        } => match body {
-            SetExpr::Select(statement) => {
-                let Select {
-                    from:
-                        TableWithJoins {
-                            relation, joins, ..
-                        },
-                    projection,
-                    ..
-                } = statement.as_ref();
+                projection,
+                ..
+            } = statement.as_ref();
 
-                let labels = fetch_labels(storage, relation, joins, projection).await?;
-                match labels {
-                    None => Ok(None),
-                    Some(labels) if alias_columns.is_empty() => Ok(Some(labels)),
-                    Some(labels) if alias_columns.len() > labels.len() => {
-                        Err(FetchError::TooManyColumnAliases(
-                            name.to_string(),
-                            labels.len(),
-                            alias_columns.len(),
-                        )
-                        .into())
-                    }
-                    Some(labels) => Ok(Some(
-                        alias_columns
-                            .iter()
-                            .cloned()
-                            .chain(labels[alias_columns.len()..labels.len()].to_vec())
-                            .collect(),
-                    )),
-                }
-            }
-            SetExpr::Values(Values(values_list)) => {
-                let total_len = values_list[0].len();
-                let alias_len = alias_columns.len();
-                if alias_len > total_len {
-                    return Err(FetchError::TooManyColumnAliases(
-                        name.into(),
-                        total_len,
-                        alias_len,
-                    )
-                    .into());
-                }
-                let labels = (alias_len + 1..=total_len).map(|i| format!("column{}", i));
-                let labels = alias_columns
-                    .iter()
-                    .cloned()
-                    .chain(labels)
-                    .collect::<Vec<_>>();
+            fetch_labels(storage, relation, joins, projection, filter_context).await
+        }
+        SetExpr::Values(Values(values_list)) => {
+            let labels = (1..=values_list[0].len())
+                .map(|i| format!("column{}", i))
+                .collect();
 
-                Ok(Some(labels))
-            }
-        },
+            Ok(Some(labels))
+        }
+        SetExpr::SetOperation { left, .. } => {
+            fetch_set_expr_labels(storage, left, filter_context).await
+        }
     }
 }
 
 async fn fetch_join_columns<'a, T: GStore>(
     storage: &T,
     joins: &'a [Join],
+    filter_context: &Option<Rc<RowContext<'a>>>,
 ) -> Result<Option<Vec<(&'a String, Vec<String>)>>> {
     let columns = stream::iter(joins)
         .filter_map(|join| async {
             let relation = &join.relation;
             let alias = get_alias(relation);
 
-            fetch_relation_columns(storage, relation)
+            fetch_relation_columns(storage, relation, filter_context)
                 .await
                 .map(|columns| Some((alias, columns?)))
                 .transpose()
@@ -522,15 +853,16 @@ async fn fetch_join_columns<'a, T: GStore>(
     Ok((columns.len() == joins.len()).then_some(columns))
 }
 
-pub async fn fetch_labels<T: GStore>(
+pub async fn fetch_labels<'a, T: GStore>(
     storage: &T,
-    relation: &TableFactor,
-    joins: &[Join],
+    relation: &'a TableFactor,
+    joins: &'a [Join],
     projection: &[SelectItem],
+    filter_context: &Option<Rc<RowContext<'a>>>,
 ) -> Result<Option<Vec<String>>> {
     let table_alias = get_alias(relation);
-    let columns = fetch_relation_columns(storage, relation).await?;
-    let join_columns = fetch_join_columns(storage, joins).await?;
+    let columns = fetch_relation_columns(storage, relation, filter_context).await?;
+    let join_columns = fetch_join_columns(storage, joins, filter_context).await?;
 
     if (columns.is_none() || join_columns.is_none())
         && projection.iter().any(|item| {
@@ -1,18 +1,32 @@
 use {
     super::{
+        context::RowContext,
+        filter::check_expr,
+        returning::returning,
         select::select,
-        validate::{validate_unique, ColumnValidation},
+        sequence::{identity_sequence_name, next_value, resolve_sequences},
+        trigger::{fire_triggers, TriggerRow},
+        update::{Update, UpdateError},
+        validate::{validate_check, validate_unique, ColumnValidation},
+        Payload,
     },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, ForeignKey, Query, SetExpr, Values},
-        data::{Key, Row, Schema, Value},
-        executor::{evaluate::evaluate_stateless, limit::Limit},
+        ast::{
+            Assignment, AstLiteral, Check, ColumnDef, ColumnIdentityOption, ColumnUniqueOption,
+            Expr, ForeignKey, OnConflict, OnConflictAction, Query, SelectItem, SetExpr,
+            TriggerEvent, TriggerTiming, Values,
+        },
+        data::{primary_key_indexes, Key, Row, Schema, Value},
+        executor::{
+            evaluate::{evaluate_stateless_with, Evaluated},
+            limit::Limit,
+        },
         result::Result,
         store::{DataRow, GStore, GStoreMut},
     },
     futures::stream::{self, StreamExt, TryStreamExt},
     serde::Serialize,
-    std::{fmt::Debug, rc::Rc},
+    std::{borrow::Cow, fmt::Debug, rc::Rc},
     thiserror::Error as ThisError,
 };
 
@@ -48,6 +62,15 @@ pub enum InsertError {
 
     #[error("unreachable referencing column name: {0}")]
     ConflictReferencingColumnName(String),
+
+    #[error("no unique or primary key constraint matches the conflict target: {0}")]
+    ConflictTargetNotUnique(String),
+
+    #[error("ON CONFLICT DO UPDATE cannot affect the row '{0:?}' a second time")]
+    ConflictRowAffectedTwice(Key),
+
+    #[error("cannot insert a value into column generated always as identity: {0}")]
+    IdentityColumnAlwaysGenerated(String),
 }
 
 enum RowsData {
@@ -55,44 +78,172 @@ enum RowsData {
     Insert(Vec<(Key, DataRow)>),
 }
 
+/// Stored row updated by `ON CONFLICT DO UPDATE`, `old` holds the values before the update.
+struct UpdatedRow {
+    key: Key,
+    old: Vec<Value>,
+    new: DataRow,
+}
+
+enum ConflictTarget {
+    /// key indexes
+    PrimaryKey(Vec<usize>),
+    /// unique column index
+    Unique(usize),
+}
+
 pub async fn insert<T: GStore + GStoreMut>(
     storage: &mut T,
     table_name: &str,
     columns: &[String],
     source: &Query,
-) -> Result<usize> {
+    on_conflict: Option<&OnConflict>,
+    select_items: &[SelectItem],
+    context: Option<Rc<RowContext<'_>>>,
+) -> Result<Payload> {
     let Schema {
         column_defs,
+        primary_key,
         foreign_keys,
+        checks,
+        triggers,
         ..
     } = storage
         .fetch_schema(table_name)
         .await?
         .ok_or_else(|| InsertError::TableNotFound(table_name.to_owned()))?;
 
-    let rows = match column_defs {
-        Some(column_defs) => {
+    let labels = column_defs
+        .as_ref()
+        .map(|column_defs| {
+            column_defs
+                .iter()
+                .map(|column_def| column_def.name.to_owned())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let labels = Rc::<[String]>::from(labels);
+
+    let (rows, updated_rows) = match (column_defs, on_conflict) {
+        (Some(column_defs), _) => {
             fetch_vec_rows(
                 storage,
                 table_name,
                 column_defs,
+                primary_key,
                 columns,
                 source,
                 foreign_keys,
+                checks,
+                on_conflict,
+                context.as_ref().map(Rc::clone),
             )
             .await
         }
-        None => fetch_map_rows(storage, source).await.map(RowsData::Append),
+        (None, Some(OnConflict { columns, .. })) if !columns.is_empty() => {
+            Err(InsertError::ConflictTargetNotUnique(columns.join(", ")).into())
+        }
+        (None, _) => fetch_map_rows(storage, source, context.as_ref().map(Rc::clone))
+            .await
+            .map(|rows| (RowsData::Append(rows), Vec::new())),
     }?;
 
-    match rows {
+    let into_row = |data_row: &DataRow| match data_row {
+        DataRow::Vec(values) => Row::Vec {
+            columns: Rc::clone(&labels),
+            values: values.clone(),
+        },
+        DataRow::Map(values) => Row::Map(values.clone()),
+    };
+
+    let (inserted_rows, changed_rows) = match (triggers.is_empty(), select_items.is_empty()) {
+        (true, true) => (Vec::new(), Vec::new()),
+        _ => {
+            let inserted_rows = match &rows {
+                RowsData::Append(rows) => rows.iter().map(into_row).collect::<Vec<_>>(),
+                RowsData::Insert(rows) => rows
+                    .iter()
+                    .map(|(_, row)| into_row(row))
+                    .collect::<Vec<_>>(),
+            };
+            let changed_rows = updated_rows
+                .iter()
+                .map(|UpdatedRow { old, new, .. }| {
+                    let old = Row::Vec {
+                        columns: Rc::clone(&labels),
+                        values: old.clone(),
+                    };
+
+                    (old, into_row(new))
+                })
+                .collect::<Vec<_>>();
+
+            (inserted_rows, changed_rows)
+        }
+    };
+
+    let returned_rows = match select_items.is_empty() {
+        true => Vec::new(),
+        false => inserted_rows
+            .iter()
+            .chain(changed_rows.iter().map(|(_, new)| new))
+            .cloned()
+            .collect(),
+    };
+
+    let depth = context.as_deref().map_or(0, RowContext::trigger_depth);
+    let insert_trigger_rows = inserted_rows
+        .iter()
+        .map(|row| TriggerRow {
+            old: None,
+            new: Some(row),
+        })
+        .collect::<Vec<_>>();
+    let update_trigger_rows = changed_rows
+        .iter()
+        .map(|(old, new)| TriggerRow {
+            old: Some(old),
+            new: Some(new),
+        })
+        .collect::<Vec<_>>();
+
+    fire_triggers(
+        storage,
+        &triggers,
+        TriggerTiming::Before,
+        TriggerEvent::Insert,
+        &insert_trigger_rows,
+        depth,
+    )
+    .await?;
+    fire_triggers(
+        storage,
+        &triggers,
+        TriggerTiming::Before,
+        TriggerEvent::Update,
+        &update_trigger_rows,
+        depth,
+    )
+    .await?;
+
+    let num_updated_rows = updated_rows.len();
+    if num_updated_rows > 0 {
+        let updated_rows = updated_rows
+            .into_iter()
+            .map(|UpdatedRow { key, new, .. }| (key, new))
+            .collect();
+
+        storage.insert_data(table_name, updated_rows).await?;
+    }
+
+    let num_rows = match rows {
         RowsData::Append(rows) => {
             let num_rows = rows.len();
 
             storage
                 .append_data(table_name, rows)
                 .await
-                .map(|_| num_rows)
+                .map(|_| num_rows + num_updated_rows)
         }
         RowsData::Insert(rows) => {
             let num_rows = rows.len();
@@ -100,78 +251,137 @@ pub async fn insert<T: GStore + GStoreMut>(
             storage
                 .insert_data(table_name, rows)
                 .await
-                .map(|_| num_rows)
+                .map(|_| num_rows + num_updated_rows)
         }
+    }?;
+
+    fire_triggers(
+        storage,
+        &triggers,
+        TriggerTiming::After,
+        TriggerEvent::Insert,
+        &insert_trigger_rows,
+        depth,
+    )
+    .await?;
+    fire_triggers(
+        storage,
+        &triggers,
+        TriggerTiming::After,
+        TriggerEvent::Update,
+        &update_trigger_rows,
+        depth,
+    )
+    .await?;
+
+    match select_items.is_empty() {
+        true => Ok(Payload::Insert(num_rows)),
+        false => returning(storage, table_name, select_items, returned_rows, context).await,
     }
 }
 
-async fn fetch_vec_rows<T: GStore>(
-    storage: &T,
+async fn fetch_vec_rows<T: GStore + GStoreMut>(
+    storage: &mut T,
     table_name: &str,
     column_defs: Vec<ColumnDef>,
+    primary_key: Vec<String>,
     columns: &[String],
     source: &Query,
     foreign_keys: Vec<ForeignKey>,
-) -> Result<RowsData> {
+    checks: Vec<Check>,
+    on_conflict: Option<&OnConflict>,
+    context: Option<Rc<RowContext<'_>>>,
+) -> Result<(RowsData, Vec<UpdatedRow>)> {
     let labels = Rc::from(
         column_defs
             .iter()
             .map(|column_def| column_def.name.to_owned())
             .collect::<Vec<_>>(),
     );
+    let key_indexes = primary_key_indexes(&column_defs, &primary_key);
     let column_defs = Rc::from(column_defs);
-    let column_validation = ColumnValidation::All(&column_defs);
-
-    #[derive(futures_enum::Stream)]
-    enum Rows<I1, I2> {
-        Values(I1),
-        Select(I2),
-    }
+    let column_validation = ColumnValidation::All(&column_defs, &primary_key);
 
     let rows = match &source.body {
         SetExpr::Values(Values(values_list)) => {
-            let limit = Limit::new(source.limit.as_ref(), source.offset.as_ref()).await?;
-            let rows = stream::iter(values_list).then(|values| {
-                let column_defs = Rc::clone(&column_defs);
-                let labels = Rc::clone(&labels);
-
-                async move {
-                    Ok(Row::Vec {
-                        columns: labels,
-                        values: fill_values(&column_defs, columns, values).await?,
-                    })
-                }
-            });
-            let rows = limit.apply(rows);
-            let rows = rows.map(|row| row?.try_into_vec());
+            let limit = Limit::new(
+                source.limit.as_ref(),
+                source.offset.as_ref(),
+                context.as_ref().map(Rc::clone),
+            )
+            .await?;
+            let values_list = limit
+                .apply(stream::iter(values_list))
+                .collect::<Vec<_>>()
+                .await;
+
+            // rows are filled one by one as NEXTVAL writes to the storage
+            let mut rows = Vec::with_capacity(values_list.len());
+            for values in values_list {
+                let context = context.as_ref().map(Rc::clone);
+                let values =
+                    fill_values(storage, table_name, &column_defs, columns, values, context)
+                        .await?;
+
+                rows.push(values);
+            }
 
-            Rows::Values(rows)
+            rows
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
-                let values = row?.try_into_vec()?;
-
-                column_defs
-                    .iter()
-                    .zip(values.iter())
-                    .try_for_each(|(column_def, value)| {
-                        let ColumnDef {
-                            data_type,
-                            nullable,
-                            ..
-                        } = column_def;
-
-                        value.validate_type(data_type)?;
-                        value.validate_null(*nullable)
-                    })?;
-
-                Ok(values)
-            });
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
+            select(&*storage, source, context.as_ref().map(Rc::clone))
+                .await?
+                .map(|row| {
+                    let values = row?.try_into_vec()?;
+
+                    column_defs
+                        .iter()
+                        .zip(values.iter())
+                        .try_for_each(|(column_def, value)| {
+                            let ColumnDef {
+                                data_type,
+                                nullable,
+                                ..
+                            } = column_def;
+
+                            value.validate_type(data_type)?;
+                            value.validate_null(*nullable)
+                        })?;
+
+                    Ok(values)
+                })
+                .try_collect::<Vec<Vec<Value>>>()
+                .await?
+        }
+    };
 
-            Rows::Select(rows)
+    let storage = &*storage;
+    let (rows, updated_rows) = match on_conflict {
+        Some(on_conflict) => {
+            resolve_conflicts(
+                storage,
+                table_name,
+                &column_defs,
+                &key_indexes,
+                &labels,
+                &foreign_keys,
+                &checks,
+                on_conflict,
+                rows,
+                context,
+            )
+            .await?
         }
-    }
-    .try_collect::<Vec<Vec<Value>>>()
+        None => (rows, Vec::new()),
+    };
+
+    validate_check(
+        storage,
+        table_name,
+        &column_defs,
+        &checks,
+        rows.iter().map(|values| values.as_slice()),
+    )
     .await?;
 
     validate_unique(
@@ -184,23 +394,263 @@ async fn fetch_vec_rows<T: GStore>(
 
     validate_foreign_key(storage, &column_defs, foreign_keys, &rows).await?;
 
-    let primary_key = column_defs.iter().position(|ColumnDef { unique, .. }| {
-        unique == &Some(ColumnUniqueOption { is_primary: true })
-    });
-
-    match primary_key {
-        Some(i) => rows
+    let rows = match key_indexes.is_empty() {
+        false => rows
             .into_iter()
             .filter_map(|values| {
-                values
-                    .get(i)
-                    .map(Key::try_from)
-                    .map(|result| result.map(|key| (key, values.into())))
+                key_indexes
+                    .iter()
+                    .map(|i| values.get(*i).map(Key::try_from))
+                    .collect::<Option<Result<Vec<_>>>>()
+                    .map(|keys| keys.map(|keys| (Key::from_primary_keys(keys), values.into())))
             })
             .collect::<Result<Vec<_>>>()
-            .map(RowsData::Insert),
-        None => Ok(RowsData::Append(rows.into_iter().map(Into::into).collect())),
+            .map(RowsData::Insert)?,
+        true => RowsData::Append(rows.into_iter().map(Into::into).collect()),
+    };
+
+    Ok((rows, updated_rows))
+}
+
+/// Separates the rows conflicting with a stored row on the conflict target from the rows to insert,
+/// conflicting rows are skipped by `DO NOTHING` and turn into updates of the stored row by `DO UPDATE`.
+async fn resolve_conflicts<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    primary_key: &[usize],
+    labels: &Rc<[String]>,
+    foreign_keys: &[ForeignKey],
+    checks: &[Check],
+    on_conflict: &OnConflict,
+    rows: Vec<Vec<Value>>,
+    context: Option<Rc<RowContext<'_>>>,
+) -> Result<(Vec<Vec<Value>>, Vec<UpdatedRow>)> {
+    let OnConflict { columns, action } = on_conflict;
+    let targets = fetch_conflict_targets(column_defs, primary_key, columns)?;
+
+    let has_unique_target = targets
+        .iter()
+        .any(|target| matches!(target, ConflictTarget::Unique(_)));
+    let stored_rows = match has_unique_target {
+        true => {
+            storage
+                .scan_data(table_name)
+                .await?
+                .try_collect::<Vec<_>>()
+                .await?
+        }
+        false => Vec::new(),
+    };
+
+    let (assignments, selection) = match action {
+        OnConflictAction::DoNothing => (None, None),
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => (Some(assignments), selection.as_ref()),
+    };
+    let primary_key_assignment =
+        assignments
+            .into_iter()
+            .flatten()
+            .find(|Assignment { id, .. }| {
+                column_defs.iter().any(|ColumnDef { name, unique, .. }| {
+                    name == id && unique == &Some(ColumnUniqueOption { is_primary: true })
+                })
+            });
+    if let Some(Assignment { id, .. }) = primary_key_assignment {
+        return Err(UpdateError::UpdateOnPrimaryKeyNotSupported(id.to_owned()).into());
+    }
+
+    let update = assignments
+        .map(|assignments| {
+            let context = context.as_ref().map(Rc::clone);
+
+            Update::new(storage, table_name, assignments, Some(column_defs), context)
+        })
+        .transpose()?;
+
+    let mut inserted_rows = Vec::new();
+    let mut updated_rows: Vec<(Key, Vec<Value>, Vec<Value>)> = Vec::new();
+
+    for values in rows {
+        let Some((key, stored_values)) =
+            find_conflict(storage, table_name, &targets, &stored_rows, &values).await?
+        else {
+            inserted_rows.push(values);
+            continue;
+        };
+
+        let Some(update) = &update else {
+            continue;
+        };
+
+        if updated_rows
+            .iter()
+            .any(|(updated_key, _, _)| updated_key == &key)
+        {
+            return Err(InsertError::ConflictRowAffectedTwice(key).into());
+        }
+
+        let excluded = Row::Vec {
+            columns: Rc::clone(labels),
+            values,
+        };
+        let excluded = RowContext::new(
+            "excluded",
+            Cow::Owned(excluded),
+            context.as_ref().map(Rc::clone),
+        );
+        let excluded = Rc::new(excluded);
+        let stored = Row::Vec {
+            columns: Rc::clone(labels),
+            values: stored_values.clone(),
+        };
+
+        if let Some(expr) = selection {
+            let context = RowContext::new(
+                table_name,
+                Cow::Borrowed(&stored),
+                Some(Rc::clone(&excluded)),
+            );
+
+            if !check_expr(storage, Some(Rc::new(context)), None, expr).await? {
+                continue;
+            }
+        }
+
+        let values = update
+            .apply_with_context(stored, Some(excluded), foreign_keys)
+            .await?
+            .try_into_vec()?;
+
+        updated_rows.push((key, stored_values, values));
+    }
+
+    if let (OnConflictAction::DoUpdate { assignments, .. }, false) =
+        (action, updated_rows.is_empty())
+    {
+        let columns = assignments
+            .iter()
+            .map(|Assignment { id, .. }| id.to_owned())
+            .collect();
+        let column_validation = ColumnValidation::SpecifiedColumns(column_defs, columns);
+        let rows = updated_rows.iter().map(|(_, _, values)| values.as_slice());
+
+        validate_check(storage, table_name, column_defs, checks, rows.clone()).await?;
+        validate_unique(storage, table_name, column_validation, rows).await?;
+    }
+
+    let updated_rows = updated_rows
+        .into_iter()
+        .map(|(key, old, new)| UpdatedRow {
+            key,
+            old,
+            new: DataRow::Vec(new),
+        })
+        .collect();
+
+    Ok((inserted_rows, updated_rows))
+}
+
+fn fetch_conflict_targets(
+    column_defs: &[ColumnDef],
+    primary_key: &[usize],
+    columns: &[String],
+) -> Result<Vec<ConflictTarget>> {
+    let unique_columns = column_defs
+        .iter()
+        .enumerate()
+        .filter(|(_, ColumnDef { unique, .. })| {
+            unique == &Some(ColumnUniqueOption { is_primary: false })
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    if columns.is_empty() {
+        let primary_key =
+            (!primary_key.is_empty()).then(|| ConflictTarget::PrimaryKey(primary_key.to_vec()));
+        let targets = primary_key
+            .into_iter()
+            .chain(unique_columns.into_iter().map(ConflictTarget::Unique))
+            .collect();
+
+        return Ok(targets);
+    }
+
+    let mut indexes = columns
+        .iter()
+        .map(|column| {
+            column_defs
+                .iter()
+                .position(|ColumnDef { name, .. }| name == column)
+                .ok_or_else(|| InsertError::WrongColumnName(column.to_owned()).into())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    indexes.sort_unstable();
+
+    let mut primary_key_columns = primary_key.to_vec();
+    primary_key_columns.sort_unstable();
+
+    match indexes.as_slice() {
+        indexes if indexes == primary_key_columns.as_slice() => {
+            Ok(vec![ConflictTarget::PrimaryKey(primary_key.to_vec())])
+        }
+        [i] if unique_columns.contains(i) => Ok(vec![ConflictTarget::Unique(*i)]),
+        _ => Err(InsertError::ConflictTargetNotUnique(columns.join(", ")).into()),
+    }
+}
+
+async fn find_conflict<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    targets: &[ConflictTarget],
+    stored_rows: &[(Key, DataRow)],
+    values: &[Value],
+) -> Result<Option<(Key, Vec<Value>)>> {
+    for target in targets {
+        match target {
+            ConflictTarget::PrimaryKey(indexes) => {
+                let key = indexes
+                    .iter()
+                    .map(|i| values.get(*i).map(Key::try_from))
+                    .collect::<Option<Result<Vec<_>>>>()
+                    .transpose()?
+                    .map(Key::from_primary_keys);
+                let Some(key) = key else {
+                    continue;
+                };
+
+                if let Some(DataRow::Vec(stored_values)) =
+                    storage.fetch_data(table_name, &key).await?
+                {
+                    return Ok(Some((key, stored_values)));
+                }
+            }
+            ConflictTarget::Unique(i) => {
+                let value = match values.get(*i) {
+                    Some(value) if !value.is_null() => value,
+                    _ => continue,
+                };
+
+                let conflict = stored_rows
+                    .iter()
+                    .find_map(|(key, data_row)| match data_row {
+                        DataRow::Vec(stored_values) if stored_values.get(*i) == Some(value) => {
+                            Some((key.clone(), stored_values.clone()))
+                        }
+                        _ => None,
+                    });
+
+                if conflict.is_some() {
+                    return Ok(conflict);
+                }
+            }
+        }
     }
+
+    Ok(None)
 }
 
 async fn validate_foreign_key<T: GStore>(
@@ -255,7 +705,11 @@ async fn validate_foreign_key<T: GStore>(
     Ok(())
 }
 
-async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<DataRow>> {
+async fn fetch_map_rows<T: GStore>(
+    storage: &T,
+    source: &Query,
+    context: Option<Rc<RowContext<'_>>>,
+) -> Result<Vec<DataRow>> {
     #[derive(futures_enum::Stream)]
     enum Rows<I1, I2> {
         Values(I1),
@@ -264,13 +718,19 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
 
     let rows = match &source.body {
         SetExpr::Values(Values(values_list)) => {
-            let limit = Limit::new(source.limit.as_ref(), source.offset.as_ref()).await?;
+            let limit = Limit::new(
+                source.limit.as_ref(),
+                source.offset.as_ref(),
+                context.as_ref().map(Rc::clone),
+            )
+            .await?;
+            let context = &context;
             let rows = stream::iter(values_list).then(|values| async move {
                 if values.len() > 1 {
                     return Err(InsertError::OnlySingleValueAcceptedForSchemalessRow.into());
                 }
 
-                evaluate_stateless(None, &values[0])
+                evaluate_stateless_with(context.as_ref().map(Rc::clone), &values[0])
                     .await?
                     .try_into()
                     .map(Row::Map)
@@ -280,8 +740,8 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
 
             Rows::Values(rows)
         }
-        SetExpr::Select(_) => {
-            let rows = select(storage, source, None).await?.map(|row| {
+        SetExpr::Select(_) | SetExpr::SetOperation { .. } => {
+            let rows = select(storage, source, context).await?.map(|row| {
                 let row = row?;
 
                 if let Row::Vec { values, .. } = &row {
@@ -304,10 +764,13 @@ async fn fetch_map_rows<T: GStore>(storage: &T, source: &Query) -> Result<Vec<Da
     Ok(rows)
 }
 
-async fn fill_values(
+async fn fill_values<T: GStore + GStoreMut>(
+    storage: &mut T,
+    table_name: &str,
     column_defs: &[ColumnDef],
     columns: &[String],
     values: &[Expr],
+    context: Option<Rc<RowContext<'_>>>,
 ) -> Result<Vec<Value>> {
     if !columns.is_empty() && values.len() != columns.len() {
         return Err(InsertError::ColumnAndValuesNotMatched.into());
@@ -337,36 +800,67 @@ async fn fill_values(
 
     let column_name_value_list = columns.zip(values.iter()).collect::<Vec<(_, _)>>();
 
-    let values = stream::iter(column_defs)
-        .then(|column_def| {
-            let column_name_value_list = &column_name_value_list;
+    if let Some((name, _)) = column_name_value_list.iter().find(|(name, _)| {
+        column_defs.iter().any(|column_def| {
+            &&column_def.name == name
+                && matches!(
+                    column_def.identity,
+                    Some(ColumnIdentityOption { always: true, .. })
+                )
+        })
+    }) {
+        return Err(InsertError::IdentityColumnAlwaysGenerated(name.to_string()).into());
+    }
 
-            async move {
-                let ColumnDef {
-                    name: def_name,
-                    data_type,
-                    nullable,
-                    ..
-                } = column_def;
+    let mut values = Vec::with_capacity(column_defs.len());
+    for column_def in column_defs {
+        let ColumnDef {
+            name: def_name,
+            data_type,
+            nullable,
+            default,
+            identity,
+            ..
+        } = column_def;
 
-                let value = column_name_value_list
-                    .iter()
-                    .find(|(name, _)| name == &def_name)
-                    .map(|(_, value)| value);
-
-                match (value, &column_def.default, nullable) {
-                    (Some(&expr), _, _) | (None, Some(expr), _) => evaluate_stateless(None, expr)
-                        .await?
-                        .try_into_value(data_type, *nullable),
-                    (None, None, true) => Ok(Value::Null),
-                    (None, None, false) => {
-                        Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into())
-                    }
-                }
+        let value = column_name_value_list
+            .iter()
+            .find(|(name, _)| name == &def_name)
+            .map(|(_, value)| *value);
+
+        let mut expr = match (value, default, identity) {
+            (Some(expr), _, _) | (None, Some(expr), _) => expr.clone(),
+            (None, None, Some(_)) => {
+                let sequence_name = identity_sequence_name(table_name, def_name);
+                let value = next_value(storage, &sequence_name).await?;
+
+                Expr::Literal(AstLiteral::Number(value.into()))
             }
-        })
-        .try_collect::<Vec<Value>>()
-        .await?;
+            (None, None, None) if *nullable => {
+                values.push(Value::Null);
+                continue;
+            }
+            (None, None, None) => {
+                return Err(InsertError::LackOfRequiredColumn(def_name.to_owned()).into());
+            }
+        };
+
+        resolve_sequences(storage, &mut expr).await?;
+
+        let evaluated = evaluate_stateless_with(context.as_ref().map(Rc::clone), &expr).await?;
+        let value = match (&expr, evaluated) {
+            // a bound parameter takes the type of its column, as a literal does
+            (Expr::Parameter(_), Evaluated::Value(value)) => {
+                let value = value.cast(data_type)?;
+                value.validate_null(*nullable)?;
+
+                value
+            }
+            (_, evaluated) => evaluated.try_into_value(data_type, *nullable)?,
+        };
+
+        values.push(value);
+    }
 
     Ok(values)
 }
@@ -1,26 +1,35 @@
 use {
-    super::fetch::{fetch_relation_columns, fetch_relation_rows},
+    super::{
+        explain::{measure, Probe},
+        fetch::{fetch_relation_columns, fetch_relation_rows},
+    },
     crate::{
         ast::{
             Expr, Join as AstJoin, JoinConstraint, JoinExecutor as AstJoinExecutor,
-            JoinOperator as AstJoinOperator, TableFactor,
+            JoinOperator as AstJoinOperator, TableFactor, TableWithJoins,
         },
         data::{get_alias, Key, Row, Value},
         executor::{context::RowContext, evaluate::evaluate, filter::check_expr},
-        result::Result,
+        result::{Error, Result},
         store::GStore,
     },
     futures::{
         future,
-        stream::{self, empty, once, Stream, StreamExt, TryStreamExt},
+        stream::{self, once, Stream, StreamExt, TryStreamExt},
+    },
+    std::{
+        borrow::Cow,
+        cell::RefCell,
+        collections::{HashMap, HashSet},
+        pin::Pin,
+        rc::Rc,
     },
-    itertools::Itertools,
-    std::{borrow::Cow, collections::HashMap, pin::Pin, rc::Rc},
     utils::OrStream,
 };
 
 pub struct Join<'a, T: GStore> {
     storage: &'a T,
+    relation: &'a TableFactor,
     join_clauses: &'a [AstJoin],
     filter_context: Option<Rc<RowContext<'a>>>,
 }
@@ -31,11 +40,13 @@ type Joined<'a> = Pin<Box<dyn Stream<Item = Result<JoinItem<'a>>> + 'a>>;
 impl<'a, T: GStore> Join<'a, T> {
     pub fn new(
         storage: &'a T,
+        relation: &'a TableFactor,
         join_clauses: &'a [AstJoin],
         filter_context: Option<Rc<RowContext<'a>>>,
     ) -> Self {
         Self {
             storage,
+            relation,
             join_clauses,
             filter_context,
         }
@@ -44,23 +55,140 @@ impl<'a, T: GStore> Join<'a, T> {
     pub async fn apply(
         self,
         rows: impl Stream<Item = Result<RowContext<'a>>> + 'a,
+        probes: Option<&'a [Probe]>,
     ) -> Result<Joined<'a>> {
-        let init_rows: Joined = Box::pin(rows.map(|row| row.map(Rc::new)));
+        let mut rows: Joined = Box::pin(rows.map(|row| row.map(Rc::new)));
+        let mut relations = vec![self.relation];
 
-        stream::iter(self.join_clauses)
-            .map(Ok)
-            .try_fold(init_rows, |rows, join_clause| {
-                let filter_context = self.filter_context.as_ref().map(Rc::clone);
+        for (i, join_clause) in self.join_clauses.iter().enumerate() {
+            let filter_context = self.filter_context.as_ref().map(Rc::clone);
 
-                async move { join(self.storage, filter_context, join_clause, rows).await }
-            })
-            .await
+            rows = join(self.storage, filter_context, &relations, join_clause, rows).await?;
+            if let Some(probe) = probes.and_then(|probes| probes.get(i)) {
+                rows = Box::pin(measure(rows, Some(probe)));
+            }
+            relations.push(&join_clause.relation);
+        }
+
+        Ok(rows)
     }
 }
 
+/// Joins the `targets` of `UPDATE ... FROM` or `DELETE ... USING` with the rows of `from` at once,
+/// `target_join` is the planned join of the target table which follows every relation of `from`.
+///
+/// Returns the first match of each target in the order of the joined rows of `from`,
+/// `None` for the targets without any match.
+/// The target row is resolved before the joined rows by the columns which both of them have.
+pub async fn join_targets<'a, T: GStore>(
+    storage: &'a T,
+    targets: Vec<Row>,
+    from: &'a TableWithJoins,
+    target_join: &'a AstJoin,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<Vec<Option<Rc<RowContext<'a>>>>> {
+    let AstJoin {
+        relation: target,
+        join_operator,
+        join_executor,
+    } = target_join;
+    let where_clause = match join_operator {
+        AstJoinOperator::Inner(JoinConstraint::On(expr)) => Some(expr),
+        _ => None,
+    };
+    let table_alias = get_alias(target);
+    let join_executor = match join_executor {
+        AstJoinExecutor::NestedLoop => JoinExecutor::Materialized(targets),
+        AstJoinExecutor::Hash {
+            key_expr,
+            value_expr,
+            where_clause,
+        } => {
+            let rows = stream::iter(targets.into_iter().map(Ok));
+
+            JoinExecutor::hash(
+                storage,
+                table_alias,
+                rows,
+                filter_context.as_ref().map(Rc::clone),
+                (key_expr, value_expr, where_clause),
+                true,
+            )
+            .await?
+        }
+    };
+    let rows: &[Row] = match &join_executor {
+        JoinExecutor::Materialized(rows) | JoinExecutor::Hash { rows, .. } => rows,
+        JoinExecutor::NestedLoop => &[],
+    };
+
+    let TableWithJoins { relation, joins } = from;
+    let joined = fetch_relation_rows(storage, relation, &filter_context)
+        .await?
+        .map(move |row| {
+            let row = row?;
+            let alias = get_alias(relation);
+
+            Ok(RowContext::new(alias, Cow::Owned(row), None))
+        });
+    let mut joined = Join::new(
+        storage,
+        relation,
+        joins,
+        filter_context.as_ref().map(Rc::clone),
+    )
+    .apply(joined, None)
+    .await?;
+
+    let mut matches = vec![None; rows.len()];
+    while let Some(project_context) = joined.try_next().await? {
+        let filter_context = match &filter_context {
+            Some(filter_context) => Rc::new(RowContext::concat(
+                Rc::clone(&project_context),
+                Rc::clone(filter_context),
+            )),
+            None => project_context,
+        };
+        let candidates = match &join_executor {
+            JoinExecutor::Hash {
+                rows_map,
+                value_expr,
+                ..
+            } => {
+                let hash_key =
+                    evaluate(storage, Some(Rc::clone(&filter_context)), None, value_expr)
+                        .await
+                        .map(Key::try_from)??;
+
+                rows_map.get(&hash_key).cloned().unwrap_or_default()
+            }
+            JoinExecutor::Materialized(_) | JoinExecutor::NestedLoop => (0..rows.len()).collect(),
+        };
+
+        for index in candidates {
+            if matches[index].is_some() {
+                continue;
+            }
+
+            matches[index] = check_where_clause(
+                storage,
+                table_alias,
+                Some(Rc::clone(&filter_context)),
+                Some(Rc::clone(&filter_context)),
+                where_clause,
+                Cow::Borrowed(&rows[index]),
+            )
+            .await?;
+        }
+    }
+
+    Ok(matches)
+}
+
 async fn join<'a, T: GStore>(
     storage: &'a T,
     filter_context: Option<Rc<RowContext<'a>>>,
+    left_relations: &[&'a TableFactor],
     ast_join: &'a AstJoin,
     left_rows: impl Stream<Item = Result<JoinItem<'a>>> + 'a,
 ) -> Result<Joined<'a>> {
@@ -70,48 +198,79 @@ async fn join<'a, T: GStore>(
         join_executor,
     } = ast_join;
 
+    let (join_operator, where_clause) = match join_operator {
+        AstJoinOperator::Inner(JoinConstraint::None) => (JoinOperator::Inner, None),
+        AstJoinOperator::Inner(JoinConstraint::On(where_clause)) => {
+            (JoinOperator::Inner, Some(where_clause))
+        }
+        AstJoinOperator::LeftOuter(JoinConstraint::None) => (JoinOperator::LeftOuter, None),
+        AstJoinOperator::LeftOuter(JoinConstraint::On(where_clause)) => {
+            (JoinOperator::LeftOuter, Some(where_clause))
+        }
+        AstJoinOperator::RightOuter(JoinConstraint::None) => (JoinOperator::RightOuter, None),
+        AstJoinOperator::RightOuter(JoinConstraint::On(where_clause)) => {
+            (JoinOperator::RightOuter, Some(where_clause))
+        }
+        AstJoinOperator::FullOuter(JoinConstraint::None) => (JoinOperator::FullOuter, None),
+        AstJoinOperator::FullOuter(JoinConstraint::On(where_clause)) => {
+            (JoinOperator::FullOuter, Some(where_clause))
+        }
+    };
+
     let table_alias = get_alias(relation);
     let join_executor = JoinExecutor::new(
         storage,
         relation,
         filter_context.as_ref().map(Rc::clone),
         join_executor,
+        join_operator.keeps_right(),
     )
     .await
     .map(Rc::new)?;
+    let matched = Rc::new(RefCell::new(HashSet::new()));
+    let null_context = match join_operator.keeps_right() {
+        true => null_context(storage, left_relations, &filter_context).await?,
+        false => None,
+    };
+    let unmatched = {
+        let join_executor = Rc::clone(&join_executor);
+        let matched = Rc::clone(&matched);
 
-    let (join_operator, where_clause) = match join_operator {
-        AstJoinOperator::Inner(JoinConstraint::None) => (JoinOperator::Inner, None),
-        AstJoinOperator::Inner(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::Inner, Some(where_clause))
-        }
-        AstJoinOperator::LeftOuter(JoinConstraint::None) => (JoinOperator::LeftOuter, None),
-        AstJoinOperator::LeftOuter(JoinConstraint::On(where_clause)) => {
-            (JoinOperator::LeftOuter, Some(where_clause))
-        }
+        stream::once(async move {
+            let matched = matched.borrow();
+            let rows: &[Row] = match join_executor.as_ref() {
+                JoinExecutor::Materialized(rows) | JoinExecutor::Hash { rows, .. } => rows,
+                JoinExecutor::NestedLoop => &[],
+            };
+            let rows = rows
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !matched.contains(index))
+                .map(|(_, row)| {
+                    let row = Cow::Owned(row.clone());
+                    let next = null_context.as_ref().map(Rc::clone);
+
+                    Ok(Rc::new(RowContext::new(table_alias, row, next)))
+                })
+                .collect::<Vec<_>>();
+
+            Ok::<_, Error>(stream::iter(rows))
+        })
+        .try_flatten()
     };
 
-    let columns = fetch_relation_columns(storage, relation)
+    let columns = fetch_relation_columns(storage, relation, &filter_context)
         .await?
         .map(Rc::from);
     let rows = left_rows.and_then(move |project_context| {
-        let init_context = {
-            let init_row = match columns.as_ref() {
-                Some(columns) => Row::Vec {
-                    columns: Rc::clone(columns),
-                    values: columns.iter().map(|_| Value::Null).collect(),
-                },
-                None => Row::Map(HashMap::new()),
-            };
-
-            Rc::new(RowContext::new(
-                table_alias,
-                Cow::Owned(init_row),
-                Some(Rc::clone(&project_context)),
-            ))
-        };
+        let init_context = Rc::new(RowContext::new(
+            table_alias,
+            Cow::Owned(null_row(columns.as_ref())),
+            Some(Rc::clone(&project_context)),
+        ));
         let filter_context = filter_context.as_ref().map(Rc::clone);
         let join_executor = Rc::clone(&join_executor);
+        let matched = join_operator.keeps_right().then(|| Rc::clone(&matched));
 
         async move {
             let filter_context = match filter_context {
@@ -124,13 +283,40 @@ async fn join<'a, T: GStore>(
             let filter_context = Some(filter_context);
 
             #[derive(futures_enum::Stream)]
-            enum Rows<I1, I2, I3> {
+            enum Rows<I1, I2> {
                 NestedLoop(I1),
-                Hash(I2),
-                Empty(I3),
+                Collected(I2),
             }
-            let rows = match join_executor.as_ref() {
-                JoinExecutor::NestedLoop => {
+            let candidates = match join_executor.as_ref() {
+                JoinExecutor::NestedLoop => None,
+                JoinExecutor::Materialized(rows) => Some(rows.iter().enumerate().collect()),
+                JoinExecutor::Hash {
+                    rows,
+                    rows_map,
+                    value_expr,
+                } => {
+                    let hash_key = evaluate(
+                        storage,
+                        filter_context.as_ref().map(Rc::clone),
+                        None,
+                        value_expr,
+                    )
+                    .await
+                    .map(Key::try_from)??;
+
+                    let candidates = match rows_map.get(&hash_key) {
+                        Some(indexes) => {
+                            indexes.iter().map(|&index| (index, &rows[index])).collect()
+                        }
+                        None => Vec::new(),
+                    };
+
+                    Some(candidates)
+                }
+            };
+
+            let rows = match candidates {
+                None => {
                     let rows = fetch_relation_rows(storage, relation, &filter_context)
                         .await?
                         .and_then(|row| future::ok(Cow::Owned(row)))
@@ -146,53 +332,42 @@ async fn join<'a, T: GStore>(
                         });
                     Rows::NestedLoop(rows)
                 }
-                JoinExecutor::Hash {
-                    rows_map,
-                    value_expr,
-                } => {
-                    let rows = evaluate(
-                        storage,
-                        filter_context.as_ref().map(Rc::clone),
-                        None,
-                        value_expr,
-                    )
-                    .await
-                    .map(Key::try_from)?
-                    .map(|hash_key| rows_map.get(&hash_key))?;
-
-                    match rows {
-                        None => Rows::Empty(empty()),
-                        Some(rows) => {
-                            let rows = stream::iter(rows)
-                                .filter_map(|row| {
-                                    let filter_context = filter_context.as_ref().map(Rc::clone);
-                                    let project_context = Some(Rc::clone(&project_context));
-
-                                    async {
-                                        check_where_clause(
-                                            storage,
-                                            table_alias,
-                                            filter_context,
-                                            project_context,
-                                            where_clause,
-                                            Cow::Borrowed(row),
-                                        )
-                                        .await
-                                        .transpose()
-                                    }
-                                })
-                                .collect::<Vec<_>>()
-                                .await;
-
-                            Rows::Hash(stream::iter(rows))
-                        }
-                    }
+                Some(candidates) => {
+                    let rows = stream::iter(candidates)
+                        .filter_map(|(index, row)| {
+                            let filter_context = filter_context.as_ref().map(Rc::clone);
+                            let project_context = Some(Rc::clone(&project_context));
+                            let matched = matched.as_ref().map(Rc::clone);
+
+                            async move {
+                                let row = check_where_clause(
+                                    storage,
+                                    table_alias,
+                                    filter_context,
+                                    project_context,
+                                    where_clause,
+                                    Cow::Borrowed(row),
+                                )
+                                .await
+                                .transpose();
+
+                                if let (Some(Ok(_)), Some(matched)) = (&row, matched) {
+                                    matched.borrow_mut().insert(index);
+                                }
+
+                                row
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .await;
+
+                    Rows::Collected(stream::iter(rows))
                 }
             };
 
-            let rows: Joined = match join_operator {
-                JoinOperator::Inner => Box::pin(rows),
-                JoinOperator::LeftOuter => {
+            let rows: Joined = match join_operator.keeps_left() {
+                false => Box::pin(rows),
+                true => {
                     let init_rows = once(async { Ok(init_context) });
 
                     Box::pin(OrStream::new(rows, init_rows))
@@ -203,19 +378,74 @@ async fn join<'a, T: GStore>(
         }
     });
 
-    Ok(Box::pin(rows.try_flatten()))
+    let rows = rows.try_flatten();
+
+    match join_operator.keeps_right() {
+        true => Ok(Box::pin(rows.chain(unmatched))),
+        false => Ok(Box::pin(rows)),
+    }
+}
+
+/// Builds the context of NULL rows for every relation on the left side,
+/// which is joined with right rows that matched none of the left rows.
+async fn null_context<'a, T: GStore>(
+    storage: &'a T,
+    relations: &[&'a TableFactor],
+    filter_context: &Option<Rc<RowContext<'a>>>,
+) -> Result<Option<Rc<RowContext<'a>>>> {
+    let mut context = None;
+
+    for relation in relations {
+        let columns = fetch_relation_columns(storage, relation, filter_context)
+            .await?
+            .map(Rc::from);
+        let row = null_row(columns.as_ref());
+
+        context = Some(Rc::new(RowContext::new(
+            get_alias(relation),
+            Cow::Owned(row),
+            context,
+        )));
+    }
+
+    Ok(context)
+}
+
+fn null_row(columns: Option<&Rc<[String]>>) -> Row {
+    match columns {
+        Some(columns) => Row::Vec {
+            columns: Rc::clone(columns),
+            values: columns.iter().map(|_| Value::Null).collect(),
+        },
+        None => Row::Map(HashMap::new()),
+    }
 }
 
 #[derive(Copy, Clone)]
 enum JoinOperator {
     Inner,
     LeftOuter,
+    RightOuter,
+    FullOuter,
+}
+
+impl JoinOperator {
+    fn keeps_left(self) -> bool {
+        matches!(self, Self::LeftOuter | Self::FullOuter)
+    }
+
+    fn keeps_right(self) -> bool {
+        matches!(self, Self::RightOuter | Self::FullOuter)
+    }
 }
 
 enum JoinExecutor<'a> {
     NestedLoop,
+    /// Right rows fetched once, so that the ones without any match can be found afterwards
+    Materialized(Vec<Row>),
     Hash {
-        rows_map: HashMap<Key, Vec<Row>>,
+        rows: Vec<Row>,
+        rows_map: HashMap<Key, Vec<usize>>,
         value_expr: &'a Expr,
     },
 }
@@ -223,11 +453,20 @@ enum JoinExecutor<'a> {
 impl<'a> JoinExecutor<'a> {
     async fn new<T: GStore>(
         storage: &'a T,
-        relation: &TableFactor,
+        relation: &'a TableFactor,
         filter_context: Option<Rc<RowContext<'a>>>,
         ast_join_executor: &'a AstJoinExecutor,
+        keeps_unmatched: bool,
     ) -> Result<JoinExecutor<'a>> {
         let (key_expr, value_expr, where_clause) = match ast_join_executor {
+            AstJoinExecutor::NestedLoop if keeps_unmatched => {
+                let rows = fetch_relation_rows(storage, relation, &filter_context)
+                    .await?
+                    .try_collect()
+                    .await?;
+
+                return Ok(Self::Materialized(rows));
+            }
             AstJoinExecutor::NestedLoop => return Ok(Self::NestedLoop),
             AstJoinExecutor::Hash {
                 key_expr,
@@ -235,41 +474,76 @@ impl<'a> JoinExecutor<'a> {
                 where_clause,
             } => (key_expr, value_expr, where_clause),
         };
+        let rows = fetch_relation_rows(storage, relation, &filter_context).await?;
 
-        let rows_map = fetch_relation_rows(storage, relation, &filter_context)
-            .await?
+        Self::hash(
+            storage,
+            get_alias(relation),
+            rows,
+            filter_context,
+            (key_expr, value_expr, where_clause),
+            keeps_unmatched,
+        )
+        .await
+    }
+
+    /// Builds the hash table of `rows` keyed by `key_expr`, the rows without any key are kept
+    /// only if `keeps_unmatched`, then the indexes of the hashed rows follow `rows`.
+    async fn hash<T: GStore>(
+        storage: &'a T,
+        table_alias: &'a str,
+        rows: impl Stream<Item = Result<Row>>,
+        filter_context: Option<Rc<RowContext<'a>>>,
+        (key_expr, value_expr, where_clause): (&'a Expr, &'a Expr, &'a Option<Expr>),
+        keeps_unmatched: bool,
+    ) -> Result<JoinExecutor<'a>> {
+        let entries = rows
             .try_filter_map(|row| {
                 let filter_context = filter_context.as_ref().map(Rc::clone);
 
                 async move {
-                    let filter_context = Rc::new(RowContext::new(
-                        get_alias(relation),
-                        Cow::Borrowed(&row),
-                        filter_context,
-                    ));
-
-                    let hash_key: Key =
-                        evaluate(storage, Some(Rc::clone(&filter_context)), None, key_expr)
-                            .await?
-                            .try_into()?;
-
-                    if matches!(hash_key, Key::None) {
-                        return Ok(None);
-                    }
-
-                    match where_clause {
-                        Some(expr) => check_expr(storage, Some(filter_context), None, expr)
-                            .await
-                            .map(|pass| pass.then_some((hash_key, row))),
-                        None => Ok(Some((hash_key, row))),
-                    }
+                    let hash_key = {
+                        let filter_context = Rc::new(RowContext::new(
+                            table_alias,
+                            Cow::Borrowed(&row),
+                            filter_context,
+                        ));
+
+                        let hash_key: Key =
+                            evaluate(storage, Some(Rc::clone(&filter_context)), None, key_expr)
+                                .await?
+                                .try_into()?;
+
+                        match (hash_key, where_clause) {
+                            (Key::None, _) => None,
+                            (hash_key, Some(expr)) => {
+                                check_expr(storage, Some(filter_context), None, expr)
+                                    .await?
+                                    .then_some(hash_key)
+                            }
+                            (hash_key, None) => Some(hash_key),
+                        }
+                    };
+
+                    Ok((hash_key.is_some() || keeps_unmatched).then_some((hash_key, row)))
                 }
             })
             .try_collect::<Vec<_>>()
-            .await?
-            .into_iter()
-            .into_group_map();
+            .await?;
+
+        let mut rows = Vec::with_capacity(entries.len());
+        let mut rows_map: HashMap<Key, Vec<usize>> = HashMap::new();
+
+        for (hash_key, row) in entries {
+            if let Some(hash_key) = hash_key {
+                rows_map.entry(hash_key).or_default().push(rows.len());
+            }
+
+            rows.push(row);
+        }
+
         Ok(Self::Hash {
+            rows,
             rows_map,
             value_expr,
         })
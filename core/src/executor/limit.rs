@@ -1,11 +1,12 @@
 use {
-    super::evaluate::evaluate_stateless,
+    super::{context::RowContext, evaluate::evaluate_stateless_with},
     crate::{
         ast::Expr,
-        data::{Row, Value},
+        data::Value,
         result::{Error, Result},
     },
     futures::stream::{Stream, StreamExt},
+    std::rc::Rc,
 };
 
 pub struct Limit {
@@ -14,17 +15,25 @@ pub struct Limit {
 }
 
 impl Limit {
-    pub async fn new(limit: Option<&Expr>, offset: Option<&Expr>) -> Result<Self> {
-        let eval = |expr| async move {
-            let expr = match expr {
-                Some(expr) => expr,
-                None => return Ok(None),
-            };
-
-            let evaluated = evaluate_stateless(None, expr).await?;
-            let size: usize = Value::try_from(evaluated)?.try_into()?;
-
-            Result::<Option<usize>, Error>::Ok(Some(size))
+    pub async fn new(
+        limit: Option<&Expr>,
+        offset: Option<&Expr>,
+        context: Option<Rc<RowContext<'_>>>,
+    ) -> Result<Self> {
+        let eval = |expr| {
+            let context = context.as_ref().map(Rc::clone);
+
+            async move {
+                let expr = match expr {
+                    Some(expr) => expr,
+                    None => return Ok(None),
+                };
+
+                let evaluated = evaluate_stateless_with(context, expr).await?;
+                let size: usize = Value::try_from(evaluated)?.try_into()?;
+
+                Result::<Option<usize>, Error>::Ok(Some(size))
+            }
         };
 
         let limit = eval(limit).await?;
@@ -33,10 +42,7 @@ impl Limit {
         Ok(Self { limit, offset })
     }
 
-    pub fn apply<'a>(
-        &self,
-        rows: impl Stream<Item = Result<Row>> + 'a,
-    ) -> impl Stream<Item = Result<Row>> + 'a {
+    pub fn apply<'a, T: 'a>(&self, rows: impl Stream<Item = T> + 'a) -> impl Stream<Item = T> + 'a {
         #[derive(futures_enum::Stream)]
         enum S<S1, S2, S3, S4> {
             Both(S3),
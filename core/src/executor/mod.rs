@@ -4,15 +4,22 @@ mod context;
 mod delete;
 mod evaluate;
 mod execute;
+mod explain;
 mod fetch;
 mod filter;
 mod insert;
 mod join;
 mod limit;
+mod referential;
+mod returning;
 mod select;
+mod sequence;
 mod sort;
+mod stream;
+mod trigger;
 mod update;
 mod validate;
+mod window;
 
 pub use {
     aggregate::AggregateError,
@@ -20,11 +27,18 @@ pub use {
     context::RowContext,
     delete::DeleteError,
     evaluate::{evaluate_stateless, EvaluateError},
-    execute::{execute, ExecuteError, Payload, PayloadVariable},
+    execute::{execute, execute_with_params, ExecuteError, Payload, PayloadVariable},
+    explain::{Analyzed, ExplainNode},
     fetch::FetchError,
     insert::InsertError,
-    select::SelectError,
+    select::{SelectError, MAX_RECURSION_DEPTH},
+    sequence::{identity_sequence_name, SequenceError},
     sort::SortError,
+    stream::{select_stream, PayloadStream},
+    trigger::{TriggerError, MAX_TRIGGER_DEPTH},
     update::UpdateError,
     validate::ValidateError,
+    window::WindowError,
 };
+
+pub(crate) use select::select_with_labels;
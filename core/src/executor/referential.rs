@@ -0,0 +1,243 @@
+use {
+    super::{
+        fetch::{fetch, fetch_columns},
+        validate::ValidateError,
+    },
+    crate::{
+        ast::Expr,
+        data::{Key, Row, Value},
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    futures::stream::TryStreamExt,
+    std::{
+        collections::{HashMap, HashSet},
+        mem,
+        rc::Rc,
+    },
+};
+
+/// Row deleted or updated by a referential action, `new` is `None` for a deleted row.
+pub struct ActionRow {
+    pub table_name: String,
+    pub old: Row,
+    pub new: Option<Row>,
+}
+
+/// Rows deleted and updated by a statement including its referential actions.
+///
+/// Referential actions are resolved against the storage overlaid with the changes so far,
+/// nothing is written until [`Changes::apply`] so that a failing action leaves the storage as it was.
+#[derive(Default)]
+pub struct Changes {
+    deleted: HashMap<String, HashSet<Key>>,
+    /// `key -> (new key, row)`
+    updated: HashMap<String, HashMap<Key, (Key, Row)>>,
+    /// Rows changed by referential actions in the order they were resolved
+    actions: Vec<ActionRow>,
+}
+
+impl Changes {
+    /// Records the rows of `table_name` changed by a referential action as `(old, new)`,
+    /// so the statement fires the triggers of the table for them.
+    pub fn record_action(
+        &mut self,
+        table_name: &str,
+        rows: impl IntoIterator<Item = (Row, Option<Row>)>,
+    ) {
+        let rows = rows.into_iter().map(|(old, new)| ActionRow {
+            table_name: table_name.to_owned(),
+            old,
+            new,
+        });
+
+        self.actions.extend(rows);
+    }
+
+    /// Takes the rows recorded by [`Changes::record_action`].
+    pub fn take_actions(&mut self) -> Vec<ActionRow> {
+        mem::take(&mut self.actions)
+    }
+
+    /// Records the deleted rows and returns the ones which were not deleted yet.
+    pub fn delete(&mut self, table_name: &str, rows: Vec<(Key, Row)>) -> Vec<(Key, Row)> {
+        let deleted = self.deleted.entry(table_name.to_owned()).or_default();
+        let mut updated = self.updated.get_mut(table_name);
+
+        rows.into_iter()
+            .filter(|(key, _)| {
+                if let Some(updated) = updated.as_mut() {
+                    updated.remove(key);
+                }
+
+                deleted.insert(key.clone())
+            })
+            .collect()
+    }
+
+    /// Records the updated rows given as `(key, new key, row)`,
+    /// a changed key must not collide with any other row of the table.
+    pub async fn update<T: GStore>(
+        &mut self,
+        storage: &T,
+        table_name: &str,
+        rows: Vec<(Key, Key, Row)>,
+    ) -> Result<()> {
+        let moved_keys = rows
+            .iter()
+            .filter(|(key, new_key, _)| key != new_key)
+            .map(|(_, new_key, _)| new_key.clone())
+            .collect::<Vec<_>>();
+
+        let updated = self.updated.entry(table_name.to_owned()).or_default();
+        for (key, new_key, row) in rows {
+            updated.insert(key, (new_key, row));
+        }
+
+        if moved_keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut occupied = HashMap::<&Key, usize>::new();
+        for (new_key, _) in updated.values() {
+            *occupied.entry(new_key).or_default() += 1;
+        }
+
+        let deleted = self.deleted.get(table_name);
+        for new_key in moved_keys {
+            let stored = !updated.contains_key(&new_key)
+                && !deleted.is_some_and(|deleted| deleted.contains(&new_key))
+                && storage.fetch_data(table_name, &new_key).await?.is_some();
+            let occupied = occupied.get(&new_key).copied().unwrap_or_default();
+
+            if occupied + usize::from(stored) > 1 {
+                return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(new_key).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the rows whose `column_name` is one of `values` as they are after the changes.
+    pub async fn fetch_rows<T: GStore>(
+        &self,
+        storage: &T,
+        table_name: &str,
+        column_name: &str,
+        values: &[Value],
+    ) -> Result<Vec<(Key, Row)>> {
+        let expr = Expr::InList {
+            expr: Box::new(Expr::Identifier(column_name.to_owned())),
+            list: values
+                .iter()
+                .cloned()
+                .map(Expr::try_from)
+                .collect::<Result<_>>()?,
+            negated: false,
+        };
+        let columns = fetch_columns(storage, table_name).await?.map(Rc::from);
+        let stored_rows = fetch(storage, table_name, columns, Some(&expr), None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let updated = self.updated.get(table_name);
+        let stored_keys = stored_rows
+            .iter()
+            .map(|(key, _)| key.clone())
+            .collect::<HashSet<_>>();
+        let updated_rows = updated
+            .into_iter()
+            .flatten()
+            .filter(|(key, _)| !stored_keys.contains(*key))
+            .map(|(key, (_, row))| (key.clone(), row.clone()));
+        let deleted = self.deleted.get(table_name);
+
+        let rows = stored_rows
+            .into_iter()
+            .map(
+                |(key, row)| match updated.and_then(|updated| updated.get(&key)) {
+                    Some((_, row)) => (key, row.clone()),
+                    None => (key, row),
+                },
+            )
+            .chain(updated_rows)
+            .filter(|(key, row)| {
+                !deleted.is_some_and(|deleted| deleted.contains(key))
+                    && row
+                        .get_value(column_name)
+                        .is_some_and(|value| values.contains(value))
+            })
+            .collect();
+
+        Ok(rows)
+    }
+
+    /// Checks whether a row of `key` exists after the changes.
+    pub async fn exists<T: GStore>(
+        &self,
+        storage: &T,
+        table_name: &str,
+        key: &Key,
+    ) -> Result<bool> {
+        let updated = self.updated.get(table_name);
+
+        if updated
+            .into_iter()
+            .flat_map(HashMap::values)
+            .any(|(new_key, _)| new_key == key)
+        {
+            return Ok(true);
+        }
+
+        let removed = self
+            .deleted
+            .get(table_name)
+            .is_some_and(|deleted| deleted.contains(key))
+            || updated.is_some_and(|updated| updated.contains_key(key));
+        if removed {
+            return Ok(false);
+        }
+
+        storage
+            .fetch_data(table_name, key)
+            .await
+            .map(|data_row| data_row.is_some())
+    }
+
+    /// Writes the changes, deleted rows and the old keys of moved rows are removed first.
+    pub async fn apply<T: GStoreMut>(self, storage: &mut T) -> Result<()> {
+        let Self {
+            deleted, updated, ..
+        } = self;
+
+        for (table_name, keys) in deleted {
+            if !keys.is_empty() {
+                storage
+                    .delete_data(&table_name, keys.into_iter().collect())
+                    .await?;
+            }
+        }
+
+        for (table_name, rows) in updated {
+            let moved_keys = rows
+                .iter()
+                .filter(|(key, (new_key, _))| key != &new_key)
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+            if !moved_keys.is_empty() {
+                storage.delete_data(&table_name, moved_keys).await?;
+            }
+
+            let rows = rows
+                .into_values()
+                .map(|(new_key, row)| (new_key, row.into()))
+                .collect::<Vec<_>>();
+            if !rows.is_empty() {
+                storage.insert_data(&table_name, rows).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
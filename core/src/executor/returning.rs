@@ -0,0 +1,52 @@
+use {
+    super::{context::RowContext, fetch::fetch_labels, select::Project, Payload},
+    crate::{
+        ast::{SelectItem, TableFactor},
+        data::Row,
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, StreamExt, TryStreamExt},
+    std::{borrow::Cow, rc::Rc},
+};
+
+/// Evaluates the `RETURNING` items over the rows affected by `INSERT`, `UPDATE` or `DELETE`.
+pub async fn returning<T: GStore>(
+    storage: &T,
+    table_name: &str,
+    select_items: &[SelectItem],
+    rows: Vec<Row>,
+    context: Option<Rc<RowContext<'_>>>,
+) -> Result<Payload> {
+    let relation = TableFactor::Table {
+        name: table_name.to_owned(),
+        alias: None,
+        index: None,
+    };
+    let labels = fetch_labels(storage, &relation, &[], select_items, &None).await?;
+    let columns = labels.as_deref().map(Rc::from);
+    let project = Project::new(storage, context, select_items);
+    let project = &project;
+
+    let rows = stream::iter(rows.iter())
+        .then(|row| {
+            let columns = columns.as_ref().map(Rc::clone);
+            let context = Rc::new(RowContext::new(table_name, Cow::Borrowed(row), None));
+
+            async move { project.apply(None, columns, context).await }
+        })
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter();
+
+    match labels {
+        Some(labels) => rows
+            .map(Row::try_into_vec)
+            .collect::<Result<_>>()
+            .map(|rows| Payload::Select { labels, rows }),
+        None => rows
+            .map(Row::try_into_map)
+            .collect::<Result<_>>()
+            .map(Payload::SelectMap),
+    }
+}
@@ -0,0 +1,199 @@
+use {
+    super::{select_set_expr, select_with_labels, sort_stateless, SelectError},
+    crate::{
+        ast::{Cte, Query, SetExpr, SetOperator, TableFactor, With},
+        data::Row,
+        executor::{
+            context::{CteTable, RowContext},
+            fetch::alias_labels,
+            limit::Limit,
+        },
+        result::Result,
+        store::GStore,
+    },
+    futures::stream::{self, TryStreamExt},
+    std::{collections::HashSet, rc::Rc},
+};
+
+/// Number of times the recursive term of a `WITH RECURSIVE` query may run
+pub const MAX_RECURSION_DEPTH: usize = 1000;
+
+pub async fn materialize<'a, T: GStore>(
+    storage: &'a T,
+    with: &'a With,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<Option<Rc<RowContext<'a>>>> {
+    let With {
+        recursive,
+        cte_tables,
+    } = with;
+
+    let mut context = filter_context;
+
+    for Cte { alias, query } in cte_tables {
+        let name = alias.name.as_str();
+        let (labels, rows) = match &query.body {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                all,
+                left,
+                right,
+            } if *recursive && references(right, name) => {
+                let (labels, rows) = select_set_expr(storage, left, context.clone()).await?;
+                let labels = labels
+                    .map(|labels| alias_labels(alias, labels))
+                    .transpose()?;
+                let rows = iterate(
+                    storage,
+                    name,
+                    labels.as_deref(),
+                    *all,
+                    right,
+                    &context,
+                    rows,
+                )
+                .await?;
+                let rows = sort_and_limit(query, rows, &context).await?;
+
+                (labels, rows)
+            }
+            _ => {
+                let (labels, rows) = select_with_labels(storage, query, context.clone()).await?;
+                let rows = rows.try_collect::<Vec<_>>().await?;
+                let labels = labels
+                    .map(|labels| alias_labels(alias, labels))
+                    .transpose()?;
+
+                (labels, rows)
+            }
+        };
+
+        let rows = relabel(labels.as_deref(), rows);
+        let table = CteTable { labels, rows };
+
+        context = Some(Rc::new(RowContext::new_cte(name, table, context)));
+    }
+
+    Ok(context)
+}
+
+/// Runs the recursive term with the rows found in the previous step until no new row comes out,
+/// fails once the term has run [`MAX_RECURSION_DEPTH`] times and still finds new rows.
+async fn iterate<'a, T: GStore>(
+    storage: &'a T,
+    name: &'a str,
+    labels: Option<&[String]>,
+    all: bool,
+    recursive_term: &'a SetExpr,
+    filter_context: &Option<Rc<RowContext<'a>>>,
+    anchor_rows: Vec<Row>,
+) -> Result<Vec<Row>> {
+    let mut keys = HashSet::new();
+    let mut distinct = |rows: Vec<Row>| -> Result<Vec<Row>> {
+        if all {
+            return Ok(rows);
+        }
+
+        let mut distinct_rows = Vec::new();
+        for row in rows {
+            if keys.insert(super::set_operation::row_key(&row)?) {
+                distinct_rows.push(row);
+            }
+        }
+
+        Ok(distinct_rows)
+    };
+
+    let mut working_rows = distinct(relabel(labels, anchor_rows))?;
+    let mut rows = working_rows.clone();
+
+    let mut depth = 0;
+    while !working_rows.is_empty() {
+        if depth == MAX_RECURSION_DEPTH {
+            return Err(SelectError::RecursionDepthExceeded(name.to_owned(), depth).into());
+        }
+
+        depth += 1;
+
+        let table = CteTable {
+            labels: labels.map(<[String]>::to_vec),
+            rows: working_rows,
+        };
+        let context = RowContext::new_cte(name, table, filter_context.clone());
+        let (recursive_labels, new_rows) =
+            select_set_expr(storage, recursive_term, Some(Rc::new(context))).await?;
+
+        if let (Some(labels), Some(recursive_labels)) = (labels, &recursive_labels) {
+            if labels.len() != recursive_labels.len() {
+                return Err(SelectError::NumberOfColumnsDifferent(SetOperator::Union).into());
+            }
+        }
+
+        working_rows = distinct(relabel(labels, new_rows))?;
+        rows.extend(working_rows.iter().cloned());
+    }
+
+    Ok(rows)
+}
+
+async fn sort_and_limit(
+    query: &Query,
+    rows: Vec<Row>,
+    filter_context: &Option<Rc<RowContext<'_>>>,
+) -> Result<Vec<Row>> {
+    let Query {
+        order_by,
+        limit,
+        offset,
+        ..
+    } = query;
+
+    let rows = sort_stateless(rows, order_by, filter_context).await?;
+    let limit = Limit::new(limit.as_ref(), offset.as_ref(), filter_context.clone()).await?;
+
+    limit
+        .apply(stream::iter(rows.into_iter().map(Ok)))
+        .try_collect()
+        .await
+}
+
+fn relabel(labels: Option<&[String]>, rows: Vec<Row>) -> Vec<Row> {
+    let columns: Rc<[String]> = match labels {
+        Some(labels) => Rc::from(labels),
+        None => return rows,
+    };
+
+    rows.into_iter()
+        .map(|row| match row {
+            Row::Vec { values, .. } => Row::Vec {
+                columns: Rc::clone(&columns),
+                values,
+            },
+            Row::Map(values) => Row::Map(values),
+        })
+        .collect()
+}
+
+fn references(set_expr: &SetExpr, name: &str) -> bool {
+    match set_expr {
+        SetExpr::Select(select) => {
+            let is_target = |table_factor: &TableFactor| {
+                matches!(
+                    table_factor,
+                    TableFactor::Table { name: table_name, .. } if table_name == name
+                )
+            };
+
+            is_target(&select.from.relation)
+                || select
+                    .from
+                    .joins
+                    .iter()
+                    .any(|join| is_target(&join.relation))
+        }
+        SetExpr::Values(_) => false,
+        SetExpr::SetOperation { left, right, .. } => {
+            references(left, name) || references(right, name)
+        }
+    }
+}
@@ -1,7 +1,13 @@
-use {serde::Serialize, std::fmt::Debug, thiserror::Error};
+use {crate::ast::SetOperator, serde::Serialize, std::fmt::Debug, thiserror::Error};
 
 #[derive(Error, Serialize, Debug, PartialEq, Eq)]
 pub enum SelectError {
     #[error("VALUES lists must all be the same length")]
     NumberOfValuesDifferent,
+
+    #[error("each {0} query must have the same number of columns")]
+    NumberOfColumnsDifferent(SetOperator),
+
+    #[error("recursive query {0} exceeded the maximum recursion depth of {1}")]
+    RecursionDepthExceeded(String, usize),
 }
@@ -1,19 +1,22 @@
+mod cte;
 mod error;
 mod project;
+mod set_operation;
 
-pub use error::SelectError;
+pub use {cte::MAX_RECURSION_DEPTH, error::SelectError, project::Project};
 
 use {
-    self::project::Project,
     super::{
         aggregate::Aggregator,
         context::{AggregateContext, RowContext},
-        evaluate::evaluate_stateless,
+        evaluate::evaluate_stateless_with,
+        explain::{measure, QueryProbes},
         fetch::{fetch_labels, fetch_relation_rows},
         filter::Filter,
         join::Join,
         limit::Limit,
         sort::Sort,
+        window::Windower,
     },
     crate::{
         ast::{Expr, OrderByExpr, Query, Select, SetExpr, TableWithJoins, Values},
@@ -22,12 +25,18 @@ use {
         store::GStore,
     },
     async_recursion::async_recursion,
-    futures::stream::{self, Stream, StreamExt, TryStreamExt},
-    std::{borrow::Cow, rc::Rc},
+    futures::{
+        future,
+        stream::{self, Stream, StreamExt, TryStreamExt},
+    },
+    std::{borrow::Cow, collections::HashSet, rc::Rc},
     utils::Vector,
 };
 
-async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<String>)> {
+async fn rows_with_labels(
+    exprs_list: &[Vec<Expr>],
+    filter_context: &Option<Rc<RowContext<'_>>>,
+) -> Result<(Vec<Row>, Vec<String>)> {
     let first_len = exprs_list[0].len();
     let labels = (1..=first_len)
         .map(|i| format!("column{}", i))
@@ -45,7 +54,7 @@ async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<Str
         let mut values = Vec::with_capacity(exprs.len());
 
         for (i, expr) in exprs.iter().enumerate() {
-            let evaluated = evaluate_stateless(None, expr).await?;
+            let evaluated = evaluate_stateless_with(filter_context.clone(), expr).await?;
 
             let value = match column_types[i] {
                 Some(ref data_type) => evaluated.try_into_value(data_type, true)?,
@@ -69,15 +78,25 @@ async fn rows_with_labels(exprs_list: &[Vec<Expr>]) -> Result<(Vec<Row>, Vec<Str
     Ok((rows, labels))
 }
 
-async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<Row>> {
+async fn sort_stateless(
+    rows: Vec<Row>,
+    order_by: &[OrderByExpr],
+    filter_context: &Option<Rc<RowContext<'_>>>,
+) -> Result<Vec<Row>> {
     let sorted = stream::iter(rows.into_iter())
         .then(|row| async move {
             stream::iter(order_by)
                 .then(|OrderByExpr { expr, asc }| {
-                    let row = Some(&row);
+                    let context = Rc::new(row.as_context());
+                    let context = match filter_context {
+                        Some(filter_context) => {
+                            Rc::new(RowContext::concat(context, Rc::clone(filter_context)))
+                        }
+                        None => context,
+                    };
 
                     async move {
-                        evaluate_stateless(row.map(Row::as_context), expr)
+                        evaluate_stateless_with(Some(context), expr)
                             .await
                             .and_then(Value::try_from)
                             .and_then(Key::try_from)
@@ -99,42 +118,91 @@ async fn sort_stateless(rows: Vec<Row>, order_by: &[OrderByExpr]) -> Result<Vec<
     Ok(sorted)
 }
 
+pub async fn select_with_labels<'a, T: GStore>(
+    storage: &'a T,
+    query: &'a Query,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
+    select_query(storage, query, filter_context, None).await
+}
+
+/// Selects rows of the query, operators of the top level `SELECT` are measured by `probes`
+/// for `EXPLAIN ANALYZE`.
 #[async_recursion(?Send)]
-pub async fn select_with_labels<'a, T>(
+pub async fn select_query<'a, T>(
     storage: &'a T,
     query: &'a Query,
     filter_context: Option<Rc<RowContext<'a>>>,
+    probes: Option<&'a QueryProbes>,
 ) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)>
 where
     T: GStore,
 {
     #[derive(futures_enum::Stream)]
-    enum Row<S1, S2> {
+    enum Row<S1, S2, S3> {
         Select(S2),
         Values(S1),
+        SetOperation(S3),
+    }
+
+    let Query {
+        with,
+        body,
+        order_by,
+        limit,
+        offset,
+    } = query;
+    let limit = Limit::new(limit.as_ref(), offset.as_ref(), filter_context.clone()).await?;
+    let filter_context = match with {
+        Some(with) => cte::materialize(storage, with, filter_context).await?,
+        None => filter_context,
+    };
+
+    match body {
+        SetExpr::Select(select) => {
+            let (labels, rows) =
+                select_rows(storage, select, order_by, filter_context, probes).await?;
+            let rows = limit.apply(rows);
+
+            Ok((labels, Row::Select(rows)))
+        }
+        SetExpr::Values(Values(values_list)) => {
+            let (rows, labels) = rows_with_labels(values_list, &filter_context).await?;
+            let rows = sort_stateless(rows, order_by, &filter_context).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = limit.apply(rows);
+
+            Ok((Some(labels), Row::Values(rows)))
+        }
+        SetExpr::SetOperation { .. } => {
+            let (labels, rows) = select_set_expr(storage, body, filter_context.clone()).await?;
+            let rows = sort_stateless(rows, order_by, &filter_context).await?;
+            let rows = stream::iter(rows.into_iter().map(Ok));
+            let rows = limit.apply(rows);
+
+            Ok((labels, Row::SetOperation(rows)))
+        }
     }
+}
 
+async fn select_rows<'a, T: GStore>(
+    storage: &'a T,
+    select: &'a Select,
+    order_by: &'a [OrderByExpr],
+    filter_context: Option<Rc<RowContext<'a>>>,
+    probes: Option<&'a QueryProbes>,
+) -> Result<(Option<Vec<String>>, impl Stream<Item = Result<Row>> + 'a)> {
     let Select {
+        distinct,
         from: table_with_joins,
         selection: where_clause,
         projection,
         group_by,
         having,
-    } = match &query.body {
-        SetExpr::Select(statement) => statement.as_ref(),
-        SetExpr::Values(Values(values_list)) => {
-            let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
-            let (rows, labels) = rows_with_labels(values_list).await?;
-            let rows = sort_stateless(rows, &query.order_by).await?;
-            let rows = stream::iter(rows.into_iter().map(Ok));
-            let rows = limit.apply(rows);
-
-            return Ok((Some(labels), Row::Values(rows)));
-        }
-    };
+    } = select;
 
     let TableWithJoins { relation, joins } = &table_with_joins;
-    let rows = fetch_relation_rows(storage, relation, &None)
+    let rows = fetch_relation_rows(storage, relation, &filter_context)
         .await?
         .map(move |row| {
             let row = row?;
@@ -142,8 +210,14 @@ where
 
             Ok(RowContext::new(alias, Cow::Owned(row), None))
         });
+    let rows = measure(rows, probes.map(|probes| &probes.scan));
 
-    let join = Join::new(storage, joins, filter_context.as_ref().map(Rc::clone));
+    let join = Join::new(
+        storage,
+        relation,
+        joins,
+        filter_context.as_ref().map(Rc::clone),
+    );
     let aggregate = Aggregator::new(
         storage,
         projection,
@@ -151,20 +225,23 @@ where
         having.as_ref(),
         filter_context.as_ref().map(Rc::clone),
     );
+    let window = Windower::new(
+        storage,
+        projection,
+        order_by,
+        filter_context.as_ref().map(Rc::clone),
+    );
     let filter = Rc::new(Filter::new(
         storage,
         where_clause.as_ref(),
         filter_context.as_ref().map(Rc::clone),
         None,
     ));
-    let limit = Limit::new(query.limit.as_ref(), query.offset.as_ref()).await?;
-    let sort = Sort::new(
-        storage,
-        filter_context.as_ref().map(Rc::clone),
-        &query.order_by,
-    );
+    let sort = Sort::new(storage, filter_context.as_ref().map(Rc::clone), order_by);
 
-    let rows = join.apply(rows).await?;
+    let rows = join
+        .apply(rows, probes.map(|probes| probes.joins.as_slice()))
+        .await?;
     let rows = rows.try_filter_map(move |project_context| {
         let filter = Rc::clone(&filter);
 
@@ -175,10 +252,13 @@ where
                 .map(|pass| pass.then_some(project_context))
         }
     });
+    let rows = measure(rows, probes.map(|probes| &probes.filter));
 
     let rows = aggregate.apply(rows).await?;
+    let rows = measure(rows, probes.map(|probes| &probes.aggregate));
+    let rows = window.apply(rows).await?;
 
-    let labels = fetch_labels(storage, relation, joins, projection)
+    let labels = fetch_labels(storage, relation, joins, projection, &filter_context)
         .await?
         .map(Rc::from);
 
@@ -198,12 +278,68 @@ where
             Ok((aggregated, next, row))
         }
     });
+    let rows = measure(rows, probes.map(|probes| &probes.project));
+
+    let mut distinct_keys = distinct.then(HashSet::new);
+    let rows = rows.try_filter_map(move |(aggregated, next, row)| {
+        let row = match distinct_keys.as_mut() {
+            Some(keys) => set_operation::row_key(&row)
+                .map(|key| keys.insert(key).then_some((aggregated, next, row))),
+            None => Ok(Some((aggregated, next, row))),
+        };
+
+        future::ready(row)
+    });
+    let rows = measure(rows, probes.map(|probes| &probes.distinct));
 
     let rows = sort.apply(rows, get_alias(relation)).await?;
-    let rows = limit.apply(rows);
+    let rows = measure(rows, probes.map(|probes| &probes.sort));
     let labels = labels.map(|labels| labels.iter().cloned().collect());
 
-    Ok((labels, Row::Select(rows)))
+    Ok((labels, rows))
+}
+
+#[async_recursion(?Send)]
+async fn select_set_expr<'a, T>(
+    storage: &'a T,
+    set_expr: &'a SetExpr,
+    filter_context: Option<Rc<RowContext<'a>>>,
+) -> Result<(Option<Vec<String>>, Vec<Row>)>
+where
+    T: GStore,
+{
+    match set_expr {
+        SetExpr::Select(select) => {
+            let (labels, rows) = select_rows(storage, select, &[], filter_context, None).await?;
+            let rows = rows.try_collect::<Vec<_>>().await?;
+
+            Ok((labels, rows))
+        }
+        SetExpr::Values(Values(values_list)) => rows_with_labels(values_list, &filter_context)
+            .await
+            .map(|(rows, labels)| (Some(labels), rows)),
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let (labels, left_rows) =
+                select_set_expr(storage, left, filter_context.as_ref().map(Rc::clone)).await?;
+            let (right_labels, right_rows) =
+                select_set_expr(storage, right, filter_context).await?;
+
+            if let (Some(labels), Some(right_labels)) = (&labels, &right_labels) {
+                if labels.len() != right_labels.len() {
+                    return Err(SelectError::NumberOfColumnsDifferent(*op).into());
+                }
+            }
+
+            let rows = set_operation::apply(*op, *all, labels.as_deref(), left_rows, right_rows)?;
+
+            Ok((labels, rows))
+        }
+    }
 }
 
 pub async fn select<'a, T: GStore>(
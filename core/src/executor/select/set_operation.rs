@@ -0,0 +1,123 @@
+use {
+    crate::{
+        ast::SetOperator,
+        data::{Key, Row, Value},
+        result::Result,
+    },
+    itertools::Itertools,
+    ordered_float::OrderedFloat,
+    std::{
+        collections::{HashMap, HashSet},
+        rc::Rc,
+    },
+};
+
+pub fn apply(
+    op: SetOperator,
+    all: bool,
+    labels: Option<&[String]>,
+    left: Vec<Row>,
+    right: Vec<Row>,
+) -> Result<Vec<Row>> {
+    let rows = match (op, all) {
+        (SetOperator::Union, true) => left.into_iter().chain(right).collect(),
+        (SetOperator::Union, false) => distinct(left.into_iter().chain(right))?,
+        (SetOperator::Intersect, _) | (SetOperator::Except, _) => {
+            let mut counts = HashMap::<Vec<Key>, usize>::new();
+            for row in right.iter() {
+                *counts.entry(row_key(row)?).or_default() += 1;
+            }
+
+            let intersect = op == SetOperator::Intersect;
+            let mut emitted = HashSet::new();
+            let mut rows = Vec::new();
+
+            for row in left {
+                let key = row_key(&row)?;
+                let found = match counts.get_mut(&key) {
+                    Some(count) if *count > 0 => {
+                        if all {
+                            *count -= 1;
+                        }
+
+                        true
+                    }
+                    _ => false,
+                };
+
+                if found != intersect || (!all && !emitted.insert(key)) {
+                    continue;
+                }
+
+                rows.push(row);
+            }
+
+            rows
+        }
+    };
+
+    let columns: Rc<[String]> = match labels {
+        Some(labels) => Rc::from(labels),
+        None => return Ok(rows),
+    };
+
+    let rows = rows
+        .into_iter()
+        .map(|row| match row {
+            Row::Vec { values, .. } => Row::Vec {
+                columns: Rc::clone(&columns),
+                values,
+            },
+            Row::Map(values) => Row::Map(values),
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn distinct(rows: impl Iterator<Item = Row>) -> Result<Vec<Row>> {
+    let mut keys = HashSet::new();
+
+    rows.filter_map(|row| match row_key(&row) {
+        Ok(key) => keys.insert(key).then_some(Ok(row)),
+        Err(error) => Some(Err(error)),
+    })
+    .collect()
+}
+
+pub fn row_key(row: &Row) -> Result<Vec<Key>> {
+    match row {
+        Row::Vec { values, .. } => values.iter().map(value_key).collect(),
+        Row::Map(values) => map_key(values.iter()),
+    }
+}
+
+/// Lists, maps and points cannot be keys, they are keyed by their items wrapped in a
+/// `Key::List` tagged with the kind of the value, so values of different kinds never collide.
+fn value_key(value: &Value) -> Result<Key> {
+    let tagged = |tag, keys| Key::List(vec![Key::U8(tag), Key::List(keys)]);
+
+    match value {
+        Value::List(list) => list
+            .iter()
+            .map(value_key)
+            .collect::<Result<Vec<_>>>()
+            .map(|keys| tagged(0, keys)),
+        Value::Map(map) => map_key(map.iter()).map(|keys| tagged(1, keys)),
+        Value::Point(point) => Ok(tagged(
+            2,
+            vec![
+                Key::F64(OrderedFloat(point.x)),
+                Key::F64(OrderedFloat(point.y)),
+            ],
+        )),
+        _ => Key::try_from(value),
+    }
+}
+
+fn map_key<'a>(entries: impl Iterator<Item = (&'a String, &'a Value)>) -> Result<Vec<Key>> {
+    entries
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .flat_map(|(name, value)| [Ok(Key::Str(name.to_owned())), value_key(value)])
+        .collect()
+}
@@ -0,0 +1,284 @@
+use {
+    super::{evaluate::evaluate_stateless, EvaluateError},
+    crate::{
+        ast::{
+            AstLiteral, Expr, Function, Query, Select, SelectItem, SetExpr, TableFactor,
+            TableWithJoins, Values,
+        },
+        data::{Sequence, Value},
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    async_recursion::async_recursion,
+    bigdecimal::BigDecimal,
+    serde::Serialize,
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum SequenceError {
+    #[error("sequence not found: {0}")]
+    SequenceNotFound(String),
+
+    #[error("CURRVAL of sequence '{0}' is not yet defined, call NEXTVAL first")]
+    CurrentValueNotDefined(String),
+
+    #[error("sequence '{0}' reached its limit")]
+    ValueOutOfRange(String),
+
+    #[error("NEXTVAL and SETVAL are only supported in INSERT VALUES, column defaults and SELECT without FROM")]
+    NextvalNotSupported,
+}
+
+/// Name of the sequence generating the values of an identity column.
+pub fn identity_sequence_name(table_name: &str, column_name: &str) -> String {
+    format!("{table_name}_{column_name}_seq")
+}
+
+/// Advances the sequence and returns its new value.
+pub async fn next_value<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_name: &str,
+) -> Result<i64> {
+    let mut sequence = storage
+        .fetch_sequence(sequence_name)
+        .await?
+        .ok_or_else(|| SequenceError::SequenceNotFound(sequence_name.to_owned()))?;
+    let value = sequence
+        .next_value()
+        .ok_or_else(|| SequenceError::ValueOutOfRange(sequence_name.to_owned()))?;
+
+    sequence.current = Some(value);
+    storage.insert_sequence(sequence).await?;
+
+    Ok(value)
+}
+
+/// Returns the value most recently returned by `NEXTVAL` of the sequence.
+pub async fn current_value<T: GStore>(storage: &T, sequence_name: &str) -> Result<i64> {
+    storage
+        .fetch_sequence(sequence_name)
+        .await?
+        .ok_or_else(|| SequenceError::SequenceNotFound(sequence_name.to_owned()))?
+        .current
+        .ok_or_else(|| SequenceError::CurrentValueNotDefined(sequence_name.to_owned()).into())
+}
+
+/// Sets the current value of the sequence, the following `NEXTVAL` continues from it.
+pub async fn set_value<T: GStore + GStoreMut>(
+    storage: &mut T,
+    sequence_name: &str,
+    value: i64,
+) -> Result<i64> {
+    let sequence = storage
+        .fetch_sequence(sequence_name)
+        .await?
+        .ok_or_else(|| SequenceError::SequenceNotFound(sequence_name.to_owned()))?;
+
+    storage
+        .insert_sequence(Sequence {
+            current: Some(value),
+            ..sequence
+        })
+        .await?;
+
+    Ok(value)
+}
+
+/// Replaces every `NEXTVAL`, `CURRVAL` and `SETVAL` call in `expr` by its value, from left to right.
+///
+/// Subqueries are left as they are, a `NEXTVAL` call in them fails on evaluation.
+#[async_recursion(?Send)]
+pub async fn resolve_sequences<T: GStore + GStoreMut>(
+    storage: &mut T,
+    expr: &mut Expr,
+) -> Result<()> {
+    match expr {
+        Expr::Function(function) => {
+            for expr in function.as_exprs_mut() {
+                resolve_sequences(storage, expr).await?;
+            }
+
+            let function: &Function = function;
+            let value = match function {
+                Function::Nextval(name) => {
+                    let name = sequence_name(function, name).await?;
+
+                    next_value(storage, &name).await?
+                }
+                Function::Currval(name) => {
+                    let name = sequence_name(function, name).await?;
+
+                    current_value(storage, &name).await?
+                }
+                Function::Setval { name, value } => {
+                    let name = sequence_name(function, name).await?;
+                    let value = evaluate_stateless(None, value)
+                        .await
+                        .and_then(Value::try_from)
+                        .and_then(|value| i64::try_from(&value))
+                        .map_err(|_| {
+                            EvaluateError::FunctionRequiresIntegerValue(function.to_string())
+                        })?;
+
+                    set_value(storage, &name, value).await?
+                }
+                _ => return Ok(()),
+            };
+
+            *expr = Expr::Literal(AstLiteral::Number(value.into()));
+
+            Ok(())
+        }
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier { .. }
+        | Expr::Literal(_)
+        | Expr::TypedString { .. }
+        | Expr::Parameter(_)
+        | Expr::InSubquery { .. }
+        | Expr::Exists { .. }
+        | Expr::Subquery(_) => Ok(()),
+        Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Interval { expr, .. } => resolve_sequences(storage, expr).await,
+        Expr::BinaryOp { left, right, .. }
+        | Expr::Like {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | Expr::ILike {
+            expr: left,
+            pattern: right,
+            ..
+        } => {
+            resolve_sequences(storage, left).await?;
+            resolve_sequences(storage, right).await
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            resolve_sequences(storage, expr).await?;
+            resolve_sequences(storage, low).await?;
+            resolve_sequences(storage, high).await
+        }
+        Expr::InList { expr, list, .. } => {
+            resolve_sequences(storage, expr).await?;
+
+            for expr in list {
+                resolve_sequences(storage, expr).await?;
+            }
+
+            Ok(())
+        }
+        Expr::Aggregate(aggregate) => match aggregate.as_expr_mut() {
+            Some(expr) => resolve_sequences(storage, expr).await,
+            None => Ok(()),
+        },
+        Expr::Window(window) => {
+            for expr in window.as_exprs_mut() {
+                resolve_sequences(storage, expr).await?;
+            }
+
+            Ok(())
+        }
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            if let Some(operand) = operand {
+                resolve_sequences(storage, operand).await?;
+            }
+
+            for (when, then) in when_then {
+                resolve_sequences(storage, when).await?;
+                resolve_sequences(storage, then).await?;
+            }
+
+            match else_result {
+                Some(else_result) => resolve_sequences(storage, else_result).await,
+                None => Ok(()),
+            }
+        }
+        Expr::ArrayIndex { obj, indexes } => {
+            resolve_sequences(storage, obj).await?;
+
+            for expr in indexes {
+                resolve_sequences(storage, expr).await?;
+            }
+
+            Ok(())
+        }
+        Expr::Array { elem } => {
+            for expr in elem {
+                resolve_sequences(storage, expr).await?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Returns a copy of the query with its sequence calls resolved when it produces a single row,
+/// the calls in other queries would be evaluated once per row so they are left as they are.
+pub async fn resolve_query_sequences<T: GStore + GStoreMut>(
+    storage: &mut T,
+    query: &Query,
+) -> Result<Option<Query>> {
+    let single_row = match &query.body {
+        SetExpr::Values(_) => true,
+        SetExpr::Select(select) => matches!(
+            select.as_ref(),
+            Select {
+                from: TableWithJoins {
+                    relation: TableFactor::Series {
+                        size: Expr::Literal(AstLiteral::Number(size)),
+                        ..
+                    },
+                    joins,
+                },
+                selection: None,
+                group_by,
+                having: None,
+                ..
+            } if size == &BigDecimal::from(1) && joins.is_empty() && group_by.is_empty()
+        ),
+        SetExpr::SetOperation { .. } => false,
+    };
+
+    if !single_row || query.with.is_some() {
+        return Ok(None);
+    }
+
+    let mut query = query.clone();
+    match &mut query.body {
+        SetExpr::Values(Values(values_list)) => {
+            for expr in values_list.iter_mut().flatten() {
+                resolve_sequences(storage, expr).await?;
+            }
+        }
+        SetExpr::Select(select) => {
+            for select_item in &mut select.projection {
+                if let SelectItem::Expr { expr, .. } = select_item {
+                    resolve_sequences(storage, expr).await?;
+                }
+            }
+        }
+        SetExpr::SetOperation { .. } => {}
+    }
+
+    Ok(Some(query))
+}
+
+async fn sequence_name(function: &Function, expr: &Expr) -> Result<String> {
+    match evaluate_stateless(None, expr)
+        .await
+        .and_then(Value::try_from)?
+    {
+        Value::Str(name) => Ok(name),
+        _ => Err(EvaluateError::FunctionRequiresStringValue(function.to_string()).into()),
+    }
+}
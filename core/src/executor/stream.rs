@@ -0,0 +1,47 @@
+use {
+    super::{context::RowContext, select::select_with_labels, Payload},
+    crate::{ast::Query, data::Value, result::Result, store::GStore},
+    futures::stream::{LocalBoxStream, StreamExt, TryStreamExt},
+    std::{collections::HashMap, rc::Rc},
+};
+
+/// Streamed counterpart of [`Payload`],
+/// rows of a query are fetched from the storage only while the stream is polled.
+pub enum PayloadStream<'a> {
+    Select {
+        labels: Vec<String>,
+        rows: LocalBoxStream<'a, Result<Vec<Value>>>,
+    },
+    SelectMap(LocalBoxStream<'a, Result<HashMap<String, Value>>>),
+    Payload(Payload),
+}
+
+impl PayloadStream<'_> {
+    /// Collects the remaining rows into a [`Payload`].
+    pub async fn collect(self) -> Result<Payload> {
+        match self {
+            PayloadStream::Select { labels, rows } => rows
+                .try_collect()
+                .await
+                .map(|rows| Payload::Select { labels, rows }),
+            PayloadStream::SelectMap(rows) => rows.try_collect().await.map(Payload::SelectMap),
+            PayloadStream::Payload(payload) => Ok(payload),
+        }
+    }
+}
+
+pub async fn select_stream<'a, T: GStore>(
+    storage: &'a T,
+    query: &'a Query,
+    context: Option<Rc<RowContext<'a>>>,
+) -> Result<PayloadStream<'a>> {
+    let (labels, rows) = select_with_labels(storage, query, context).await?;
+
+    Ok(match labels {
+        Some(labels) => PayloadStream::Select {
+            labels,
+            rows: rows.map(|row| row?.try_into_vec()).boxed_local(),
+        },
+        None => PayloadStream::SelectMap(rows.map(|row| row?.try_into_map()).boxed_local()),
+    })
+}
@@ -0,0 +1,170 @@
+use {
+    super::{
+        context::RowContext, execute::execute_inner, filter::check_expr, referential::ActionRow,
+    },
+    crate::{
+        ast::{Trigger, TriggerEvent, TriggerTiming},
+        data::Row,
+        plan::plan,
+        result::Result,
+        store::{GStore, GStoreMut},
+    },
+    async_recursion::async_recursion,
+    serde::Serialize,
+    std::{fmt::Debug, rc::Rc},
+    thiserror::Error as ThisError,
+};
+
+/// Number of triggers a trigger body may be nested in, a trigger fired past it fails
+pub const MAX_TRIGGER_DEPTH: usize = 32;
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum TriggerError {
+    #[error("column not found in trigger row: {0}")]
+    RowColumnNotFound(String),
+
+    #[error("trigger {0} exceeded the maximum nesting depth of {1}")]
+    NestingDepthExceeded(String, usize),
+}
+
+/// Row changed by a statement, `old` is `None` on INSERT and `new` is `None` on DELETE.
+pub struct TriggerRow<'a> {
+    pub old: Option<&'a Row>,
+    pub new: Option<&'a Row>,
+}
+
+/// Runs the triggers of `event` at `timing` once for each row.
+///
+/// Body statements are executed by [`execute_inner`], so they share the transaction of the
+/// statement which changes the rows, `NEW` and `OLD` are resolved from the row when they are
+/// evaluated. `depth` is the [`RowContext::trigger_depth`] of the statement, a trigger fired
+/// in [`MAX_TRIGGER_DEPTH`] nested triggers fails.
+#[async_recursion(?Send)]
+pub async fn fire_triggers<'a, T: GStore + GStoreMut>(
+    storage: &'a mut T,
+    triggers: &'a [Trigger],
+    timing: TriggerTiming,
+    event: TriggerEvent,
+    rows: &'a [TriggerRow<'a>],
+    depth: usize,
+) -> Result<()> {
+    let triggers = triggers
+        .iter()
+        .filter(|trigger| trigger.timing == timing && trigger.events.contains(&event))
+        .collect::<Vec<_>>();
+    if triggers.is_empty() {
+        return Ok(());
+    }
+
+    let mut bodies = Vec::with_capacity(triggers.len());
+    for Trigger { body, .. } in &triggers {
+        let mut statements = Vec::with_capacity(body.len());
+        for statement in body {
+            statements.push(plan(&*storage, statement.clone()).await?);
+        }
+
+        bodies.push(statements);
+    }
+
+    for TriggerRow { old, new } in rows {
+        let context = Rc::new(RowContext::Trigger {
+            new: *new,
+            old: *old,
+            depth: depth + 1,
+        });
+
+        for (
+            Trigger {
+                name, condition, ..
+            },
+            body,
+        ) in triggers.iter().zip(&bodies)
+        {
+            if let Some(condition) = condition {
+                let context = Some(Rc::clone(&context));
+
+                if !check_expr(&*storage, context, None, condition).await? {
+                    continue;
+                }
+            }
+
+            if depth == MAX_TRIGGER_DEPTH {
+                return Err(TriggerError::NestingDepthExceeded(name.to_owned(), depth).into());
+            }
+
+            for statement in body {
+                execute_inner(storage, statement, Some(Rc::clone(&context))).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the triggers of the tables whose rows are changed by referential actions,
+/// the `DELETE` triggers for the deleted rows and the `UPDATE` triggers for the updated rows.
+pub async fn fire_action_triggers<T: GStore + GStoreMut>(
+    storage: &mut T,
+    timing: TriggerTiming,
+    actions: &[ActionRow],
+    depth: usize,
+) -> Result<()> {
+    let mut table_names = Vec::new();
+    for ActionRow { table_name, .. } in actions {
+        if !table_names.contains(&table_name) {
+            table_names.push(table_name);
+        }
+    }
+
+    for table_name in table_names {
+        let triggers = storage
+            .fetch_schema(table_name)
+            .await?
+            .map(|schema| schema.triggers)
+            .unwrap_or_default();
+        if triggers.is_empty() {
+            continue;
+        }
+
+        let rows = actions
+            .iter()
+            .filter(|action| &action.table_name == table_name);
+        let deleted_rows = rows
+            .clone()
+            .filter(|ActionRow { new, .. }| new.is_none())
+            .map(|ActionRow { old, .. }| TriggerRow {
+                old: Some(old),
+                new: None,
+            })
+            .collect::<Vec<_>>();
+        let updated_rows = rows
+            .filter_map(|ActionRow { old, new, .. }| {
+                new.as_ref().map(|new| TriggerRow {
+                    old: Some(old),
+                    new: Some(new),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        fire_triggers(
+            storage,
+            &triggers,
+            timing,
+            TriggerEvent::Delete,
+            &deleted_rows,
+            depth,
+        )
+        .await?;
+        fire_triggers(
+            storage,
+            &triggers,
+            timing,
+            TriggerEvent::Update,
+            &updated_rows,
+            depth,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
@@ -1,17 +1,22 @@
 use {
     super::{
         context::RowContext,
-        evaluate::{evaluate, Evaluated},
+        evaluate::{evaluate, evaluate_stateless, Evaluated},
+        fetch::FetchError,
+        referential::Changes,
+        validate::{validate_check, validate_unique, ColumnValidation},
+        Referencing,
     },
     crate::{
-        ast::{Assignment, ColumnDef, ColumnUniqueOption, ForeignKey},
-        data::{Key, Row, Value},
+        ast::{Assignment, ColumnDef, Expr, ForeignKey, ReferentialAction},
+        data::{primary_key_indexes, Key, Row, Schema, Value},
         result::{Error, Result},
         store::GStore,
     },
+    async_recursion::async_recursion,
     futures::stream::{self, StreamExt, TryStreamExt},
     serde::Serialize,
-    std::{borrow::Cow, fmt::Debug, rc::Rc},
+    std::{borrow::Cow, collections::HashMap, fmt::Debug, iter, rc::Rc},
     thiserror::Error,
     utils::HashMapExt,
 };
@@ -33,6 +38,9 @@ pub enum UpdateError {
         column_name: String,
         referenced_value: String,
     },
+
+    #[error("referencing column exists: {0}")]
+    ReferencingColumnExists(String),
 }
 
 pub struct Update<'a, T: GStore> {
@@ -40,6 +48,7 @@ pub struct Update<'a, T: GStore> {
     table_name: &'a str,
     fields: &'a [Assignment],
     column_defs: Option<&'a [ColumnDef]>,
+    context: Option<Rc<RowContext<'a>>>,
 }
 
 impl<'a, T: GStore> Update<'a, T> {
@@ -48,6 +57,7 @@ impl<'a, T: GStore> Update<'a, T> {
         table_name: &'a str,
         fields: &'a [Assignment],
         column_defs: Option<&'a [ColumnDef]>,
+        context: Option<Rc<RowContext<'a>>>,
     ) -> Result<Self> {
         if let Some(column_defs) = column_defs {
             for assignment in fields.iter() {
@@ -55,10 +65,6 @@ impl<'a, T: GStore> Update<'a, T> {
 
                 if column_defs.iter().all(|col_def| &col_def.name != id) {
                     return Err(UpdateError::ColumnNotFound(id.to_owned()).into());
-                } else if column_defs.iter().any(|ColumnDef { name, unique, .. }| {
-                    name == id && matches!(unique, Some(ColumnUniqueOption { is_primary: true }))
-                }) {
-                    return Err(UpdateError::UpdateOnPrimaryKeyNotSupported(id.to_owned()).into());
                 }
             }
         }
@@ -68,11 +74,26 @@ impl<'a, T: GStore> Update<'a, T> {
             table_name,
             fields,
             column_defs,
+            context,
         })
     }
 
     pub async fn apply(&self, row: Row, foreign_keys: &[ForeignKey]) -> Result<Row> {
-        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), None);
+        let context = self.context.as_ref().map(Rc::clone);
+
+        self.apply_with_context(row, context, foreign_keys).await
+    }
+
+    /// Applies the assignments while `next` stays resolvable behind the updated row,
+    /// e.g. the `excluded` row of `INSERT ... ON CONFLICT DO UPDATE`,
+    /// `next` should be chained to the context given to [`Update::new`].
+    pub async fn apply_with_context(
+        &self,
+        row: Row,
+        next: Option<Rc<RowContext<'_>>>,
+        foreign_keys: &[ForeignKey],
+    ) -> Result<Row> {
+        let context = RowContext::new(self.table_name, Cow::Borrowed(&row), next);
         let context = Some(Rc::new(context));
 
         let assignments = stream::iter(self.fields.iter())
@@ -98,6 +119,10 @@ impl<'a, T: GStore> Update<'a, T> {
 
                             let value = match evaluated {
                                 Evaluated::Literal(v) => Value::try_from_literal(data_type, &v)?,
+                                // a bound parameter takes the type of its column, as a literal does
+                                Evaluated::Value(v) if matches!(value_expr, Expr::Parameter(_)) => {
+                                    v.cast(data_type)?
+                                }
                                 Evaluated::Value(v) => {
                                     v.validate_type(data_type)?;
                                     v
@@ -180,3 +205,271 @@ impl<'a, T: GStore> Update<'a, T> {
         })
     }
 }
+
+/// Records the updated rows given as `(key, old row, new row)` and resolves the `ON UPDATE`
+/// actions of the rows referencing them, the key follows the updated primary key.
+#[async_recursion(?Send)]
+pub async fn resolve_update<T: GStore>(
+    storage: &T,
+    changes: &mut Changes,
+    table_name: &str,
+    column_defs: Option<&[ColumnDef]>,
+    primary_key: &[String],
+    rows: Vec<(Key, Row, Row)>,
+) -> Result<()> {
+    let key_indexes = column_defs
+        .map(|column_defs| primary_key_indexes(column_defs, primary_key))
+        .unwrap_or_default();
+    let updated_rows = rows
+        .iter()
+        .map(|(key, _, row)| {
+            let new_key = match (key_indexes.is_empty(), row) {
+                (false, Row::Vec { values, .. }) => key_indexes
+                    .iter()
+                    .map(|i| {
+                        values
+                            .get(*i)
+                            .ok_or_else(|| UpdateError::ConflictOnSchema.into())
+                            .and_then(Key::try_from)
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .map(Key::from_primary_keys)?,
+                _ => key.clone(),
+            };
+
+            Ok((key.clone(), new_key, row.clone()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let moved = updated_rows.iter().any(|(key, new_key, _)| key != new_key);
+    changes.update(storage, table_name, updated_rows).await?;
+
+    // Only a primary key can be referenced, so rows keeping their keys have nothing to resolve.
+    if !moved {
+        return Ok(());
+    }
+
+    let referencings = storage.fetch_referencings(table_name).await?;
+
+    for Referencing {
+        table_name: referencing_table_name,
+        foreign_key,
+    } in &referencings
+    {
+        let ForeignKey {
+            referencing_column_name,
+            referenced_column_name,
+            on_update,
+            ..
+        } = foreign_key;
+
+        let mut old_values = Vec::new();
+        let mut updated_values = HashMap::new();
+        for (_, old_row, new_row) in &rows {
+            let (Some(old_value), Some(new_value)) = (
+                old_row.get_value(referenced_column_name),
+                new_row.get_value(referenced_column_name),
+            ) else {
+                continue;
+            };
+
+            if !old_value.is_null() && old_value != new_value {
+                updated_values.insert(Key::try_from(old_value)?, new_value.clone());
+                old_values.push(old_value.clone());
+            }
+        }
+        if old_values.is_empty() {
+            continue;
+        }
+
+        let referencing_rows = changes
+            .fetch_rows(
+                storage,
+                referencing_table_name,
+                referencing_column_name,
+                &old_values,
+            )
+            .await?;
+        if referencing_rows.is_empty() {
+            continue;
+        }
+
+        let referencing_rows = match on_update {
+            ReferentialAction::NoAction | ReferentialAction::Restrict => {
+                return Err(UpdateError::ReferencingColumnExists(format!(
+                    "{referencing_table_name}.{referencing_column_name}"
+                ))
+                .into());
+            }
+            ReferentialAction::Cascade => referencing_rows
+                .into_iter()
+                .map(|(key, row)| {
+                    let value = row
+                        .get_value(referencing_column_name)
+                        .map(Key::try_from)
+                        .transpose()?
+                        .and_then(|old_key| updated_values.get(&old_key))
+                        .cloned()
+                        .ok_or(UpdateError::ConflictOnSchema)?;
+
+                    Ok((key, row, value))
+                })
+                .collect::<Result<Vec<_>>>()?,
+            ReferentialAction::SetNull | ReferentialAction::SetDefault => {
+                let value = referential_value(
+                    storage,
+                    changes,
+                    referencing_table_name,
+                    foreign_key,
+                    on_update,
+                )
+                .await?;
+
+                referencing_rows
+                    .into_iter()
+                    .map(|(key, row)| (key, row, value.clone()))
+                    .collect()
+            }
+        };
+
+        set_referencing_column(
+            storage,
+            changes,
+            referencing_table_name,
+            referencing_column_name,
+            referencing_rows,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Sets the referencing column of `rows` given as `(key, row, value)` by a referential action,
+/// the updated rows are validated and their own referencing rows are resolved in turn.
+pub async fn set_referencing_column<T: GStore>(
+    storage: &T,
+    changes: &mut Changes,
+    table_name: &str,
+    column_name: &str,
+    rows: Vec<(Key, Row, Value)>,
+) -> Result<()> {
+    let Schema {
+        column_defs,
+        primary_key,
+        checks,
+        ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| FetchError::TableNotFound(table_name.to_owned()))?;
+
+    let rows = rows
+        .into_iter()
+        .map(|(key, row, value)| {
+            let new_row = match row.clone() {
+                Row::Vec {
+                    columns,
+                    mut values,
+                } => {
+                    let i = columns
+                        .iter()
+                        .position(|column| column == column_name)
+                        .ok_or_else(|| UpdateError::ColumnNotFound(column_name.to_owned()))?;
+                    values[i] = value;
+
+                    Row::Vec { columns, values }
+                }
+                Row::Map(values) => {
+                    Row::Map(values.concat(iter::once((column_name.to_owned(), value))))
+                }
+            };
+
+            Ok((key, row, new_row))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if let Some(column_defs) = column_defs.as_deref() {
+        let column_validation =
+            ColumnValidation::SpecifiedColumns(column_defs, vec![column_name.to_owned()]);
+        let values = rows.iter().filter_map(|(_, _, row)| match row {
+            Row::Vec { values, .. } => Some(values.as_slice()),
+            Row::Map(_) => None,
+        });
+
+        validate_check(storage, table_name, column_defs, &checks, values.clone()).await?;
+        validate_unique(storage, table_name, column_validation, values).await?;
+    }
+
+    changes.record_action(
+        table_name,
+        rows.iter()
+            .map(|(_, old, new)| (old.clone(), Some(new.clone()))),
+    );
+
+    resolve_update(
+        storage,
+        changes,
+        table_name,
+        column_defs.as_deref(),
+        &primary_key,
+        rows,
+    )
+    .await
+}
+
+/// Value set to the referencing column by `SET NULL` or `SET DEFAULT`,
+/// a non-null default must still be found on the referenced table.
+pub async fn referential_value<T: GStore>(
+    storage: &T,
+    changes: &Changes,
+    table_name: &str,
+    foreign_key: &ForeignKey,
+    action: &ReferentialAction,
+) -> Result<Value> {
+    let ForeignKey {
+        referencing_column_name,
+        referenced_table_name,
+        referenced_column_name,
+        ..
+    } = foreign_key;
+
+    let ColumnDef {
+        data_type,
+        nullable,
+        default,
+        ..
+    } = storage
+        .fetch_schema(table_name)
+        .await?
+        .ok_or_else(|| FetchError::TableNotFound(table_name.to_owned()))?
+        .column_defs
+        .and_then(|column_defs| {
+            column_defs
+                .into_iter()
+                .find(|column_def| &column_def.name == referencing_column_name)
+        })
+        .ok_or_else(|| UpdateError::ColumnNotFound(referencing_column_name.to_owned()))?;
+
+    let value = match (action, default) {
+        (ReferentialAction::SetDefault, Some(expr)) => evaluate_stateless(None, &expr)
+            .await?
+            .try_into_value(&data_type, nullable)?,
+        _ => Value::Null,
+    };
+    value.validate_null(nullable)?;
+
+    if !value.is_null()
+        && !changes
+            .exists(storage, referenced_table_name, &Key::try_from(&value)?)
+            .await?
+    {
+        return Err(UpdateError::CannotFindReferencedValue {
+            table_name: referenced_table_name.to_owned(),
+            column_name: referenced_column_name.to_owned(),
+            referenced_value: String::from(value),
+        }
+        .into());
+    }
+
+    Ok(value)
+}
@@ -1,14 +1,19 @@
 use {
+    super::{
+        context::RowContext,
+        evaluate::{between, binary_op, evaluate},
+    },
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption},
-        data::{Key, Value},
+        ast::{BinaryOperator, Check, ColumnDef, ColumnUniqueOption, Expr, ToSql, UnaryOperator},
+        data::{primary_key_indexes, Key, Row, Value},
         result::Result,
-        store::{DataRow, Store},
+        store::{DataRow, GStore, Store},
     },
+    async_recursion::async_recursion,
     futures::stream::TryStreamExt,
     im_rc::HashSet,
     serde::Serialize,
-    std::fmt::Debug,
+    std::{borrow::Cow, fmt::Debug, rc::Rc},
     thiserror::Error as ThisError,
     utils::Vector,
 };
@@ -26,11 +31,14 @@ pub enum ValidateError {
 
     #[error("duplicate entry '{0:?}' for primary_key field")]
     DuplicateEntryOnPrimaryKeyField(Key),
+
+    #[error("check constraint violated: {0}")]
+    CheckConstraintViolated(String),
 }
 
 pub enum ColumnValidation<'column_def> {
-    /// `INSERT`
-    All(&'column_def [ColumnDef]),
+    /// `INSERT`, with the columns of a `PRIMARY KEY (..)` constraint
+    All(&'column_def [ColumnDef], &'column_def [String]),
     /// `UPDATE`
     SpecifiedColumns(&'column_def [ColumnDef], Vec<String>),
 }
@@ -89,21 +97,15 @@ pub async fn validate_unique<T: Store>(
     row_iter: impl Iterator<Item = &[Value]> + Clone,
 ) -> Result<()> {
     enum Columns {
-        /// key index
-        PrimaryKeyOnly(usize),
+        /// key indexes
+        PrimaryKeyOnly(Vec<usize>),
         /// `[(key_index, table_name)]`
         All(Vec<(usize, String)>),
     }
 
     let columns = match &column_validation {
-        ColumnValidation::All(column_defs) => {
-            let primary_key_index = column_defs
-                .iter()
-                .enumerate()
-                .find(|(_, ColumnDef { unique, .. })| {
-                    unique == &Some(ColumnUniqueOption { is_primary: true })
-                })
-                .map(|(i, _)| i);
+        ColumnValidation::All(column_defs, primary_key) => {
+            let key_indexes = primary_key_indexes(column_defs, primary_key);
             let other_unique_column_def_count = column_defs
                 .iter()
                 .filter(|ColumnDef { unique, .. }| {
@@ -111,8 +113,14 @@ pub async fn validate_unique<T: Store>(
                 })
                 .count();
 
-            match (primary_key_index, other_unique_column_def_count) {
-                (Some(primary_key_index), 0) => Columns::PrimaryKeyOnly(primary_key_index),
+            match (key_indexes.len(), other_unique_column_def_count) {
+                (1.., 0) => Columns::PrimaryKeyOnly(key_indexes),
+                (2.., _) => {
+                    validate_primary_key(storage, table_name, &key_indexes, row_iter.clone())
+                        .await?;
+
+                    Columns::All(fetch_all_unique_columns(column_defs))
+                }
                 _ => Columns::All(fetch_all_unique_columns(column_defs)),
             }
         }
@@ -122,18 +130,8 @@ pub async fn validate_unique<T: Store>(
     };
 
     match columns {
-        Columns::PrimaryKeyOnly(primary_key_index) => {
-            for primary_key in
-                row_iter.filter_map(|row| row.get(primary_key_index).map(Key::try_from))
-            {
-                let key = primary_key?;
-
-                if storage.fetch_data(table_name, &key).await?.is_some() {
-                    return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(key).into());
-                }
-            }
-
-            Ok(())
+        Columns::PrimaryKeyOnly(primary_key_indexes) => {
+            validate_primary_key(storage, table_name, &primary_key_indexes, row_iter).await
         }
         Columns::All(columns) => {
             let unique_constraints: Vec<_> = create_unique_constraints(columns, row_iter)?.into();
@@ -171,6 +169,202 @@ pub async fn validate_unique<T: Store>(
     }
 }
 
+/// Evaluates the column and table `CHECK` constraints against each row,
+/// a row fails only when a constraint evaluates to `FALSE`, `NULL` passes.
+pub async fn validate_check<'a, T: GStore>(
+    storage: &T,
+    table_name: &str,
+    column_defs: &[ColumnDef],
+    checks: &[Check],
+    row_iter: impl Iterator<Item = &'a [Value]>,
+) -> Result<()> {
+    let checks = column_defs
+        .iter()
+        .filter_map(|ColumnDef { check, .. }| check.as_ref().map(|expr| (None, expr)))
+        .chain(
+            checks
+                .iter()
+                .map(|Check { name, expr }| (name.as_deref(), expr)),
+        )
+        .collect::<Vec<_>>();
+
+    if checks.is_empty() {
+        return Ok(());
+    }
+
+    let columns = column_defs
+        .iter()
+        .map(|column_def| column_def.name.to_owned())
+        .collect::<Rc<[String]>>();
+
+    for values in row_iter {
+        let row = Row::Vec {
+            columns: Rc::clone(&columns),
+            values: values.to_vec(),
+        };
+        let context = Rc::new(RowContext::new(table_name, Cow::Borrowed(&row), None));
+
+        for (name, expr) in &checks {
+            if evaluate_check(storage, Rc::clone(&context), expr).await? != Some(false) {
+                continue;
+            }
+
+            let constraint = name.map(ToOwned::to_owned).unwrap_or_else(|| expr.to_sql());
+
+            return Err(ValidateError::CheckConstraintViolated(constraint).into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Evaluates a `CHECK` expression with three-valued logic, `None` stands for `NULL`.
+///
+/// [`evaluate`] takes a comparison with `NULL` as `FALSE`, here comparisons, `IN` and `BETWEEN`
+/// with a `NULL` operand are `NULL`, and `AND`, `OR` and `NOT` combine the results of their
+/// operands.
+#[async_recursion(?Send)]
+async fn evaluate_check<'a, T: GStore>(
+    storage: &'a T,
+    context: Rc<RowContext<'a>>,
+    expr: &'a Expr,
+) -> Result<Option<bool>> {
+    let check = |expr| evaluate_check(storage, Rc::clone(&context), expr);
+    let eval = |expr| evaluate(storage, Some(Rc::clone(&context)), None, expr);
+
+    match expr {
+        Expr::Nested(expr) => check(expr).await,
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr,
+        } => Ok(check(expr).await?.map(|v| !v)),
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let left = check(left).await?;
+            let right = check(right).await?;
+
+            Ok(match (left, right) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            })
+        }
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Or,
+            right,
+        } => {
+            let left = check(left).await?;
+            let right = check(right).await?;
+
+            Ok(match (left, right) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            })
+        }
+        Expr::BinaryOp {
+            left,
+            op:
+                op @ (BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq),
+            right,
+        } => {
+            let left = eval(left).await?;
+            let right = eval(right).await?;
+
+            if left.is_null() || right.is_null() {
+                return Ok(None);
+            }
+
+            Ok(Some(bool::try_from(binary_op(op, left, right)?)?))
+        }
+        Expr::InList {
+            expr,
+            list,
+            negated,
+        } => {
+            let target = eval(expr).await?;
+
+            if target.is_null() {
+                return Ok(None);
+            }
+
+            let mut has_null = false;
+
+            for item in list {
+                let item = eval(item).await?;
+
+                if item.is_null() {
+                    has_null = true;
+                } else if item.evaluate_eq(&target) {
+                    return Ok(Some(!negated));
+                }
+            }
+
+            Ok((!has_null).then_some(*negated))
+        }
+        Expr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => {
+            let target = eval(expr).await?;
+            let low = eval(low).await?;
+            let high = eval(high).await?;
+
+            if target.is_null() || low.is_null() || high.is_null() {
+                return Ok(None);
+            }
+
+            Ok(Some(bool::try_from(between(target, *negated, low, high)?)?))
+        }
+        _ => {
+            let evaluated = eval(expr).await?;
+
+            if evaluated.is_null() {
+                return Ok(None);
+            }
+
+            Ok(Some(bool::try_from(evaluated)?))
+        }
+    }
+}
+
+async fn validate_primary_key<'a, T: Store>(
+    storage: &T,
+    table_name: &str,
+    primary_key_indexes: &[usize],
+    row_iter: impl Iterator<Item = &'a [Value]>,
+) -> Result<()> {
+    for row in row_iter {
+        let keys = primary_key_indexes
+            .iter()
+            .filter_map(|i| row.get(*i).map(Key::try_from))
+            .collect::<Result<Vec<_>>>()?;
+
+        if keys.len() != primary_key_indexes.len() {
+            continue;
+        }
+
+        let key = Key::from_primary_keys(keys);
+
+        if storage.fetch_data(table_name, &key).await?.is_some() {
+            return Err(ValidateError::DuplicateEntryOnPrimaryKeyField(key).into());
+        }
+    }
+
+    Ok(())
+}
+
 fn create_unique_constraints<'a>(
     unique_columns: Vec<(usize, String)>,
     row_iter: impl Iterator<Item = &'a [Value]> + Clone,
@@ -193,14 +387,28 @@ fn create_unique_constraints<'a>(
         })
 }
 
+/// Columns of a composite primary key are not unique by themselves,
+/// so only a single-column primary key is checked as a unique column.
 fn fetch_all_unique_columns(column_defs: &[ColumnDef]) -> Vec<(usize, String)> {
+    let composite = column_defs
+        .iter()
+        .filter(|ColumnDef { unique, .. }| unique == &Some(ColumnUniqueOption { is_primary: true }))
+        .count()
+        > 1;
+
     column_defs
         .iter()
         .enumerate()
-        .filter_map(|(i, table_col)| table_col.unique.map(|_| (i, table_col.name.to_owned())))
+        .filter_map(|(i, table_col)| match table_col.unique {
+            Some(ColumnUniqueOption { is_primary: true }) if composite => None,
+            Some(_) => Some((i, table_col.name.to_owned())),
+            None => None,
+        })
         .collect()
 }
 
+/// Updated primary keys are checked while the updated rows are recorded,
+/// as a stored row holding the new key may be moving away in the same statement.
 fn fetch_specified_unique_columns(
     all_column_defs: &[ColumnDef],
     specified_columns: &[String],
@@ -209,7 +417,7 @@ fn fetch_specified_unique_columns(
         .iter()
         .enumerate()
         .filter_map(|(i, table_col)| {
-            (table_col.unique.is_some()
+            (table_col.unique == Some(ColumnUniqueOption { is_primary: false })
                 && specified_columns.iter().any(|col| col == &table_col.name))
             .then_some((i, table_col.name.to_owned()))
         })
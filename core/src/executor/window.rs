@@ -0,0 +1,347 @@
+use {
+    super::{
+        aggregate::AggrValue,
+        context::{AggregateContext, RowContext},
+        evaluate::evaluate,
+        sort::sort_by,
+    },
+    crate::{
+        ast::{Expr, OrderByExpr, SelectItem, ToSql, Window, WindowFunction},
+        data::{Key, Value},
+        result::{Error, Result},
+        store::GStore,
+    },
+    futures::stream::{self, Stream, TryStreamExt},
+    serde::Serialize,
+    std::{
+        cmp::Ordering,
+        collections::{HashMap, HashSet},
+        fmt::Debug,
+        ops::Range,
+        rc::Rc,
+    },
+    thiserror::Error as ThisError,
+};
+
+#[derive(ThisError, Serialize, Debug, PartialEq, Eq)]
+pub enum WindowError {
+    #[error("offset must be a non-negative integer: {0}")]
+    InvalidOffset(String),
+}
+
+/// Evaluated ORDER BY keys and function arguments of a single row
+struct Entry {
+    order: Vec<(Key, Option<bool>)>,
+    args: Vec<Value>,
+}
+
+pub struct Windower<'a, T: GStore> {
+    storage: &'a T,
+    fields: &'a [SelectItem],
+    order_by: &'a [OrderByExpr],
+    filter_context: Option<Rc<RowContext<'a>>>,
+}
+
+impl<'a, T: GStore> Windower<'a, T> {
+    pub fn new(
+        storage: &'a T,
+        fields: &'a [SelectItem],
+        order_by: &'a [OrderByExpr],
+        filter_context: Option<Rc<RowContext<'a>>>,
+    ) -> Self {
+        Self {
+            storage,
+            fields,
+            order_by,
+            filter_context,
+        }
+    }
+
+    pub async fn apply(
+        &self,
+        rows: impl Stream<Item = Result<AggregateContext<'a>>>,
+    ) -> Result<impl Stream<Item = Result<AggregateContext<'a>>>> {
+        #[derive(futures_enum::Stream)]
+        enum S<T1, T2> {
+            NonWindow(T1),
+            Window(T2),
+        }
+
+        let windows = self.windows();
+        if windows.is_empty() {
+            return Ok(S::NonWindow(rows));
+        }
+
+        let rows = rows.try_collect::<Vec<_>>().await?;
+        let mut values = vec![HashMap::new(); rows.len()];
+
+        for window in windows {
+            let evaluated = self.evaluate_window(window, &rows).await?;
+
+            for (values, value) in values.iter_mut().zip(evaluated) {
+                values.insert(window, value);
+            }
+        }
+
+        let rows = rows.into_iter().zip(values).map(|(row, values)| {
+            let AggregateContext { aggregated, next } = row;
+            let next = Rc::new(RowContext::new_window(values, next));
+
+            Ok(AggregateContext { aggregated, next })
+        });
+
+        Ok(S::Window(stream::iter(rows)))
+    }
+
+    fn windows(&self) -> Vec<&'a Window> {
+        let mut windows = Vec::new();
+        let exprs = self
+            .fields
+            .iter()
+            .filter_map(|field| match field {
+                SelectItem::Expr { expr, .. } => Some(expr),
+                _ => None,
+            })
+            .chain(self.order_by.iter().map(|OrderByExpr { expr, .. }| expr));
+
+        for expr in exprs {
+            find_windows(expr, &mut windows);
+        }
+
+        windows
+    }
+
+    async fn evaluate_window(
+        &self,
+        window: &'a Window,
+        rows: &[AggregateContext<'a>],
+    ) -> Result<Vec<Value>> {
+        let Window {
+            function,
+            partition_by,
+            order_by,
+        } = window;
+
+        let mut partitions = HashMap::new();
+        let mut entries = Vec::with_capacity(rows.len());
+
+        for (index, AggregateContext { aggregated, next }) in rows.iter().enumerate() {
+            let context = match &self.filter_context {
+                Some(filter_context) => Rc::new(RowContext::concat(
+                    Rc::clone(next),
+                    Rc::clone(filter_context),
+                )),
+                None => Rc::clone(next),
+            };
+            let aggregated = aggregated.clone().map(Rc::new);
+            let eval = |expr: &'a Expr| {
+                let context = Some(Rc::clone(&context));
+                let aggregated = aggregated.as_ref().map(Rc::clone);
+
+                async move {
+                    evaluate(self.storage, context, aggregated, expr)
+                        .await?
+                        .try_into()
+                }
+            };
+
+            let partition = stream::iter(partition_by.iter().map(Ok))
+                .and_then(|expr| async { eval(expr).await.and_then(Key::try_from) })
+                .try_collect::<Vec<_>>()
+                .await?;
+            let order = stream::iter(order_by.iter().map(Ok))
+                .and_then(|OrderByExpr { expr, asc }| async {
+                    eval(expr)
+                        .await
+                        .and_then(Key::try_from)
+                        .map(|key| (key, *asc))
+                })
+                .try_collect::<Vec<_>>()
+                .await?;
+            let args = stream::iter(function.as_exprs().map(Ok))
+                .and_then(eval)
+                .try_collect::<Vec<Value>>()
+                .await?;
+
+            partitions
+                .entry(partition)
+                .or_insert_with(Vec::new)
+                .push(index);
+            entries.push(Entry { order, args });
+        }
+
+        let mut evaluated = vec![Value::Null; rows.len()];
+
+        for (_, mut indexes) in partitions {
+            indexes.sort_by(|a, b| sort_by(&entries[*a].order, &entries[*b].order));
+
+            let peers = peer_groups(&indexes, &entries);
+            let values = evaluate_partition(function, &indexes, &peers, &entries).await?;
+
+            for (index, value) in indexes.into_iter().zip(values) {
+                evaluated[index] = value;
+            }
+        }
+
+        Ok(evaluated)
+    }
+}
+
+/// Splits sorted partition rows into ranges of rows sharing the same ORDER BY keys
+fn peer_groups(indexes: &[usize], entries: &[Entry]) -> Vec<Range<usize>> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+
+    for position in 1..=indexes.len() {
+        let is_peer = indexes.get(position).map(|&index| {
+            let prev = &entries[indexes[position - 1]].order;
+
+            sort_by(prev, &entries[index].order) == Ordering::Equal
+        });
+
+        if is_peer != Some(true) {
+            groups.push(start..position);
+            start = position;
+        }
+    }
+
+    groups
+}
+
+/// Evaluates the function for each row of a sorted partition, in partition order.
+/// Aggregates use the default frame, from the partition start to the last peer of the current row.
+async fn evaluate_partition(
+    function: &WindowFunction,
+    indexes: &[usize],
+    peers: &[Range<usize>],
+    entries: &[Entry],
+) -> Result<Vec<Value>> {
+    let mut values = vec![Value::Null; indexes.len()];
+
+    match function {
+        WindowFunction::RowNumber => {
+            for (position, value) in values.iter_mut().enumerate() {
+                *value = Value::I64(position as i64 + 1);
+            }
+        }
+        WindowFunction::Rank | WindowFunction::DenseRank => {
+            for (rank, peer) in peers.iter().enumerate() {
+                let rank = match function {
+                    WindowFunction::Rank => peer.start + 1,
+                    _ => rank + 1,
+                };
+
+                for value in &mut values[peer.clone()] {
+                    *value = Value::I64(rank as i64);
+                }
+            }
+        }
+        WindowFunction::Lag {
+            offset, default, ..
+        }
+        | WindowFunction::Lead {
+            offset, default, ..
+        } => {
+            for (position, value) in values.iter_mut().enumerate() {
+                let mut args = entries[indexes[position]].args.iter().skip(1);
+                let distance = match offset.as_ref().and(args.next()) {
+                    Some(distance) => i64::try_from(distance)
+                        .ok()
+                        .and_then(|distance| usize::try_from(distance).ok())
+                        .ok_or_else(|| -> Error {
+                            WindowError::InvalidOffset(function.to_sql()).into()
+                        })?,
+                    None => 1,
+                };
+                let default = default
+                    .as_ref()
+                    .and(args.next())
+                    .cloned()
+                    .unwrap_or(Value::Null);
+
+                let position = match function {
+                    WindowFunction::Lag { .. } => position.checked_sub(distance),
+                    _ => position.checked_add(distance),
+                };
+
+                *value = match position.and_then(|position| indexes.get(position)) {
+                    Some(&index) => entries[index].args.first().cloned().unwrap_or(Value::Null),
+                    None => default,
+                };
+            }
+        }
+        WindowFunction::Aggregate(aggregate) => {
+            let mut state: Option<AggrValue> = None;
+            let mut distinct_values = HashSet::new();
+
+            for peer in peers {
+                for &index in &indexes[peer.clone()] {
+                    let value = entries[index].args.first().unwrap_or(&Value::Null);
+
+                    if aggregate.is_distinct() && !distinct_values.insert(Key::try_from(value)?) {
+                        continue;
+                    }
+
+                    state = Some(match state {
+                        Some(state) => state.accumulate(value)?.unwrap_or(state),
+                        None => AggrValue::new(aggregate, value)?,
+                    });
+                }
+
+                let value = match &state {
+                    Some(state) => state.clone().export().await?,
+                    None => Value::Null,
+                };
+
+                for slot in &mut values[peer.clone()] {
+                    *slot = value.clone();
+                }
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+fn find_windows<'a>(expr: &'a Expr, windows: &mut Vec<&'a Window>) {
+    match expr {
+        Expr::Window(window) => {
+            if !windows.contains(&window.as_ref()) {
+                windows.push(window);
+            }
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            for expr in [expr, low, high] {
+                find_windows(expr, windows);
+            }
+        }
+        Expr::BinaryOp { left, right, .. } => {
+            find_windows(left, windows);
+            find_windows(right, windows);
+        }
+        Expr::UnaryOp { expr, .. } | Expr::Nested(expr) => find_windows(expr, windows),
+        Expr::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            let exprs = operand
+                .iter()
+                .map(AsRef::as_ref)
+                .chain(when_then.iter().flat_map(|(when, then)| [when, then]))
+                .chain(else_result.iter().map(AsRef::as_ref));
+
+            for expr in exprs {
+                find_windows(expr, windows);
+            }
+        }
+        Expr::Function(function) => {
+            for expr in function.as_exprs() {
+                find_windows(expr, windows);
+            }
+        }
+        _ => {}
+    }
+}
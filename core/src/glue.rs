@@ -1,7 +1,11 @@
 use {
     crate::{
-        ast::Statement,
-        executor::{execute, Payload},
+        ast::{Query, Statement},
+        data::{Row, Value},
+        executor::{
+            execute, execute_with_params, select_stream, select_with_labels, ExecuteError, Payload,
+            PayloadStream,
+        },
         parse_sql::parse,
         plan::plan,
         result::Result,
@@ -9,22 +13,44 @@ use {
         translate::translate,
     },
     futures::{
+        channel::mpsc::{self, Sender},
+        future,
         stream::{self, StreamExt},
-        TryStreamExt,
+        FutureExt, SinkExt, TryStreamExt,
     },
 };
 
+/// Statement parsed and planned once by [`Glue::prepare`],
+/// it can be executed many times with [`Glue::execute_with_params`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedStatement {
+    statement: Statement,
+}
+
+impl PreparedStatement {
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+}
+
 #[derive(Debug)]
 pub struct Glue<T: GStore + GStoreMut> {
     pub storage: T,
+    /// Autocommit transaction opened by [`Glue::execute_stream`] which is not committed yet
+    stream_autocommit: bool,
 }
 
 impl<T: GStore + GStoreMut> Glue<T> {
     pub fn new(storage: T) -> Self {
-        Self { storage }
+        Self {
+            storage,
+            stream_autocommit: false,
+        }
     }
 
     pub async fn plan<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Statement>> {
+        self.finish_stream().await?;
+
         let parsed = parse(sql)?;
         let storage = &self.storage;
         stream::iter(parsed)
@@ -35,9 +61,98 @@ impl<T: GStore + GStoreMut> Glue<T> {
     }
 
     pub async fn execute_stmt(&mut self, statement: &Statement) -> Result<Payload> {
+        self.finish_stream().await?;
+
         execute(&mut self.storage, statement).await
     }
 
+    /// Executes the statement like [`Glue::execute_stmt`], but rows of a query are not collected,
+    /// they are fetched from the storage while the returned stream is polled.
+    ///
+    /// Outside of an explicit transaction, the query runs in an autocommit transaction.
+    /// It is rolled back when the query fails and committed when the returned stream is exhausted,
+    /// a stream dropped before its end leaves it to [`Glue::finish_stream`] or the next execution.
+    pub async fn execute_stream<'a>(
+        &'a mut self,
+        statement: &'a Statement,
+    ) -> Result<PayloadStream<'a>> {
+        self.finish_stream().await?;
+
+        let query = match statement {
+            Statement::Query(query) => query,
+            _ => {
+                return self
+                    .execute_stmt(statement)
+                    .await
+                    .map(PayloadStream::Payload)
+            }
+        };
+
+        if !self.storage.begin(true).await? {
+            return select_stream(&self.storage, query, None).await;
+        }
+        self.stream_autocommit = true;
+
+        // the rows borrow the storage until they are dropped, so they are forwarded through
+        // a channel by a task which finishes the transaction once they are done
+        let (mut sender, receiver) = mpsc::channel(0);
+        let task = async move {
+            let result = match forward_rows(&self.storage, query, &mut sender).await {
+                Ok(()) => self.finish_stream().await,
+                Err(error) => self.rollback_stream().await.and(Err(error)),
+            };
+
+            if let Err(error) = result {
+                let _ = sender.send(Err(error)).await;
+            }
+        };
+        let task = task.into_stream().filter_map(|()| future::ready(None));
+        let mut messages = stream::select(receiver, task).boxed_local();
+
+        let labels = match messages.next().await {
+            Some(Ok(StreamMessage::Labels(labels))) => labels,
+            Some(Err(error)) => return Err(error),
+            Some(Ok(StreamMessage::Row(_))) | None => {
+                unreachable!("labels are sent before the rows")
+            }
+        };
+        let rows = messages.filter_map(|message| {
+            future::ready(match message {
+                Ok(StreamMessage::Row(row)) => Some(Ok(row)),
+                Ok(StreamMessage::Labels(_)) => None,
+                Err(error) => Some(Err(error)),
+            })
+        });
+
+        Ok(match labels {
+            Some(labels) => PayloadStream::Select {
+                labels,
+                rows: rows.map(|row| row?.try_into_vec()).boxed_local(),
+            },
+            None => PayloadStream::SelectMap(rows.map(|row| row?.try_into_map()).boxed_local()),
+        })
+    }
+
+    /// Commits the autocommit transaction opened by the last [`Glue::execute_stream`].
+    pub async fn finish_stream(&mut self) -> Result<()> {
+        if self.stream_autocommit {
+            self.stream_autocommit = false;
+            self.storage.commit().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Rolls back the autocommit transaction opened by the last [`Glue::execute_stream`].
+    pub async fn rollback_stream(&mut self) -> Result<()> {
+        if self.stream_autocommit {
+            self.stream_autocommit = false;
+            self.storage.rollback().await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn execute<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<Vec<Payload>> {
         let statements = self.plan(sql).await?;
         let mut payloads = Vec::<Payload>::new();
@@ -48,4 +163,57 @@ impl<T: GStore + GStoreMut> Glue<T> {
 
         Ok(payloads)
     }
+
+    /// Plans a single statement which may contain `$1`, `$2`, ... or `?` placeholders.
+    pub async fn prepare<Sql: AsRef<str>>(&mut self, sql: Sql) -> Result<PreparedStatement> {
+        let mut statements = self.plan(sql).await?;
+        if statements.len() != 1 {
+            return Err(ExecuteError::PrepareRequiresSingleStatement(statements.len()).into());
+        }
+
+        Ok(PreparedStatement {
+            statement: statements.remove(0),
+        })
+    }
+
+    /// Executes the prepared statement, `$1` is bound to `params[0]`.
+    pub async fn execute_with_params(
+        &mut self,
+        prepared: &PreparedStatement,
+        params: &[Value],
+    ) -> Result<Payload> {
+        self.finish_stream().await?;
+
+        execute_with_params(&mut self.storage, &prepared.statement, params).await
+    }
+}
+
+enum StreamMessage {
+    Labels(Option<Vec<String>>),
+    Row(Row),
+}
+
+/// Sends the labels and then the rows of the query, it stops early when the receiver is dropped.
+async fn forward_rows<T: GStore>(
+    storage: &T,
+    query: &Query,
+    sender: &mut Sender<Result<StreamMessage>>,
+) -> Result<()> {
+    let (labels, rows) = select_with_labels(storage, query, None).await?;
+    if sender
+        .send(Ok(StreamMessage::Labels(labels)))
+        .await
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let mut rows = Box::pin(rows);
+    while let Some(row) = rows.next().await {
+        if sender.send(Ok(StreamMessage::Row(row?))).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
 }
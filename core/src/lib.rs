@@ -20,8 +20,8 @@ pub mod prelude {
     pub use crate::{
         ast::DataType,
         data::{Key, Value},
-        executor::{execute, Payload, PayloadVariable},
-        glue::Glue,
+        executor::{execute, Payload, PayloadStream, PayloadVariable},
+        glue::{Glue, PreparedStatement},
         parse_sql::parse,
         plan::plan,
         result::{Error, Result},
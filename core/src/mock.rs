@@ -4,7 +4,7 @@ use {
         result::{Error, Result},
         store::{
             AlterTable, CustomFunction, CustomFunctionMut, DataRow, Index, IndexMut, Metadata,
-            RowIter, Store, StoreMut, Transaction,
+            RowIter, Sequence, SequenceMut, Store, StoreMut, Transaction, View, ViewMut,
         },
     },
     async_trait::async_trait,
@@ -41,6 +41,18 @@ impl CustomFunction for MockStorage {}
 #[async_trait(?Send)]
 impl CustomFunctionMut for MockStorage {}
 
+#[async_trait(?Send)]
+impl View for MockStorage {}
+
+#[async_trait(?Send)]
+impl ViewMut for MockStorage {}
+
+#[async_trait(?Send)]
+impl Sequence for MockStorage {}
+
+#[async_trait(?Send)]
+impl SequenceMut for MockStorage {}
+
 #[async_trait(?Send)]
 impl Store for MockStorage {
     async fn fetch_schema(&self, table_name: &str) -> Result<Option<Schema>> {
@@ -129,6 +141,8 @@ mod tests {
                 nullable: false,
                 default: None,
                 unique: None,
+                check: None,
+                identity: None,
                 comment: None,
             },
         ))
@@ -136,17 +150,19 @@ mod tests {
         assert!(block_on(storage.drop_column("Foo", "col", false)).is_err());
 
         // Index & IndexMut
-        assert!(block_on(storage.scan_indexed_data("Foo", "idx_col", None, None)).is_err());
+        assert!(
+            block_on(storage.scan_indexed_data("Foo", "idx_col", None, &[], None, None)).is_err()
+        );
         assert!(block_on(storage.create_index(
             "Foo",
             "idx_col",
-            &OrderByExpr {
+            &[OrderByExpr {
                 expr: Expr::TypedString {
                     data_type: DataType::Boolean,
                     value: "true".to_owned(),
                 },
                 asc: None,
-            },
+            }],
         ))
         .is_err());
         assert!(block_on(storage.drop_index("Foo", "idx_col")).is_err());
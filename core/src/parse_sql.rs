@@ -1,5 +1,8 @@
 use {
-    crate::result::{Error, Result},
+    crate::{
+        ast::{TriggerEvent, TriggerTiming},
+        result::{Error, Result},
+    },
     sqlparser::{
         ast::{
             Assignment as SqlAssignment, ColumnDef as SqlColumnDef, DataType as SqlDataType,
@@ -7,15 +10,208 @@ use {
             SelectItem as SqlSelectItem, Statement as SqlStatement,
         },
         dialect::PostgreSqlDialect,
-        parser::Parser,
-        tokenizer::Tokenizer,
+        keywords::Keyword,
+        parser::{Parser, ParserError},
+        tokenizer::{Token, TokenWithLocation, Tokenizer},
     },
 };
 
 const DIALECT: PostgreSqlDialect = PostgreSqlDialect {};
 
-pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<SqlStatement>> {
-    Parser::parse_sql(&DIALECT, sql.as_ref()).map_err(|e| Error::Parser(format!("{:#?}", e)))
+/// Statement returned by [`parse`].
+///
+/// Triggers are parsed by GlueSQL itself, the body of `CREATE TRIGGER` is a list of statements
+/// which `sqlparser` does not support.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedStatement {
+    Statement(SqlStatement),
+    CreateTrigger {
+        name: String,
+        table_name: String,
+        timing: TriggerTiming,
+        events: Vec<TriggerEvent>,
+        condition: Option<SqlExpr>,
+        body: Vec<SqlStatement>,
+    },
+    DropTrigger {
+        if_exists: bool,
+        name: String,
+        table_name: String,
+    },
+}
+
+pub fn parse<Sql: AsRef<str>>(sql: Sql) -> Result<Vec<ParsedStatement>> {
+    let mut tokens = Tokenizer::new(&DIALECT, sql.as_ref())
+        .tokenize_with_location()
+        .map_err(|e| Error::Parser(format!("{:#?}", e)))?;
+    number_placeholders(&mut tokens);
+
+    let mut parser = Parser::new(&DIALECT).with_tokens_with_locations(tokens);
+
+    parse_statements(&mut parser).map_err(|e| Error::Parser(format!("{:#?}", e)))
+}
+
+fn parse_statements(parser: &mut Parser) -> Result<Vec<ParsedStatement>, ParserError> {
+    let mut statements = Vec::new();
+    let mut expecting_delimiter = false;
+
+    loop {
+        while parser.consume_token(&Token::SemiColon) {
+            expecting_delimiter = false;
+        }
+
+        let token = parser.peek_token();
+        if token.token == Token::EOF {
+            return Ok(statements);
+        } else if expecting_delimiter {
+            return parser.expected("end of statement", token);
+        }
+
+        let statement = if parser.parse_keywords(&[Keyword::CREATE, Keyword::TRIGGER]) {
+            parse_create_trigger(parser)?
+        } else if parser.parse_keywords(&[Keyword::DROP, Keyword::TRIGGER]) {
+            parse_drop_trigger(parser)?
+        } else {
+            parser.parse_statement().map(ParsedStatement::Statement)?
+        };
+
+        statements.push(statement);
+        expecting_delimiter = true;
+    }
+}
+
+/// `CREATE TRIGGER <name> { BEFORE | AFTER } <event> [ OR <event> ]... ON <table>
+/// FOR EACH ROW [ WHEN (<condition>) ] { <statement> | BEGIN <statement>; ... END }`
+fn parse_create_trigger(parser: &mut Parser) -> Result<ParsedStatement, ParserError> {
+    let name = parse_name(parser)?;
+    let timing = if parse_word(parser, "BEFORE") {
+        TriggerTiming::Before
+    } else if parser.parse_keyword(Keyword::AFTER) {
+        TriggerTiming::After
+    } else {
+        return parser.expected("BEFORE or AFTER", parser.peek_token());
+    };
+
+    let mut events = vec![parse_trigger_event(parser)?];
+    while parser.parse_keyword(Keyword::OR) {
+        events.push(parse_trigger_event(parser)?);
+    }
+
+    parser.expect_keyword(Keyword::ON)?;
+    let table_name = parse_name(parser)?;
+
+    parser.expect_keyword(Keyword::FOR)?;
+    parser.expect_keyword(Keyword::EACH)?;
+    parser.expect_keyword(Keyword::ROW)?;
+
+    let condition = match parser.parse_keyword(Keyword::WHEN) {
+        true => {
+            parser.expect_token(&Token::LParen)?;
+            let condition = parser.parse_expr()?;
+            parser.expect_token(&Token::RParen)?;
+
+            Some(condition)
+        }
+        false => None,
+    };
+
+    if !parser.parse_keyword(Keyword::BEGIN) {
+        let body = vec![parser.parse_statement()?];
+
+        return Ok(ParsedStatement::CreateTrigger {
+            name,
+            table_name,
+            timing,
+            events,
+            condition,
+            body,
+        });
+    }
+
+    let mut body = Vec::new();
+    loop {
+        while parser.consume_token(&Token::SemiColon) {}
+
+        if parser.parse_keyword(Keyword::END) {
+            break;
+        }
+
+        body.push(parser.parse_statement()?);
+
+        if !parser.consume_token(&Token::SemiColon) {
+            parser.expect_keyword(Keyword::END)?;
+            break;
+        }
+    }
+
+    Ok(ParsedStatement::CreateTrigger {
+        name,
+        table_name,
+        timing,
+        events,
+        condition,
+        body,
+    })
+}
+
+/// `DROP TRIGGER [ IF EXISTS ] <name> ON <table>`
+fn parse_drop_trigger(parser: &mut Parser) -> Result<ParsedStatement, ParserError> {
+    let if_exists = parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+    let name = parse_name(parser)?;
+    parser.expect_keyword(Keyword::ON)?;
+    let table_name = parse_name(parser)?;
+
+    Ok(ParsedStatement::DropTrigger {
+        if_exists,
+        name,
+        table_name,
+    })
+}
+
+fn parse_trigger_event(parser: &mut Parser) -> Result<TriggerEvent, ParserError> {
+    if parser.parse_keyword(Keyword::INSERT) {
+        Ok(TriggerEvent::Insert)
+    } else if parser.parse_keyword(Keyword::UPDATE) {
+        Ok(TriggerEvent::Update)
+    } else if parser.parse_keyword(Keyword::DELETE) {
+        Ok(TriggerEvent::Delete)
+    } else {
+        parser.expected("INSERT, UPDATE or DELETE", parser.peek_token())
+    }
+}
+
+/// Consumes the next token if it is the given word, `BEFORE` is not a keyword of `sqlparser`.
+fn parse_word(parser: &mut Parser, expected: &str) -> bool {
+    match parser.peek_token().token {
+        Token::Word(word) if word.value.eq_ignore_ascii_case(expected) => {
+            parser.next_token();
+
+            true
+        }
+        _ => false,
+    }
+}
+
+fn parse_name(parser: &mut Parser) -> Result<String, ParserError> {
+    let token = parser.next_token();
+
+    if let Token::Word(word) = &token.token {
+        return Ok(word.value.clone());
+    }
+
+    parser.expected("identifier", token)
+}
+
+/// Numbers `?` placeholders in order of appearance, so they are bound like `$1`, `$2`, ...
+fn number_placeholders(tokens: &mut [TokenWithLocation]) {
+    tokens
+        .iter_mut()
+        .map(|TokenWithLocation { token, .. }| token)
+        .filter(|token| token.to_string() == "?")
+        .enumerate()
+        .for_each(|(index, token)| {
+            *token = Token::Placeholder(format!("${}", index + 1));
+        });
 }
 
 macro_rules! generate_parse_fn {
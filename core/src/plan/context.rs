@@ -4,7 +4,7 @@ pub enum Context<'a> {
     Data {
         alias: String,
         columns: Vec<&'a str>,
-        primary_key: Option<&'a str>,
+        primary_key: Vec<&'a str>,
         next: Option<Rc<Context<'a>>>,
     },
     Bridge {
@@ -17,7 +17,7 @@ impl<'a> Context<'a> {
     pub fn new(
         alias: String,
         columns: Vec<&'a str>,
-        primary_key: Option<&'a str>,
+        primary_key: Vec<&'a str>,
         next: Option<Rc<Context<'a>>>,
     ) -> Self {
         Context::Data {
@@ -83,10 +83,7 @@ impl<'a> Context<'a> {
 
     pub fn contains_primary_key(&self, target_column: &str) -> bool {
         match self {
-            Self::Data {
-                primary_key: Some(primary_key),
-                ..
-            } if primary_key == &target_column => true,
+            Self::Data { primary_key, .. } if primary_key == &[target_column] => true,
             Self::Data { next, .. } => next
                 .as_ref()
                 .map(|next| next.contains_primary_key(target_column))
@@ -3,14 +3,39 @@ use crate::ast::{Aggregate, CountArgExpr, Expr};
 impl Aggregate {
     pub fn as_expr(&self) -> Option<&Expr> {
         match self {
-            Aggregate::Count(CountArgExpr::Wildcard) => None,
-            Aggregate::Count(CountArgExpr::Expr(expr))
-            | Aggregate::Sum(expr)
-            | Aggregate::Max(expr)
-            | Aggregate::Min(expr)
-            | Aggregate::Avg(expr)
-            | Aggregate::Variance(expr)
-            | Aggregate::Stdev(expr) => Some(expr),
+            Aggregate::Count {
+                expr: CountArgExpr::Wildcard,
+                ..
+            } => None,
+            Aggregate::Count {
+                expr: CountArgExpr::Expr(expr),
+                ..
+            }
+            | Aggregate::Sum { expr, .. }
+            | Aggregate::Max { expr, .. }
+            | Aggregate::Min { expr, .. }
+            | Aggregate::Avg { expr, .. }
+            | Aggregate::Variance { expr, .. }
+            | Aggregate::Stdev { expr, .. } => Some(expr),
+        }
+    }
+
+    pub fn as_expr_mut(&mut self) -> Option<&mut Expr> {
+        match self {
+            Aggregate::Count {
+                expr: CountArgExpr::Wildcard,
+                ..
+            } => None,
+            Aggregate::Count {
+                expr: CountArgExpr::Expr(expr),
+                ..
+            }
+            | Aggregate::Sum { expr, .. }
+            | Aggregate::Max { expr, .. }
+            | Aggregate::Min { expr, .. }
+            | Aggregate::Avg { expr, .. }
+            | Aggregate::Variance { expr, .. }
+            | Aggregate::Stdev { expr, .. } => Some(expr),
         }
     }
 }
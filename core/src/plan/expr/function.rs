@@ -3,216 +3,229 @@ use {
     std::iter::{empty, once},
 };
 
-impl Function {
-    pub fn as_exprs(&self) -> impl Iterator<Item = &Expr> {
-        #[derive(iter_enum::Iterator)]
-        enum Exprs<I0, I1, I2, I3, I4, I5, I6> {
-            Empty(I0),
-            Single(I1),
-            Double(I2),
-            Triple(I3),
-            VariableArgs(I4),
-            VariableArgsWithSingle(I5),
-            Quadruple(I6),
-        }
-
-        match self {
-            Self::Now() | Function::Pi() | Function::GenerateUuid() | Self::Rand(None) => {
-                Exprs::Empty(empty())
-            }
-            Self::Lower(expr)
-            | Self::Length(expr)
-            | Self::Initcap(expr)
-            | Self::Upper(expr)
-            | Self::Sin(expr)
-            | Self::Cos(expr)
-            | Self::Tan(expr)
-            | Self::Asin(expr)
-            | Self::Acos(expr)
-            | Self::Atan(expr)
-            | Self::Radians(expr)
-            | Self::Degrees(expr)
-            | Self::Ceil(expr)
-            | Self::Rand(Some(expr))
-            | Self::Round(expr)
-            | Self::Floor(expr)
-            | Self::Exp(expr)
-            | Self::Ln(expr)
-            | Self::Log2(expr)
-            | Self::Log10(expr)
-            | Self::Sqrt(expr)
-            | Self::Abs(expr)
-            | Self::Sign(expr)
-            | Self::Ascii(expr)
-            | Self::Chr(expr)
-            | Self::Md5(expr)
-            | Self::LastDay(expr)
-            | Self::Ltrim { expr, chars: None }
-            | Self::Rtrim { expr, chars: None }
-            | Self::Trim {
-                expr,
-                filter_chars: None,
-                ..
-            }
-            | Self::Reverse(expr)
-            | Self::Cast { expr, .. }
-            | Self::Extract { expr, .. }
-            | Self::GetX(expr)
-            | Self::GetY(expr)
-            | Self::IsEmpty(expr)
-            | Self::Sort { expr, order: None }
-            | Self::Dedup(expr)
-            | Self::Entries(expr)
-            | Self::Keys(expr)
-            | Self::Values(expr) => Exprs::Single([expr].into_iter()),
-            Self::Left { expr, size: expr2 }
-            | Self::Right { expr, size: expr2 }
-            | Self::Lpad {
-                expr,
-                size: expr2,
-                fill: None,
-            }
-            | Self::Rpad {
-                expr,
-                size: expr2,
-                fill: None,
-            }
-            | Self::Trim {
-                expr,
-                filter_chars: Some(expr2),
-                ..
-            }
-            | Self::Log {
-                antilog: expr,
-                base: expr2,
-            }
-            | Self::Div {
-                dividend: expr,
-                divisor: expr2,
-            }
-            | Self::Mod {
-                dividend: expr,
-                divisor: expr2,
-            }
-            | Self::Gcd {
-                left: expr,
-                right: expr2,
-            }
-            | Self::Lcm {
-                left: expr,
-                right: expr2,
-            }
-            | Self::Format {
-                expr,
-                format: expr2,
-            }
-            | Self::ToDate {
-                expr,
-                format: expr2,
-            }
-            | Self::ToTimestamp {
-                expr,
-                format: expr2,
-            }
-            | Self::ToTime {
-                expr,
-                format: expr2,
-            }
-            | Self::Power { expr, power: expr2 }
-            | Self::Ltrim {
-                expr,
-                chars: Some(expr2),
-            }
-            | Self::Rtrim {
-                expr,
-                chars: Some(expr2),
+macro_rules! as_exprs {
+    ($fn_name: ident $(, $mut: tt)?) => {
+        pub fn $fn_name(&$($mut)? self) -> impl Iterator<Item = &$($mut)? Expr> {
+            #[derive(iter_enum::Iterator)]
+            enum Exprs<I0, I1, I2, I3, I4, I5, I6> {
+                Empty(I0),
+                Single(I1),
+                Double(I2),
+                Triple(I3),
+                VariableArgs(I4),
+                VariableArgsWithSingle(I5),
+                Quadruple(I6),
             }
-            | Self::Repeat { expr, num: expr2 }
-            | Self::Substr {
-                expr,
-                start: expr2,
-                count: None,
-            }
-            | Self::IfNull { expr, then: expr2 }
-            | Self::Unwrap {
-                expr,
-                selector: expr2,
-            }
-            | Self::Position {
-                from_expr: expr2,
-                sub_expr: expr,
-            }
-            | Self::FindIdx {
-                from_expr: expr,
-                sub_expr: expr2,
-                start: None,
-            }
-            | Self::Append { expr, value: expr2 }
-            | Self::Prepend { expr, value: expr2 }
-            | Self::Skip { expr, size: expr2 }
-            | Self::Sort {
-                expr,
-                order: Some(expr2),
-            }
-            | Self::Take { expr, size: expr2 }
-            | Self::Point { x: expr, y: expr2 }
-            | Self::CalcDistance {
-                geometry1: expr,
-                geometry2: expr2,
-            }
-            | Self::AddMonth { expr, size: expr2 } => Exprs::Double([expr, expr2].into_iter()),
 
-            Self::Lpad {
-                expr,
-                size: expr2,
-                fill: Some(expr3),
-            }
-            | Self::Rpad {
-                expr,
-                size: expr2,
-                fill: Some(expr3),
-            }
-            | Self::Substr {
-                expr,
-                start: expr2,
-                count: Some(expr3),
-            }
-            | Self::Replace {
-                expr,
-                old: expr2,
-                new: expr3,
-            }
-            | Self::Slice {
-                expr,
-                start: expr2,
-                length: expr3,
-            }
-            | Self::FindIdx {
-                from_expr: expr,
-                sub_expr: expr2,
-                start: Some(expr3),
-            }
-            | Self::Splice {
-                list_data: expr,
-                begin_index: expr2,
-                end_index: expr3,
-                values: None,
-            } => Exprs::Triple([expr, expr2, expr3].into_iter()),
-            Self::Custom { name: _, exprs } => Exprs::VariableArgs(exprs.iter()),
-            Self::Coalesce(exprs) => Exprs::VariableArgs(exprs.iter()),
-            Self::Concat(exprs) => Exprs::VariableArgs(exprs.iter()),
-            Self::ConcatWs { separator, exprs } => {
-                Exprs::VariableArgsWithSingle(once(separator).chain(exprs.iter()))
+            match self {
+                Self::Now() | Function::Pi() | Function::GenerateUuid() | Self::Rand(None) => {
+                    Exprs::Empty(empty())
+                }
+                Self::Lower(expr)
+                | Self::Length(expr)
+                | Self::Initcap(expr)
+                | Self::Upper(expr)
+                | Self::Sin(expr)
+                | Self::Cos(expr)
+                | Self::Tan(expr)
+                | Self::Asin(expr)
+                | Self::Acos(expr)
+                | Self::Atan(expr)
+                | Self::Radians(expr)
+                | Self::Degrees(expr)
+                | Self::Ceil(expr)
+                | Self::Rand(Some(expr))
+                | Self::Round(expr)
+                | Self::Floor(expr)
+                | Self::Exp(expr)
+                | Self::Ln(expr)
+                | Self::Log2(expr)
+                | Self::Log10(expr)
+                | Self::Sqrt(expr)
+                | Self::Abs(expr)
+                | Self::Sign(expr)
+                | Self::Ascii(expr)
+                | Self::Chr(expr)
+                | Self::Md5(expr)
+                | Self::LastDay(expr)
+                | Self::Ltrim { expr, chars: None }
+                | Self::Rtrim { expr, chars: None }
+                | Self::Trim {
+                    expr,
+                    filter_chars: None,
+                    ..
+                }
+                | Self::Reverse(expr)
+                | Self::Cast { expr, .. }
+                | Self::Extract { expr, .. }
+                | Self::GetX(expr)
+                | Self::GetY(expr)
+                | Self::IsEmpty(expr)
+                | Self::Sort { expr, order: None }
+                | Self::Dedup(expr)
+                | Self::Nextval(expr)
+                | Self::Currval(expr)
+                | Self::Entries(expr)
+                | Self::Keys(expr)
+                | Self::Values(expr) => Exprs::Single([expr].into_iter()),
+                Self::Left { expr, size: expr2 }
+                | Self::Right { expr, size: expr2 }
+                | Self::Lpad {
+                    expr,
+                    size: expr2,
+                    fill: None,
+                }
+                | Self::Rpad {
+                    expr,
+                    size: expr2,
+                    fill: None,
+                }
+                | Self::Trim {
+                    expr,
+                    filter_chars: Some(expr2),
+                    ..
+                }
+                | Self::Log {
+                    antilog: expr,
+                    base: expr2,
+                }
+                | Self::Div {
+                    dividend: expr,
+                    divisor: expr2,
+                }
+                | Self::Mod {
+                    dividend: expr,
+                    divisor: expr2,
+                }
+                | Self::Gcd {
+                    left: expr,
+                    right: expr2,
+                }
+                | Self::Lcm {
+                    left: expr,
+                    right: expr2,
+                }
+                | Self::Format {
+                    expr,
+                    format: expr2,
+                }
+                | Self::ToDate {
+                    expr,
+                    format: expr2,
+                }
+                | Self::ToTimestamp {
+                    expr,
+                    format: expr2,
+                }
+                | Self::ToTime {
+                    expr,
+                    format: expr2,
+                }
+                | Self::Power { expr, power: expr2 }
+                | Self::Setval {
+                    name: expr,
+                    value: expr2,
+                }
+                | Self::Ltrim {
+                    expr,
+                    chars: Some(expr2),
+                }
+                | Self::Rtrim {
+                    expr,
+                    chars: Some(expr2),
+                }
+                | Self::Repeat { expr, num: expr2 }
+                | Self::Substr {
+                    expr,
+                    start: expr2,
+                    count: None,
+                }
+                | Self::IfNull { expr, then: expr2 }
+                | Self::Unwrap {
+                    expr,
+                    selector: expr2,
+                }
+                | Self::Position {
+                    from_expr: expr2,
+                    sub_expr: expr,
+                }
+                | Self::FindIdx {
+                    from_expr: expr,
+                    sub_expr: expr2,
+                    start: None,
+                }
+                | Self::Append { expr, value: expr2 }
+                | Self::Prepend { expr, value: expr2 }
+                | Self::Skip { expr, size: expr2 }
+                | Self::Sort {
+                    expr,
+                    order: Some(expr2),
+                }
+                | Self::Take { expr, size: expr2 }
+                | Self::Point { x: expr, y: expr2 }
+                | Self::CalcDistance {
+                    geometry1: expr,
+                    geometry2: expr2,
+                }
+                | Self::AddMonth { expr, size: expr2 } => Exprs::Double([expr, expr2].into_iter()),
+
+                Self::Lpad {
+                    expr,
+                    size: expr2,
+                    fill: Some(expr3),
+                }
+                | Self::Rpad {
+                    expr,
+                    size: expr2,
+                    fill: Some(expr3),
+                }
+                | Self::Substr {
+                    expr,
+                    start: expr2,
+                    count: Some(expr3),
+                }
+                | Self::Replace {
+                    expr,
+                    old: expr2,
+                    new: expr3,
+                }
+                | Self::Slice {
+                    expr,
+                    start: expr2,
+                    length: expr3,
+                }
+                | Self::FindIdx {
+                    from_expr: expr,
+                    sub_expr: expr2,
+                    start: Some(expr3),
+                }
+                | Self::Splice {
+                    list_data: expr,
+                    begin_index: expr2,
+                    end_index: expr3,
+                    values: None,
+                } => Exprs::Triple([expr, expr2, expr3].into_iter()),
+                Self::Custom { name: _, exprs }
+                | Self::Coalesce(exprs)
+                | Self::Concat(exprs)
+                | Self::Greatest(exprs) => Exprs::VariableArgs(IntoIterator::into_iter(exprs)),
+                Self::ConcatWs { separator, exprs } => Exprs::VariableArgsWithSingle(
+                    once(separator).chain(IntoIterator::into_iter(exprs)),
+                ),
+                Self::Splice {
+                    list_data: expr,
+                    begin_index: expr2,
+                    end_index: expr3,
+                    values: Some(expr4),
+                } => Exprs::Quadruple([expr, expr2, expr3, expr4].into_iter()),
             }
-            Self::Greatest(exprs) => Exprs::VariableArgs(exprs.iter()),
-            Self::Splice {
-                list_data: expr,
-                begin_index: expr2,
-                end_index: expr3,
-                values: Some(expr4),
-            } => Exprs::Quadruple([expr, expr2, expr3, expr4].into_iter()),
         }
-    }
+    };
+}
+
+impl Function {
+    as_exprs!(as_exprs);
+    as_exprs!(as_exprs_mut, mut);
 }
 
 #[cfg(test)]
@@ -278,6 +291,8 @@ mod tests {
         test(r#"CAST(1 AS BOOLEAN)"#, &["1"]);
         test(r#"IS_EMPTY(col)"#, &["col"]);
         test(r#"VALUES(col)"#, &["col"]);
+        test("NEXTVAL('seq')", &["'seq'"]);
+        test("CURRVAL('seq')", &["'seq'"]);
 
         test(r#"ABS(1)"#, &["1"]);
         test(r#"ABS(-1)"#, &["-1"]);
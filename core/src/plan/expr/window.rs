@@ -0,0 +1,141 @@
+use {
+    crate::ast::{Expr, OrderByExpr, Window, WindowFunction},
+    std::iter::empty,
+};
+
+impl WindowFunction {
+    pub fn as_exprs(&self) -> impl Iterator<Item = &Expr> {
+        #[derive(iter_enum::Iterator)]
+        enum Exprs<I1, I2, I3> {
+            Empty(I1),
+            Single(I2),
+            Offset(I3),
+        }
+
+        match self {
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                Exprs::Empty(empty())
+            }
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            }
+            | WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => Exprs::Offset(
+                [Some(expr), offset.as_ref(), default.as_ref()]
+                    .into_iter()
+                    .flatten(),
+            ),
+            WindowFunction::Aggregate(aggregate) => Exprs::Single(aggregate.as_expr().into_iter()),
+        }
+    }
+
+    pub fn as_exprs_mut(&mut self) -> impl Iterator<Item = &mut Expr> {
+        #[derive(iter_enum::Iterator)]
+        enum Exprs<I1, I2, I3> {
+            Empty(I1),
+            Single(I2),
+            Offset(I3),
+        }
+
+        match self {
+            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
+                Exprs::Empty(empty())
+            }
+            WindowFunction::Lag {
+                expr,
+                offset,
+                default,
+            }
+            | WindowFunction::Lead {
+                expr,
+                offset,
+                default,
+            } => Exprs::Offset(
+                [Some(expr), offset.as_mut(), default.as_mut()]
+                    .into_iter()
+                    .flatten(),
+            ),
+            WindowFunction::Aggregate(aggregate) => {
+                Exprs::Single(aggregate.as_expr_mut().into_iter())
+            }
+        }
+    }
+}
+
+impl Window {
+    pub fn as_exprs(&self) -> impl Iterator<Item = &Expr> {
+        let Window {
+            function,
+            partition_by,
+            order_by,
+        } = self;
+
+        function
+            .as_exprs()
+            .chain(partition_by)
+            .chain(order_by.iter().map(|OrderByExpr { expr, .. }| expr))
+    }
+
+    pub fn as_exprs_mut(&mut self) -> impl Iterator<Item = &mut Expr> {
+        let Window {
+            function,
+            partition_by,
+            order_by,
+        } = self;
+
+        function
+            .as_exprs_mut()
+            .chain(partition_by)
+            .chain(order_by.iter_mut().map(|OrderByExpr { expr, .. }| expr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{Expr, Window},
+        parse_sql::parse_expr,
+        translate::translate_expr,
+    };
+
+    fn parse(sql: &str) -> Window {
+        let parsed = parse_expr(sql).unwrap();
+        let expr = translate_expr(&parsed).unwrap();
+
+        match expr {
+            Expr::Window(window) => *window,
+            _ => unreachable!("only for window tests"),
+        }
+    }
+
+    fn exprs(sql: &str) -> Vec<Expr> {
+        parse(sql).as_exprs().cloned().collect()
+    }
+
+    fn ident(name: &str) -> Expr {
+        Expr::Identifier(name.to_owned())
+    }
+
+    #[test]
+    fn as_exprs() {
+        assert_eq!(exprs("ROW_NUMBER() OVER ()"), Vec::<Expr>::new());
+        assert_eq!(
+            exprs("RANK() OVER (PARTITION BY a, b ORDER BY c DESC)"),
+            vec![ident("a"), ident("b"), ident("c")]
+        );
+        assert_eq!(
+            exprs("LAG(a, b, c) OVER (ORDER BY d)"),
+            vec![ident("a"), ident("b"), ident("c"), ident("d")]
+        );
+        assert_eq!(
+            exprs("SUM(a) OVER (PARTITION BY b)"),
+            vec![ident("a"), ident("b")]
+        );
+        assert_eq!(exprs("COUNT(*) OVER (PARTITION BY a)"), vec![ident("a")]);
+    }
+}
@@ -1,8 +1,9 @@
 use {
     crate::{
         ast::{
-            AstLiteral, BinaryOperator, Expr, Function, IndexItem, IndexOperator, OrderByExpr,
-            Query, Select, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            AstLiteral, BinaryOperator, Cte, Expr, Function, IndexItem, IndexOperator,
+            JoinOperator, OrderByExpr, Query, Select, SetExpr, Statement, TableAlias, TableFactor,
+            TableWithJoins, With,
         },
         data::{Schema, SchemaIndex, SchemaIndexOrd, TableError},
         result::{Error, Result},
@@ -24,15 +25,16 @@ impl Indexes {
     fn find(&self, target: &Expr) -> Option<String> {
         self.0
             .iter()
-            .find(|SchemaIndex { expr, .. }| expr == target)
+            .filter(|SchemaIndex { exprs, .. }| exprs.first() == Some(target))
+            .min_by_key(|SchemaIndex { exprs, .. }| exprs.len())
             .map(|SchemaIndex { name, .. }| name.to_owned())
     }
 
     fn find_ordered(&self, target: &OrderByExpr) -> Option<String> {
         self.0
             .iter()
-            .find(|SchemaIndex { expr, order, .. }| {
-                if expr != &target.expr {
+            .filter(|SchemaIndex { exprs, order, .. }| {
+                if exprs.first() != Some(&target.expr) {
                     return false;
                 }
 
@@ -44,22 +46,47 @@ impl Indexes {
                         | (Some(false), SchemaIndexOrd::Desc)
                 )
             })
+            .min_by_key(|SchemaIndex { exprs, .. }| exprs.len())
             .map(|SchemaIndex { name, .. }| name.to_owned())
     }
 }
 
 fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Query> {
     let Query {
+        with,
         body,
         order_by,
         limit,
         offset,
     } = query;
+    let with = with.map(|with| plan_with(schema_map, with)).transpose()?;
 
     let select = match body {
         SetExpr::Select(select) => select,
         SetExpr::Values(_) => {
             return Ok(Query {
+                with,
+                body,
+                order_by,
+                limit,
+                offset,
+            });
+        }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            let body = SetExpr::SetOperation {
+                op,
+                all,
+                left: plan_set_expr(schema_map, *left).map(Box::new)?,
+                right: plan_set_expr(schema_map, *right).map(Box::new)?,
+            };
+
+            return Ok(Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -68,11 +95,30 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         }
     };
 
-    let TableWithJoins { relation, .. } = &select.from;
+    let TableWithJoins { relation, joins } = &select.from;
+    let preserves_right = joins.iter().any(|join| {
+        matches!(
+            join.join_operator,
+            JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+        )
+    });
+    let primary_key_planned = matches!(relation, TableFactor::Table { index: Some(_), .. });
+
+    if preserves_right || primary_key_planned {
+        return Ok(Query {
+            with,
+            body: SetExpr::Select(select),
+            order_by,
+            limit,
+            offset,
+        });
+    }
+
     let table_name = match relation {
         TableFactor::Table { name, .. } => name,
         TableFactor::Derived { .. } => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -93,6 +139,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
         Some(Schema { indexes, .. }) => Indexes(indexes.clone()),
         None => {
             return Ok(Query {
+                with,
                 body: SetExpr::Select(select),
                 order_by,
                 limit,
@@ -107,13 +154,16 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             .map(|name| IndexItem::NonClustered {
                 name,
                 asc: value_expr.asc,
+                prefix: Vec::new(),
                 cmp_expr: None,
+                upper_cmp_expr: None,
             })
     });
 
     match index {
         index if index.is_some() => {
             let Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -137,6 +187,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             let select = Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -145,6 +196,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             };
 
             Ok(Query {
+                with,
                 body: SetExpr::Select(Box::new(select)),
                 order_by: Vector::from(order_by).pop().0.into(),
                 limit,
@@ -155,6 +207,7 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
             let select = plan_select(schema_map, &indexes, *select)?;
             let body = SetExpr::Select(Box::new(select));
             let query = Query {
+                with,
                 body,
                 order_by,
                 limit,
@@ -166,12 +219,42 @@ fn plan_query(schema_map: &HashMap<String, Schema>, query: Query) -> Result<Quer
     }
 }
 
+fn plan_set_expr(schema_map: &HashMap<String, Schema>, set_expr: SetExpr) -> Result<SetExpr> {
+    let query = Query {
+        with: None,
+        body: set_expr,
+        order_by: Vec::new(),
+        limit: None,
+        offset: None,
+    };
+
+    plan_query(schema_map, query).map(|Query { body, .. }| body)
+}
+
+fn plan_with(schema_map: &HashMap<String, Schema>, with: With) -> Result<With> {
+    let With {
+        recursive,
+        cte_tables,
+    } = with;
+
+    let cte_tables = cte_tables
+        .into_iter()
+        .map(|Cte { alias, query }| plan_query(schema_map, query).map(|query| Cte { alias, query }))
+        .collect::<Result<_>>()?;
+
+    Ok(With {
+        recursive,
+        cte_tables,
+    })
+}
+
 fn plan_select(
     schema_map: &HashMap<String, Schema>,
     indexes: &Indexes,
     select: Select,
 ) -> Result<Select> {
     let Select {
+        distinct,
         projection,
         from,
         selection,
@@ -183,6 +266,7 @@ fn plan_select(
         Some(expr) => expr,
         None => {
             return Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -192,8 +276,14 @@ fn plan_select(
         }
     };
 
-    match plan_index(schema_map, indexes, selection)? {
+    let planned = match plan_composite_index(indexes, selection) {
+        Planned::Expr(selection) => plan_index(schema_map, indexes, selection)?,
+        planned => planned,
+    };
+
+    match planned {
         Planned::Expr(selection) => Ok(Select {
+            distinct,
             projection,
             from,
             selection: Some(selection),
@@ -202,8 +292,10 @@ fn plan_select(
         }),
         Planned::IndexedExpr {
             index_name,
+            index_prefix,
             index_op,
             index_value_expr,
+            index_upper,
             selection,
         } => {
             let TableWithJoins { relation, joins } = from;
@@ -219,7 +311,9 @@ fn plan_select(
             let index = Some(IndexItem::NonClustered {
                 name: index_name,
                 asc: None,
+                prefix: index_prefix,
                 cmp_expr: Some((index_op, index_value_expr)),
+                upper_cmp_expr: index_upper,
             });
             let from = TableWithJoins {
                 relation: TableFactor::Table { name, alias, index },
@@ -227,6 +321,7 @@ fn plan_select(
             };
 
             Ok(Select {
+                distinct,
                 projection,
                 from,
                 selection,
@@ -240,8 +335,10 @@ fn plan_select(
 enum Planned {
     IndexedExpr {
         index_name: String,
+        index_prefix: Vec<Expr>,
         index_op: IndexOperator,
         index_value_expr: Expr,
+        index_upper: Option<(IndexOperator, Expr)>,
         selection: Option<Expr>,
     },
     Expr(Expr),
@@ -285,7 +382,9 @@ fn plan_index(
                 Planned::Expr(selection) => selection,
                 Planned::IndexedExpr {
                     index_name,
+                    index_prefix,
                     index_value_expr,
+                    index_upper,
                     index_op,
                     selection,
                 } => {
@@ -300,8 +399,10 @@ fn plan_index(
 
                     return Ok(Planned::IndexedExpr {
                         index_name,
+                        index_prefix,
                         index_op,
                         index_value_expr,
+                        index_upper,
                         selection: Some(selection),
                     });
                 }
@@ -315,8 +416,10 @@ fn plan_index(
                 })),
                 Planned::IndexedExpr {
                     index_name,
+                    index_prefix,
                     index_op,
                     index_value_expr,
+                    index_upper,
                     selection,
                 } => {
                     let selection = match selection {
@@ -330,7 +433,9 @@ fn plan_index(
 
                     Ok(Planned::IndexedExpr {
                         index_name,
+                        index_prefix,
                         index_value_expr,
+                        index_upper,
                         index_op,
                         selection: Some(selection),
                     })
@@ -366,6 +471,201 @@ fn plan_index(
     }
 }
 
+/// Plans an index scan which takes more than one condition of the selection, the equality prefix
+/// of a composite index or both bounds of a range.
+fn plan_composite_index(indexes: &Indexes, selection: Expr) -> Planned {
+    let conjuncts = split_conjuncts(&selection);
+
+    let planned = indexes
+        .0
+        .iter()
+        .filter_map(|SchemaIndex { name, exprs, .. }| {
+            let mut used = Vec::new();
+            let mut prefix = Vec::new();
+
+            for expr in exprs {
+                let found = conjuncts
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| !used.contains(i))
+                    .find_map(|(i, conjunct)| match search_cmp(conjunct, expr) {
+                        Some((IndexOperator::Eq, value)) => Some((i, value)),
+                        _ => None,
+                    });
+
+                match found {
+                    Some((i, value)) => {
+                        used.push(i);
+                        prefix.push(value.clone());
+                    }
+                    None => break,
+                }
+            }
+
+            let range = exprs
+                .get(prefix.len())
+                .and_then(|expr| search_range(&conjuncts, &used, expr));
+
+            let (cmp_expr, upper_cmp_expr) = match range {
+                Some(IndexRange {
+                    used: range_used,
+                    cmp_expr,
+                    upper_cmp_expr,
+                }) => {
+                    used.extend(range_used);
+
+                    (cmp_expr, upper_cmp_expr)
+                }
+                None => ((IndexOperator::Eq, prefix.pop()?), None),
+            };
+            let weight = prefix.len() + 1 + usize::from(upper_cmp_expr.is_some());
+
+            (weight > 1).then_some((name, used, prefix, cmp_expr, upper_cmp_expr, weight))
+        })
+        .reduce(|best, candidate| match candidate.5 > best.5 {
+            true => candidate,
+            false => best,
+        });
+
+    let (index_name, used, index_prefix, (index_op, index_value_expr), index_upper, _) =
+        match planned {
+            Some(planned) => planned,
+            None => return Planned::Expr(selection),
+        };
+
+    let selection = conjuncts
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !used.contains(i))
+        .map(|(_, conjunct)| conjunct.clone())
+        .reduce(|left, right| Expr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::And,
+            right: Box::new(right),
+        });
+
+    Planned::IndexedExpr {
+        index_name: index_name.to_owned(),
+        index_prefix,
+        index_op,
+        index_value_expr,
+        index_upper,
+        selection,
+    }
+}
+
+struct IndexRange {
+    used: Vec<usize>,
+    cmp_expr: (IndexOperator, Expr),
+    upper_cmp_expr: Option<(IndexOperator, Expr)>,
+}
+
+/// Finds the comparison on `target` among the unused conjuncts, with both bounds when the
+/// conjuncts have a lower and an upper bound or a BETWEEN.
+fn search_range(conjuncts: &[&Expr], used: &[usize], target: &Expr) -> Option<IndexRange> {
+    let candidates = || {
+        conjuncts
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used.contains(i))
+    };
+
+    let between = candidates().find_map(|(i, conjunct)| match unnest(conjunct) {
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } if unnest(expr) == target && is_stateless(low) && is_stateless(high) => {
+            Some((i, low, high))
+        }
+        _ => None,
+    });
+
+    if let Some((i, low, high)) = between {
+        return Some(IndexRange {
+            used: vec![i],
+            cmp_expr: (IndexOperator::GtEq, low.as_ref().clone()),
+            upper_cmp_expr: Some((IndexOperator::LtEq, high.as_ref().clone())),
+        });
+    }
+
+    let cmps = candidates()
+        .filter_map(|(i, conjunct)| {
+            search_cmp(conjunct, target).map(|(index_op, value)| (i, index_op, value))
+        })
+        .collect::<Vec<_>>();
+    let lower = cmps
+        .iter()
+        .find(|(_, index_op, _)| matches!(index_op, IndexOperator::Gt | IndexOperator::GtEq));
+    let upper = cmps
+        .iter()
+        .find(|(_, index_op, _)| matches!(index_op, IndexOperator::Lt | IndexOperator::LtEq));
+
+    match (lower, upper) {
+        (Some((i, lower_op, lower)), Some((j, upper_op, upper))) => Some(IndexRange {
+            used: vec![*i, *j],
+            cmp_expr: (lower_op.clone(), (*lower).clone()),
+            upper_cmp_expr: Some((upper_op.clone(), (*upper).clone())),
+        }),
+        _ => cmps.first().map(|(i, index_op, value)| IndexRange {
+            used: vec![*i],
+            cmp_expr: (index_op.clone(), (*value).clone()),
+            upper_cmp_expr: None,
+        }),
+    }
+}
+
+fn split_conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut conjuncts = split_conjuncts(left);
+            conjuncts.extend(split_conjuncts(right));
+
+            conjuncts
+        }
+        Expr::Nested(nested) => split_conjuncts(nested),
+        _ => vec![expr],
+    }
+}
+
+fn search_cmp<'a>(expr: &'a Expr, target: &Expr) -> Option<(IndexOperator, &'a Expr)> {
+    let (left, op, right) = match expr {
+        Expr::Nested(expr) => return search_cmp(expr, target),
+        Expr::BinaryOp { left, op, right } => (left.as_ref(), op, right.as_ref()),
+        _ => return None,
+    };
+
+    let index_op = match op {
+        BinaryOperator::Eq => IndexOperator::Eq,
+        BinaryOperator::Gt => IndexOperator::Gt,
+        BinaryOperator::GtEq => IndexOperator::GtEq,
+        BinaryOperator::Lt => IndexOperator::Lt,
+        BinaryOperator::LtEq => IndexOperator::LtEq,
+        _ => return None,
+    };
+
+    if unnest(left) == target && is_stateless(right) {
+        Some((index_op, right))
+    } else if unnest(right) == target && is_stateless(left) {
+        Some((index_op.reverse(), left))
+    } else {
+        None
+    }
+}
+
+fn unnest(mut expr: &Expr) -> &Expr {
+    while let Expr::Nested(nested) = expr {
+        expr = nested;
+    }
+
+    expr
+}
+
 fn search_is_null(indexes: &Indexes, null: bool, expr: Box<Expr>) -> Planned {
     match indexes.find(expr.as_ref()) {
         Some(index_name) => {
@@ -377,8 +677,10 @@ fn search_is_null(indexes: &Indexes, null: bool, expr: Box<Expr>) -> Planned {
 
             Planned::IndexedExpr {
                 index_name,
+                index_prefix: Vec::new(),
                 index_op,
                 index_value_expr: Expr::Literal(AstLiteral::Null),
+                index_upper: None,
                 selection: None,
             }
         }
@@ -406,8 +708,10 @@ fn search_index_op(
     {
         Planned::IndexedExpr {
             index_name,
+            index_prefix: Vec::new(),
             index_op,
             index_value_expr: *right,
+            index_upper: None,
             selection: None,
         }
     } else if let Some(index_name) = indexes
@@ -416,8 +720,10 @@ fn search_index_op(
     {
         Planned::IndexedExpr {
             index_name,
+            index_prefix: Vec::new(),
             index_op: index_op.reverse(),
             index_value_expr: *left,
+            index_upper: None,
             selection: None,
         }
     } else if let Expr::Nested(left) = *left {
@@ -1,9 +1,12 @@
 use {
-    super::{context::Context, evaluable::check_expr as check_evaluable, planner::Planner},
+    super::{
+        context::Context, evaluable::check_expr as check_evaluable, expr::PlanExpr,
+        planner::Planner,
+    },
     crate::{
         ast::{
             BinaryOperator, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query, Select,
-            SetExpr, Statement, TableWithJoins,
+            SetExpr, Statement, TableFactor, TableWithJoins,
         },
         data::Schema,
     },
@@ -24,6 +27,61 @@ pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> State
     }
 }
 
+/// Plans the join of the target table of `UPDATE ... FROM` or `DELETE ... USING`,
+/// which follows every relation of `from` with `selection` as its constraint.
+///
+/// The target row is resolved before the joined rows, so a hash value which reads the target
+/// cannot be evaluated by the joined rows alone, the join stays a nested loop join then.
+pub fn plan_target(
+    schema_map: &HashMap<String, Schema>,
+    table_name: &str,
+    from: &TableWithJoins,
+    selection: Option<Expr>,
+) -> Join {
+    let planner = JoinPlanner { schema_map };
+    let TableWithJoins { relation, joins } = from;
+    let context = joins
+        .iter()
+        .fold(planner.update_context(None, relation), |context, join| {
+            planner.update_context(context, &join.relation)
+        });
+    let join = Join {
+        relation: TableFactor::Table {
+            name: table_name.to_owned(),
+            alias: None,
+            index: None,
+        },
+        join_operator: JoinOperator::Inner(
+            selection.map_or(JoinConstraint::None, JoinConstraint::On),
+        ),
+        join_executor: JoinExecutor::NestedLoop,
+    };
+    let target_context = planner.update_context(None, &join.relation);
+    let (_, planned) = planner.join(None, context, join.clone());
+
+    match &planned.join_executor {
+        JoinExecutor::Hash { value_expr, .. } if reads_target(target_context, value_expr) => join,
+        _ => planned,
+    }
+}
+
+/// Checks whether `expr` reads any column of the target, subqueries are assumed to read it.
+fn reads_target(target_context: Option<Rc<Context<'_>>>, expr: &Expr) -> bool {
+    let reads = |expr: &Expr| reads_target(target_context.as_ref().map(Rc::clone), expr);
+
+    match expr.into() {
+        PlanExpr::None => false,
+        PlanExpr::Identifier(_) | PlanExpr::CompoundIdentifier { .. } => {
+            check_evaluable(target_context.as_ref().map(Rc::clone), expr)
+        }
+        PlanExpr::Expr(expr) => reads(expr),
+        PlanExpr::TwoExprs(expr, expr2) => reads(expr) || reads(expr2),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => reads(expr) || reads(expr2) || reads(expr3),
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(reads),
+        PlanExpr::Query(_) | PlanExpr::QueryAndExpr { .. } => true,
+    }
+}
+
 struct JoinPlanner<'a> {
     schema_map: &'a HashMap<String, Schema>,
 }
@@ -31,22 +89,18 @@ struct JoinPlanner<'a> {
 impl<'a> Planner<'a> for JoinPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
         let Query {
+            with,
             body,
             order_by,
             limit,
             offset,
         } = query;
 
-        let body = match body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
-
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => body,
-        };
+        let with = with.map(|with| self.with(outer_context.as_ref().map(Rc::clone), with));
+        let body = self.set_expr(outer_context, body);
 
         Query {
+            with,
             body,
             order_by,
             limit,
@@ -60,8 +114,31 @@ impl<'a> Planner<'a> for JoinPlanner<'a> {
 }
 
 impl<'a> JoinPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(outer_context.as_ref().map(Rc::clone), *left)),
+                right: Box::new(self.set_expr(outer_context, *right)),
+            },
+        }
+    }
+
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
         let Select {
+            distinct,
             projection,
             from,
             selection,
@@ -73,6 +150,7 @@ impl<'a> JoinPlanner<'a> {
         let selection = selection.map(|expr| self.subquery_expr(outer_context, expr));
 
         Select {
+            distinct,
             projection,
             from,
             selection,
@@ -130,13 +208,19 @@ impl<'a> JoinPlanner<'a> {
         enum JoinOp {
             Inner,
             LeftOuter,
+            RightOuter,
+            FullOuter,
         }
 
         let (join_op, expr) = match join_operator {
             JoinOperator::Inner(JoinConstraint::On(expr)) => (JoinOp::Inner, expr),
             JoinOperator::LeftOuter(JoinConstraint::On(expr)) => (JoinOp::LeftOuter, expr),
+            JoinOperator::RightOuter(JoinConstraint::On(expr)) => (JoinOp::RightOuter, expr),
+            JoinOperator::FullOuter(JoinConstraint::On(expr)) => (JoinOp::FullOuter, expr),
             JoinOperator::Inner(JoinConstraint::None)
-            | JoinOperator::LeftOuter(JoinConstraint::None) => {
+            | JoinOperator::LeftOuter(JoinConstraint::None)
+            | JoinOperator::RightOuter(JoinConstraint::None)
+            | JoinOperator::FullOuter(JoinConstraint::None) => {
                 let context = self.update_context(inner_context, &relation);
                 let join = Join {
                     relation,
@@ -161,6 +245,10 @@ impl<'a> JoinPlanner<'a> {
             (JoinOp::Inner, None) => JoinOperator::Inner(JoinConstraint::None),
             (JoinOp::LeftOuter, Some(expr)) => JoinOperator::LeftOuter(JoinConstraint::On(expr)),
             (JoinOp::LeftOuter, None) => JoinOperator::LeftOuter(JoinConstraint::None),
+            (JoinOp::RightOuter, Some(expr)) => JoinOperator::RightOuter(JoinConstraint::On(expr)),
+            (JoinOp::RightOuter, None) => JoinOperator::RightOuter(JoinConstraint::None),
+            (JoinOp::FullOuter, Some(expr)) => JoinOperator::FullOuter(JoinConstraint::On(expr)),
+            (JoinOp::FullOuter, None) => JoinOperator::FullOuter(JoinConstraint::None),
         };
 
         let context = self.update_context(inner_context, &relation);
@@ -627,6 +715,24 @@ mod tests {
             .filter(true);
         test!(actual, expected, "complex where_clause:\n{sql}");
 
+        let sql = "
+            SELECT *
+            FROM Player
+            RIGHT JOIN PlayerItem ON
+                PlayerItem.user_id = Player.id AND
+                PlayerItem.amount > 10
+            FULL JOIN Player p2 ON p2.id = PlayerItem.item_id;
+        ";
+        let actual = plan_join(&storage, sql);
+        let expected = table("Player")
+            .select()
+            .right_join("PlayerItem")
+            .hash_executor("PlayerItem.user_id", "Player.id")
+            .hash_filter("PlayerItem.amount > 10")
+            .full_join_as("Player", "p2")
+            .hash_executor("p2.id", "PlayerItem.item_id");
+        test!(actual, expected, "right and full outer join:\n{sql}");
+
         let sql = "
             SELECT *
             FROM Player
@@ -1,27 +1,69 @@
 mod context;
 mod error;
 mod evaluable;
-mod expr;
+pub(crate) mod expr;
 mod index;
 mod join;
 mod planner;
 mod primary_key;
+mod pushdown;
 mod schema;
 mod validate;
+mod view;
 
-use crate::{ast::Statement, result::Result, store::Store};
+use crate::{
+    ast::{Expr, Join, Statement, TableWithJoins},
+    result::Result,
+    store::{Store, View},
+};
 
 pub use {
-    self::validate::validate, error::*, index::plan as plan_index, join::plan as plan_join,
-    primary_key::plan as plan_primary_key, schema::fetch_schema_map,
+    self::validate::validate,
+    error::*,
+    index::plan as plan_index,
+    join::plan as plan_join,
+    primary_key::plan as plan_primary_key,
+    pushdown::plan as plan_pushdown,
+    schema::fetch_schema_map,
+    view::{expand as expand_views, plan as plan_view},
 };
 
-pub async fn plan<T: Store>(storage: &T, statement: Statement) -> Result<Statement> {
+pub async fn plan<T: Store + View>(storage: &T, statement: Statement) -> Result<Statement> {
+    if let Statement::Explain { analyze, statement } = statement {
+        let statement = plan_statement(storage, *statement).await.map(Box::new)?;
+
+        return Ok(Statement::Explain { analyze, statement });
+    }
+
+    plan_statement(storage, statement).await
+}
+
+/// Plans the join of the target table of `UPDATE ... FROM` or `DELETE ... USING`,
+/// so that the rows of `from` are joined with the target rows at once.
+pub async fn plan_target_join<T: Store>(
+    storage: &T,
+    table_name: &str,
+    from: &TableWithJoins,
+    selection: Option<&Expr>,
+) -> Result<Join> {
+    let schema_map = schema::scan_target(storage, table_name, from, selection).await?;
+
+    Ok(join::plan_target(
+        &schema_map,
+        table_name,
+        from,
+        selection.cloned(),
+    ))
+}
+
+async fn plan_statement<T: Store + View>(storage: &T, statement: Statement) -> Result<Statement> {
+    let statement = plan_view(storage, statement).await?;
     let schema_map = fetch_schema_map(storage, &statement).await?;
     validate(&schema_map, &statement)?;
     let statement = plan_primary_key(&schema_map, statement);
     let statement = plan_index(&schema_map, statement)?;
     let statement = plan_join(&schema_map, statement);
+    let statement = plan_pushdown(&schema_map, statement);
 
     Ok(statement)
 }
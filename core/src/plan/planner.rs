@@ -1,7 +1,10 @@
 use {
     super::context::Context,
     crate::{
-        ast::{ColumnDef, ColumnUniqueOption, Expr, Function, Query, TableAlias, TableFactor},
+        ast::{
+            ColumnDef, ColumnUniqueOption, Cte, Expr, Function, Query, TableAlias, TableFactor,
+            With,
+        },
         data::Schema,
     },
     std::rc::Rc,
@@ -12,12 +15,33 @@ pub trait Planner<'a> {
 
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query;
 
+    fn with(&self, outer_context: Option<Rc<Context<'a>>>, with: With) -> With {
+        let With {
+            recursive,
+            cte_tables,
+        } = with;
+
+        let cte_tables = cte_tables
+            .into_iter()
+            .map(|Cte { alias, query }| Cte {
+                alias,
+                query: self.query(outer_context.as_ref().map(Rc::clone), query),
+            })
+            .collect();
+
+        With {
+            recursive,
+            cte_tables,
+        }
+    }
+
     fn subquery_expr(&self, outer_context: Option<Rc<Context<'a>>>, expr: Expr) -> Expr {
         match expr {
             Expr::Identifier(_)
             | Expr::CompoundIdentifier { .. }
             | Expr::Literal(_)
-            | Expr::TypedString { .. } => expr,
+            | Expr::TypedString { .. }
+            | Expr::Parameter(_) => expr,
             Expr::IsNull(expr) => Expr::IsNull(Box::new(self.subquery_expr(outer_context, *expr))),
             Expr::IsNotNull(expr) => {
                 Expr::IsNotNull(Box::new(self.subquery_expr(outer_context, *expr)))
@@ -180,7 +204,7 @@ pub trait Planner<'a> {
                 })),
                 _ => Expr::Function(func),
             },
-            Expr::Aggregate(_) => expr,
+            Expr::Aggregate(_) | Expr::Window(_) => expr,
         }
     }
 
@@ -217,9 +241,10 @@ pub trait Planner<'a> {
 
         let primary_key = column_defs
             .iter()
-            .find_map(|ColumnDef { name, unique, .. }| {
+            .filter_map(|ColumnDef { name, unique, .. }| {
                 (unique == &Some(ColumnUniqueOption { is_primary: true })).then_some(name.as_str())
-            });
+            })
+            .collect::<Vec<_>>();
 
         let context = Context::new(
             alias.unwrap_or_else(|| name.to_owned()),
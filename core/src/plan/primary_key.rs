@@ -1,11 +1,15 @@
 use {
-    super::{context::Context, evaluable::check_expr as check_evaluable, planner::Planner},
+    super::{
+        context::Context, evaluable::check_expr as check_evaluable, expr::PlanExpr,
+        planner::Planner,
+    },
     crate::{
         ast::{
-            BinaryOperator, Expr, IndexItem, Query, Select, SetExpr, Statement, TableFactor,
-            TableWithJoins,
+            AstLiteral, BinaryOperator, ColumnDef, ColumnUniqueOption, DataType, Expr, IndexItem,
+            IndexOperator, Join, JoinOperator, OrderByExpr, Query, Select, SelectItem, SetExpr,
+            Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
         },
-        data::Schema,
+        data::{primary_key_indexes, BigDecimalExt, Schema},
     },
     std::{collections::HashMap, rc::Rc},
 };
@@ -29,16 +33,25 @@ struct PrimaryKeyPlanner<'a> {
 
 impl<'a> Planner<'a> for PrimaryKeyPlanner<'a> {
     fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
-        let body = match query.body {
-            SetExpr::Select(select) => {
-                let select = self.select(outer_context, *select);
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
 
-                SetExpr::Select(Box::new(select))
-            }
-            SetExpr::Values(_) => query.body,
-        };
+        let with = with.map(|with| self.with(outer_context.as_ref().map(Rc::clone), with));
+        let body = self.set_expr(outer_context, body);
+        let (body, order_by) = self.order_primary_key_range(body, order_by);
 
-        Query { body, ..query }
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
     }
 
     fn get_schema(&self, name: &str) -> Option<&'a Schema> {
@@ -55,7 +68,33 @@ enum PrimaryKey {
 }
 
 impl<'a> PrimaryKeyPlanner<'a> {
+    fn set_expr(&self, outer_context: Option<Rc<Context<'a>>>, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(outer_context.as_ref().map(Rc::clone), *left)),
+                right: Box::new(self.set_expr(outer_context, *right)),
+            },
+        }
+    }
+
     fn select(&self, outer_context: Option<Rc<Context<'a>>>, select: Select) -> Select {
+        if select.from.joins.iter().any(preserves_right) {
+            return select;
+        }
+
         let current_context = self.update_context(None, &select.from.relation);
         let current_context = select
             .from
@@ -65,14 +104,25 @@ impl<'a> PrimaryKeyPlanner<'a> {
                 self.update_context(context, &join.relation)
             });
 
-        let (index, selection) = select
-            .selection
-            .map(|expr| self.expr(outer_context, current_context, expr))
-            .map(|primary_key| match primary_key {
+        let (index, selection) = match (
+            select.selection,
+            self.composite_primary_key(&select.from.relation),
+        ) {
+            (Some(expr), Some((alias, primary_key))) => {
+                self.composite_expr(outer_context, current_context, alias, &primary_key, expr)
+            }
+            (Some(expr), None) => match self.expr(outer_context, current_context, expr) {
                 PrimaryKey::Found { index_item, expr } => (Some(index_item), expr),
                 PrimaryKey::NotFound(expr) => (None, Some(expr)),
-            })
-            .unwrap_or((None, None));
+            },
+            (None, _) => (None, None),
+        };
+        let (index, selection) = match (index, selection) {
+            (None, Some(expr)) if select.from.joins.is_empty() => {
+                self.primary_key_range(&select.from.relation, expr)
+            }
+            planned => planned,
+        };
 
         if let TableFactor::Table {
             name,
@@ -98,6 +148,203 @@ impl<'a> PrimaryKeyPlanner<'a> {
         }
     }
 
+    /// Returns the alias and the key column of a table keyed by a single primary key column.
+    fn single_primary_key<'b>(
+        &self,
+        relation: &'b TableFactor,
+    ) -> Option<(&'b str, &'a ColumnDef)> {
+        let (name, alias) = match relation {
+            TableFactor::Table { name, alias, .. } => (name, alias),
+            _ => return None,
+        };
+
+        let mut primary_key = self.get_schema(name)?.column_defs.as_ref()?.iter().filter(
+            |ColumnDef { unique, .. }| unique == &Some(ColumnUniqueOption { is_primary: true }),
+        );
+        let column_def = primary_key.next()?;
+        if primary_key.next().is_some() {
+            return None;
+        }
+
+        let alias = alias
+            .as_ref()
+            .map(|TableAlias { name, .. }| name)
+            .unwrap_or(name);
+
+        Some((alias.as_str(), column_def))
+    }
+
+    /// Plans the conjunctions which bound the key of a table keyed by a single primary key
+    /// column into a range scan, further bounds on the same side stay in the selection.
+    fn primary_key_range(
+        &self,
+        relation: &TableFactor,
+        expr: Expr,
+    ) -> (Option<IndexItem>, Option<Expr>) {
+        if !matches!(relation, TableFactor::Table { index: None, .. }) {
+            return (None, Some(expr));
+        }
+
+        let Some((alias, column_def)) = self.single_primary_key(relation) else {
+            return (None, Some(expr));
+        };
+
+        let exprs = conjuncts(&expr);
+        let mut lower = None;
+        let mut upper = None;
+        let mut used = Vec::new();
+        for (i, expr) in exprs.iter().enumerate() {
+            let (expr_lower, expr_upper) = key_bounds(expr, alias, column_def);
+            let fits = match (&expr_lower, &expr_upper) {
+                (Some(_), Some(_)) => lower.is_none() && upper.is_none(),
+                (Some(_), None) => lower.is_none(),
+                (None, Some(_)) => upper.is_none(),
+                (None, None) => false,
+            };
+
+            if fits {
+                lower = lower.or(expr_lower);
+                upper = upper.or(expr_upper);
+                used.push(i);
+            }
+        }
+
+        if used.is_empty() {
+            return (None, Some(expr));
+        }
+
+        let selection = exprs
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used.contains(i))
+            .map(|(_, expr)| (*expr).clone())
+            .reduce(|left, right| Expr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        let index_item = IndexItem::PrimaryKeyRange {
+            lower,
+            upper,
+            asc: None,
+        };
+
+        (Some(index_item), selection)
+    }
+
+    /// Scans the key range in the order of ORDER BY when the rows are only sorted by the key,
+    /// so the sort can be skipped.
+    fn order_primary_key_range(
+        &self,
+        body: SetExpr,
+        order_by: Vec<OrderByExpr>,
+    ) -> (SetExpr, Vec<OrderByExpr>) {
+        let mut select = match body {
+            SetExpr::Select(select) => select,
+            _ => return (body, order_by),
+        };
+
+        let order = match (
+            self.single_primary_key(&select.from.relation),
+            &order_by[..],
+        ) {
+            (Some((alias, column_def)), [OrderByExpr { expr, asc }])
+                if is_key_column(expr, alias, &column_def.name)
+                    && is_row_order_kept(&select, alias, &column_def.name) =>
+            {
+                asc.unwrap_or(true)
+            }
+            _ => return (SetExpr::Select(select), order_by),
+        };
+
+        match &mut select.from.relation {
+            TableFactor::Table {
+                index: Some(IndexItem::PrimaryKeyRange { asc, .. }),
+                ..
+            } => {
+                *asc = Some(order);
+
+                (SetExpr::Select(select), Vec::new())
+            }
+            _ => (SetExpr::Select(select), order_by),
+        }
+    }
+
+    /// Returns the alias and the key columns of a table keyed by several primary key columns.
+    fn composite_primary_key<'b>(
+        &self,
+        relation: &'b TableFactor,
+    ) -> Option<(&'b str, Vec<&'a str>)> {
+        let (name, alias) = match relation {
+            TableFactor::Table {
+                name,
+                alias,
+                index: None,
+            } => (name, alias),
+            _ => return None,
+        };
+
+        let schema = self.get_schema(name)?;
+        let column_defs = schema.column_defs.as_ref()?;
+        let primary_key = primary_key_indexes(column_defs, &schema.primary_key)
+            .into_iter()
+            .map(|i| column_defs[i].name.as_str())
+            .collect::<Vec<_>>();
+
+        let alias = alias
+            .as_ref()
+            .map(|TableAlias { name, .. }| name)
+            .unwrap_or(name);
+
+        (primary_key.len() > 1).then_some((alias.as_str(), primary_key))
+    }
+
+    /// A composite primary key is only usable for a point lookup when every key column
+    /// is bound by an equality in the top-level conjunction of the selection.
+    fn composite_expr(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        current_context: Option<Rc<Context<'a>>>,
+        alias: &str,
+        primary_key: &[&str],
+        expr: Expr,
+    ) -> (Option<IndexItem>, Option<Expr>) {
+        let outer_context = Context::concat(current_context, outer_context);
+        let exprs = conjuncts(&expr);
+        let positions = primary_key
+            .iter()
+            .map(|column| {
+                exprs
+                    .iter()
+                    .position(|expr| bound_value(expr, alias, column).is_some())
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let Some(positions) = positions else {
+            return (None, Some(self.subquery_expr(outer_context, expr)));
+        };
+
+        let elem = positions
+            .iter()
+            .zip(primary_key)
+            .filter_map(|(i, column)| bound_value(exprs[*i], alias, column))
+            .cloned()
+            .collect();
+        let selection = exprs
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !positions.contains(i))
+            .map(|(_, expr)| (*expr).clone())
+            .reduce(|left, right| Expr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            })
+            .map(|expr| self.subquery_expr(outer_context, expr));
+
+        (Some(IndexItem::CompositePrimaryKey(elem)), selection)
+    }
+
     fn expr(
         &self,
         outer_context: Option<Rc<Context<'a>>>,
@@ -213,15 +460,185 @@ impl<'a> PrimaryKeyPlanner<'a> {
     }
 }
 
+fn conjuncts(expr: &Expr) -> Vec<&Expr> {
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut exprs = conjuncts(left);
+            exprs.extend(conjuncts(right));
+
+            exprs
+        }
+        Expr::Nested(expr) => conjuncts(expr),
+        _ => vec![expr],
+    }
+}
+
+/// Returns `value` when `expr` is `column = value` and `value` can be evaluated without any row.
+fn bound_value<'b>(expr: &'b Expr, alias: &str, column: &str) -> Option<&'b Expr> {
+    let check_column = |key: &Expr| match key {
+        Expr::Identifier(ident) => ident == column,
+        Expr::CompoundIdentifier {
+            alias: key_alias,
+            ident,
+        } => key_alias == alias && ident == column,
+        _ => false,
+    };
+
+    match expr {
+        Expr::BinaryOp {
+            left: key,
+            op: BinaryOperator::Eq,
+            right: value,
+        }
+        | Expr::BinaryOp {
+            left: value,
+            op: BinaryOperator::Eq,
+            right: key,
+        } if check_column(key) && check_evaluable(None, value) => Some(value.as_ref()),
+        _ => None,
+    }
+}
+
+fn is_key_column(expr: &Expr, alias: &str, column: &str) -> bool {
+    match expr {
+        Expr::Identifier(ident) => ident == column,
+        Expr::CompoundIdentifier {
+            alias: key_alias,
+            ident,
+        } => key_alias == alias && ident == column,
+        _ => false,
+    }
+}
+
+/// Returns the lower and upper bounds which `expr` puts on the key column.
+fn key_bounds(
+    expr: &Expr,
+    alias: &str,
+    column_def: &ColumnDef,
+) -> (Option<(IndexOperator, Expr)>, Option<(IndexOperator, Expr)>) {
+    let check_column = |key: &Expr| is_key_column(key, alias, &column_def.name);
+    let check_value = |value: &Expr| is_key_of(value, &column_def.data_type);
+
+    match expr {
+        Expr::BinaryOp { left, op, right } => {
+            let op = match op {
+                BinaryOperator::Gt => IndexOperator::Gt,
+                BinaryOperator::GtEq => IndexOperator::GtEq,
+                BinaryOperator::Lt => IndexOperator::Lt,
+                BinaryOperator::LtEq => IndexOperator::LtEq,
+                _ => return (None, None),
+            };
+            let (op, value) = if check_column(left) && check_value(right) {
+                (op, right.as_ref().clone())
+            } else if check_column(right) && check_value(left) {
+                (op.reverse(), left.as_ref().clone())
+            } else {
+                return (None, None);
+            };
+
+            match op {
+                IndexOperator::Gt | IndexOperator::GtEq => (Some((op, value)), None),
+                _ => (None, Some((op, value))),
+            }
+        }
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } if check_column(expr) && check_value(low) && check_value(high) => (
+            Some((IndexOperator::GtEq, low.as_ref().clone())),
+            Some((IndexOperator::LtEq, high.as_ref().clone())),
+        ),
+        _ => (None, None),
+    }
+}
+
+/// Returns true when `expr` evaluates without any row to a key of the same type as the
+/// `data_type` key column, keys of different types are not ordered by their values.
+/// Bound parameters are cast to the type of the key column when the key is fetched.
+fn is_key_of(expr: &Expr, data_type: &DataType) -> bool {
+    match (expr, data_type) {
+        (Expr::Parameter(_), _) => true,
+        (
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            },
+            DataType::Int,
+        ) => {
+            matches!(expr.as_ref(), Expr::Literal(AstLiteral::Number(_)))
+                && is_key_of(expr, data_type)
+        }
+        (Expr::Literal(AstLiteral::Number(n)), DataType::Int) => n.to_i64().is_some(),
+        (Expr::Literal(AstLiteral::QuotedString(_)), DataType::Text) => true,
+        (
+            Expr::TypedString {
+                data_type: target, ..
+            },
+            _,
+        ) => target == data_type,
+        _ => false,
+    }
+}
+
+/// Rows keep the scan order unless they are grouped, or unless the key column name is the label
+/// of another projected expression which ORDER BY would refer to.
+fn is_row_order_kept(select: &Select, alias: &str, column: &str) -> bool {
+    let Select {
+        projection,
+        group_by,
+        having,
+        ..
+    } = select;
+
+    group_by.is_empty()
+        && having.is_none()
+        && projection.iter().all(|item| match item {
+            SelectItem::Expr { expr, label } => {
+                !contains_grouping(expr) && (label != column || is_key_column(expr, alias, column))
+            }
+            SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => true,
+        })
+}
+
+fn contains_grouping(expr: &Expr) -> bool {
+    match PlanExpr::from(expr) {
+        _ if matches!(expr, Expr::Aggregate(_) | Expr::Window(_)) => true,
+        PlanExpr::None | PlanExpr::Identifier(_) | PlanExpr::CompoundIdentifier { .. } => false,
+        PlanExpr::Expr(expr) => contains_grouping(expr),
+        PlanExpr::TwoExprs(expr, expr2) => contains_grouping(expr) || contains_grouping(expr2),
+        PlanExpr::ThreeExprs(expr, expr2, expr3) => {
+            contains_grouping(expr) || contains_grouping(expr2) || contains_grouping(expr3)
+        }
+        PlanExpr::MultiExprs(exprs) => exprs.into_iter().any(contains_grouping),
+        PlanExpr::Query(_) => false,
+        PlanExpr::QueryAndExpr { expr, .. } => contains_grouping(expr),
+    }
+}
+
+/// RIGHT and FULL OUTER joins keep right rows which no left row matches,
+/// so filtering the left relation by its primary key is no longer equivalent.
+fn preserves_right(join: &Join) -> bool {
+    matches!(
+        join.join_operator,
+        JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use {
         super::plan as plan_primary_key,
         crate::{
             ast::{
-                AstLiteral, BinaryOperator, Expr, IndexItem, Join, JoinConstraint, JoinExecutor,
-                JoinOperator, Query, Select, SelectItem, SetExpr, Statement, TableFactor,
-                TableWithJoins, Values,
+                AstLiteral, BinaryOperator, Expr, IndexItem, IndexOperator, Join, JoinConstraint,
+                JoinExecutor, JoinOperator, Query, Select, SelectItem, SetExpr, Statement,
+                TableAlias, TableFactor, TableWithJoins, Values,
             },
             mock::{run, MockStorage},
             parse_sql::{parse, parse_expr},
@@ -241,6 +658,7 @@ mod tests {
 
     fn select(select: Select) -> Statement {
         Statement::Query(Query {
+            with: None,
             body: SetExpr::Select(Box::new(select)),
             limit: None,
             offset: None,
@@ -266,6 +684,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE id = 1;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -284,6 +703,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE 1 = id;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -302,6 +722,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE id = 1 AND True;";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -326,6 +747,7 @@ mod tests {
         ";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -359,6 +781,7 @@ mod tests {
         ";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -391,6 +814,7 @@ mod tests {
         let sql = "SELECT * FROM Player JOIN Badge WHERE Player.id = 1";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -417,6 +841,7 @@ mod tests {
         let sql = "SELECT * FROM Player JOIN Badge WHERE Player.id = Badge.user_id";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -448,7 +873,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Wildcard],
                     from: TableWithJoins {
                         relation: TableFactor::Table {
@@ -468,6 +895,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -502,7 +930,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("name".to_owned()),
                         label: "name".to_owned(),
@@ -525,6 +955,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -553,7 +984,9 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = {
             let subquery = Query {
+                with: None,
                 body: SetExpr::Select(Box::new(Select {
+                    distinct: false,
                     projection: vec![SelectItem::Expr {
                         expr: Expr::Identifier("id".to_owned()),
                         label: "id".to_owned(),
@@ -576,6 +1009,7 @@ mod tests {
             };
 
             select(Select {
+                distinct: false,
                 projection: vec![SelectItem::Wildcard],
                 from: TableWithJoins {
                     relation: TableFactor::Table {
@@ -600,17 +1034,20 @@ mod tests {
         let actual = plan(&storage, sql);
         let expected = Statement::Delete {
             table_name: "Player".to_owned(),
+            using: None,
             selection: Some(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("id".to_owned())),
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Literal(AstLiteral::Number(1.into()))),
             }),
+            returning: Vec::new(),
         };
         assert_eq!(actual, expected, "delete statement:\n{sql}");
 
         let sql = "VALUES (1), (2);";
         let actual = plan(&storage, sql);
         let expected = Statement::Query(Query {
+            with: None,
             body: SetExpr::Values(Values(vec![
                 vec![Expr::Literal(AstLiteral::Number(1.into()))],
                 vec![Expr::Literal(AstLiteral::Number(2.into()))],
@@ -624,6 +1061,7 @@ mod tests {
         let sql = "SELECT * FROM Player WHERE (name);";
         let actual = plan(&storage, sql);
         let expected = select(Select {
+            distinct: false,
             projection: vec![SelectItem::Wildcard],
             from: TableWithJoins {
                 relation: TableFactor::Table {
@@ -639,4 +1077,192 @@ mod tests {
         });
         assert_eq!(actual, expected, "nested:\n{sql}");
     }
+
+    #[test]
+    fn composite() {
+        let storage = run("
+            CREATE TABLE Enrollment (
+                student_id INTEGER,
+                course TEXT,
+                grade TEXT,
+                PRIMARY KEY (student_id, course)
+            );
+        ");
+
+        let select_enrollment = |alias: Option<&str>, index, selection| {
+            select(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Enrollment".to_owned(),
+                        alias: alias.map(|name| TableAlias {
+                            name: name.to_owned(),
+                            columns: Vec::new(),
+                        }),
+                        index,
+                    },
+                    joins: Vec::new(),
+                },
+                selection,
+                group_by: Vec::new(),
+                having: None,
+            })
+        };
+
+        let sql = "SELECT * FROM Enrollment WHERE course = 'math' AND student_id = 1;";
+        let actual = plan(&storage, sql);
+        let expected = select_enrollment(
+            None,
+            Some(IndexItem::CompositePrimaryKey(vec![
+                expr("1"),
+                expr("'math'"),
+            ])),
+            None,
+        );
+        assert_eq!(actual, expected, "all key columns bound:\n{sql}");
+
+        let sql = "
+            SELECT * FROM Enrollment e
+            WHERE
+                grade = 'A'
+                AND (e.student_id = 1 AND True)
+                AND 'math' = course;
+        ";
+        let actual = plan(&storage, sql);
+        let expected = select_enrollment(
+            Some("e"),
+            Some(IndexItem::CompositePrimaryKey(vec![
+                expr("1"),
+                expr("'math'"),
+            ])),
+            Some(expr("grade = 'A' AND True")),
+        );
+        assert_eq!(actual, expected, "remaining selection:\n{sql}");
+
+        let sql = "SELECT * FROM Enrollment WHERE student_id = 1;";
+        let actual = plan(&storage, sql);
+        let expected = select_enrollment(None, None, Some(expr("student_id = 1")));
+        assert_eq!(actual, expected, "partially bound:\n{sql}");
+
+        let sql = "SELECT * FROM Enrollment WHERE student_id = 1 OR course = 'math';";
+        let actual = plan(&storage, sql);
+        let expected =
+            select_enrollment(None, None, Some(expr("student_id = 1 OR course = 'math'")));
+        assert_eq!(actual, expected, "OR binary op:\n{sql}");
+
+        let storage = run("
+            CREATE TABLE Enrollment (
+                student_id INTEGER,
+                course TEXT,
+                grade TEXT,
+                PRIMARY KEY (course, student_id)
+            );
+        ");
+
+        let sql = "SELECT * FROM Enrollment WHERE student_id = 1 AND course = 'math';";
+        let actual = plan(&storage, sql);
+        let expected = select_enrollment(
+            None,
+            Some(IndexItem::CompositePrimaryKey(vec![
+                expr("'math'"),
+                expr("1"),
+            ])),
+            None,
+        );
+        assert_eq!(
+            actual, expected,
+            "key columns in the order of the constraint:\n{sql}"
+        );
+    }
+
+    #[test]
+    fn range() {
+        let storage = run("
+            CREATE TABLE Player (
+                id INTEGER PRIMARY KEY,
+                name TEXT
+            );
+        ");
+
+        let select_player = |index, selection| {
+            select(Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: "Player".to_owned(),
+                        alias: None,
+                        index,
+                    },
+                    joins: Vec::new(),
+                },
+                selection,
+                group_by: Vec::new(),
+                having: None,
+            })
+        };
+        let range = |lower: Option<(IndexOperator, &str)>, upper: Option<(IndexOperator, &str)>| {
+            Some(IndexItem::PrimaryKeyRange {
+                lower: lower.map(|(op, sql)| (op, expr(sql))),
+                upper: upper.map(|(op, sql)| (op, expr(sql))),
+                asc: None,
+            })
+        };
+
+        let sql = "SELECT * FROM Player WHERE id > 1 AND name = 'a' AND 10 >= id;";
+        let actual = plan(&storage, sql);
+        let expected = select_player(
+            range(
+                Some((IndexOperator::Gt, "1")),
+                Some((IndexOperator::LtEq, "10")),
+            ),
+            Some(expr("name = 'a'")),
+        );
+        assert_eq!(actual, expected, "lower and upper bounds:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE id BETWEEN -5 AND 5 AND id < 3;";
+        let actual = plan(&storage, sql);
+        let expected = select_player(
+            range(
+                Some((IndexOperator::GtEq, "-5")),
+                Some((IndexOperator::LtEq, "5")),
+            ),
+            Some(expr("id < 3")),
+        );
+        assert_eq!(actual, expected, "BETWEEN and remaining bound:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE id < $1;";
+        let actual = plan(&storage, sql);
+        let expected = select_player(range(None, Some((IndexOperator::Lt, "$1"))), None);
+        assert_eq!(actual, expected, "parameter:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE id > 1.5 OR id < 0;";
+        let actual = plan(&storage, sql);
+        let expected = select_player(None, Some(expr("id > 1.5 OR id < 0")));
+        assert_eq!(actual, expected, "OR binary op:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE id > 1.5 AND id < 'a';";
+        let actual = plan(&storage, sql);
+        let expected = select_player(None, Some(expr("id > 1.5 AND id < 'a'")));
+        assert_eq!(actual, expected, "values of other types:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE id > 1 ORDER BY id DESC;";
+        let actual = plan(&storage, sql);
+        let expected = select_player(
+            Some(IndexItem::PrimaryKeyRange {
+                lower: Some((IndexOperator::Gt, expr("1"))),
+                upper: None,
+                asc: Some(false),
+            }),
+            None,
+        );
+        assert_eq!(actual, expected, "ORDER BY key:\n{sql}");
+
+        let sql = "SELECT * FROM Player WHERE id > 1 ORDER BY name;";
+        let Statement::Query(Query { order_by, .. }) = plan(&storage, sql) else {
+            unreachable!("only for SELECT: {sql}");
+        };
+        assert_eq!(order_by.len(), 1, "ORDER BY other column:\n{sql}");
+    }
 }
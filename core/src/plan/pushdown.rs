@@ -0,0 +1,456 @@
+use {
+    super::{context::Context, expr::PlanExpr, planner::Planner},
+    crate::{
+        ast::{
+            AstLiteral, BinaryOperator, ColumnDef, DataType, Expr, IndexItem, OrderByExpr, Query,
+            Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins,
+            UnaryOperator,
+        },
+        data::{BigDecimalExt, Schema},
+    },
+    std::{collections::HashMap, rc::Rc},
+};
+
+/// Splits the WHERE clause of single table scans into the conditions which
+/// [`Store::scan_data_filtered`] can evaluate and the residual ones, and also
+/// passes down the columns the query actually reads.
+///
+/// [`Store::scan_data_filtered`]: crate::store::Store::scan_data_filtered
+pub fn plan(schema_map: &HashMap<String, Schema>, statement: Statement) -> Statement {
+    let planner = PushdownPlanner { schema_map };
+
+    match statement {
+        Statement::Query(query) => {
+            let query = planner.query(None, query);
+
+            Statement::Query(query)
+        }
+        _ => statement,
+    }
+}
+
+struct PushdownPlanner<'a> {
+    schema_map: &'a HashMap<String, Schema>,
+}
+
+impl<'a> Planner<'a> for PushdownPlanner<'a> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let with = with.map(|with| self.with(outer_context.as_ref().map(Rc::clone), with));
+        let body = self.set_expr(outer_context, body, &order_by);
+
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, name: &str) -> Option<&'a Schema> {
+        self.schema_map.get(name)
+    }
+}
+
+impl<'a> PushdownPlanner<'a> {
+    fn set_expr(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        set_expr: SetExpr,
+        order_by: &[OrderByExpr],
+    ) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => {
+                let select = self.select(outer_context, *select, order_by);
+
+                SetExpr::Select(Box::new(select))
+            }
+            SetExpr::Values(_) => set_expr,
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(
+                    outer_context.as_ref().map(Rc::clone),
+                    *left,
+                    order_by,
+                )),
+                right: Box::new(self.set_expr(outer_context, *right, order_by)),
+            },
+        }
+    }
+
+    fn select(
+        &self,
+        outer_context: Option<Rc<Context<'a>>>,
+        select: Select,
+        order_by: &[OrderByExpr],
+    ) -> Select {
+        let current_context = self.update_context(None, &select.from.relation);
+        let outer_context = Context::concat(current_context, outer_context);
+        let selection = select
+            .selection
+            .map(|expr| self.subquery_expr(outer_context, expr));
+        let select = Select {
+            selection,
+            ..select
+        };
+
+        let (name, alias, column_defs) = match &select.from.relation {
+            TableFactor::Table {
+                name,
+                alias,
+                index: None,
+            } if select.from.joins.is_empty() => match self.get_schema(name) {
+                Some(Schema {
+                    column_defs: Some(column_defs),
+                    ..
+                }) => (name, alias, column_defs),
+                _ => return select,
+            },
+            _ => return select,
+        };
+
+        let alias = alias
+            .as_ref()
+            .map(|TableAlias { name, .. }| name)
+            .unwrap_or(name);
+        let (predicates, selection) = match select.selection {
+            Some(expr) => {
+                let (predicates, residual): (Vec<_>, Vec<_>) = conjuncts(expr)
+                    .into_iter()
+                    .partition(|expr| is_pushable(expr, alias, column_defs));
+                let selection = residual.into_iter().reduce(|left, right| Expr::BinaryOp {
+                    left: Box::new(left),
+                    op: BinaryOperator::And,
+                    right: Box::new(right),
+                });
+
+                (predicates, selection)
+            }
+            None => (Vec::new(), None),
+        };
+        let select = Select {
+            selection,
+            ..select
+        };
+        let columns = required_columns(&select, order_by, column_defs);
+
+        if predicates.is_empty() && columns.is_none() {
+            return select;
+        }
+
+        let Select {
+            from: TableWithJoins { relation, joins },
+            ..
+        } = select;
+        let relation = match relation {
+            TableFactor::Table { name, alias, .. } => TableFactor::Table {
+                name,
+                alias,
+                index: Some(IndexItem::Scan {
+                    predicates,
+                    columns,
+                }),
+            },
+            _ => relation,
+        };
+
+        Select {
+            from: TableWithJoins { relation, joins },
+            ..select
+        }
+    }
+}
+
+fn conjuncts(expr: Expr) -> Vec<Expr> {
+    match expr {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let mut exprs = conjuncts(*left);
+            exprs.extend(conjuncts(*right));
+
+            exprs
+        }
+        Expr::Nested(expr) => match *expr {
+            expr @ Expr::BinaryOp {
+                op: BinaryOperator::And,
+                ..
+            } => conjuncts(expr),
+            expr => vec![Expr::Nested(Box::new(expr))],
+        },
+        _ => vec![expr],
+    }
+}
+
+/// Returns true when `expr` compares a column of the scanned table with constants of a type
+/// which compares with the column values the same way as the executor does.
+fn is_pushable(expr: &Expr, alias: &str, column_defs: &[ColumnDef]) -> bool {
+    let column = |expr: &Expr| {
+        let name = match expr {
+            Expr::Identifier(ident) => ident,
+            Expr::CompoundIdentifier {
+                alias: target,
+                ident,
+            } if target == alias => ident,
+            _ => return None,
+        };
+
+        column_defs
+            .iter()
+            .find(|column_def| &column_def.name == name)
+    };
+
+    match expr {
+        Expr::BinaryOp { left, op, right }
+            if matches!(
+                op,
+                BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::LtEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::GtEq
+            ) =>
+        {
+            match (column(left), column(right)) {
+                (Some(column_def), None) => is_constant_of(right, &column_def.data_type),
+                (None, Some(column_def)) => is_constant_of(left, &column_def.data_type),
+                _ => false,
+            }
+        }
+        Expr::IsNull(expr) | Expr::IsNotNull(expr) => column(expr).is_some(),
+        Expr::InList { expr, list, .. } => column(expr).is_some_and(|column_def| {
+            list.iter()
+                .all(|item| is_constant_of(item, &column_def.data_type))
+        }),
+        Expr::Between {
+            expr,
+            negated: false,
+            low,
+            high,
+        } => column(expr).is_some_and(|column_def| {
+            is_constant_of(low, &column_def.data_type)
+                && is_constant_of(high, &column_def.data_type)
+        }),
+        _ => false,
+    }
+}
+
+fn is_constant_of(expr: &Expr, data_type: &DataType) -> bool {
+    match (expr, data_type) {
+        (
+            Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            },
+            DataType::Int | DataType::Float,
+        ) => {
+            matches!(expr.as_ref(), Expr::Literal(AstLiteral::Number(_)))
+                && is_constant_of(expr, data_type)
+        }
+        (Expr::Literal(AstLiteral::Number(n)), DataType::Int) => n.to_i64().is_some(),
+        (Expr::Literal(AstLiteral::Number(_)), DataType::Float) => true,
+        (Expr::Literal(AstLiteral::QuotedString(_)), DataType::Text) => true,
+        (Expr::Literal(AstLiteral::Boolean(_)), DataType::Boolean) => true,
+        (
+            Expr::TypedString {
+                data_type: target, ..
+            },
+            _,
+        ) => target == data_type,
+        _ => false,
+    }
+}
+
+/// Returns the columns of the table read by the query in the schema order, or `None` when the
+/// query reads every column or the used columns cannot be told apart, e.g. by `*` or subqueries.
+fn required_columns(
+    select: &Select,
+    order_by: &[OrderByExpr],
+    column_defs: &[ColumnDef],
+) -> Option<Vec<String>> {
+    let mut exprs = Vec::new();
+    for item in &select.projection {
+        match item {
+            SelectItem::Expr { expr, .. } => exprs.push(expr),
+            SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => return None,
+        }
+    }
+    exprs.extend(select.selection.iter());
+    exprs.extend(select.group_by.iter());
+    exprs.extend(select.having.iter());
+    exprs.extend(order_by.iter().map(|OrderByExpr { expr, .. }| expr));
+
+    let mut names = Vec::new();
+    while let Some(expr) = exprs.pop() {
+        match PlanExpr::from(expr) {
+            PlanExpr::None => {}
+            PlanExpr::Identifier(ident) | PlanExpr::CompoundIdentifier { ident, .. } => {
+                names.push(ident)
+            }
+            PlanExpr::Expr(expr) => exprs.push(expr),
+            PlanExpr::TwoExprs(expr, expr2) => exprs.extend([expr, expr2]),
+            PlanExpr::ThreeExprs(expr, expr2, expr3) => exprs.extend([expr, expr2, expr3]),
+            PlanExpr::MultiExprs(multi_exprs) => exprs.extend(multi_exprs),
+            PlanExpr::Query(_) | PlanExpr::QueryAndExpr { .. } => return None,
+        }
+    }
+
+    let columns = column_defs
+        .iter()
+        .filter(|ColumnDef { name, .. }| names.contains(&name.as_str()))
+        .map(|ColumnDef { name, .. }| name.to_owned())
+        .collect::<Vec<_>>();
+
+    (columns.len() < column_defs.len()).then_some(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan as plan_pushdown,
+        crate::{
+            ast::{Expr, IndexItem, Query, SetExpr, Statement, TableFactor},
+            mock::{run, MockStorage},
+            parse_sql::{parse, parse_expr},
+            plan::fetch_schema_map,
+            translate::{translate, translate_expr},
+        },
+        futures::executor::block_on,
+    };
+
+    /// Returns the planned scan of the first SELECT and its residual selection.
+    fn plan(storage: &MockStorage, sql: &str) -> (Option<IndexItem>, Option<Expr>) {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+        let statement = translate(&parsed).unwrap();
+        let schema_map = block_on(fetch_schema_map(storage, &statement)).unwrap();
+
+        match plan_pushdown(&schema_map, statement) {
+            Statement::Query(Query {
+                body: SetExpr::Select(select),
+                ..
+            }) => match select.from.relation {
+                TableFactor::Table { index, .. } => (index, select.selection),
+                _ => unreachable!("only for table scans: {sql}"),
+            },
+            _ => unreachable!("only for SELECT: {sql}"),
+        }
+    }
+
+    fn expr(sql: &str) -> Expr {
+        let parsed = parse_expr(sql).expect(sql);
+
+        translate_expr(&parsed).expect(sql)
+    }
+
+    fn scan(predicates: &[&str], columns: Option<&[&str]>) -> Option<IndexItem> {
+        Some(IndexItem::Scan {
+            predicates: predicates.iter().map(|sql| expr(sql)).collect(),
+            columns: columns.map(|columns| columns.iter().map(ToString::to_string).collect()),
+        })
+    }
+
+    #[test]
+    fn split_selection() {
+        let storage = run("
+            CREATE TABLE Item (
+                id INTEGER,
+                name TEXT,
+                price FLOAT,
+                created DATE
+            );
+        ");
+
+        let sql = "SELECT * FROM Item WHERE id > 1 AND name LIKE 'a%' AND price BETWEEN 1 AND 2.5";
+        let actual = plan(&storage, sql);
+        let expected = (
+            scan(&["id > 1", "price BETWEEN 1 AND 2.5"], None),
+            Some(expr("name LIKE 'a%'")),
+        );
+        assert_eq!(actual, expected, "pushable and residual conditions:\n{sql}");
+
+        let sql = "
+            SELECT * FROM Item i
+            WHERE 3 <= i.id AND (name IS NULL AND i.created = DATE '2024-01-01')
+        ";
+        let actual = plan(&storage, sql);
+        let expected = (
+            scan(
+                &["3 <= i.id", "name IS NULL", "i.created = DATE '2024-01-01'"],
+                None,
+            ),
+            None,
+        );
+        assert_eq!(actual, expected, "nested conjunctions with alias:\n{sql}");
+
+        let sql = "SELECT * FROM Item WHERE id IN (1, 2) AND id = 1.5 AND name = 1 AND price > -1";
+        let actual = plan(&storage, sql);
+        let expected = (
+            scan(&["id IN (1, 2)", "price > -1"], None),
+            Some(expr("id = 1.5 AND name = 1")),
+        );
+        assert_eq!(
+            actual, expected,
+            "constants must match the column type:\n{sql}"
+        );
+
+        let sql = "SELECT * FROM Item WHERE id = 1 OR price > 1";
+        let actual = plan(&storage, sql);
+        let expected = (None, Some(expr("id = 1 OR price > 1")));
+        assert_eq!(actual, expected, "disjunction is not pushed:\n{sql}");
+
+        let sql = "SELECT * FROM Item WHERE id = price AND Other.id = 1";
+        let actual = plan(&storage, sql);
+        let expected = (None, Some(expr("id = price AND Other.id = 1")));
+        assert_eq!(
+            actual, expected,
+            "column to column and outer column:\n{sql}"
+        );
+    }
+
+    #[test]
+    fn required_columns() {
+        let storage = run("
+            CREATE TABLE Item (
+                id INTEGER,
+                name TEXT,
+                price FLOAT,
+                memo TEXT
+            );
+        ");
+
+        let sql = "SELECT name FROM Item WHERE id > 1 ORDER BY price";
+        let actual = plan(&storage, sql);
+        let expected = (scan(&["id > 1"], Some(&["name", "price"])), None);
+        assert_eq!(actual, expected, "projection and order by:\n{sql}");
+
+        let sql = "SELECT memo, COUNT(*) FROM Item GROUP BY memo HAVING SUM(price) > 1";
+        let actual = plan(&storage, sql);
+        let expected = (scan(&[], Some(&["price", "memo"])), None);
+        assert_eq!(actual, expected, "group by and having:\n{sql}");
+
+        let sql = "SELECT id, name, price, memo FROM Item";
+        let actual = plan(&storage, sql);
+        assert_eq!(actual, (None, None), "every column:\n{sql}");
+
+        let sql = "SELECT id FROM Item WHERE EXISTS (SELECT * FROM Item WHERE id = 1)";
+        let actual = plan(&storage, sql);
+        assert!(actual.0.is_none(), "subquery in selection:\n{sql}");
+    }
+}
@@ -2,8 +2,8 @@ use {
     super::expr::PlanExpr,
     crate::{
         ast::{
-            Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr,
-            Statement, TableFactor, TableWithJoins,
+            Cte, Expr, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem, SetExpr,
+            Statement, TableFactor, TableWithJoins, With,
         },
         data::Schema,
         result::Result,
@@ -47,6 +47,18 @@ pub async fn fetch_schema_map<T: Store>(
 
             Ok(schema_list)
         }
+        Statement::Update {
+            table_name,
+            from: Some(from),
+            selection,
+            ..
+        }
+        | Statement::Delete {
+            table_name,
+            using: Some(from),
+            selection,
+            ..
+        } => scan_target(storage, table_name, from, selection.as_ref()).await,
         Statement::DropTable { names, .. } => {
             stream::iter(names)
                 .filter_map(|table_name| async {
@@ -63,18 +75,45 @@ pub async fn fetch_schema_map<T: Store>(
     }
 }
 
-async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<String, Schema>> {
+/// Fetches the schemas used by `UPDATE ... FROM` or `DELETE ... USING`.
+pub(super) async fn scan_target<T: Store>(
+    storage: &T,
+    table_name: &str,
+    from: &TableWithJoins,
+    selection: Option<&Expr>,
+) -> Result<HashMap<String, Schema>> {
+    let table_schema = storage
+        .fetch_schema(table_name)
+        .await?
+        .map(|schema| HashMap::from([(table_name.to_owned(), schema)]))
+        .unwrap_or_else(HashMap::new);
+    let from = scan_table_with_joins(storage, from).await?;
+    let selection = match selection {
+        Some(expr) => scan_expr(storage, expr).await?,
+        None => HashMap::new(),
+    };
+
+    Ok(table_schema
+        .into_iter()
+        .chain(from)
+        .chain(selection)
+        .collect())
+}
+
+#[async_recursion(?Send)]
+async fn scan_query<T>(storage: &T, query: &Query) -> Result<HashMap<String, Schema>>
+where
+    T: Store,
+{
     let Query {
+        with,
         body,
         limit,
         offset,
         ..
     } = query;
 
-    let schema_list = match body {
-        SetExpr::Select(select) => scan_select(storage, select).await?,
-        SetExpr::Values(_) => HashMap::new(),
-    };
+    let schema_list = scan_set_expr(storage, body).await?;
 
     let schema_list = match (limit, offset) {
         (Some(limit), Some(offset)) => schema_list
@@ -89,11 +128,50 @@ async fn scan_query<T: Store>(storage: &T, query: &Query) -> Result<HashMap<Stri
         (None, None) => schema_list,
     };
 
+    let schema_list = match with {
+        Some(With { cte_tables, .. }) => {
+            let mut schema_list: HashMap<String, Schema> = stream::iter(cte_tables)
+                .then(|Cte { query, .. }| scan_query(storage, query))
+                .try_collect::<Vec<HashMap<String, Schema>>>()
+                .await?
+                .into_iter()
+                .flatten()
+                .chain(schema_list)
+                .collect();
+
+            // CTE names shadow storage tables, so they must not be planned with table schemas
+            for Cte { alias, .. } in cte_tables {
+                schema_list.remove(&alias.name);
+            }
+
+            schema_list
+        }
+        None => schema_list,
+    };
+
     Ok(schema_list)
 }
 
+#[async_recursion(?Send)]
+async fn scan_set_expr<T>(storage: &T, set_expr: &SetExpr) -> Result<HashMap<String, Schema>>
+where
+    T: Store,
+{
+    match set_expr {
+        SetExpr::Select(select) => scan_select(storage, select).await,
+        SetExpr::Values(_) => Ok(HashMap::new()),
+        SetExpr::SetOperation { left, right, .. } => {
+            let left = scan_set_expr(storage, left).await?;
+            let right = scan_set_expr(storage, right).await?;
+
+            Ok(left.into_iter().chain(right).collect())
+        }
+    }
+}
+
 async fn scan_select<T: Store>(storage: &T, select: &Select) -> Result<HashMap<String, Schema>> {
     let Select {
+        distinct: _,
         projection,
         from,
         selection,
@@ -155,13 +233,17 @@ async fn scan_join<T: Store>(storage: &T, join: &Join) -> Result<HashMap<String,
     let schema_list = scan_table_factor(storage, relation).await?;
     let schema_list = match join_operator {
         JoinOperator::Inner(JoinConstraint::On(expr))
-        | JoinOperator::LeftOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
+        | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+        | JoinOperator::RightOuter(JoinConstraint::On(expr))
+        | JoinOperator::FullOuter(JoinConstraint::On(expr)) => scan_expr(storage, expr)
             .await?
             .into_iter()
             .chain(schema_list)
             .collect(),
         JoinOperator::Inner(JoinConstraint::None)
-        | JoinOperator::LeftOuter(JoinConstraint::None) => schema_list,
+        | JoinOperator::LeftOuter(JoinConstraint::None)
+        | JoinOperator::RightOuter(JoinConstraint::None)
+        | JoinOperator::FullOuter(JoinConstraint::None) => schema_list,
     };
 
     Ok(schema_list)
@@ -274,6 +356,9 @@ mod tests {
         test("INSERT INTO Foo VALUES (1), (2), (3);", &["Foo"]);
         test("DROP TABLE Foo, Bar;", &["Bar", "Foo"]);
 
+        test("DELETE FROM Foo USING Bar;", &["Bar", "Foo"]);
+        test("UPDATE Foo SET id = 1 FROM Bar;", &["Bar", "Foo"]);
+
         // Unimplemented
         test("DELETE FROM Foo;", &[]);
     }
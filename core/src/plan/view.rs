@@ -0,0 +1,449 @@
+use {
+    super::{context::Context, planner::Planner, PlanError},
+    crate::{
+        ast::{
+            Assignment, Cte, Join, JoinConstraint, JoinOperator, Query, Select, SelectItem,
+            SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Values, With,
+        },
+        data::{Schema, View as StructView},
+        result::Result,
+        store::View,
+    },
+    std::{cell::Cell, collections::HashMap, rc::Rc},
+};
+
+pub async fn plan<T: View>(storage: &T, statement: Statement) -> Result<Statement> {
+    if !matches!(
+        statement,
+        Statement::Query(_)
+            | Statement::Insert { .. }
+            | Statement::CreateTable { .. }
+            | Statement::Update { .. }
+            | Statement::Delete { .. }
+    ) {
+        return Ok(statement);
+    }
+
+    let views = storage
+        .fetch_all_views()
+        .await?
+        .into_iter()
+        .map(|view| (view.view_name.clone(), view))
+        .collect::<HashMap<_, _>>();
+
+    if views.is_empty() {
+        return Ok(statement);
+    }
+
+    expand(&views, statement)
+}
+
+/// Replaces every view referenced in `FROM` with a derived table of its query,
+/// views referenced by the view query are expanded recursively.
+pub fn expand(views: &HashMap<String, StructView>, statement: Statement) -> Result<Statement> {
+    let recursive_view = Cell::new(None);
+    let planner = ViewPlanner {
+        views,
+        expanding: Vec::new(),
+        cte_names: Vec::new(),
+        recursive_view: &recursive_view,
+    };
+
+    let statement = match statement {
+        Statement::Query(query) => Statement::Query(planner.query(None, query)),
+        Statement::Insert {
+            table_name,
+            columns,
+            source,
+            on_conflict,
+            returning,
+        } => Statement::Insert {
+            table_name,
+            columns,
+            source: planner.query(None, source),
+            on_conflict,
+            returning,
+        },
+        Statement::CreateTable {
+            if_not_exists,
+            name,
+            columns,
+            primary_key,
+            source,
+            engine,
+            foreign_keys,
+            checks,
+            comment,
+        } => Statement::CreateTable {
+            if_not_exists,
+            name,
+            columns,
+            primary_key,
+            source: source.map(|source| Box::new(planner.query(None, *source))),
+            engine,
+            foreign_keys,
+            checks,
+            comment,
+        },
+        Statement::Update {
+            table_name,
+            assignments,
+            from,
+            selection,
+            returning,
+        } => Statement::Update {
+            table_name,
+            assignments: assignments
+                .into_iter()
+                .map(|Assignment { id, value }| Assignment {
+                    id,
+                    value: planner.subquery_expr(None, value),
+                })
+                .collect(),
+            from: from.map(|from| planner.table_with_joins(from)),
+            selection: selection.map(|expr| planner.subquery_expr(None, expr)),
+            returning,
+        },
+        Statement::Delete {
+            table_name,
+            using,
+            selection,
+            returning,
+        } => Statement::Delete {
+            table_name,
+            using: using.map(|using| planner.table_with_joins(using)),
+            selection: selection.map(|expr| planner.subquery_expr(None, expr)),
+            returning,
+        },
+        _ => statement,
+    };
+
+    match recursive_view.into_inner() {
+        Some(view_name) => Err(PlanError::ViewReferencesItself(view_name).into()),
+        None => Ok(statement),
+    }
+}
+
+struct ViewPlanner<'a> {
+    views: &'a HashMap<String, StructView>,
+    /// views whose queries are being expanded
+    expanding: Vec<&'a str>,
+    /// CTE names in scope, they shadow views of the same name
+    cte_names: Vec<String>,
+    recursive_view: &'a Cell<Option<String>>,
+}
+
+impl<'a> Planner<'a> for ViewPlanner<'a> {
+    fn query(&self, outer_context: Option<Rc<Context<'a>>>, query: Query) -> Query {
+        let Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        } = query;
+
+        let cte_names = with
+            .iter()
+            .flat_map(|With { cte_tables, .. }| cte_tables)
+            .map(|Cte { alias, .. }| alias.name.clone());
+        let planner = ViewPlanner {
+            views: self.views,
+            expanding: self.expanding.clone(),
+            cte_names: self.cte_names.iter().cloned().chain(cte_names).collect(),
+            recursive_view: self.recursive_view,
+        };
+
+        let with = with.map(|with| planner.with(outer_context.as_ref().map(Rc::clone), with));
+        let body = planner.set_expr(body);
+        let order_by = order_by
+            .into_iter()
+            .map(|mut order_by_expr| {
+                order_by_expr.expr = planner.subquery_expr(None, order_by_expr.expr);
+                order_by_expr
+            })
+            .collect();
+
+        Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        }
+    }
+
+    fn get_schema(&self, _: &str) -> Option<&'a Schema> {
+        None
+    }
+}
+
+impl<'a> ViewPlanner<'a> {
+    fn set_expr(&self, set_expr: SetExpr) -> SetExpr {
+        match set_expr {
+            SetExpr::Select(select) => SetExpr::Select(Box::new(self.select(*select))),
+            SetExpr::Values(Values(values_list)) => {
+                let values_list = values_list
+                    .into_iter()
+                    .map(|values| {
+                        values
+                            .into_iter()
+                            .map(|expr| self.subquery_expr(None, expr))
+                            .collect()
+                    })
+                    .collect();
+
+                SetExpr::Values(Values(values_list))
+            }
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(self.set_expr(*left)),
+                right: Box::new(self.set_expr(*right)),
+            },
+        }
+    }
+
+    fn select(&self, select: Select) -> Select {
+        let Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        } = select;
+
+        let projection = projection
+            .into_iter()
+            .map(|select_item| match select_item {
+                SelectItem::Expr { expr, label } => SelectItem::Expr {
+                    expr: self.subquery_expr(None, expr),
+                    label,
+                },
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => select_item,
+            })
+            .collect();
+        let from = self.table_with_joins(from);
+        let selection = selection.map(|expr| self.subquery_expr(None, expr));
+        let group_by = group_by
+            .into_iter()
+            .map(|expr| self.subquery_expr(None, expr))
+            .collect();
+        let having = having.map(|expr| self.subquery_expr(None, expr));
+
+        Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+        }
+    }
+
+    fn table_with_joins(&self, table_with_joins: TableWithJoins) -> TableWithJoins {
+        let TableWithJoins { relation, joins } = table_with_joins;
+
+        let relation = self.table_factor(relation);
+        let joins = joins
+            .into_iter()
+            .map(|join| {
+                let Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                } = join;
+
+                let relation = self.table_factor(relation);
+                let join_constraint = |constraint| match constraint {
+                    JoinConstraint::On(expr) => JoinConstraint::On(self.subquery_expr(None, expr)),
+                    JoinConstraint::None => JoinConstraint::None,
+                };
+                let join_operator = match join_operator {
+                    JoinOperator::Inner(constraint) => {
+                        JoinOperator::Inner(join_constraint(constraint))
+                    }
+                    JoinOperator::LeftOuter(constraint) => {
+                        JoinOperator::LeftOuter(join_constraint(constraint))
+                    }
+                    JoinOperator::RightOuter(constraint) => {
+                        JoinOperator::RightOuter(join_constraint(constraint))
+                    }
+                    JoinOperator::FullOuter(constraint) => {
+                        JoinOperator::FullOuter(join_constraint(constraint))
+                    }
+                };
+
+                Join {
+                    relation,
+                    join_operator,
+                    join_executor,
+                }
+            })
+            .collect();
+
+        TableWithJoins { relation, joins }
+    }
+
+    fn table_factor(&self, table_factor: TableFactor) -> TableFactor {
+        match table_factor {
+            TableFactor::Table { name, alias, index } => {
+                let view = match self.views.get(&name) {
+                    Some(view) if !self.cte_names.contains(&name) => view,
+                    _ => return TableFactor::Table { name, alias, index },
+                };
+
+                if self.expanding.contains(&view.view_name.as_str()) {
+                    self.recursive_view.set(Some(name.clone()));
+
+                    return TableFactor::Table { name, alias, index };
+                }
+
+                let planner = ViewPlanner {
+                    views: self.views,
+                    expanding: self
+                        .expanding
+                        .iter()
+                        .copied()
+                        .chain([view.view_name.as_str()])
+                        .collect(),
+                    cte_names: Vec::new(),
+                    recursive_view: self.recursive_view,
+                };
+                let subquery = planner.query(None, view.query.clone());
+                let alias = match alias {
+                    Some(alias) if !alias.columns.is_empty() => alias,
+                    Some(TableAlias { name, .. }) => TableAlias {
+                        name,
+                        columns: view.columns.clone(),
+                    },
+                    None => TableAlias {
+                        name,
+                        columns: view.columns.clone(),
+                    },
+                };
+
+                TableFactor::Derived { subquery, alias }
+            }
+            TableFactor::Derived { subquery, alias } => TableFactor::Derived {
+                subquery: self.query(None, subquery),
+                alias,
+            },
+            TableFactor::Series { .. } | TableFactor::Dictionary { .. } => table_factor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::plan,
+        crate::{
+            ast::Statement,
+            data::View,
+            error::PlanError,
+            mock::{run, MockStorage},
+            parse_sql::{parse, parse_query},
+            result::Result,
+            translate::{translate, translate_query},
+        },
+        futures::executor::block_on,
+        std::collections::HashMap,
+    };
+
+    fn view(view_name: &str, columns: &[&str], sql: &str) -> View {
+        let query = parse_query(sql).and_then(|query| translate_query(&query));
+
+        View {
+            view_name: view_name.to_owned(),
+            columns: columns.iter().map(|column| (*column).to_owned()).collect(),
+            query: query.expect(sql),
+        }
+    }
+
+    fn expand(views: &[View], sql: &str) -> Result<Statement> {
+        let views = views
+            .iter()
+            .map(|view| (view.view_name.clone(), view.clone()))
+            .collect::<HashMap<_, _>>();
+        super::expand(&views, statement(sql))
+    }
+
+    fn statement(sql: &str) -> Statement {
+        let parsed = parse(sql).expect(sql).into_iter().next().unwrap();
+
+        translate(&parsed).unwrap()
+    }
+
+    #[test]
+    fn view_expansion() {
+        let views = [
+            view("Adult", &[], "SELECT id, name FROM Player WHERE age >= 20"),
+            view("Named", &["n"], "SELECT name FROM Adult"),
+        ];
+        let test = |sql, expected| {
+            assert_eq!(expand(&views, sql), Ok(statement(expected)), "{sql}");
+        };
+
+        test(
+            "SELECT * FROM Adult",
+            "SELECT * FROM (SELECT id, name FROM Player WHERE age >= 20) AS Adult",
+        );
+        test(
+            "SELECT * FROM Adult AS A WHERE id IN (SELECT n FROM Named)",
+            "
+            SELECT * FROM (SELECT id, name FROM Player WHERE age >= 20) AS A
+            WHERE id IN (
+                SELECT n FROM (
+                    SELECT name FROM (SELECT id, name FROM Player WHERE age >= 20) AS Adult
+                ) AS Named (n)
+            )
+            ",
+        );
+        test(
+            "SELECT * FROM Player JOIN Adult ON Adult.id = Player.id",
+            "
+            SELECT * FROM Player
+            JOIN (SELECT id, name FROM Player WHERE age >= 20) AS Adult ON Adult.id = Player.id
+            ",
+        );
+        test(
+            "DELETE FROM Player WHERE id IN (SELECT id FROM Adult)",
+            "
+            DELETE FROM Player
+            WHERE id IN (SELECT id FROM (SELECT id, name FROM Player WHERE age >= 20) AS Adult)
+            ",
+        );
+        test(
+            "WITH Adult AS (SELECT 1) SELECT * FROM Adult",
+            "WITH Adult AS (SELECT 1) SELECT * FROM Adult",
+        );
+    }
+
+    #[test]
+    fn view_references_itself() {
+        let views = [
+            view("A", &[], "SELECT * FROM B"),
+            view("B", &[], "SELECT * FROM A"),
+        ];
+
+        assert_eq!(
+            expand(&views, "SELECT * FROM A"),
+            Err(PlanError::ViewReferencesItself("A".to_owned()).into())
+        );
+    }
+
+    #[test]
+    fn without_views() {
+        let storage: MockStorage = run("CREATE TABLE Foo (id INTEGER);");
+        let sql = "SELECT * FROM Foo";
+
+        assert_eq!(block_on(plan(&storage, statement(sql))), Ok(statement(sql)));
+    }
+}
@@ -8,7 +8,8 @@ pub use crate::{
     },
     executor::{
         AggregateError, AlterError, DeleteError, EvaluateError, ExecuteError, FetchError,
-        InsertError, SelectError, SortError, UpdateError, ValidateError,
+        InsertError, SelectError, SequenceError, SortError, TriggerError, UpdateError,
+        ValidateError, WindowError,
     },
     plan::PlanError,
     store::{AlterTableError, IndexError},
@@ -41,12 +42,18 @@ pub enum Error {
     Fetch(#[from] FetchError),
     #[error("select: {0}")]
     Select(#[from] SelectError),
+    #[error("sequence: {0}")]
+    Sequence(#[from] SequenceError),
+    #[error("trigger: {0}")]
+    Trigger(#[from] TriggerError),
     #[error("evaluate: {0}")]
     Evaluate(#[from] EvaluateError),
     #[error("aggregate: {0}")]
     Aggregate(#[from] AggregateError),
     #[error("sort: {0}")]
     Sort(#[from] SortError),
+    #[error("window: {0}")]
+    Window(#[from] WindowError),
     #[error("insert: {0}")]
     Insert(#[from] InsertError),
     #[error("update: {0}")]
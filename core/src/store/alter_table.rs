@@ -1,6 +1,7 @@
 use {
     crate::{
-        ast::ColumnDef,
+        ast::{ColumnDef, Trigger},
+        data::Value,
         result::{Error, Result},
     },
     async_trait::async_trait,
@@ -28,6 +29,18 @@ pub enum AlterTableError {
 
     #[error("Schemaless table does not support ALTER TABLE: {0}")]
     SchemalessTableFound(String),
+
+    #[error("Altering column not found: {0}")]
+    AlteringColumnNotFound(String),
+}
+
+/// Converts a stored value of the column replaced by [`AlterTable::alter_column`] into the new
+/// data type, fails if the value is `NULL` and the column is no longer nullable.
+pub fn convert_column_value(value: &Value, column_def: &ColumnDef) -> Result<Value> {
+    let value = value.cast(&column_def.data_type)?;
+    value.validate_null(column_def.nullable)?;
+
+    Ok(value)
 }
 
 #[async_trait(?Send)]
@@ -65,4 +78,25 @@ pub trait AlterTable {
 
         Err(Error::StorageMsg(msg))
     }
+
+    /// Replaces the definition of the column named `column_def.name`, existing values of the column
+    /// have to be converted by [`convert_column_value`].
+    async fn alter_column(&mut self, _table_name: &str, _column_def: &ColumnDef) -> Result<()> {
+        let msg = "[Storage] AlterTable::alter_column is not supported".to_owned();
+
+        Err(Error::StorageMsg(msg))
+    }
+
+    /// Adds the trigger to the schema of the table, the executor checks the trigger name is not taken.
+    async fn create_trigger(&mut self, _table_name: &str, _trigger: &Trigger) -> Result<()> {
+        let msg = "[Storage] AlterTable::create_trigger is not supported".to_owned();
+
+        Err(Error::StorageMsg(msg))
+    }
+
+    async fn drop_trigger(&mut self, _table_name: &str, _trigger_name: &str) -> Result<()> {
+        let msg = "[Storage] AlterTable::drop_trigger is not supported".to_owned();
+
+        Err(Error::StorageMsg(msg))
+    }
 }
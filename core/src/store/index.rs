@@ -40,12 +40,19 @@ pub enum IndexError {
 
 #[async_trait(?Send)]
 pub trait Index {
+    /// Scans the rows of an index, `prefix` holds the values of the leading expressions of a
+    /// composite index and `cmp_value` compares the next one.
+    ///
+    /// When `upper_cmp_value` is given, `cmp_value` is the lower bound (`>` or `>=`) and
+    /// `upper_cmp_value` the upper bound (`<` or `<=`) of a range.
     async fn scan_indexed_data(
         &self,
         _table_name: &str,
         _index_name: &str,
         _asc: Option<bool>,
+        _prefix: &[Value],
         _cmp_value: Option<(&IndexOperator, Value)>,
+        _upper_cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
         Err(Error::StorageMsg(
             "[Storage] Index::scan_indexed_data is not supported".to_owned(),
@@ -59,7 +66,7 @@ pub trait IndexMut {
         &mut self,
         _table_name: &str,
         _index_name: &str,
-        _column: &OrderByExpr,
+        _columns: &[OrderByExpr],
     ) -> Result<()> {
         let msg = "[Storage] Index::create_index is not supported".to_owned();
 
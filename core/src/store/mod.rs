@@ -3,42 +3,73 @@ mod data_row;
 mod function;
 mod index;
 mod metadata;
+mod scan;
+mod sequence;
 mod transaction;
+mod view;
 
-pub trait GStore: Store + Index + Metadata + CustomFunction {}
-impl<S: Store + Index + Metadata + CustomFunction> GStore for S {}
+pub trait GStore: Store + Index + Metadata + CustomFunction + View + Sequence {}
+impl<S: Store + Index + Metadata + CustomFunction + View + Sequence> GStore for S {}
 
 pub trait GStoreMut:
-    StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut
+    StoreMut
+    + IndexMut
+    + AlterTable
+    + Transaction
+    + CustomFunction
+    + CustomFunctionMut
+    + ViewMut
+    + SequenceMut
 {
 }
-impl<S: StoreMut + IndexMut + AlterTable + Transaction + CustomFunction + CustomFunctionMut>
-    GStoreMut for S
+impl<
+        S: StoreMut
+            + IndexMut
+            + AlterTable
+            + Transaction
+            + CustomFunction
+            + CustomFunctionMut
+            + ViewMut
+            + SequenceMut,
+    > GStoreMut for S
 {
 }
 
 pub use {
-    alter_table::{AlterTable, AlterTableError},
+    alter_table::{convert_column_value, AlterTable, AlterTableError},
     data_row::DataRow,
     function::{CustomFunction, CustomFunctionMut},
     index::{Index, IndexError, IndexMut},
     metadata::{MetaIter, Metadata},
+    scan::{project_row, ScanOperator, ScanPredicate},
+    sequence::{Sequence, SequenceMut},
     transaction::Transaction,
+    view::{View, ViewMut},
 };
 
 use {
     crate::{
+        ast::{ColumnDef, ColumnUniqueOption},
         data::{Key, Schema},
         executor::Referencing,
         result::{Error, Result},
     },
     async_trait::async_trait,
-    futures::stream::Stream,
-    std::pin::Pin,
+    futures::{
+        future,
+        stream::{self, Stream, TryStreamExt},
+    },
+    std::{
+        ops::{Bound, RangeBounds},
+        pin::Pin,
+    },
 };
 
 pub type RowIter<'a> = Pin<Box<dyn Stream<Item = Result<(Key, DataRow)>> + 'a>>;
 
+/// Lower and upper bounds of the primary key scanned by [`Store::scan_data_range`].
+pub type KeyRange = (Bound<Key>, Bound<Key>);
+
 /// By implementing `Store` trait, you can run `SELECT` query.
 #[async_trait(?Send)]
 pub trait Store {
@@ -50,6 +81,98 @@ pub trait Store {
 
     async fn scan_data(&self, table_name: &str) -> Result<RowIter<'_>>;
 
+    /// Scans the rows which satisfy `predicate`, each row keeps only the values of `columns` in
+    /// that order when `columns` is given.
+    ///
+    /// Both are planned from the query, storages which can skip data by themselves override this.
+    /// The default scans every row with [`Store::scan_data`] and filters and projects it here.
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        predicate: Option<&ScanPredicate>,
+        columns: Option<&[String]>,
+    ) -> Result<RowIter<'_>> {
+        let rows = self.scan_data(table_name).await?;
+        if predicate.is_none() && columns.is_none() {
+            return Ok(rows);
+        }
+
+        let labels = self
+            .fetch_schema(table_name)
+            .await?
+            .and_then(|schema| schema.column_defs)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|ColumnDef { name, .. }| name)
+            .collect::<Vec<_>>();
+        let predicate = predicate.cloned();
+        let columns = columns.map(<[String]>::to_vec);
+
+        let rows = rows.try_filter_map(move |(key, row)| {
+            let pass = predicate
+                .as_ref()
+                .map(|predicate| predicate.check(&labels, &row))
+                .unwrap_or(true);
+            if !pass {
+                return future::ready(Ok(None));
+            }
+
+            let row = match &columns {
+                Some(columns) => project_row(&labels, columns, row),
+                None => row,
+            };
+
+            future::ready(Ok(Some((key, row))))
+        });
+
+        Ok(Box::pin(rows))
+    }
+
+    /// Scans the rows whose primary key is within `range`, in the order of the key or in the
+    /// reverse order when `asc` is false.
+    ///
+    /// The planner only uses this for tables keyed by a single primary key column and never
+    /// passes an empty range. The default sorts the rows of [`Store::scan_data`] by the key column,
+    /// storages which keep their rows ordered by the key override this.
+    async fn scan_data_range(
+        &self,
+        table_name: &str,
+        range: KeyRange,
+        asc: bool,
+    ) -> Result<RowIter<'_>> {
+        let position = self
+            .fetch_schema(table_name)
+            .await?
+            .and_then(|schema| schema.column_defs)
+            .and_then(|column_defs| {
+                column_defs.iter().position(|ColumnDef { unique, .. }| {
+                    unique == &Some(ColumnUniqueOption { is_primary: true })
+                })
+            });
+
+        let mut rows = Vec::new();
+        let mut data_rows = self.scan_data(table_name).await?;
+        while let Some((key, row)) = data_rows.try_next().await? {
+            let primary_key = match (position, &row) {
+                (Some(i), DataRow::Vec(values)) if i < values.len() => Key::try_from(&values[i])?,
+                _ => key.clone(),
+            };
+
+            if range.contains(&primary_key) {
+                rows.push((primary_key, (key, row)));
+            }
+        }
+
+        rows.sort_by(|(a, _), (b, _)| a.cmp(b));
+        if !asc {
+            rows.reverse();
+        }
+
+        let rows = rows.into_iter().map(|(_, item)| Ok(item));
+
+        Ok(Box::pin(stream::iter(rows)))
+    }
+
     async fn fetch_referencings(&self, table_name: &str) -> Result<Vec<Referencing>> {
         let schemas = self.fetch_all_schemas().await?;
 
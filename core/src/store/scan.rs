@@ -0,0 +1,204 @@
+use {
+    super::DataRow,
+    crate::data::Value,
+    serde::{Deserialize, Serialize},
+    std::{cmp::Ordering, fmt::Debug, mem::replace},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScanOperator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl ScanOperator {
+    /// Returns the operator which gives the same result when both sides are swapped.
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Eq => Self::Eq,
+            Self::NotEq => Self::NotEq,
+            Self::Lt => Self::Gt,
+            Self::LtEq => Self::GtEq,
+            Self::Gt => Self::Lt,
+            Self::GtEq => Self::LtEq,
+        }
+    }
+}
+
+/// Conditions of the WHERE clause pushed down to [`Store::scan_data_filtered`].
+///
+/// Every comparison is between a column and a constant of a comparable type, a row passes only
+/// when the condition is true, so comparisons against `NULL` never pass.
+///
+/// [`Store::scan_data_filtered`]: super::Store::scan_data_filtered
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ScanPredicate {
+    Compare {
+        column: String,
+        op: ScanOperator,
+        value: Value,
+    },
+    IsNull {
+        column: String,
+        negated: bool,
+    },
+    InList {
+        column: String,
+        list: Vec<Value>,
+        negated: bool,
+    },
+    And(Vec<ScanPredicate>),
+}
+
+impl ScanPredicate {
+    /// Checks the predicate against a row, `labels` are the column names of `DataRow::Vec` rows.
+    pub fn check(&self, labels: &[String], row: &DataRow) -> bool {
+        let get_value = |column: &str| match row {
+            DataRow::Vec(values) => labels
+                .iter()
+                .position(|label| label == column)
+                .and_then(|i| values.get(i)),
+            DataRow::Map(values) => values.get(column),
+        };
+
+        match self {
+            Self::Compare { column, op, value } => {
+                let target = match get_value(column) {
+                    Some(target) if !target.is_null() && !value.is_null() => target,
+                    _ => return false,
+                };
+
+                match op {
+                    ScanOperator::Eq => target.evaluate_eq(value),
+                    ScanOperator::NotEq => !target.evaluate_eq(value),
+                    ScanOperator::Lt => target.evaluate_cmp(value) == Some(Ordering::Less),
+                    ScanOperator::LtEq => matches!(
+                        target.evaluate_cmp(value),
+                        Some(Ordering::Less | Ordering::Equal)
+                    ),
+                    ScanOperator::Gt => target.evaluate_cmp(value) == Some(Ordering::Greater),
+                    ScanOperator::GtEq => matches!(
+                        target.evaluate_cmp(value),
+                        Some(Ordering::Greater | Ordering::Equal)
+                    ),
+                }
+            }
+            Self::IsNull { column, negated } => {
+                let is_null = get_value(column).map(Value::is_null).unwrap_or(true);
+
+                is_null != *negated
+            }
+            Self::InList {
+                column,
+                list,
+                negated,
+            } => match get_value(column) {
+                Some(target) if !target.is_null() => {
+                    list.iter().any(|value| target.evaluate_eq(value)) != *negated
+                }
+                _ => false,
+            },
+            Self::And(predicates) => predicates
+                .iter()
+                .all(|predicate| predicate.check(labels, row)),
+        }
+    }
+}
+
+/// Keeps the values of `columns` in the given order, missing columns are filled with `NULL`.
+pub fn project_row(labels: &[String], columns: &[String], row: DataRow) -> DataRow {
+    match row {
+        DataRow::Vec(mut values) => columns
+            .iter()
+            .map(|column| {
+                labels
+                    .iter()
+                    .position(|label| label == column)
+                    .and_then(|i| values.get_mut(i))
+                    .map(|value| replace(value, Value::Null))
+                    .unwrap_or(Value::Null)
+            })
+            .collect::<Vec<_>>()
+            .into(),
+        DataRow::Map(_) => row,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{project_row, ScanOperator, ScanPredicate},
+        crate::{data::Value, store::DataRow},
+    };
+
+    fn labels() -> Vec<String> {
+        vec!["id".to_owned(), "name".to_owned()]
+    }
+
+    fn row(id: Value, name: &str) -> DataRow {
+        vec![id, Value::Str(name.to_owned())].into()
+    }
+
+    #[test]
+    fn check() {
+        let compare = |op, value| ScanPredicate::Compare {
+            column: "id".to_owned(),
+            op,
+            value,
+        };
+
+        let labels = labels();
+        let row1 = row(Value::I64(1), "a");
+        let null = row(Value::Null, "b");
+
+        assert!(compare(ScanOperator::Eq, Value::I64(1)).check(&labels, &row1));
+        assert!(compare(ScanOperator::Eq, Value::F64(1.0)).check(&labels, &row1));
+        assert!(!compare(ScanOperator::NotEq, Value::I64(1)).check(&labels, &row1));
+        assert!(compare(ScanOperator::Lt, Value::I64(2)).check(&labels, &row1));
+        assert!(compare(ScanOperator::GtEq, Value::I64(1)).check(&labels, &row1));
+        assert!(!compare(ScanOperator::Gt, Value::I64(1)).check(&labels, &row1));
+        assert!(!compare(ScanOperator::NotEq, Value::I64(1)).check(&labels, &null));
+        assert!(!compare(ScanOperator::Eq, Value::Null).check(&labels, &row1));
+
+        let is_null = |negated| ScanPredicate::IsNull {
+            column: "id".to_owned(),
+            negated,
+        };
+        assert!(is_null(false).check(&labels, &null));
+        assert!(is_null(true).check(&labels, &row1));
+
+        let in_list = |negated| ScanPredicate::InList {
+            column: "id".to_owned(),
+            list: vec![Value::I64(1), Value::I64(3)],
+            negated,
+        };
+        assert!(in_list(false).check(&labels, &row1));
+        assert!(!in_list(true).check(&labels, &row1));
+        assert!(!in_list(true).check(&labels, &null));
+
+        let and = ScanPredicate::And(vec![
+            compare(ScanOperator::Gt, Value::I64(0)),
+            ScanPredicate::Compare {
+                column: "name".to_owned(),
+                op: ScanOperator::Eq,
+                value: Value::Str("b".to_owned()),
+            },
+        ]);
+        assert!(!and.check(&labels, &row1));
+        assert!(and.check(&labels, &row(Value::I64(2), "b")));
+    }
+
+    #[test]
+    fn project() {
+        let columns = vec!["name".to_owned(), "missing".to_owned()];
+        let projected = project_row(&labels(), &columns, row(Value::I64(1), "a"));
+
+        let expected = DataRow::Vec(vec![Value::Str("a".to_owned()), Value::Null]);
+
+        assert_eq!(projected, expected);
+    }
+}
@@ -0,0 +1,37 @@
+use {
+    crate::{
+        data::Sequence as StructSequence,
+        result::{Error, Result},
+    },
+    async_trait::async_trait,
+};
+
+/// Storages without sequence support simply have no sequences.
+#[async_trait(?Send)]
+pub trait Sequence {
+    async fn fetch_sequence(&self, _sequence_name: &str) -> Result<Option<StructSequence>> {
+        Ok(None)
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        Ok(Vec::new())
+    }
+}
+
+/// `NEXTVAL` advances a sequence by inserting it again with the new current value,
+/// so the counter follows the transaction of the storage.
+#[async_trait(?Send)]
+pub trait SequenceMut {
+    /// Inserts the sequence, replacing an existing sequence of the same name.
+    async fn insert_sequence(&mut self, _sequence: StructSequence) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+
+    async fn delete_sequence(&mut self, _sequence_name: &str) -> Result<()> {
+        Err(Error::StorageMsg(
+            "[Storage] Sequence is not supported".to_owned(),
+        ))
+    }
+}
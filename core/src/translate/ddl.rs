@@ -3,13 +3,19 @@ use {
         data_type::translate_data_type, expr::translate_expr, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{AlterTableOperation, ColumnDef, ColumnUniqueOption, OperateFunctionArg},
+        ast::{
+            AlterColumnOperation, AlterTableOperation, AstLiteral, ColumnDef, ColumnIdentityOption,
+            ColumnUniqueOption, DataType, Expr, OperateFunctionArg, SequenceOptions, UnaryOperator,
+        },
+        data::BigDecimalExt,
         result::Result,
     },
     sqlparser::ast::{
+        AlterColumnOperation as SqlAlterColumnOperation,
         AlterTableOperation as SqlAlterTableOperation, ColumnDef as SqlColumnDef,
         ColumnOption as SqlColumnOption, ColumnOptionDef as SqlColumnOptionDef,
-        OperateFunctionArg as SqlOperateFunctionArg,
+        DataType as SqlDataType, Expr as SqlExpr, GeneratedAs as SqlGeneratedAs,
+        OperateFunctionArg as SqlOperateFunctionArg, SequenceOptions as SqlSequenceOptions,
     },
 };
 
@@ -42,6 +48,45 @@ pub fn translate_alter_table_operation(
                 table_name: translate_object_name(table_name)?,
             })
         }
+        SqlAlterTableOperation::AlterColumn { column_name, op } => {
+            let operation = match op {
+                SqlAlterColumnOperation::SetDataType {
+                    data_type,
+                    using: None,
+                } => AlterColumnOperation::SetDataType {
+                    data_type: translate_data_type(data_type)?,
+                },
+                SqlAlterColumnOperation::SetDefault { value } => AlterColumnOperation::SetDefault {
+                    default: translate_expr(value)?,
+                },
+                SqlAlterColumnOperation::DropDefault => AlterColumnOperation::DropDefault,
+                SqlAlterColumnOperation::SetNotNull => AlterColumnOperation::SetNotNull,
+                SqlAlterColumnOperation::DropNotNull => AlterColumnOperation::DropNotNull,
+                SqlAlterColumnOperation::AddGenerated {
+                    generated_as:
+                        Some(generated_as @ (SqlGeneratedAs::Always | SqlGeneratedAs::ByDefault)),
+                    sequence_options,
+                } => AlterColumnOperation::AddIdentity {
+                    identity: ColumnIdentityOption {
+                        always: matches!(generated_as, SqlGeneratedAs::Always),
+                        options: translate_sequence_options(
+                            sequence_options.as_deref().unwrap_or_default(),
+                        )?,
+                    },
+                },
+                _ => {
+                    return Err(TranslateError::UnsupportedAlterTableOperation(
+                        sql_alter_table_operation.to_string(),
+                    )
+                    .into())
+                }
+            };
+
+            Ok(AlterTableOperation::AlterColumn {
+                column_name: column_name.value.to_owned(),
+                operation,
+            })
+        }
         _ => Err(TranslateError::UnsupportedAlterTableOperation(
             sql_alter_table_operation.to_string(),
         )
@@ -57,16 +102,30 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
         ..
     } = sql_column_def;
 
-    let (nullable, default, unique, comment) = options.iter().try_fold(
-        (true, None, None, None),
-        |(nullable, default, unique, comment), SqlColumnOptionDef { option, .. }| -> Result<_> {
+    let (data_type, nullable, identity) = match translate_serial_data_type(data_type) {
+        Some(data_type) => {
+            let identity = ColumnIdentityOption {
+                always: false,
+                options: SequenceOptions::default(),
+            };
+
+            (data_type, false, Some(identity))
+        }
+        None => (translate_data_type(data_type)?, true, None),
+    };
+
+    let (nullable, default, unique, check, identity, comment) = options.iter().try_fold(
+        (nullable, None, None, None, identity, None),
+        |(nullable, default, unique, check, identity, comment),
+         SqlColumnOptionDef { option, .. }|
+         -> Result<_> {
             match option {
-                SqlColumnOption::Null => Ok((nullable, default, unique, comment)),
-                SqlColumnOption::NotNull => Ok((false, default, unique, comment)),
+                SqlColumnOption::Null => Ok((nullable, default, unique, check, identity, comment)),
+                SqlColumnOption::NotNull => Ok((false, default, unique, check, identity, comment)),
                 SqlColumnOption::Default(default) => {
                     let default = translate_expr(default).map(Some)?;
 
-                    Ok((nullable, default, unique, comment))
+                    Ok((nullable, default, unique, check, identity, comment))
                 }
                 SqlColumnOption::Unique { is_primary, .. } => {
                     let nullable = if *is_primary { false } else { nullable };
@@ -74,11 +133,37 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
                         is_primary: *is_primary,
                     });
 
-                    Ok((nullable, default, unique, comment))
+                    Ok((nullable, default, unique, check, identity, comment))
                 }
-                SqlColumnOption::Comment(comment) => {
-                    Ok((nullable, default, unique, Some(comment.to_string())))
+                SqlColumnOption::Check(check) => {
+                    let check = translate_expr(check).map(Some)?;
+
+                    Ok((nullable, default, unique, check, identity, comment))
+                }
+                SqlColumnOption::Generated {
+                    generated_as:
+                        generated_as @ (SqlGeneratedAs::Always | SqlGeneratedAs::ByDefault),
+                    sequence_options,
+                    generation_expr: None,
+                    ..
+                } => {
+                    let identity = Some(ColumnIdentityOption {
+                        always: matches!(generated_as, SqlGeneratedAs::Always),
+                        options: translate_sequence_options(
+                            sequence_options.as_deref().unwrap_or_default(),
+                        )?,
+                    });
+
+                    Ok((false, default, unique, check, identity, comment))
                 }
+                SqlColumnOption::Comment(comment) => Ok((
+                    nullable,
+                    default,
+                    unique,
+                    check,
+                    identity,
+                    Some(comment.to_string()),
+                )),
                 _ => Err(TranslateError::UnsupportedColumnOption(option.to_string()).into()),
             }
         },
@@ -86,14 +171,80 @@ pub fn translate_column_def(sql_column_def: &SqlColumnDef) -> Result<ColumnDef>
 
     Ok(ColumnDef {
         name: name.value.to_owned(),
-        data_type: translate_data_type(data_type)?,
+        data_type,
         nullable,
         default,
         unique,
+        check,
+        identity,
         comment,
     })
 }
 
+/// `SERIAL` and `BIGSERIAL` are integer columns generated by default as identity.
+fn translate_serial_data_type(sql_data_type: &SqlDataType) -> Option<DataType> {
+    let SqlDataType::Custom(name, _) = sql_data_type else {
+        return None;
+    };
+
+    match name.0.first()?.value.to_uppercase().as_str() {
+        "SERIAL" | "BIGSERIAL" => Some(DataType::Int),
+        _ => None,
+    }
+}
+
+pub fn translate_sequence_options(
+    sql_sequence_options: &[SqlSequenceOptions],
+) -> Result<SequenceOptions> {
+    let mut options = SequenceOptions::default();
+
+    for sql_sequence_option in sql_sequence_options {
+        match sql_sequence_option {
+            SqlSequenceOptions::IncrementBy(expr, _) => {
+                options.increment = Some(translate_sequence_value(sql_sequence_option, expr)?);
+            }
+            SqlSequenceOptions::StartWith(expr, _) => {
+                options.start = Some(translate_sequence_value(sql_sequence_option, expr)?);
+            }
+            _ => {
+                return Err(TranslateError::UnsupportedSequenceOption(
+                    sql_sequence_option.to_string().trim().to_owned(),
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+fn translate_sequence_value(
+    sql_sequence_option: &SqlSequenceOptions,
+    sql_expr: &SqlExpr,
+) -> Result<i64> {
+    let number = match translate_expr(sql_expr)? {
+        Expr::Literal(AstLiteral::Number(number)) => Some(number),
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr,
+        } => match *expr {
+            Expr::Literal(AstLiteral::Number(number)) => Some(-number),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    number
+        .as_ref()
+        .and_then(BigDecimalExt::to_i64)
+        .ok_or_else(|| {
+            TranslateError::UnsupportedSequenceOption(
+                sql_sequence_option.to_string().trim().to_owned(),
+            )
+            .into()
+        })
+}
+
 pub fn translate_operate_function_arg(arg: &SqlOperateFunctionArg) -> Result<OperateFunctionArg> {
     let name = arg
         .name
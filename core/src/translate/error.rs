@@ -5,11 +5,8 @@ pub enum TranslateError {
     #[error("unimplemented - select on two or more than tables are not supported")]
     TooManyTables,
 
-    #[error("unimplemented - select distinct is not supported")]
-    SelectDistinctNotSupported,
-
-    #[error("unimplemented - composite index is not supported")]
-    CompositeIndexNotSupported,
+    #[error("unimplemented - select distinct on is not supported: DISTINCT ON ({0})")]
+    SelectDistinctOnNotSupported(String),
 
     #[error("unimplemented - join on update not supported")]
     JoinOnUpdateNotSupported,
@@ -51,6 +48,9 @@ pub enum TranslateError {
     #[error("named function arg is not supported")]
     NamedFunctionArgNotSupported,
 
+    #[error("DISTINCT is only supported in aggregate functions: {0}")]
+    DistinctFunctionArgNotSupported(String),
+
     #[error("unnamed function arg is not supported")]
     UnNamedFunctionArgNotSupported,
 
@@ -78,9 +78,6 @@ pub enum TranslateError {
     #[error("SAFE_CAST(..) is not supported")]
     SafeCastNotSupported,
 
-    #[error("unsupported multiple alter table operations, expected: `ALTER TABLE <table> <operation>`, got: `ALTER TABLE <table> <operation>, <operation>, ..`")]
-    UnsupportedMultipleAlterTableOperations,
-
     #[error("unreachable empty alter table operation")]
     UnreachableEmptyAlterTableOperation,
 
@@ -93,6 +90,15 @@ pub enum TranslateError {
     #[error("qualified wildcard is not supported - COUNT({0})")]
     QualifiedWildcardInCountNotSupported(String),
 
+    #[error("unsupported window function: {0}")]
+    UnsupportedWindowFunction(String),
+
+    #[error("named window is not supported: {0}")]
+    NamedWindowNotSupported(String),
+
+    #[error("unsupported window frame: OVER {0}")]
+    UnsupportedWindowFrame(String),
+
     #[error("order by - NULLS (FIRST | LAST) is not supported")]
     OrderByNullsFirstOrLastNotSupported,
 
@@ -105,6 +111,9 @@ pub enum TranslateError {
     #[error("unsupported statement: {0}")]
     UnsupportedStatement(String),
 
+    #[error("EXPLAIN supports SELECT, INSERT, UPDATE and DELETE only: {0}")]
+    UnsupportedExplainStatement(String),
+
     #[error("unsupported expr: {0}")]
     UnsupportedExpr(String),
 
@@ -117,6 +126,9 @@ pub enum TranslateError {
     #[error("unsupported ast literal: {0}")]
     UnsupportedAstLiteral(String),
 
+    #[error("unsupported placeholder: {0}, expected: $1, $2, ... or ?")]
+    UnsupportedPlaceholder(String),
+
     #[error("unreachable unary operator: {0}")]
     UnreachableUnaryOperator(String),
 
@@ -129,6 +141,9 @@ pub enum TranslateError {
     #[error("unsupported query set expr: {0}")]
     UnsupportedQuerySetExpr(String),
 
+    #[error("unsupported set quantifier: {0}")]
+    UnsupportedSetQuantifier(String),
+
     #[error("unsupported query table factor: {0}")]
     UnsupportedQueryTableFactor(String),
 
@@ -141,6 +156,9 @@ pub enum TranslateError {
     #[error("unsupported column option: {0}")]
     UnsupportedColumnOption(String),
 
+    #[error("unsupported sequence option: {0}")]
+    UnsupportedSequenceOption(String),
+
     #[error("unsupported alter table operation: {0}")]
     UnsupportedAlterTableOperation(String),
 
@@ -176,4 +194,22 @@ pub enum TranslateError {
 
     #[error("unsupported constraint: {0}")]
     UnsupportedConstraint(String),
+
+    #[error("multiple primary keys are not supported")]
+    MultiplePrimaryKeyNotSupported,
+
+    #[error("primary key column not found: {0}")]
+    PrimaryKeyColumnNotFound(String),
+
+    #[error("unsupported on insert clause: {0}")]
+    UnsupportedOnInsert(String),
+
+    #[error("unsupported conflict target: {0}")]
+    UnsupportedConflictTarget(String),
+
+    #[error("materialized view is not supported")]
+    MaterializedViewNotSupported,
+
+    #[error("unsupported statement in trigger body: {0}")]
+    UnsupportedTriggerStatement(String),
 }
@@ -17,7 +17,7 @@ use {
     sqlparser::ast::{
         Array, CeilFloorKind as SqlCeilFloorKind, DateTimeField as SqlDateTimeField,
         Expr as SqlExpr, Interval as SqlInterval, OrderByExpr as SqlOrderByExpr,
-        Subscript as SqlSubscript,
+        Subscript as SqlSubscript, Value as SqlValue,
     },
 };
 
@@ -102,6 +102,7 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
         }),
         SqlExpr::Extract { field, expr, .. } => translate_extract(field, expr),
         SqlExpr::Nested(expr) => translate_expr(expr).map(Box::new).map(Expr::Nested),
+        SqlExpr::Value(SqlValue::Placeholder(placeholder)) => translate_placeholder(placeholder),
         SqlExpr::Value(value) => translate_ast_literal(value).map(Expr::Literal),
         SqlExpr::TypedString { data_type, value } => Ok(Expr::TypedString {
             data_type: translate_data_type(data_type)?,
@@ -210,6 +211,15 @@ pub fn translate_expr(sql_expr: &SqlExpr) -> Result<Expr> {
     }
 }
 
+fn translate_placeholder(placeholder: &str) -> Result<Expr> {
+    placeholder
+        .strip_prefix('$')
+        .and_then(|index| index.parse::<usize>().ok())
+        .filter(|index| *index > 0)
+        .map(Expr::Parameter)
+        .ok_or_else(|| TranslateError::UnsupportedPlaceholder(placeholder.to_owned()).into())
+}
+
 pub fn translate_order_by_expr(sql_order_by_expr: &SqlOrderByExpr) -> Result<OrderByExpr> {
     let SqlOrderByExpr {
         expr,
@@ -1,18 +1,20 @@
 use {
     super::{
         ast_literal::{translate_datetime_field, translate_trim_where_field},
-        expr::translate_expr,
+        expr::{translate_expr, translate_order_by_expr},
         translate_data_type, translate_object_name, TranslateError,
     },
     crate::{
-        ast::{Aggregate, CountArgExpr, Expr, Function},
+        ast::{Aggregate, CountArgExpr, Expr, Function, Window, WindowFunction},
         result::Result,
     },
     sqlparser::ast::{
         CastFormat as SqlCastFormat, CastKind as SqlCastKind, DataType as SqlDataType,
-        DateTimeField as SqlDateTimeField, Expr as SqlExpr, Function as SqlFunction,
-        FunctionArg as SqlFunctionArg, FunctionArgExpr as SqlFunctionArgExpr,
-        FunctionArguments as SqlFunctionArguments, TrimWhereField as SqlTrimWhereField,
+        DateTimeField as SqlDateTimeField, DuplicateTreatment as SqlDuplicateTreatment,
+        Expr as SqlExpr, Function as SqlFunction, FunctionArg as SqlFunctionArg,
+        FunctionArgExpr as SqlFunctionArgExpr, FunctionArguments as SqlFunctionArguments,
+        TrimWhereField as SqlTrimWhereField, WindowSpec as SqlWindowSpec,
+        WindowType as SqlWindowType,
     },
 };
 
@@ -191,9 +193,109 @@ pub fn translate_function_arg_exprs(
         .collect::<Result<Vec<_>>>()
 }
 
+fn translate_window(
+    sql_function: &SqlFunction,
+    name: String,
+    function_arg_exprs: Vec<&SqlFunctionArgExpr>,
+    over: &SqlWindowType,
+) -> Result<Expr> {
+    let SqlWindowSpec {
+        window_name,
+        partition_by,
+        order_by,
+        window_frame,
+    } = match over {
+        SqlWindowType::WindowSpec(spec) => spec,
+        SqlWindowType::NamedWindow(window_name) => {
+            return Err(TranslateError::NamedWindowNotSupported(window_name.to_string()).into());
+        }
+    };
+
+    if let Some(window_name) = window_name {
+        return Err(TranslateError::NamedWindowNotSupported(window_name.to_string()).into());
+    } else if window_frame.is_some() {
+        return Err(TranslateError::UnsupportedWindowFrame(over.to_string()).into());
+    }
+
+    let function = match name.as_str() {
+        "ROW_NUMBER" | "RANK" | "DENSE_RANK" | "LAG" | "LEAD"
+            if is_distinct(&sql_function.args) =>
+        {
+            return Err(TranslateError::DistinctFunctionArgNotSupported(name).into());
+        }
+        "ROW_NUMBER" | "RANK" | "DENSE_RANK" => {
+            check_len(name.clone(), function_arg_exprs.len(), 0)?;
+
+            match name.as_str() {
+                "ROW_NUMBER" => WindowFunction::RowNumber,
+                "RANK" => WindowFunction::Rank,
+                _ => WindowFunction::DenseRank,
+            }
+        }
+        "LAG" | "LEAD" => {
+            let args = translate_function_arg_exprs(function_arg_exprs)?;
+            check_len_range(name.clone(), args.len(), 1, 3)?;
+
+            let expr = translate_expr(args[0])?;
+            let offset = args.get(1).map(|arg| translate_expr(arg)).transpose()?;
+            let default = args.get(2).map(|arg| translate_expr(arg)).transpose()?;
+
+            match name.as_str() {
+                "LAG" => WindowFunction::Lag {
+                    expr,
+                    offset,
+                    default,
+                },
+                _ => WindowFunction::Lead {
+                    expr,
+                    offset,
+                    default,
+                },
+            }
+        }
+        _ => {
+            let sql_function = SqlFunction {
+                over: None,
+                ..sql_function.clone()
+            };
+
+            match translate_function(&sql_function)? {
+                Expr::Aggregate(aggregate) => WindowFunction::Aggregate(*aggregate),
+                _ => return Err(TranslateError::UnsupportedWindowFunction(name).into()),
+            }
+        }
+    };
+
+    let partition_by = partition_by
+        .iter()
+        .map(translate_expr)
+        .collect::<Result<Vec<_>>>()?;
+    let order_by = order_by
+        .iter()
+        .map(translate_order_by_expr)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Expr::Window(Box::new(Window {
+        function,
+        partition_by,
+        order_by,
+    })))
+}
+
+fn is_distinct(args: &SqlFunctionArguments) -> bool {
+    matches!(
+        args,
+        SqlFunctionArguments::List(list)
+            if list.duplicate_treatment == Some(SqlDuplicateTreatment::Distinct)
+    )
+}
+
 pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
-    let SqlFunction { name, args, .. } = sql_function;
+    let SqlFunction {
+        name, args, over, ..
+    } = sql_function;
     let name = translate_object_name(name)?.to_uppercase();
+    let distinct = is_distinct(args);
     let args = match args {
         SqlFunctionArguments::None => Vec::new(),
         SqlFunctionArguments::Subquery(_) => {
@@ -212,6 +314,10 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         })
         .collect::<Result<Vec<_>>>()?;
 
+    if let Some(over) = over {
+        return translate_window(sql_function, name, function_arg_exprs, over);
+    }
+
     if name.as_str() == "COUNT" {
         check_len(name, args.len(), 1)?;
 
@@ -226,18 +332,26 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
             SqlFunctionArgExpr::Wildcard => CountArgExpr::Wildcard,
         };
 
-        return Ok(Expr::Aggregate(Box::new(Aggregate::Count(count_arg))));
+        return Ok(Expr::Aggregate(Box::new(Aggregate::Count {
+            expr: count_arg,
+            distinct,
+        })));
     }
 
     let args = translate_function_arg_exprs(function_arg_exprs)?;
 
     match name.as_str() {
-        "SUM" => translate_aggregate_one_arg(Aggregate::Sum, args, name),
-        "MIN" => translate_aggregate_one_arg(Aggregate::Min, args, name),
-        "MAX" => translate_aggregate_one_arg(Aggregate::Max, args, name),
-        "AVG" => translate_aggregate_one_arg(Aggregate::Avg, args, name),
-        "VARIANCE" => translate_aggregate_one_arg(Aggregate::Variance, args, name),
-        "STDEV" => translate_aggregate_one_arg(Aggregate::Stdev, args, name),
+        "SUM" => translate_aggregate_one_arg(|expr| Aggregate::Sum { expr, distinct }, args, name),
+        "MIN" => translate_aggregate_one_arg(|expr| Aggregate::Min { expr, distinct }, args, name),
+        "MAX" => translate_aggregate_one_arg(|expr| Aggregate::Max { expr, distinct }, args, name),
+        "AVG" => translate_aggregate_one_arg(|expr| Aggregate::Avg { expr, distinct }, args, name),
+        "VARIANCE" => {
+            translate_aggregate_one_arg(|expr| Aggregate::Variance { expr, distinct }, args, name)
+        }
+        "STDEV" => {
+            translate_aggregate_one_arg(|expr| Aggregate::Stdev { expr, distinct }, args, name)
+        }
+        _ if distinct => Err(TranslateError::DistinctFunctionArgNotSupported(name).into()),
         "COALESCE" => {
             let exprs = args
                 .into_iter()
@@ -488,6 +602,16 @@ pub fn translate_function(sql_function: &SqlFunction) -> Result<Expr> {
         "ABS" => translate_function_one_arg(Function::Abs, args, name),
         "SIGN" => translate_function_one_arg(Function::Sign, args, name),
         "GENERATE_UUID" => translate_function_zero_arg(Function::GenerateUuid(), args, name),
+        "NEXTVAL" => translate_function_one_arg(Function::Nextval, args, name),
+        "CURRVAL" => translate_function_one_arg(Function::Currval, args, name),
+        "SETVAL" => {
+            check_len(name, args.len(), 2)?;
+
+            let name = translate_expr(args[0])?;
+            let value = translate_expr(args[1])?;
+
+            Ok(Expr::Function(Box::new(Function::Setval { name, value })))
+        }
         "FORMAT" => {
             check_len(name, args.len(), 2)?;
 
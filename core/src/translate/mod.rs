@@ -12,33 +12,89 @@ pub use self::{
     ddl::{translate_column_def, translate_operate_function_arg},
     error::TranslateError,
     expr::{translate_expr, translate_order_by_expr},
-    query::{alias_or_name, translate_query, translate_select_item},
+    query::{alias_or_name, translate_query, translate_select_item, translate_table_with_joins},
 };
 
 use {
     crate::{
-        ast::{Assignment, ForeignKey, ReferentialAction, Statement, Variable},
+        ast::{
+            Assignment, Check, ColumnDef, ColumnUniqueOption, ForeignKey, OnConflict,
+            OnConflictAction, ReferentialAction, SelectItem, Statement, Trigger, Variable,
+        },
+        parse_sql::ParsedStatement,
         result::Result,
     },
-    ddl::translate_alter_table_operation,
+    ddl::{translate_alter_table_operation, translate_sequence_options},
     sqlparser::ast::{
         Assignment as SqlAssignment, AssignmentTarget as SqlAssignmentTarget,
-        CommentDef as SqlCommentDef, CreateFunctionBody as SqlCreateFunctionBody,
-        CreateIndex as SqlCreateIndex, CreateTable as SqlCreateTable, Delete as SqlDelete,
-        FromTable as SqlFromTable, Ident as SqlIdent, Insert as SqlInsert,
-        ObjectName as SqlObjectName, ObjectType as SqlObjectType,
-        ReferentialAction as SqlReferentialAction, Statement as SqlStatement,
-        TableConstraint as SqlTableConstraint, TableFactor, TableWithJoins,
+        CommentDef as SqlCommentDef, ConflictTarget as SqlConflictTarget,
+        CreateFunctionBody as SqlCreateFunctionBody, CreateIndex as SqlCreateIndex,
+        CreateTable as SqlCreateTable, Delete as SqlDelete, FromTable as SqlFromTable,
+        Ident as SqlIdent, Insert as SqlInsert, ObjectName as SqlObjectName,
+        ObjectType as SqlObjectType, OnConflict as SqlOnConflict,
+        OnConflictAction as SqlOnConflictAction, OnInsert as SqlOnInsert,
+        ReferentialAction as SqlReferentialAction, SelectItem as SqlSelectItem,
+        Statement as SqlStatement, TableConstraint as SqlTableConstraint, TableFactor,
+        TableWithJoins,
     },
 };
 
-pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
+pub fn translate(parsed: &ParsedStatement) -> Result<Statement> {
+    match parsed {
+        ParsedStatement::Statement(sql_statement) => translate_statement(sql_statement),
+        ParsedStatement::CreateTrigger {
+            name,
+            table_name,
+            timing,
+            events,
+            condition,
+            body,
+        } => {
+            let body = body
+                .iter()
+                .map(|sql_statement| match sql_statement {
+                    SqlStatement::Insert(_)
+                    | SqlStatement::Update { .. }
+                    | SqlStatement::Delete(_) => translate_statement(sql_statement),
+                    _ => Err(TranslateError::UnsupportedTriggerStatement(
+                        sql_statement.to_string(),
+                    )
+                    .into()),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Statement::CreateTrigger {
+                table_name: table_name.to_owned(),
+                trigger: Trigger {
+                    name: name.to_owned(),
+                    timing: *timing,
+                    events: events.to_owned(),
+                    condition: condition.as_ref().map(translate_expr).transpose()?,
+                    body,
+                },
+            })
+        }
+        ParsedStatement::DropTrigger {
+            if_exists,
+            name,
+            table_name,
+        } => Ok(Statement::DropTrigger {
+            if_exists: *if_exists,
+            name: name.to_owned(),
+            table_name: table_name.to_owned(),
+        }),
+    }
+}
+
+fn translate_statement(sql_statement: &SqlStatement) -> Result<Statement> {
     match sql_statement {
         SqlStatement::Query(query) => translate_query(query).map(Statement::Query),
         SqlStatement::Insert(SqlInsert {
             table_name,
             columns,
             source,
+            on,
+            returning,
             ..
         }) => {
             let table_name = translate_object_name(table_name)?;
@@ -49,17 +105,23 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                     TranslateError::DefaultValuesOnInsertNotSupported(table_name.clone()).into()
                 })
                 .and_then(translate_query)?;
+            let on_conflict = on.as_ref().map(translate_on_insert).transpose()?;
+            let returning = translate_returning(returning)?;
 
             Ok(Statement::Insert {
                 table_name,
                 columns,
                 source,
+                on_conflict,
+                returning,
             })
         }
         SqlStatement::Update {
             table,
             assignments,
+            from,
             selection,
+            returning,
             ..
         } => Ok(Statement::Update {
             table_name: translate_table_with_join(table)?,
@@ -67,10 +129,16 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .iter()
                 .map(translate_assignment)
                 .collect::<Result<_>>()?,
+            from: from.as_ref().map(translate_table_with_joins).transpose()?,
             selection: selection.as_ref().map(translate_expr).transpose()?,
+            returning: translate_returning(returning)?,
         }),
         SqlStatement::Delete(SqlDelete {
-            from, selection, ..
+            from,
+            using,
+            selection,
+            returning,
+            ..
         }) => {
             let from = match from {
                 SqlFromTable::WithFromKeyword(from) => from,
@@ -84,9 +152,17 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .next()
                 .ok_or(TranslateError::UnreachableEmptyTable)??;
 
+            let using = match using.as_deref() {
+                Some([using]) => Some(translate_table_with_joins(using)?),
+                Some([]) | None => None,
+                Some(_) => return Err(TranslateError::TooManyTables.into()),
+            };
+
             Ok(Statement::Delete {
                 table_name,
+                using,
                 selection: selection.as_ref().map(translate_expr).transpose()?,
+                returning: translate_returning(returning)?,
             })
         }
         SqlStatement::CreateTable(SqlCreateTable {
@@ -99,24 +175,43 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             comment,
             ..
         }) => {
-            let columns = columns
+            let mut columns = columns
                 .iter()
                 .map(translate_column_def)
                 .collect::<Result<Vec<_>>>()?;
 
+            let (primary_keys, constraints): (Vec<_>, Vec<_>) =
+                constraints.iter().partition(|constraint| {
+                    matches!(constraint, SqlTableConstraint::PrimaryKey { .. })
+                });
+            let (checks, foreign_keys): (Vec<_>, Vec<_>) = constraints
+                .into_iter()
+                .partition(|constraint| matches!(constraint, SqlTableConstraint::Check { .. }));
+
+            let mut primary_key = Vec::new();
+            for constraint in primary_keys {
+                primary_key = translate_primary_key(&mut columns, constraint)?;
+            }
+
             let columns = (!columns.is_empty()).then_some(columns);
 
             let name = translate_object_name(name)?;
 
-            let foreign_keys = constraints
-                .iter()
+            let foreign_keys = foreign_keys
+                .into_iter()
                 .map(translate_foreign_key)
                 .collect::<Result<Vec<_>>>()?;
 
+            let checks = checks
+                .into_iter()
+                .map(translate_check)
+                .collect::<Result<Vec<_>>>()?;
+
             Ok(Statement::CreateTable {
                 if_not_exists: *if_not_exists,
                 name,
                 columns,
+                primary_key,
                 source: match query {
                     Some(v) => Some(translate_query(v).map(Box::new)?),
                     None => None,
@@ -125,6 +220,7 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                     .as_ref()
                     .map(|table_engine| table_engine.name.to_owned()),
                 foreign_keys,
+                checks,
                 comment: comment.as_ref().map(|comment| match comment {
                     SqlCommentDef::WithEq(comment) => comment.to_owned(),
                     SqlCommentDef::WithoutEq(comment) => comment.to_owned(),
@@ -134,18 +230,16 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
         SqlStatement::AlterTable {
             name, operations, ..
         } => {
-            if operations.len() > 1 {
-                return Err(TranslateError::UnsupportedMultipleAlterTableOperations.into());
+            if operations.is_empty() {
+                return Err(TranslateError::UnreachableEmptyAlterTableOperation.into());
             }
 
-            let operation = operations
-                .iter()
-                .next()
-                .ok_or(TranslateError::UnreachableEmptyAlterTableOperation)?;
-
             Ok(Statement::AlterTable {
                 name: translate_object_name(name)?,
-                operation: translate_alter_table_operation(operation)?,
+                operations: operations
+                    .iter()
+                    .map(translate_alter_table_operation)
+                    .collect::<Result<_>>()?,
             })
         }
         SqlStatement::Drop {
@@ -162,6 +256,62 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
                 .collect::<Result<Vec<_>>>()?,
             cascade: *cascade,
         }),
+        SqlStatement::Drop {
+            object_type: SqlObjectType::View,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropView {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
+        }),
+        SqlStatement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            ..
+        } => {
+            if *materialized {
+                return Err(TranslateError::MaterializedViewNotSupported.into());
+            }
+
+            Ok(Statement::CreateView {
+                or_replace: *or_replace,
+                name: translate_object_name(name)?,
+                columns: columns
+                    .iter()
+                    .map(|column| column.name.value.to_owned())
+                    .collect(),
+                query: translate_query(query).map(Box::new)?,
+            })
+        }
+        SqlStatement::CreateSequence {
+            if_not_exists,
+            name,
+            sequence_options,
+            ..
+        } => Ok(Statement::CreateSequence {
+            if_not_exists: *if_not_exists,
+            name: translate_object_name(name)?,
+            options: translate_sequence_options(sequence_options)?,
+        }),
+        SqlStatement::Drop {
+            object_type: SqlObjectType::Sequence,
+            if_exists,
+            names,
+            ..
+        } => Ok(Statement::DropSequence {
+            if_exists: *if_exists,
+            names: names
+                .iter()
+                .map(translate_object_name)
+                .collect::<Result<Vec<_>>>()?,
+        }),
         SqlStatement::DropFunction {
             if_exists,
             func_desc,
@@ -179,10 +329,6 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             columns,
             ..
         }) => {
-            if columns.len() > 1 {
-                return Err(TranslateError::CompositeIndexNotSupported.into());
-            }
-
             let Some(name) = name else {
                 return Err(TranslateError::UnsupportedUnnamedIndex.into());
             };
@@ -196,7 +342,10 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
             Ok(Statement::CreateIndex {
                 name,
                 table_name: translate_object_name(table_name)?,
-                column: translate_order_by_expr(&columns[0])?,
+                columns: columns
+                    .iter()
+                    .map(translate_order_by_expr)
+                    .collect::<Result<Vec<_>>>()?,
             })
         }
         SqlStatement::Drop {
@@ -222,6 +371,21 @@ pub fn translate(sql_statement: &SqlStatement) -> Result<Statement> {
 
             Ok(Statement::DropIndex { name, table_name })
         }
+        SqlStatement::Explain {
+            analyze,
+            statement,
+            format: None,
+            ..
+        } => match statement.as_ref() {
+            SqlStatement::Query(_)
+            | SqlStatement::Insert(_)
+            | SqlStatement::Update { .. }
+            | SqlStatement::Delete(_) => Ok(Statement::Explain {
+                analyze: *analyze,
+                statement: Box::new(translate_statement(statement)?),
+            }),
+            _ => Err(TranslateError::UnsupportedExplainStatement(statement.to_string()).into()),
+        },
         SqlStatement::StartTransaction { .. } => Ok(Statement::StartTransaction),
         SqlStatement::Commit { .. } => Ok(Statement::Commit),
         SqlStatement::Rollback { .. } => Ok(Statement::Rollback),
@@ -343,19 +507,65 @@ pub fn translate_idents(idents: &[SqlIdent]) -> Vec<String> {
     idents.iter().map(|v| v.value.to_owned()).collect()
 }
 
-pub fn translate_referential_action(
-    action: &Option<SqlReferentialAction>,
-) -> Result<ReferentialAction> {
+pub fn translate_referential_action(action: &Option<SqlReferentialAction>) -> ReferentialAction {
     use SqlReferentialAction::*;
 
-    let action = action.unwrap_or(NoAction);
-
-    match action {
-        NoAction | Restrict => Ok(ReferentialAction::NoAction),
-        _ => Err(TranslateError::UnsupportedConstraint(action.to_string()).into()),
+    match action.unwrap_or(NoAction) {
+        NoAction => ReferentialAction::NoAction,
+        Restrict => ReferentialAction::Restrict,
+        Cascade => ReferentialAction::Cascade,
+        SetNull => ReferentialAction::SetNull,
+        SetDefault => ReferentialAction::SetDefault,
     }
 }
 
+fn translate_returning(returning: &Option<Vec<SqlSelectItem>>) -> Result<Vec<SelectItem>> {
+    returning
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(translate_select_item)
+        .collect()
+}
+
+fn translate_on_insert(sql_on_insert: &SqlOnInsert) -> Result<OnConflict> {
+    let SqlOnInsert::OnConflict(SqlOnConflict {
+        conflict_target,
+        action,
+    }) = sql_on_insert
+    else {
+        return Err(TranslateError::UnsupportedOnInsert(sql_on_insert.to_string()).into());
+    };
+
+    let columns = match conflict_target {
+        Some(SqlConflictTarget::Columns(columns)) => translate_idents(columns),
+        Some(conflict_target) => {
+            return Err(
+                TranslateError::UnsupportedConflictTarget(conflict_target.to_string()).into(),
+            );
+        }
+        None => Vec::new(),
+    };
+
+    let action = match action {
+        SqlOnConflictAction::DoNothing => OnConflictAction::DoNothing,
+        SqlOnConflictAction::DoUpdate(do_update) => OnConflictAction::DoUpdate {
+            assignments: do_update
+                .assignments
+                .iter()
+                .map(translate_assignment)
+                .collect::<Result<_>>()?,
+            selection: do_update
+                .selection
+                .as_ref()
+                .map(translate_expr)
+                .transpose()?,
+        },
+    };
+
+    Ok(OnConflict { columns, action })
+}
+
 pub fn translate_foreign_key(table_constraint: &SqlTableConstraint) -> Result<ForeignKey> {
     match table_constraint {
         SqlTableConstraint::ForeignKey {
@@ -395,17 +605,62 @@ pub fn translate_foreign_key(table_constraint: &SqlTableConstraint) -> Result<Fo
                 referencing_column_name,
                 referenced_table_name,
                 referenced_column_name,
-                on_delete: translate_referential_action(on_delete)?,
-                on_update: translate_referential_action(on_update)?,
+                on_delete: translate_referential_action(on_delete),
+                on_update: translate_referential_action(on_update),
             })
         }
         _ => Err(TranslateError::UnsupportedConstraint(table_constraint.to_string()).into()),
     }
 }
 
+pub fn translate_check(table_constraint: &SqlTableConstraint) -> Result<Check> {
+    let SqlTableConstraint::Check { name, expr } = table_constraint else {
+        return Err(TranslateError::UnsupportedConstraint(table_constraint.to_string()).into());
+    };
+
+    Ok(Check {
+        name: name.as_ref().map(|name| name.value.to_owned()),
+        expr: translate_expr(expr)?,
+    })
+}
+
+/// Marks the columns of a table-level `PRIMARY KEY (..)` constraint as primary key columns
+/// and returns them in the order of the key.
+fn translate_primary_key(
+    column_defs: &mut [ColumnDef],
+    table_constraint: &SqlTableConstraint,
+) -> Result<Vec<String>> {
+    let SqlTableConstraint::PrimaryKey { columns, .. } = table_constraint else {
+        return Err(TranslateError::UnsupportedConstraint(table_constraint.to_string()).into());
+    };
+
+    if column_defs
+        .iter()
+        .any(|ColumnDef { unique, .. }| unique == &Some(ColumnUniqueOption { is_primary: true }))
+    {
+        return Err(TranslateError::MultiplePrimaryKeyNotSupported.into());
+    }
+
+    let primary_key = translate_idents(columns);
+    for column_name in &primary_key {
+        let column_def = column_defs
+            .iter_mut()
+            .find(|ColumnDef { name, .. }| name == column_name)
+            .ok_or_else(|| TranslateError::PrimaryKeyColumnNotFound(column_name.to_owned()))?;
+
+        column_def.nullable = false;
+        column_def.unique = Some(ColumnUniqueOption { is_primary: true });
+    }
+
+    Ok(primary_key)
+}
+
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::parse_sql::parse};
+    use {
+        super::*,
+        crate::{ast::ToSql, parse_sql::parse},
+    };
 
     #[test]
     fn statement() {
@@ -428,4 +683,191 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn update_from_delete_using() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual = translate_sql(
+            "UPDATE Orders SET status = s.status FROM Staging AS s WHERE Orders.id = s.id",
+        )
+        .map(|statement| statement.to_sql());
+        let expected = Ok(
+            r#"UPDATE "Orders" SET "status" = "s"."status" FROM "Staging" AS "s" WHERE "Orders"."id" = "s"."id";"#
+                .to_owned(),
+        );
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql(
+            "DELETE FROM Orders USING Staging s JOIN Item i ON s.item_id = i.id WHERE Orders.id = s.id",
+        )
+        .map(|statement| statement.to_sql());
+        let expected = Ok(
+            r#"DELETE FROM "Orders" USING "Staging" AS "s" INNER JOIN "Item" AS "i" ON "s"."item_id" = "i"."id" WHERE "Orders"."id" = "s"."id";"#
+                .to_owned(),
+        );
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("DELETE FROM Orders USING Staging, Item");
+        let expected = Err(TranslateError::TooManyTables.into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn primary_key_constraint() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual = translate_sql("CREATE TABLE Foo (a INT, b TEXT, c INT, PRIMARY KEY (a, b))")
+            .map(|statement| statement.to_sql());
+        let expected = Ok(
+            r#"CREATE TABLE "Foo" ("a" INT NOT NULL, "b" TEXT NOT NULL, "c" INT NULL, PRIMARY KEY ("a", "b"));"#
+                .to_owned(),
+        );
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("CREATE TABLE Foo (a INT, b TEXT, PRIMARY KEY (b, a))")
+            .map(|statement| statement.to_sql());
+        let expected = Ok(
+            r#"CREATE TABLE "Foo" ("a" INT NOT NULL, "b" TEXT NOT NULL, PRIMARY KEY ("b", "a"));"#
+                .to_owned(),
+        );
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("CREATE TABLE Foo (a INT PRIMARY KEY, b INT, PRIMARY KEY (b))");
+        let expected = Err(TranslateError::MultiplePrimaryKeyNotSupported.into());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("CREATE TABLE Foo (a INT, PRIMARY KEY (a, b))");
+        let expected = Err(TranslateError::PrimaryKeyColumnNotFound("b".to_owned()).into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn view() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual = translate_sql("CREATE OR REPLACE VIEW Foo (a, b) AS SELECT id, name FROM Bar")
+            .map(|statement| statement.to_sql());
+        let expected = Ok(
+            r#"CREATE OR REPLACE VIEW "Foo" ("a", "b") AS SELECT "id", "name" FROM "Bar";"#
+                .to_owned(),
+        );
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("CREATE MATERIALIZED VIEW Foo AS SELECT * FROM Bar");
+        let expected = Err(TranslateError::MaterializedViewNotSupported.into());
+        assert_eq!(actual, expected);
+
+        let actual =
+            translate_sql("DROP VIEW IF EXISTS Foo, Bar").map(|statement| statement.to_sql());
+        let expected = Ok(r#"DROP VIEW IF EXISTS "Foo", "Bar";"#.to_owned());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sequence() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual =
+            translate_sql("CREATE SEQUENCE IF NOT EXISTS Foo INCREMENT BY -2 START WITH 10")
+                .map(|statement| statement.to_sql());
+        let expected =
+            Ok(r#"CREATE SEQUENCE IF NOT EXISTS "Foo" INCREMENT BY -2 START WITH 10;"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("CREATE SEQUENCE Foo MAXVALUE 100");
+        let expected =
+            Err(TranslateError::UnsupportedSequenceOption("MAXVALUE 100".to_owned()).into());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("CREATE SEQUENCE Foo START WITH 1.5");
+        let expected =
+            Err(TranslateError::UnsupportedSequenceOption("START WITH 1.5".to_owned()).into());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql(
+            "CREATE TABLE Foo (a SERIAL, b INT GENERATED ALWAYS AS IDENTITY (START WITH 5))",
+        )
+        .map(|statement| statement.to_sql());
+        let expected = Ok(r#"CREATE TABLE "Foo" ("a" INT NOT NULL GENERATED BY DEFAULT AS IDENTITY, "b" INT NOT NULL GENERATED ALWAYS AS IDENTITY (START WITH 5));"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual =
+            translate_sql("DROP SEQUENCE IF EXISTS Foo, Bar").map(|statement| statement.to_sql());
+        let expected = Ok(r#"DROP SEQUENCE IF EXISTS "Foo", "Bar";"#.to_owned());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn placeholder() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual = translate_sql("DELETE FROM Foo WHERE id = $2 AND name = $1")
+            .map(|statement| statement.to_sql());
+        let expected = Ok(r#"DELETE FROM "Foo" WHERE "id" = $2 AND "name" = $1;"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("DELETE FROM Foo WHERE id = ? AND name = '?' AND num < ?")
+            .map(|statement| statement.to_sql());
+        let expected =
+            Ok(r#"DELETE FROM "Foo" WHERE "id" = $1 AND "name" = '?' AND "num" < $2;"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("DELETE FROM Foo WHERE id = $0");
+        let expected = Err(TranslateError::UnsupportedPlaceholder("$0".to_owned()).into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn explain() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual = translate_sql("EXPLAIN SELECT id FROM Foo");
+        let expected = translate_sql("SELECT id FROM Foo").map(|statement| Statement::Explain {
+            analyze: false,
+            statement: Box::new(statement),
+        });
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("EXPLAIN ANALYZE DELETE FROM Foo").map(|s| s.to_sql());
+        let expected = Ok(r#"EXPLAIN ANALYZE DELETE FROM "Foo";"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("EXPLAIN DROP TABLE Foo");
+        let expected =
+            Err(TranslateError::UnsupportedExplainStatement("DROP TABLE Foo".to_owned()).into());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trigger() {
+        let translate_sql = |sql| parse(sql).and_then(|parsed| translate(&parsed[0]));
+
+        let actual = translate_sql(
+            "CREATE TRIGGER Audit AFTER INSERT OR DELETE ON Foo FOR EACH ROW
+            WHEN (NEW.id > 1) BEGIN INSERT INTO Log VALUES (NEW.id); DELETE FROM Bar; END",
+        )
+        .map(|statement| statement.to_sql());
+        let expected = Ok(r#"CREATE TRIGGER "Audit" AFTER INSERT OR DELETE ON "Foo" FOR EACH ROW WHEN ("NEW"."id" > 1) BEGIN INSERT INTO "Log" VALUES ("NEW"."id"); DELETE FROM "Bar"; END;"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql(
+            "CREATE TRIGGER Audit BEFORE UPDATE ON Foo FOR EACH ROW UPDATE Bar SET id = OLD.id",
+        )
+        .map(|statement| statement.to_sql());
+        let expected = Ok(r#"CREATE TRIGGER "Audit" BEFORE UPDATE ON "Foo" FOR EACH ROW BEGIN UPDATE "Bar" SET "id" = "OLD"."id"; END;"#.to_owned());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql(
+            "CREATE TRIGGER Audit AFTER INSERT ON Foo FOR EACH ROW BEGIN DROP TABLE Bar; END",
+        );
+        let expected =
+            Err(TranslateError::UnsupportedTriggerStatement("DROP TABLE Bar".to_owned()).into());
+        assert_eq!(actual, expected);
+
+        let actual = translate_sql("DROP TRIGGER IF EXISTS Audit ON Foo")
+            .map(|statement| statement.to_sql());
+        let expected = Ok(r#"DROP TRIGGER IF EXISTS "Audit" ON "Foo";"#.to_owned());
+        assert_eq!(actual, expected);
+    }
 }
@@ -5,22 +5,27 @@ use {
     },
     crate::{
         ast::{
-            AstLiteral, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator, Query,
-            Select, SelectItem, SetExpr, TableAlias, TableFactor, TableWithJoins, Values,
+            AstLiteral, Cte, Dictionary, Expr, Join, JoinConstraint, JoinExecutor, JoinOperator,
+            Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+            TableWithJoins, Values, With,
         },
         result::Result,
     },
+    itertools::Itertools,
     sqlparser::ast::{
-        Expr as SqlExpr, FunctionArg as SqlFunctionArg, GroupByExpr as SqlGroupByExpr,
-        Join as SqlJoin, JoinConstraint as SqlJoinConstraint, JoinOperator as SqlJoinOperator,
-        Query as SqlQuery, Select as SqlSelect, SelectItem as SqlSelectItem, SetExpr as SqlSetExpr,
-        TableAlias as SqlTableAlias, TableFactor as SqlTableFactor,
-        TableFunctionArgs as SqlTableFunctionArgs, TableWithJoins as SqlTableWithJoins,
+        Cte as SqlCte, Distinct as SqlDistinct, Expr as SqlExpr, FunctionArg as SqlFunctionArg,
+        GroupByExpr as SqlGroupByExpr, Join as SqlJoin, JoinConstraint as SqlJoinConstraint,
+        JoinOperator as SqlJoinOperator, Query as SqlQuery, Select as SqlSelect,
+        SelectItem as SqlSelectItem, SetExpr as SqlSetExpr, SetOperator as SqlSetOperator,
+        SetQuantifier as SqlSetQuantifier, TableAlias as SqlTableAlias,
+        TableFactor as SqlTableFactor, TableFunctionArgs as SqlTableFunctionArgs,
+        TableWithJoins as SqlTableWithJoins, With as SqlWith,
     },
 };
 
 pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
     let SqlQuery {
+        with,
         body,
         order_by,
         limit,
@@ -28,6 +33,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         ..
     } = sql_query;
 
+    let with = with.as_ref().map(translate_with).transpose()?;
     let body = translate_set_expr(body)?;
     let order_by = order_by
         .iter()
@@ -40,6 +46,7 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
         .transpose()?;
 
     Ok(Query {
+        with,
         body,
         order_by,
         limit,
@@ -47,6 +54,31 @@ pub fn translate_query(sql_query: &SqlQuery) -> Result<Query> {
     })
 }
 
+fn translate_with(sql_with: &SqlWith) -> Result<With> {
+    let SqlWith {
+        recursive,
+        cte_tables,
+    } = sql_with;
+
+    let cte_tables = cte_tables
+        .iter()
+        .map(|SqlCte { alias, query, .. }| {
+            let SqlTableAlias { name, columns } = alias;
+            let alias = TableAlias {
+                name: name.value.to_owned(),
+                columns: translate_idents(columns),
+            };
+
+            translate_query(query).map(|query| Cte { alias, query })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(With {
+        recursive: *recursive,
+        cte_tables,
+    })
+}
+
 fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
     match sql_set_expr {
         SqlSetExpr::Select(select) => translate_select(select).map(Box::new).map(SetExpr::Select),
@@ -56,6 +88,52 @@ fn translate_set_expr(sql_set_expr: &SqlSetExpr) -> Result<SetExpr> {
             .collect::<Result<_>>()
             .map(Values)
             .map(SetExpr::Values),
+        SqlSetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let op = match op {
+                SqlSetOperator::Union => SetOperator::Union,
+                SqlSetOperator::Except => SetOperator::Except,
+                SqlSetOperator::Intersect => SetOperator::Intersect,
+            };
+            let all = match set_quantifier {
+                SqlSetQuantifier::All => true,
+                SqlSetQuantifier::Distinct | SqlSetQuantifier::None => false,
+                _ => {
+                    return Err(TranslateError::UnsupportedSetQuantifier(
+                        set_quantifier.to_string(),
+                    )
+                    .into())
+                }
+            };
+
+            Ok(SetExpr::SetOperation {
+                op,
+                all,
+                left: translate_set_expr(left).map(Box::new)?,
+                right: translate_set_expr(right).map(Box::new)?,
+            })
+        }
+        SqlSetExpr::Query(query) => {
+            let SqlQuery {
+                with: None,
+                body,
+                order_by: None,
+                limit: None,
+                offset: None,
+                ..
+            } = query.as_ref()
+            else {
+                return Err(
+                    TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into(),
+                );
+            };
+
+            translate_set_expr(body)
+        }
         _ => Err(TranslateError::UnsupportedQuerySetExpr(sql_set_expr.to_string()).into()),
     }
 }
@@ -75,9 +153,15 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
         return Err(TranslateError::TooManyTables.into());
     }
 
-    if distinct.is_some() {
-        return Err(TranslateError::SelectDistinctNotSupported.into());
-    }
+    let distinct = match distinct {
+        Some(SqlDistinct::Distinct) => true,
+        Some(SqlDistinct::On(exprs)) => {
+            let exprs = exprs.iter().map(ToString::to_string).join(", ");
+
+            return Err(TranslateError::SelectDistinctOnNotSupported(exprs).into());
+        }
+        None => false,
+    };
 
     let from = match from.first() {
         Some(sql_table_with_joins) => translate_table_with_joins(sql_table_with_joins)?,
@@ -101,6 +185,7 @@ fn translate_select(sql_select: &SqlSelect) -> Result<Select> {
     };
 
     Ok(Select {
+        distinct,
         projection: projection
             .iter()
             .map(translate_select_item)
@@ -141,7 +226,9 @@ pub fn translate_select_item(sql_select_item: &SqlSelectItem) -> Result<SelectIt
     }
 }
 
-fn translate_table_with_joins(sql_table_with_joins: &SqlTableWithJoins) -> Result<TableWithJoins> {
+pub fn translate_table_with_joins(
+    sql_table_with_joins: &SqlTableWithJoins,
+) -> Result<TableWithJoins> {
     let SqlTableWithJoins { relation, joins } = sql_table_with_joins;
 
     Ok(TableWithJoins {
@@ -265,6 +352,12 @@ fn translate_join(sql_join: &SqlJoin) -> Result<Join> {
         SqlJoinOperator::LeftOuter(sql_join_constraint) => {
             translate_constraint(sql_join_constraint).map(JoinOperator::LeftOuter)
         }
+        SqlJoinOperator::RightOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::RightOuter)
+        }
+        SqlJoinOperator::FullOuter(sql_join_constraint) => {
+            translate_constraint(sql_join_constraint).map(JoinOperator::FullOuter)
+        }
         _ => {
             Err(TranslateError::UnsupportedJoinOperator(format!("{:?}", sql_join_operator)).into())
         }
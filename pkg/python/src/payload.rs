@@ -102,9 +102,19 @@ fn convert_payload(payload: Payload) -> Json {
         Payload::AlterTable => json!({ "type": "ALTER TABLE" }),
         Payload::CreateIndex => json!({ "type": "CREATE INDEX" }),
         Payload::DropIndex => json!({ "type": "DROP INDEX" }),
+        Payload::CreateView => json!({ "type": "CREATE VIEW" }),
+        Payload::DropView => json!({ "type": "DROP VIEW" }),
+        Payload::CreateSequence => json!({ "type": "CREATE SEQUENCE" }),
+        Payload::DropSequence => json!({ "type": "DROP SEQUENCE" }),
+        Payload::CreateTrigger => json!({ "type": "CREATE TRIGGER" }),
+        Payload::DropTrigger => json!({ "type": "DROP TRIGGER" }),
         Payload::StartTransaction => json!({ "type": "BEGIN" }),
         Payload::Commit => json!({ "type": "COMMIT" }),
         Payload::Rollback => json!({ "type": "ROLLBACK" }),
+        Payload::Explain(plan) => json!({
+            "type": "EXPLAIN",
+            "plan": plan
+        }),
         Payload::ShowVariable(PayloadVariable::Version(version)) => {
             json!({
                 "type": "SHOW VERSION",
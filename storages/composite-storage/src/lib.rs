@@ -21,6 +21,7 @@ impl<T: GStore + GStoreMut> IStorage for T {}
 pub struct CompositeStorage {
     pub storages: HashMap<String, Box<dyn IStorage>>,
     pub default_engine: Option<String>,
+    transaction: Option<transaction::Participants>,
 }
 
 impl CompositeStorage {
@@ -92,3 +93,7 @@ impl gluesql_core::store::IndexMut for CompositeStorage {}
 impl Metadata for CompositeStorage {}
 impl gluesql_core::store::CustomFunction for CompositeStorage {}
 impl gluesql_core::store::CustomFunctionMut for CompositeStorage {}
+impl gluesql_core::store::View for CompositeStorage {}
+impl gluesql_core::store::ViewMut for CompositeStorage {}
+impl gluesql_core::store::Sequence for CompositeStorage {}
+impl gluesql_core::store::SequenceMut for CompositeStorage {}
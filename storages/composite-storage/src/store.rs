@@ -5,7 +5,7 @@ use {
     gluesql_core::{
         data::{Key, Schema},
         error::Result,
-        store::{DataRow, RowIter, Store},
+        store::{DataRow, KeyRange, RowIter, ScanPredicate, Store},
     },
 };
 
@@ -49,4 +49,28 @@ impl Store for CompositeStorage {
             .scan_data(table_name)
             .await
     }
+
+    async fn scan_data_filtered(
+        &self,
+        table_name: &str,
+        predicate: Option<&ScanPredicate>,
+        columns: Option<&[String]>,
+    ) -> Result<RowIter<'_>> {
+        self.fetch_storage(table_name)
+            .await?
+            .scan_data_filtered(table_name, predicate, columns)
+            .await
+    }
+
+    async fn scan_data_range(
+        &self,
+        table_name: &str,
+        range: KeyRange,
+        asc: bool,
+    ) -> Result<RowIter<'_>> {
+        self.fetch_storage(table_name)
+            .await?
+            .scan_data_range(table_name, range, asc)
+            .await
+    }
 }
@@ -7,33 +7,128 @@ use {
     },
 };
 
+/// Engines which opened a transaction, kept in the order they are committed.
+#[derive(Debug)]
+pub(crate) struct Participants {
+    autocommit: bool,
+    engines: Vec<String>,
+}
+
+impl CompositeStorage {
+    fn engines(&self) -> Vec<String> {
+        let mut engines = self.storages.keys().cloned().collect::<Vec<_>>();
+        engines.sort();
+        engines
+    }
+
+    /// Rolls back every engine, the first error is returned after all of them are tried.
+    async fn rollback_engines(&mut self, engines: &[String]) -> Result<()> {
+        let mut result = Ok(());
+
+        for engine in engines {
+            let storage = match self.storages.get_mut(engine) {
+                Some(storage) => storage,
+                None => continue,
+            };
+
+            if let Err(error) = storage.rollback().await {
+                result = result.and(Err(Error::StorageMsg(format!(
+                    "[CompositeStorage] failed to rollback engine {engine}: {error}"
+                ))));
+            }
+        }
+
+        result
+    }
+}
+
 #[async_trait(?Send)]
 impl Transaction for CompositeStorage {
+    /// `BEGIN` opens a transaction on every registered engine and fails when any of them cannot
+    /// roll back. In autocommit, engines which do not open a transaction by themselves are opened
+    /// an explicit one so that a failed statement rolls back all of them, engines which cannot
+    /// roll back at all are left out.
     async fn begin(&mut self, autocommit: bool) -> Result<bool> {
-        if autocommit {
-            for storage in self.storages.values_mut() {
-                storage.begin(autocommit).await?;
+        match (&self.transaction, autocommit) {
+            (Some(_), false) => {
+                return Err(Error::StorageMsg(
+                    "[CompositeStorage] nested transaction is not supported".to_owned(),
+                ));
+            }
+            (Some(Participants { autocommit, .. }), true) => return Ok(*autocommit),
+            (None, _) => {}
+        }
+
+        let mut engines = Vec::new();
+        for engine in self.engines() {
+            let storage = match self.storages.get_mut(&engine) {
+                Some(storage) => storage,
+                None => continue,
+            };
+
+            match storage.begin(autocommit).await {
+                Ok(true) => engines.push(engine),
+                Ok(false) if !autocommit => engines.push(engine),
+                Ok(false) => {
+                    if storage.begin(false).await.is_ok() {
+                        engines.push(engine);
+                    }
+                }
+                Err(error) => {
+                    // the error of the engine is kept over a failure of the rollback
+                    let _ = self.rollback_engines(&engines).await;
+
+                    if autocommit {
+                        return Err(error);
+                    }
+
+                    return Err(Error::StorageMsg(format!(
+                        "[CompositeStorage] engine {engine} cannot roll back: {error}"
+                    )));
+                }
             }
+        }
 
-            return Ok(true);
+        if autocommit && engines.is_empty() {
+            return Ok(false);
         }
 
-        Err(Error::StorageMsg(
-            "[CompositeStorage] Transaction::begin is not supported".to_owned(),
-        ))
+        self.transaction = Some(Participants {
+            autocommit,
+            engines,
+        });
+
+        Ok(autocommit)
     }
 
     async fn rollback(&mut self) -> Result<()> {
-        for storage in self.storages.values_mut() {
-            storage.commit().await?;
+        match self.transaction.take() {
+            Some(Participants { engines, .. }) => self.rollback_engines(&engines).await,
+            None => Ok(()),
         }
-
-        Ok(())
     }
 
+    /// Engines are committed one by one, once an engine fails to commit the rest are rolled back
+    /// while the engines already committed keep their changes.
     async fn commit(&mut self) -> Result<()> {
-        for storage in self.storages.values_mut() {
-            storage.commit().await?;
+        let engines = match self.transaction.take() {
+            Some(Participants { engines, .. }) => engines,
+            None => return Ok(()),
+        };
+
+        for (i, engine) in engines.iter().enumerate() {
+            let storage = match self.storages.get_mut(engine) {
+                Some(storage) => storage,
+                None => continue,
+            };
+
+            if let Err(error) = storage.commit().await {
+                let _ = self.rollback_engines(&engines[i + 1..]).await;
+
+                return Err(Error::StorageMsg(format!(
+                    "[CompositeStorage] failed to commit engine {engine}: {error}"
+                )));
+            }
         }
 
         Ok(())
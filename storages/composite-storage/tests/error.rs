@@ -97,7 +97,7 @@ async fn composite_storage_index() {
 
     assert_eq!(
         glue.storage
-            .scan_indexed_data("Idx", "hello", None, None)
+            .scan_indexed_data("Idx", "hello", None, &[], None, None)
             .await
             .map(|_| ()),
         Err(Error::StorageMsg(
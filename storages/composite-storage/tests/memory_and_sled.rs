@@ -1,7 +1,11 @@
 use {
     gluesql_composite_storage::CompositeStorage,
-    gluesql_core::prelude::{Error, Glue, Value::I64},
+    gluesql_core::{
+        prelude::{Error, Glue, Payload, Value::I64},
+        store::Store,
+    },
     gluesql_memory_storage::MemoryStorage,
+    gluesql_shared_memory_storage::SharedMemoryStorage,
     gluesql_sled_storage::SledStorage,
     std::fs,
     test_suite::*,
@@ -56,8 +60,59 @@ async fn memory_and_sled() {
         )
     );
 
+    macro_rules! count {
+        ($sql: literal) => {
+            match glue.execute($sql).await.unwrap().into_iter().next() {
+                Some(Payload::Select { rows, .. }) => rows.len(),
+                payload => panic!("unexpected payload: {payload:?}"),
+            }
+        };
+    }
+
+    glue.execute("BEGIN;").await.unwrap();
+    glue.execute("INSERT INTO Foo VALUES (6);").await.unwrap();
+    glue.execute("DELETE FROM Bar WHERE foo_id = 3;")
+        .await
+        .unwrap();
+    assert_eq!(count!("SELECT * FROM Foo;"), 6);
+    assert_eq!(count!("SELECT * FROM Bar;"), 2);
     assert_eq!(
         glue.execute("BEGIN;").await.unwrap_err(),
-        Error::StorageMsg("[CompositeStorage] Transaction::begin is not supported".to_owned()),
+        Error::StorageMsg("[CompositeStorage] nested transaction is not supported".to_owned()),
     );
+    glue.execute("ROLLBACK;").await.unwrap();
+    assert_eq!(count!("SELECT * FROM Foo;"), 5);
+    assert_eq!(count!("SELECT * FROM Bar;"), 5);
+
+    glue.execute("BEGIN;").await.unwrap();
+    glue.execute("INSERT INTO Foo VALUES (6);").await.unwrap();
+    glue.execute("INSERT INTO Bar VALUES (60, 6);")
+        .await
+        .unwrap();
+    glue.execute("COMMIT;").await.unwrap();
+    assert_eq!(count!("SELECT * FROM Foo;"), 6);
+    assert_eq!(count!("SELECT * FROM Bar;"), 6);
+
+    glue.storage.push("SHARED", SharedMemoryStorage::new());
+    assert_eq!(
+        glue.execute("BEGIN;").await.unwrap_err(),
+        Error::StorageMsg(
+            "[CompositeStorage] engine SHARED cannot roll back: \
+            storage: [Shared MemoryStorage] transaction is not supported"
+                .to_owned()
+        ),
+    );
+
+    glue.storage.remove("SHARED");
+    glue.execute("BEGIN;").await.unwrap();
+    glue.execute("DELETE FROM Foo;").await.unwrap();
+    glue.execute("ROLLBACK;").await.unwrap();
+    assert_eq!(count!("SELECT * FROM Foo;"), 6);
+
+    // the schema of Baz is inserted into MEMORY before its rows fail to be selected
+    assert!(glue
+        .execute("CREATE TABLE Baz AS SELECT * FROM Foo WHERE foo_id / (foo_id - 3) > 0;")
+        .await
+        .is_err());
+    assert_eq!(glue.storage.fetch_schema("Baz").await.unwrap(), None);
 }
@@ -5,8 +5,8 @@ mod store_mut;
 use {
     error::{CsvStorageError, ResultExt},
     gluesql_core::{
-        ast::{ColumnDef, ColumnUniqueOption, DataType},
-        data::{Key, Schema, Value},
+        ast::{ColumnDef, DataType},
+        data::{primary_key_indexes, Key, Schema, Value},
         error::Result,
         parse_sql::parse_data_type,
         store::{
@@ -58,6 +58,8 @@ impl CsvStorage {
                             unique: None,
                             default: None,
                             nullable: true,
+                            check: None,
+                            identity: None,
                             comment: None,
                         })
                         .collect::<Vec<_>>(),
@@ -68,9 +70,12 @@ impl CsvStorage {
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs,
+                primary_key: Vec::new(),
                 indexes: Vec::new(),
                 engine: None,
                 foreign_keys: Vec::new(),
+                checks: Vec::new(),
+                triggers: Vec::new(),
                 comment: None,
             };
 
@@ -136,6 +141,7 @@ impl CsvStorage {
 
         if let Schema {
             column_defs: Some(column_defs),
+            primary_key,
             ..
         } = schema
         {
@@ -143,13 +149,12 @@ impl CsvStorage {
                 .iter()
                 .map(|column_def| column_def.name.to_owned())
                 .collect::<Vec<_>>();
+            let primary_key = primary_key_indexes(&column_defs, &primary_key);
 
             let rows = data_rdr
                 .into_records()
                 .enumerate()
                 .map(move |(index, record)| {
-                    let mut key: Option<Key> = None;
-
                     let values = record
                         .map_storage_err()?
                         .into_iter()
@@ -160,20 +165,21 @@ impl CsvStorage {
                                 _ => Value::Str(value.to_owned()),
                             };
 
-                            let value = match &column_def.data_type {
-                                DataType::Text => value,
-                                data_type => value.cast(data_type)?,
-                            };
-
-                            if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
-                                key = Key::try_from(&value).map(Some)?;
+                            match &column_def.data_type {
+                                DataType::Text => Ok(value),
+                                data_type => value.cast(data_type),
                             }
-
-                            Ok(value)
                         })
                         .collect::<Result<Vec<Value>>>()?;
-
-                    let key = key.unwrap_or(Key::U64(index as u64));
+                    let keys = primary_key
+                        .iter()
+                        .map(|i| Key::try_from(&values[*i]))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    let key = match keys.is_empty() {
+                        false => Key::from_primary_keys(keys),
+                        true => Key::U64(index as u64),
+                    };
                     let row = DataRow::Vec(values);
 
                     Ok((key, row))
@@ -254,6 +260,10 @@ impl CsvStorage {
 impl AlterTable for CsvStorage {}
 impl CustomFunction for CsvStorage {}
 impl CustomFunctionMut for CsvStorage {}
+impl gluesql_core::store::View for CsvStorage {}
+impl gluesql_core::store::ViewMut for CsvStorage {}
+impl gluesql_core::store::Sequence for CsvStorage {}
+impl gluesql_core::store::SequenceMut for CsvStorage {}
 impl Index for CsvStorage {}
 impl IndexMut for CsvStorage {}
 impl Transaction for CsvStorage {}
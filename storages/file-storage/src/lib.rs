@@ -80,3 +80,7 @@ impl Transaction for FileStorage {}
 impl Metadata for FileStorage {}
 impl CustomFunction for FileStorage {}
 impl CustomFunctionMut for FileStorage {}
+impl gluesql_core::store::View for FileStorage {}
+impl gluesql_core::store::ViewMut for FileStorage {}
+impl gluesql_core::store::Sequence for FileStorage {}
+impl gluesql_core::store::SequenceMut for FileStorage {}
@@ -161,3 +161,7 @@ impl Transaction for GitStorage {}
 impl Metadata for GitStorage {}
 impl CustomFunction for GitStorage {}
 impl CustomFunctionMut for GitStorage {}
+impl gluesql_core::store::View for GitStorage {}
+impl gluesql_core::store::ViewMut for GitStorage {}
+impl gluesql_core::store::Sequence for GitStorage {}
+impl gluesql_core::store::SequenceMut for GitStorage {}
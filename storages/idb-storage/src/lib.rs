@@ -434,3 +434,7 @@ impl gluesql_core::store::Transaction for IdbStorage {}
 impl Metadata for IdbStorage {}
 impl gluesql_core::store::CustomFunction for IdbStorage {}
 impl gluesql_core::store::CustomFunctionMut for IdbStorage {}
+impl gluesql_core::store::View for IdbStorage {}
+impl gluesql_core::store::ViewMut for IdbStorage {}
+impl gluesql_core::store::Sequence for IdbStorage {}
+impl gluesql_core::store::SequenceMut for IdbStorage {}
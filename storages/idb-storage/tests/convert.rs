@@ -26,6 +26,8 @@ async fn convert_schema() {
             nullable: false,
             default: None,
             unique: None,
+            check: None,
+            identity: None,
             comment: None,
         },
         ColumnDef {
@@ -34,6 +36,8 @@ async fn convert_schema() {
             nullable: false,
             default: None,
             unique: None,
+            check: None,
+            identity: None,
             comment: None,
         },
         ColumnDef {
@@ -42,6 +46,8 @@ async fn convert_schema() {
             nullable: true,
             default: None,
             unique: None,
+            check: None,
+            identity: None,
             comment: None,
         },
     ];
@@ -1,3 +1,51 @@
-use {super::JsonStorage, gluesql_core::store::AlterTable};
+use {
+    super::{error::ResultExt, JsonStorage},
+    async_trait::async_trait,
+    gluesql_core::{
+        ast::ColumnDef,
+        error::{AlterTableError, Error, Result},
+        store::{convert_column_value, AlterTable, DataRow},
+    },
+    std::{fs::File, io::Write},
+};
 
-impl AlterTable for JsonStorage {}
+#[async_trait(?Send)]
+impl AlterTable for JsonStorage {
+    async fn alter_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let (prev_rows, mut schema) = self.scan_data(table_name)?;
+
+        let column_defs = schema
+            .column_defs
+            .as_mut()
+            .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()))?;
+
+        let column_index = column_defs
+            .iter()
+            .position(|ColumnDef { name, .. }| name == &column_def.name)
+            .ok_or_else(|| AlterTableError::AlteringColumnNotFound(column_def.name.to_owned()))?;
+
+        column_defs[column_index] = column_def.clone();
+
+        let rows = prev_rows
+            .map(|item| match item?.1 {
+                DataRow::Vec(mut values) => {
+                    if let Some(value) = values.get_mut(column_index) {
+                        *value = convert_column_value(value, column_def)?;
+                    }
+
+                    Ok(DataRow::Vec(values))
+                }
+                DataRow::Map(_) => Err(Error::StorageMsg(
+                    "conflict - alter_column failed: schemaless row found".to_owned(),
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let schema_path = self.schema_path(table_name);
+        let mut file = File::create(schema_path).map_storage_err()?;
+        file.write_all(schema.to_ddl().as_bytes())
+            .map_storage_err()?;
+
+        self.rewrite(schema, rows)
+    }
+}
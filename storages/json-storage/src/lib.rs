@@ -9,8 +9,7 @@ mod transaction;
 use {
     error::{JsonStorageError, OptionExt, ResultExt},
     gluesql_core::{
-        ast::ColumnUniqueOption,
-        data::{value::HashMapJsonExt, Key, Schema},
+        data::{primary_key_indexes, value::HashMapJsonExt, Key, Schema},
         error::{Error, Result},
         store::{DataRow, Metadata},
     },
@@ -54,30 +53,41 @@ impl JsonStorage {
         }
 
         let schema_path = self.schema_path(table_name);
-        let (column_defs, foreign_keys, comment) = match schema_path.exists() {
-            true => {
-                let mut file = File::open(&schema_path).map_storage_err()?;
-                let mut ddl = String::new();
-                file.read_to_string(&mut ddl).map_storage_err()?;
-
-                let schema = Schema::from_ddl(&ddl)?;
-                if schema.table_name != table_name {
-                    return Err(Error::StorageMsg(
-                        JsonStorageError::TableNameDoesNotMatchWithFile.to_string(),
-                    ));
-                }
+        let (column_defs, primary_key, foreign_keys, checks, triggers, comment) =
+            match schema_path.exists() {
+                true => {
+                    let mut file = File::open(&schema_path).map_storage_err()?;
+                    let mut ddl = String::new();
+                    file.read_to_string(&mut ddl).map_storage_err()?;
+
+                    let schema = Schema::from_ddl(&ddl)?;
+                    if schema.table_name != table_name {
+                        return Err(Error::StorageMsg(
+                            JsonStorageError::TableNameDoesNotMatchWithFile.to_string(),
+                        ));
+                    }
 
-                (schema.column_defs, schema.foreign_keys, schema.comment)
-            }
-            false => (None, Vec::new(), None),
-        };
+                    (
+                        schema.column_defs,
+                        schema.primary_key,
+                        schema.foreign_keys,
+                        schema.checks,
+                        schema.triggers,
+                        schema.comment,
+                    )
+                }
+                false => (None, Vec::new(), Vec::new(), Vec::new(), Vec::new(), None),
+            };
 
         Ok(Some(Schema {
             table_name: table_name.to_owned(),
             column_defs,
+            primary_key,
             indexes: vec![],
             engine: None,
             foreign_keys,
+            checks,
+            triggers,
             comment,
         }))
     }
@@ -164,17 +174,12 @@ impl JsonStorage {
                 }
             };
 
-            let mut key: Option<Key> = None;
             let mut values = Vec::with_capacity(column_defs.len());
             for column_def in column_defs {
                 let value = json.get(&column_def.name).map_storage_err(
                     JsonStorageError::ColumnDoesNotExist(column_def.name.clone()),
                 )?;
 
-                if column_def.unique == Some(ColumnUniqueOption { is_primary: true }) {
-                    key = Some(value.clone().try_into().map_storage_err()?);
-                }
-
                 let value = match value.get_type() {
                     Some(data_type) if data_type != column_def.data_type => {
                         value.cast(&column_def.data_type)?
@@ -185,9 +190,13 @@ impl JsonStorage {
                 values.push(value);
             }
 
-            let key = match key {
-                Some(key) => key,
-                None => get_index_key()?,
+            let keys = primary_key_indexes(column_defs, &schema2.primary_key)
+                .into_iter()
+                .map(|i| values[i].clone().try_into().map_storage_err())
+                .collect::<Result<Vec<_>>>()?;
+            let key = match keys.is_empty() {
+                false => Key::from_primary_keys(keys),
+                true => get_index_key()?,
             };
             let row = DataRow::Vec(values);
 
@@ -207,3 +216,7 @@ where
 }
 
 impl Metadata for JsonStorage {}
+impl gluesql_core::store::View for JsonStorage {}
+impl gluesql_core::store::ViewMut for JsonStorage {}
+impl gluesql_core::store::Sequence for JsonStorage {}
+impl gluesql_core::store::SequenceMut for JsonStorage {}
@@ -154,7 +154,7 @@ where
 }
 
 impl JsonStorage {
-    fn rewrite(&mut self, schema: Schema, rows: Vec<DataRow>) -> Result<()> {
+    pub(crate) fn rewrite(&mut self, schema: Schema, rows: Vec<DataRow>) -> Result<()> {
         let json_path = self.json_path(&schema.table_name);
         let (path, is_json) = match json_path.exists() {
             true => (json_path, true),
@@ -27,3 +27,10 @@ impl Tester<JsonStorage> for JsonTester {
 }
 
 generate_store_tests!(tokio::test, JsonTester);
+
+declare_test_fn!(
+    tokio::test,
+    JsonTester,
+    alter_table_alter_column,
+    alter::alter_table_alter_column
+);
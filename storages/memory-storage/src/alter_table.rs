@@ -2,16 +2,19 @@ use {
     super::MemoryStorage,
     async_trait::async_trait,
     gluesql_core::{
-        ast::ColumnDef,
+        ast::{ColumnDef, Trigger},
         data::Value,
         error::{AlterTableError, Error, Result},
-        store::{AlterTable, DataRow},
+        store::{convert_column_value, AlterTable, DataRow},
     },
 };
 
 #[async_trait(?Send)]
 impl AlterTable for MemoryStorage {
     async fn rename_schema(&mut self, table_name: &str, new_table_name: &str) -> Result<()> {
+        self.save_item(table_name);
+        self.save_item(new_table_name);
+
         let mut item = self
             .items
             .remove(table_name)
@@ -30,8 +33,7 @@ impl AlterTable for MemoryStorage {
         new_column_name: &str,
     ) -> Result<()> {
         let item = self
-            .items
-            .get_mut(table_name)
+            .item_mut(table_name)
             .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
 
         let column_defs = item
@@ -53,14 +55,19 @@ impl AlterTable for MemoryStorage {
             .ok_or(AlterTableError::RenamingColumnNotFound)?;
 
         new_column_name.clone_into(&mut column_def.name);
+        item.schema
+            .primary_key
+            .iter_mut()
+            .filter(|name| *name == old_column_name)
+            .for_each(|name| new_column_name.clone_into(name));
+        item.rename_index_column(old_column_name, new_column_name);
 
         Ok(())
     }
 
     async fn add_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
         let item = self
-            .items
-            .get_mut(table_name)
+            .item_mut(table_name)
             .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
 
         let column_defs = item
@@ -122,8 +129,7 @@ impl AlterTable for MemoryStorage {
         if_exists: bool,
     ) -> Result<()> {
         let item = self
-            .items
-            .get_mut(table_name)
+            .item_mut(table_name)
             .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
 
         let column_defs = item
@@ -156,6 +162,8 @@ impl AlterTable for MemoryStorage {
                         }
                     }
                 }
+
+                item.drop_index_column(column_name);
             }
             None if if_exists => {}
             None => {
@@ -165,4 +173,64 @@ impl AlterTable for MemoryStorage {
 
         Ok(())
     }
+
+    async fn alter_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let item = self
+            .item_mut(table_name)
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        let column_defs = item
+            .schema
+            .column_defs
+            .as_mut()
+            .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()))?;
+
+        let column_index = column_defs
+            .iter()
+            .position(|ColumnDef { name, .. }| name == &column_def.name)
+            .ok_or_else(|| AlterTableError::AlteringColumnNotFound(column_def.name.to_owned()))?;
+
+        let mut rows = item.rows.clone();
+        for (_, row) in rows.iter_mut() {
+            match row {
+                DataRow::Vec(values) => {
+                    if let Some(value) = values.get_mut(column_index) {
+                        *value = convert_column_value(value, column_def)?;
+                    }
+                }
+                DataRow::Map(_) => {
+                    return Err(Error::StorageMsg(
+                        "conflict - alter_column failed: schemaless row found".to_owned(),
+                    ));
+                }
+            }
+        }
+
+        column_defs[column_index] = column_def.clone();
+        item.rows = rows;
+
+        Ok(())
+    }
+
+    async fn create_trigger(&mut self, table_name: &str, trigger: &Trigger) -> Result<()> {
+        let item = self
+            .item_mut(table_name)
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        item.schema.triggers.push(trigger.clone());
+
+        Ok(())
+    }
+
+    async fn drop_trigger(&mut self, table_name: &str, trigger_name: &str) -> Result<()> {
+        let item = self
+            .item_mut(table_name)
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        item.schema
+            .triggers
+            .retain(|Trigger { name, .. }| name != trigger_name);
+
+        Ok(())
+    }
 }
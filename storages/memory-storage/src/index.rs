@@ -1,26 +1,210 @@
 use {
-    super::MemoryStorage,
+    super::{Item, MemoryStorage},
     async_trait::async_trait,
+    futures::stream::iter,
     gluesql_core::{
-        ast::{IndexOperator, OrderByExpr},
-        data::Value,
-        error::{Error, Result},
-        store::{Index, IndexMut, RowIter},
+        ast::{Expr, IndexOperator, OrderByExpr},
+        chrono::Utc,
+        data::{Key, SchemaIndex, SchemaIndexOrd, Value},
+        error::{IndexError, Result},
+        executor::evaluate_stateless,
+        store::{DataRow, Index, IndexMut, RowIter},
     },
+    std::{collections::BTreeMap, iter::once},
 };
 
+/// Keys of the rows grouped by the evaluated index expressions, in the order they were added.
+pub type IndexData = BTreeMap<Vec<Key>, Vec<Key>>;
+
+impl Item {
+    async fn index_key(&self, exprs: &[Expr], row: &DataRow) -> Result<Vec<Key>> {
+        let columns = self.schema.column_defs.as_ref().map(|column_defs| {
+            column_defs
+                .iter()
+                .map(|column_def| column_def.name.clone())
+                .collect::<Vec<_>>()
+        });
+
+        let mut keys = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            let context = Some(row.as_context(columns.as_deref()));
+            let value: Value = evaluate_stateless(context, expr).await?.try_into()?;
+
+            keys.push(Key::try_from(value)?);
+        }
+
+        Ok(keys)
+    }
+
+    pub(crate) async fn index_keys(&self, row: &DataRow) -> Result<Vec<(String, Vec<Key>)>> {
+        let mut index_keys = Vec::with_capacity(self.schema.indexes.len());
+        for SchemaIndex { name, exprs, .. } in &self.schema.indexes {
+            let index_key = self.index_key(exprs, row).await?;
+
+            index_keys.push((name.clone(), index_key));
+        }
+
+        Ok(index_keys)
+    }
+
+    /// Adds `key` to the index data, `index_keys` are computed by [`Item::index_keys`]
+    /// before the rows change, so that a failed evaluation leaves the item untouched.
+    pub(crate) fn insert_index_data(&mut self, key: &Key, index_keys: Vec<(String, Vec<Key>)>) {
+        for (index_name, index_key) in index_keys {
+            self.indexes
+                .entry(index_name)
+                .or_default()
+                .entry(index_key)
+                .or_default()
+                .push(key.clone());
+        }
+    }
+
+    pub(crate) fn delete_index_data(&mut self, key: &Key, index_keys: Vec<(String, Vec<Key>)>) {
+        for (index_name, index_key) in index_keys {
+            let index = match self.indexes.get_mut(&index_name) {
+                Some(index) => index,
+                None => continue,
+            };
+
+            if let Some(keys) = index.get_mut(&index_key) {
+                keys.retain(|data_key| data_key != key);
+
+                if keys.is_empty() {
+                    index.remove(&index_key);
+                }
+            }
+        }
+    }
+
+    /// Index data is kept as is, only the column name in the index expressions changes.
+    pub(crate) fn rename_index_column(&mut self, old_column_name: &str, new_column_name: &str) {
+        for index in self.schema.indexes.iter_mut() {
+            index.rename_column(old_column_name, new_column_name);
+        }
+    }
+
+    /// Indexes which refer to the dropped column are dropped along with their index data.
+    pub(crate) fn drop_index_column(&mut self, column_name: &str) {
+        let (dropped, indexes) = self
+            .schema
+            .indexes
+            .drain(..)
+            .partition::<Vec<_>, _>(|index| index.contains_column(column_name));
+
+        self.schema.indexes = indexes;
+        for SchemaIndex { name, .. } in dropped {
+            self.indexes.remove(&name);
+        }
+    }
+}
+
+impl MemoryStorage {
+    pub fn scan_indexed_data(
+        &self,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        prefix: &[Value],
+        cmp_value: Option<(&IndexOperator, Value)>,
+        upper_cmp_value: Option<(&IndexOperator, Value)>,
+    ) -> Result<Vec<(Key, DataRow)>> {
+        let item = self
+            .items
+            .get(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+        let index = item
+            .indexes
+            .get(index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        let prefix = prefix
+            .iter()
+            .cloned()
+            .map(Key::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        let to_key = |cmp_value: Option<(&IndexOperator, Value)>| {
+            cmp_value
+                .map(|(op, value)| Key::try_from(value).map(|key| (op.clone(), key)))
+                .transpose()
+        };
+
+        let (lower, upper) = match (to_key(cmp_value)?, to_key(upper_cmp_value)?) {
+            (Some((IndexOperator::Eq, key)), None) => (
+                Some((IndexOperator::GtEq, key.clone())),
+                Some((IndexOperator::LtEq, key)),
+            ),
+            (Some((op @ (IndexOperator::Lt | IndexOperator::LtEq), key)), None) => {
+                (None, Some((op, key)))
+            }
+            bounds => bounds,
+        };
+
+        // index keys are compared by the value right after the prefix
+        let depth = prefix.len();
+        let is_above_lower = |index_key: &[Key]| match (&lower, index_key.get(depth)) {
+            (Some((IndexOperator::Gt, key)), Some(value)) => value > key,
+            (Some((_, key)), Some(value)) => value >= key,
+            _ => true,
+        };
+        let is_below_upper = |index_key: &[Key]| match (&upper, index_key.get(depth)) {
+            (Some((IndexOperator::Lt, key)), Some(value)) => value < key,
+            (Some((_, key)), Some(value)) => value <= key,
+            _ => true,
+        };
+
+        let start = match &lower {
+            Some((_, key)) => prefix.iter().cloned().chain(once(key.clone())).collect(),
+            None => prefix.clone(),
+        };
+
+        let mut entries = index
+            .range(start..)
+            .skip_while(|(index_key, _)| !is_above_lower(index_key))
+            .take_while(|(index_key, _)| {
+                index_key.starts_with(&prefix) && is_below_upper(index_key)
+            })
+            .map(|(_, keys)| keys)
+            .collect::<Vec<_>>();
+
+        if asc == Some(false) {
+            entries.reverse();
+        }
+
+        let rows = entries
+            .into_iter()
+            .flatten()
+            .filter_map(|key| item.rows.get(key).map(|row| (key.clone(), row.clone())))
+            .collect();
+
+        Ok(rows)
+    }
+}
+
 #[async_trait(?Send)]
 impl Index for MemoryStorage {
     async fn scan_indexed_data(
         &self,
-        _table_name: &str,
-        _index_name: &str,
-        _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        prefix: &[Value],
+        cmp_value: Option<(&IndexOperator, Value)>,
+        upper_cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+        let rows = MemoryStorage::scan_indexed_data(
+            self,
+            table_name,
+            index_name,
+            asc,
+            prefix,
+            cmp_value,
+            upper_cmp_value,
+        )?
+        .into_iter()
+        .map(Ok);
+
+        Ok(Box::pin(iter(rows)))
     }
 }
 
@@ -28,18 +212,61 @@ impl Index for MemoryStorage {
 impl IndexMut for MemoryStorage {
     async fn create_index(
         &mut self,
-        _table_name: &str,
-        _index_name: &str,
-        _column: &OrderByExpr,
+        table_name: &str,
+        index_name: &str,
+        columns: &[OrderByExpr],
     ) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+        let item = self
+            .item_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        if item
+            .schema
+            .indexes
+            .iter()
+            .any(|index| index.name == index_name)
+        {
+            return Err(IndexError::IndexNameAlreadyExists(index_name.to_owned()).into());
+        }
+
+        let exprs = columns
+            .iter()
+            .map(|OrderByExpr { expr, .. }| expr.clone())
+            .collect::<Vec<_>>();
+
+        let mut index = IndexData::new();
+        for (key, row) in &item.rows {
+            let index_key = item.index_key(&exprs, row).await?;
+
+            index.entry(index_key).or_default().push(key.clone());
+        }
+
+        item.schema.indexes.push(SchemaIndex {
+            name: index_name.to_owned(),
+            exprs,
+            order: SchemaIndexOrd::Both,
+            created: Utc::now().naive_utc(),
+        });
+        item.indexes.insert(index_name.to_owned(), index);
+
+        Ok(())
     }
 
-    async fn drop_index(&mut self, _table_name: &str, _index_name: &str) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned(),
-        ))
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let item = self
+            .item_mut(table_name)
+            .ok_or_else(|| IndexError::TableNotFound(table_name.to_owned()))?;
+
+        let i = item
+            .schema
+            .indexes
+            .iter()
+            .position(|index| index.name == index_name)
+            .ok_or_else(|| IndexError::IndexNameDoesNotExist(index_name.to_owned()))?;
+
+        item.schema.indexes.remove(i);
+        item.indexes.remove(index_name);
+
+        Ok(())
     }
 }
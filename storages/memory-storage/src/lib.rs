@@ -5,14 +5,21 @@ mod index;
 mod metadata;
 mod transaction;
 
+pub use index::IndexData;
 use {
     async_trait::async_trait,
     futures::stream::iter,
     gluesql_core::{
         chrono::Utc,
-        data::{CustomFunction as StructCustomFunction, Key, Schema, Value},
+        data::{
+            CustomFunction as StructCustomFunction, Key, Schema, Sequence as StructSequence, Value,
+            View as StructView,
+        },
         error::Result,
-        store::{CustomFunction, CustomFunctionMut, DataRow, RowIter, Store, StoreMut},
+        store::{
+            CustomFunction, CustomFunctionMut, DataRow, KeyRange, RowIter, Sequence, SequenceMut,
+            Store, StoreMut, View, ViewMut,
+        },
     },
     serde::{Deserialize, Serialize},
     std::collections::{BTreeMap, HashMap},
@@ -22,6 +29,8 @@ use {
 pub struct Item {
     pub schema: Schema,
     pub rows: BTreeMap<Key, DataRow>,
+    #[serde(default)]
+    pub indexes: HashMap<String, IndexData>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -30,6 +39,12 @@ pub struct MemoryStorage {
     pub items: HashMap<String, Item>,
     pub metadata: HashMap<String, HashMap<String, Value>>,
     pub functions: HashMap<String, StructCustomFunction>,
+    #[serde(default)]
+    pub views: HashMap<String, StructView>,
+    #[serde(default)]
+    pub sequences: HashMap<String, StructSequence>,
+    #[serde(skip)]
+    snapshot: Option<transaction::Snapshot>,
 }
 
 impl MemoryStorage {
@@ -39,6 +54,24 @@ impl MemoryStorage {
             None => vec![],
         }
     }
+
+    pub fn scan_data_range(
+        &self,
+        table_name: &str,
+        range: KeyRange,
+        asc: bool,
+    ) -> Vec<(Key, DataRow)> {
+        let rows = match self.items.get(table_name) {
+            Some(item) => item.rows.range(range),
+            None => return vec![],
+        };
+        let rows = rows.map(|(key, row)| (key.clone(), row.clone()));
+
+        match asc {
+            true => rows.collect(),
+            false => rows.rev().collect(),
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -64,6 +97,65 @@ impl CustomFunctionMut for MemoryStorage {
     }
 }
 
+#[async_trait(?Send)]
+impl View for MemoryStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        Ok(self.views.get(view_name).cloned())
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let mut views = self.views.values().cloned().collect::<Vec<_>>();
+        views.sort_by(|a, b| a.view_name.cmp(&b.view_name));
+
+        Ok(views)
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for MemoryStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        self.views.insert(view.view_name.clone(), view);
+
+        Ok(())
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        self.views.remove(view_name);
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for MemoryStorage {
+    async fn fetch_sequence(&self, sequence_name: &str) -> Result<Option<StructSequence>> {
+        Ok(self.sequences.get(sequence_name).cloned())
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let mut sequences = self.sequences.values().cloned().collect::<Vec<_>>();
+        sequences.sort_by(|a, b| a.sequence_name.cmp(&b.sequence_name));
+
+        Ok(sequences)
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for MemoryStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        self.sequences
+            .insert(sequence.sequence_name.clone(), sequence);
+
+        Ok(())
+    }
+
+    async fn delete_sequence(&mut self, sequence_name: &str) -> Result<()> {
+        self.sequences.remove(sequence_name);
+
+        Ok(())
+    }
+}
+
 #[async_trait(?Send)]
 impl Store for MemoryStorage {
     async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
@@ -99,6 +191,19 @@ impl Store for MemoryStorage {
 
         Ok(Box::pin(iter(rows)))
     }
+
+    async fn scan_data_range(
+        &self,
+        table_name: &str,
+        range: KeyRange,
+        asc: bool,
+    ) -> Result<RowIter<'_>> {
+        let rows = MemoryStorage::scan_data_range(self, table_name, range, asc)
+            .into_iter()
+            .map(Ok);
+
+        Ok(Box::pin(iter(rows)))
+    }
 }
 
 #[async_trait(?Send)]
@@ -112,9 +217,12 @@ impl StoreMut for MemoryStorage {
         self.metadata.extend(meta);
 
         let table_name = schema.table_name.clone();
+        self.save_item(&table_name);
+
         let item = Item {
             schema: schema.clone(),
             rows: BTreeMap::new(),
+            indexes: HashMap::new(),
         };
         self.items.insert(table_name, item);
 
@@ -122,6 +230,7 @@ impl StoreMut for MemoryStorage {
     }
 
     async fn delete_schema(&mut self, table_name: &str) -> Result<()> {
+        self.save_item(table_name);
         self.items.remove(table_name);
         self.metadata.remove(table_name);
 
@@ -129,11 +238,22 @@ impl StoreMut for MemoryStorage {
     }
 
     async fn append_data(&mut self, table_name: &str, rows: Vec<DataRow>) -> Result<()> {
+        self.save_item(table_name);
+
         if let Some(item) = self.items.get_mut(table_name) {
+            let mut entries = Vec::with_capacity(rows.len());
             for row in rows {
+                let index_keys = item.index_keys(&row).await?;
+
+                entries.push((row, index_keys));
+            }
+
+            for (row, index_keys) in entries {
                 self.id_counter += 1;
 
-                item.rows.insert(Key::I64(self.id_counter), row);
+                let key = Key::I64(self.id_counter);
+                item.insert_index_data(&key, index_keys);
+                item.rows.insert(key, row);
             }
         }
 
@@ -141,8 +261,29 @@ impl StoreMut for MemoryStorage {
     }
 
     async fn insert_data(&mut self, table_name: &str, rows: Vec<(Key, DataRow)>) -> Result<()> {
-        if let Some(item) = self.items.get_mut(table_name) {
+        if let Some(item) = self.item_mut(table_name) {
+            // index keys are computed before any row changes, so that a failed evaluation
+            // leaves both the rows and the index data as they were
+            let mut entries = Vec::with_capacity(rows.len());
+            let mut pending = HashMap::new();
             for (key, row) in rows {
+                let old_index_keys = match (pending.get(&key), item.rows.get(&key)) {
+                    (Some(index_keys), _) => Some(index_keys.clone()),
+                    (None, Some(old_row)) => Some(item.index_keys(old_row).await?),
+                    (None, None) => None,
+                };
+                let index_keys = item.index_keys(&row).await?;
+
+                pending.insert(key.clone(), index_keys.clone());
+                entries.push((key, row, old_index_keys, index_keys));
+            }
+
+            for (key, row, old_index_keys, index_keys) in entries {
+                if let Some(old_index_keys) = old_index_keys {
+                    item.delete_index_data(&key, old_index_keys);
+                }
+
+                item.insert_index_data(&key, index_keys);
                 item.rows.insert(key, row);
             }
         }
@@ -151,8 +292,18 @@ impl StoreMut for MemoryStorage {
     }
 
     async fn delete_data(&mut self, table_name: &str, keys: Vec<Key>) -> Result<()> {
-        if let Some(item) = self.items.get_mut(table_name) {
+        if let Some(item) = self.item_mut(table_name) {
+            let mut entries = Vec::with_capacity(keys.len());
             for key in keys {
+                if let Some(row) = item.rows.get(&key) {
+                    let index_keys = item.index_keys(row).await?;
+
+                    entries.push((key, index_keys));
+                }
+            }
+
+            for (key, index_keys) in entries {
+                item.delete_index_data(&key, index_keys);
                 item.rows.remove(&key);
             }
         }
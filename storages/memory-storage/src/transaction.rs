@@ -1,12 +1,49 @@
 use {
-    super::MemoryStorage,
+    super::{Item, MemoryStorage},
     async_trait::async_trait,
     gluesql_core::{
+        data::{
+            CustomFunction as StructCustomFunction, Sequence as StructSequence, Value,
+            View as StructView,
+        },
         error::{Error, Result},
         store::Transaction,
     },
+    std::collections::HashMap,
 };
 
+/// Copy of the storage taken by `BEGIN`, restored on `ROLLBACK`.
+/// Tables are copied on their first change in the transaction, `None` marks a table created by it.
+#[derive(Debug, Clone)]
+pub(crate) struct Snapshot {
+    id_counter: i64,
+    items: HashMap<String, Option<Item>>,
+    metadata: HashMap<String, HashMap<String, Value>>,
+    functions: HashMap<String, StructCustomFunction>,
+    views: HashMap<String, StructView>,
+    sequences: HashMap<String, StructSequence>,
+}
+
+impl MemoryStorage {
+    /// Keeps the table in the snapshot before its first change in the transaction.
+    pub(crate) fn save_item(&mut self, table_name: &str) {
+        let Some(snapshot) = self.snapshot.as_mut() else {
+            return;
+        };
+
+        if !snapshot.items.contains_key(table_name) {
+            let item = self.items.get(table_name).cloned();
+
+            snapshot.items.insert(table_name.to_owned(), item);
+        }
+    }
+
+    pub(crate) fn item_mut(&mut self, table_name: &str) -> Option<&mut Item> {
+        self.save_item(table_name);
+        self.items.get_mut(table_name)
+    }
+}
+
 #[async_trait(?Send)]
 impl Transaction for MemoryStorage {
     async fn begin(&mut self, autocommit: bool) -> Result<bool> {
@@ -14,16 +51,54 @@ impl Transaction for MemoryStorage {
             return Ok(false);
         }
 
-        Err(Error::StorageMsg(
-            "[MemoryStorage] transaction is not supported".to_owned(),
-        ))
+        if self.snapshot.is_some() {
+            return Err(Error::StorageMsg(
+                "[MemoryStorage] nested transaction is not supported".to_owned(),
+            ));
+        }
+
+        self.snapshot = Some(Snapshot {
+            id_counter: self.id_counter,
+            items: HashMap::new(),
+            metadata: self.metadata.clone(),
+            functions: self.functions.clone(),
+            views: self.views.clone(),
+            sequences: self.sequences.clone(),
+        });
+
+        Ok(false)
     }
 
     async fn rollback(&mut self) -> Result<()> {
+        if let Some(snapshot) = self.snapshot.take() {
+            let Snapshot {
+                id_counter,
+                items,
+                metadata,
+                functions,
+                views,
+                sequences,
+            } = snapshot;
+
+            self.id_counter = id_counter;
+            for (table_name, item) in items {
+                match item {
+                    Some(item) => self.items.insert(table_name, item),
+                    None => self.items.remove(&table_name),
+                };
+            }
+            self.metadata = metadata;
+            self.functions = functions;
+            self.views = views;
+            self.sequences = sequences;
+        }
+
         Ok(())
     }
 
     async fn commit(&mut self) -> Result<()> {
+        self.snapshot = None;
+
         Ok(())
     }
 }
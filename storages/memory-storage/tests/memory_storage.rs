@@ -25,10 +25,28 @@ generate_store_tests!(tokio::test, MemoryTester);
 
 generate_alter_table_tests!(tokio::test, MemoryTester);
 
+generate_view_tests!(tokio::test, MemoryTester);
+
+generate_sequence_tests!(tokio::test, MemoryTester);
+
+generate_trigger_tests!(tokio::test, MemoryTester);
+
 generate_metadata_table_tests!(tokio::test, MemoryTester);
 
 generate_custom_function_tests!(tokio::test, MemoryTester);
 
+generate_transaction_tests!(tokio::test, MemoryTester);
+
+generate_transaction_alter_table_tests!(tokio::test, MemoryTester);
+
+generate_index_tests!(tokio::test, MemoryTester);
+
+generate_alter_table_index_tests!(tokio::test, MemoryTester);
+
+generate_transaction_index_tests!(tokio::test, MemoryTester);
+
+generate_metadata_index_tests!(tokio::test, MemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -44,7 +62,8 @@ macro_rules! test {
 #[tokio::test]
 async fn memory_storage_index() {
     use gluesql_core::{
-        prelude::{Error, Glue},
+        error::IndexError,
+        prelude::{Glue, Payload, Value},
         store::{Index, Store},
     };
 
@@ -62,25 +81,149 @@ async fn memory_storage_index() {
     );
 
     assert_eq!(
-        storage
-            .scan_indexed_data("Idx", "hello", None, None)
+        Index::scan_indexed_data(&storage, "Idx", "hello", None, &[], None, None)
             .await
             .map(|_| ()),
-        Err(Error::StorageMsg(
-            "[MemoryStorage] index is not supported".to_owned()
-        ))
+        Err(IndexError::TableNotFound("Idx".to_owned()).into())
     );
 
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE Idx (id INTEGER);");
+    exec!(glue "INSERT INTO Idx VALUES (1), (2);");
+    test!(
+        glue "CREATE INDEX idx_id ON Idx (id);",
+        Ok(vec![Payload::CreateIndex])
+    );
     test!(
         glue "CREATE INDEX idx_id ON Idx (id);",
-        Err(Error::StorageMsg("[MemoryStorage] index is not supported".to_owned()))
+        Err(IndexError::IndexNameAlreadyExists("idx_id".to_owned()).into())
+    );
+    exec!(glue "UPDATE Idx SET id = 3 WHERE id = 1;");
+    exec!(glue "DELETE FROM Idx WHERE id = 2;");
+    exec!(glue "INSERT INTO Idx VALUES (4);");
+    test!(
+        glue "SELECT id FROM Idx WHERE id > 2",
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(3)], vec![Value::I64(4)]],
+        }])
+    );
+    assert_eq!(
+        Index::scan_indexed_data(&glue.storage, "Idx", "idx_id", None, &[], None, None)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .as_ref()
+            .map(Vec::len),
+        Ok(2)
+    );
+
+    test!(
+        glue "DROP INDEX Idx.idx_id;",
+        Ok(vec![Payload::DropIndex])
     );
     test!(
         glue "DROP INDEX Idx.idx_id;",
-        Err(Error::StorageMsg("[MemoryStorage] index is not supported".to_owned()))
+        Err(IndexError::IndexNameDoesNotExist("idx_id".to_owned()).into())
+    );
+}
+
+#[tokio::test]
+async fn memory_storage_alter_table_index() {
+    use gluesql_core::{
+        error::IndexError,
+        prelude::{Glue, Payload, Value},
+        store::{AlterTable, Index, Store},
+    };
+
+    let storage = MemoryStorage::default();
+    let mut glue = Glue::new(storage);
+
+    exec!(glue "CREATE TABLE Idx (id INTEGER, num INTEGER);");
+    exec!(glue "INSERT INTO Idx VALUES (1, 10), (2, 20);");
+    exec!(glue "CREATE INDEX idx_num ON Idx (num + 1);");
+
+    exec!(glue "ALTER TABLE Idx RENAME COLUMN num TO amount;");
+    exec!(glue "INSERT INTO Idx VALUES (3, 30);");
+    test!(
+        glue "SELECT id FROM Idx WHERE amount + 1 > 15",
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(2)], vec![Value::I64(3)]],
+        }])
+    );
+    assert_eq!(
+        Index::scan_indexed_data(&glue.storage, "Idx", "idx_num", None, &[], None, None)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .as_ref()
+            .map(Vec::len),
+        Ok(3)
+    );
+
+    AlterTable::drop_column(&mut glue.storage, "Idx", "amount", false)
+        .await
+        .unwrap();
+    assert_eq!(
+        Store::fetch_schema(&glue.storage, "Idx")
+            .await
+            .unwrap()
+            .map(|schema| schema.indexes.len()),
+        Some(0)
+    );
+    assert_eq!(
+        Index::scan_indexed_data(&glue.storage, "Idx", "idx_num", None, &[], None, None)
+            .await
+            .map(|_| ()),
+        Err(IndexError::IndexNameDoesNotExist("idx_num".to_owned()).into())
+    );
+    exec!(glue "INSERT INTO Idx VALUES (4);");
+}
+
+#[tokio::test]
+async fn memory_storage_index_evaluation_error() {
+    use gluesql_core::{
+        prelude::{Glue, Value},
+        store::{DataRow, Index, Store, StoreMut},
+    };
+
+    let storage = MemoryStorage::default();
+    let mut glue = Glue::new(storage);
+
+    exec!(glue "CREATE TABLE Idx (id INTEGER, num INTEGER);");
+    exec!(glue "INSERT INTO Idx VALUES (1, 10);");
+    exec!(glue "CREATE INDEX idx_num ON Idx (num + 1);");
+
+    let (key, row) = Store::scan_data(&glue.storage, "Idx")
+        .await
+        .unwrap()
+        .try_next()
+        .await
+        .unwrap()
+        .unwrap();
+    let overflowed = DataRow::Vec(vec![Value::I64(1), Value::I64(i64::MAX)]);
+    assert!(
+        StoreMut::insert_data(&mut glue.storage, "Idx", vec![(key.clone(), overflowed)])
+            .await
+            .is_err()
+    );
+    assert_eq!(
+        Store::fetch_data(&glue.storage, "Idx", &key).await,
+        Ok(Some(row))
+    );
+    assert_eq!(
+        Index::scan_indexed_data(&glue.storage, "Idx", "idx_num", None, &[], None, None)
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .as_ref()
+            .map(Vec::len),
+        Ok(1)
     );
 }
 
@@ -92,7 +235,11 @@ async fn memory_storage_transaction() {
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE TxTest (id INTEGER);");
-    test!(glue "BEGIN", Err(Error::StorageMsg("[MemoryStorage] transaction is not supported".to_owned())));
+    test!(glue "BEGIN", Ok(vec![Payload::StartTransaction]));
+    test!(glue "BEGIN", Err(Error::StorageMsg("[MemoryStorage] nested transaction is not supported".to_owned())));
+    exec!(glue "INSERT INTO TxTest VALUES (1);");
+    test!(glue "ROLLBACK", Ok(vec![Payload::Rollback]));
+    test!(glue "SELECT * FROM TxTest", Ok(vec![Payload::Select { labels: vec!["id".to_owned()], rows: Vec::new() }]));
     test!(glue "COMMIT", Ok(vec![Payload::Commit]));
     test!(glue "ROLLBACK", Ok(vec![Payload::Rollback]));
 }
@@ -1,16 +1,21 @@
 use {
-    gluesql_core::ast::{Expr, ForeignKey},
+    gluesql_core::ast::{Check, Expr, ForeignKey},
     serde::{Deserialize, Serialize},
 };
 
 #[derive(Serialize, Deserialize)]
 pub struct TableDescription {
     pub foreign_keys: Vec<ForeignKey>,
+    #[serde(default)]
+    pub primary_key: Vec<String>,
+    #[serde(default)]
+    pub checks: Vec<Check>,
     pub comment: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct ColumnDescription {
     pub default: Option<Expr>,
+    pub check: Option<Expr>,
     pub comment: Option<String>,
 }
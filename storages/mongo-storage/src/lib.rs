@@ -38,6 +38,10 @@ impl Metadata for MongoStorage {}
 impl AlterTable for MongoStorage {}
 impl CustomFunction for MongoStorage {}
 impl CustomFunctionMut for MongoStorage {}
+impl gluesql_core::store::View for MongoStorage {}
+impl gluesql_core::store::ViewMut for MongoStorage {}
+impl gluesql_core::store::Sequence for MongoStorage {}
+impl gluesql_core::store::SequenceMut for MongoStorage {}
 impl Index for MongoStorage {}
 impl IndexMut for MongoStorage {}
 impl Transaction for MongoStorage {}
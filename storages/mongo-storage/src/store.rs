@@ -234,12 +234,17 @@ impl MongoStorage {
                     .map(|is_primary| ColumnUniqueOption { is_primary });
 
                     let column_description = doc.get_str("description");
-                    let ColumnDescription { default, comment } = match column_description {
+                    let ColumnDescription {
+                        default,
+                        check,
+                        comment,
+                    } = match column_description {
                         Ok(desc) => {
                             serde_json::from_str::<ColumnDescription>(desc).map_storage_err()?
                         }
                         Err(ValueAccessError::NotPresent) => ColumnDescription {
                             default: None,
+                            check: None,
                             comment: None,
                         },
                         Err(_) => {
@@ -255,6 +260,8 @@ impl MongoStorage {
                         nullable,
                         default,
                         unique,
+                        check,
+                        identity: None,
                         comment,
                     };
 
@@ -270,15 +277,20 @@ impl MongoStorage {
             let table_description = validator.get_str("description").map_storage_err()?;
             let TableDescription {
                 foreign_keys,
+                primary_key,
+                checks,
                 comment,
             } = from_str::<TableDescription>(table_description).map_storage_err()?;
 
             let schema = Schema {
                 table_name: collection_name.to_owned(),
                 column_defs,
+                primary_key,
                 indexes: Vec::new(),
                 engine: None,
                 foreign_keys,
+                checks,
+                triggers: Vec::new(),
                 comment,
             };
 
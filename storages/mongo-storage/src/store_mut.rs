@@ -95,6 +95,7 @@ impl StoreMut for MongoStorage {
 
                         let column_description = ColumnDescription {
                             default: column_def.default.clone(),
+                            check: column_def.check.clone(),
                             comment: column_def.comment.clone(),
                         };
                         let column_description =
@@ -123,7 +124,14 @@ impl StoreMut for MongoStorage {
             .unwrap_or_default();
 
         let comment = schema.comment.as_ref().map(ToOwned::to_owned);
-        let validator = Validator::new(labels, column_types, schema.foreign_keys.clone(), comment)?;
+        let validator = Validator::new(
+            labels,
+            column_types,
+            schema.primary_key.clone(),
+            schema.foreign_keys.clone(),
+            schema.checks.clone(),
+            comment,
+        )?;
 
         let schema_exists = self
             .fetch_schema(&schema.table_name)
@@ -2,7 +2,7 @@ use {
     crate::{description::TableDescription, error::ResultExt},
     bson::{doc, Document},
     gluesql_core::{
-        ast::{ColumnDef, ForeignKey},
+        ast::{Check, ColumnDef, ForeignKey},
         error::Result,
     },
     mongodb::options::CreateCollectionOptions,
@@ -23,7 +23,9 @@ impl Validator {
     pub fn new(
         labels: Vec<String>,
         column_types: Document,
+        primary_key: Vec<String>,
         foreign_keys: Vec<ForeignKey>,
+        checks: Vec<Check>,
         comment: Option<String>,
     ) -> Result<Self> {
         let mut required = vec!["_id".to_owned()];
@@ -38,6 +40,8 @@ impl Validator {
         let table_description = to_string(
             &(TableDescription {
                 foreign_keys,
+                primary_key,
+                checks,
                 comment,
             }),
         )
@@ -76,6 +76,7 @@ impl<'a> TryFrom<ParquetSchemaType<'a>> for ColumnDef {
         let nullable = inner.is_optional();
         let mut unique = None;
         let mut default = None;
+        let mut check = None;
         let mut comment = None;
 
         if let Some(metadata) = parquet_col_def.get_metadata().as_deref() {
@@ -102,6 +103,14 @@ impl<'a> TryFrom<ParquetSchemaType<'a>> for ColumnDef {
                             default = Some(tran);
                         }
                     }
+                    k if k == format!("check_{}", name) => {
+                        if let Some(value) = &kv.value {
+                            let parsed = parse_expr(value.clone())?;
+                            let tran = translate_expr(&parsed)?;
+
+                            check = Some(tran);
+                        }
+                    }
                     k if k == format!("comment_{}", name) => {
                         if let Some(value) = &kv.value {
                             comment = Some(value.clone());
@@ -117,6 +126,8 @@ impl<'a> TryFrom<ParquetSchemaType<'a>> for ColumnDef {
             nullable,
             default,
             unique,
+            check,
+            identity: None,
             comment,
         })
     }
@@ -2,8 +2,8 @@ use {
     column_def::ParquetSchemaType,
     error::{OptionExt, ParquetStorageError, ResultExt},
     gluesql_core::{
-        ast::{ColumnDef, ColumnUniqueOption, ForeignKey},
-        data::Schema,
+        ast::{Check, ColumnDef, ForeignKey},
+        data::{primary_key_indexes, Schema},
         error::{Error, Result},
         prelude::{DataType, Key, Value},
         store::{DataRow, Metadata},
@@ -61,6 +61,8 @@ impl ParquetStorage {
 
         let mut is_schemaless = false;
         let mut foreign_keys = Vec::new();
+        let mut checks = Vec::new();
+        let mut primary_key = Vec::new();
         let mut comment = None;
         if let Some(metadata) = key_value_file_metadata {
             for kv in metadata.iter() {
@@ -68,6 +70,24 @@ impl ParquetStorage {
                     is_schemaless = matches!(kv.value.as_deref(), Some("true"));
                 } else if kv.key == "comment" {
                     comment.clone_from(&kv.value)
+                } else if kv.key == "checks" {
+                    checks = kv
+                        .value
+                        .as_ref()
+                        .map(|x| from_str::<Vec<Check>>(x))
+                        .map_storage_err(Error::StorageMsg(
+                            "No value found on metadata".to_owned(),
+                        ))?
+                        .map_storage_err()?;
+                } else if kv.key == "primary_key" {
+                    primary_key = kv
+                        .value
+                        .as_ref()
+                        .map(|x| from_str::<Vec<String>>(x))
+                        .map_storage_err(Error::StorageMsg(
+                            "No value found on metadata".to_owned(),
+                        ))?
+                        .map_storage_err()?;
                 } else if kv.key.starts_with("foreign_key") {
                     let fk = kv
                         .value
@@ -103,9 +123,12 @@ impl ParquetStorage {
         Ok(Some(Schema {
             table_name: table_name.to_owned(),
             column_defs,
+            primary_key,
             indexes: vec![],
             engine: None,
             foreign_keys,
+            checks,
+            triggers: Vec::new(),
             comment,
         }))
     }
@@ -135,25 +158,31 @@ impl ParquetStorage {
         let mut key_counter: u64 = 0;
 
         if let Some(column_defs) = &fetched_schema.column_defs {
+            let primary_key = primary_key_indexes(column_defs, &fetched_schema.primary_key);
+
             for record in row_iter {
                 let record: Row = record.map_storage_err()?;
                 let mut row = Vec::new();
-                let mut key = None;
 
                 for (idx, (_, field)) in record.get_column_iter().enumerate() {
                     let value = ParquetField(field.clone()).to_value(&fetched_schema, idx)?;
-                    row.push(value.clone());
-
-                    if column_defs[idx].unique == Some(ColumnUniqueOption { is_primary: true }) {
-                        key = Key::try_from(&value).ok();
-                    }
+                    row.push(value);
                 }
 
-                let generated_key = key.unwrap_or_else(|| {
-                    let generated = Key::U64(key_counter);
-                    key_counter += 1;
-                    generated
-                });
+                let keys = primary_key
+                    .iter()
+                    .filter_map(|idx| row.get(*idx))
+                    .filter_map(|value| Key::try_from(value).ok())
+                    .collect::<Vec<_>>();
+
+                let generated_key = match keys.is_empty() {
+                    false => Key::from_primary_keys(keys),
+                    true => {
+                        let generated = Key::U64(key_counter);
+                        key_counter += 1;
+                        generated
+                    }
+                };
                 rows.push(Ok((generated_key, DataRow::Vec(row))));
             }
         } else {
@@ -187,14 +216,23 @@ impl ParquetStorage {
                 nullable: true,
                 default: None,
                 unique: None,
+                check: None,
+                identity: None,
                 comment: None,
             }]),
+            primary_key: Vec::new(),
             indexes: vec![],
             engine: None,
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            triggers: Vec::new(),
             comment: None,
         }
     }
 }
 
 impl Metadata for ParquetStorage {}
+impl gluesql_core::store::View for ParquetStorage {}
+impl gluesql_core::store::ViewMut for ParquetStorage {}
+impl gluesql_core::store::Sequence for ParquetStorage {}
+impl gluesql_core::store::SequenceMut for ParquetStorage {}
@@ -166,7 +166,7 @@ where
 }
 
 impl ParquetStorage {
-    fn rewrite(&mut self, schema: Schema, rows: Vec<DataRow>) -> Result<()> {
+    pub(crate) fn rewrite(&mut self, schema: Schema, rows: Vec<DataRow>) -> Result<()> {
         let parquet_path = self.data_path(&schema.table_name);
         let file = File::create(parquet_path).map_storage_err()?;
         self.write(schema, rows, file)
@@ -445,6 +445,8 @@ impl ParquetStorage {
                     nullable: true,
                     default: None,
                     unique: None,
+                    check: None,
+                    identity: None,
                     comment: None,
                 }]
             }
@@ -512,6 +514,13 @@ impl ParquetStorage {
                     });
                 }
 
+                if let Some(check) = &column_def.check {
+                    metadata.push(KeyValue {
+                        key: format!("check_{}", column_def.name),
+                        value: Some(ToSql::to_sql(check)),
+                    });
+                }
+
                 if let Some(comment) = &column_def.comment {
                     metadata.push(KeyValue {
                         key: format!("comment_{}", column_def.name),
@@ -539,6 +548,20 @@ impl ParquetStorage {
             });
         }
 
+        if !schema.primary_key.is_empty() {
+            metadata.push(KeyValue {
+                key: "primary_key".to_owned(),
+                value: Some(serde_json::to_string(&schema.primary_key).map_storage_err()?),
+            });
+        }
+
+        if !schema.checks.is_empty() {
+            metadata.push(KeyValue {
+                key: "checks".to_owned(),
+                value: Some(serde_json::to_string(&schema.checks).map_storage_err()?),
+            });
+        }
+
         if schema.comment.is_some() {
             metadata.push(KeyValue {
                 key: "comment".to_owned(),
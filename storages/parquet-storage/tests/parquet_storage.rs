@@ -27,3 +27,10 @@ impl Tester<ParquetStorage> for ParquetTester {
 }
 
 generate_store_tests!(tokio::test, ParquetTester);
+
+declare_test_fn!(
+    tokio::test,
+    ParquetTester,
+    alter_table_alter_column,
+    alter::alter_table_alter_column
+);
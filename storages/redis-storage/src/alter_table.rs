@@ -5,7 +5,7 @@ use {
         ast::ColumnDef,
         data::Value,
         error::{AlterTableError, Error, Result},
-        store::{AlterTable, DataRow, Store},
+        store::{convert_column_value, AlterTable, DataRow, Store},
     },
     redis::Commands,
 };
@@ -65,6 +65,11 @@ impl AlterTable for RedisStorage {
                 .ok_or(AlterTableError::RenamingColumnNotFound)?;
 
             new_column_name.clone_into(&mut column_def.name);
+            schema
+                .primary_key
+                .iter_mut()
+                .filter(|name| *name == old_column_name)
+                .for_each(|name| new_column_name.clone_into(name));
 
             self.redis_delete_schema(table_name)?;
             self.redis_store_schema(&schema)?;
@@ -251,4 +256,92 @@ impl AlterTable for RedisStorage {
 
         Ok(())
     }
+
+    async fn alter_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let mut schema = self
+            .fetch_schema(table_name)
+            .await?
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        let column_defs = schema
+            .column_defs
+            .as_mut()
+            .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()))?;
+
+        let column_index = column_defs
+            .iter()
+            .position(|ColumnDef { name, .. }| name == &column_def.name)
+            .ok_or_else(|| AlterTableError::AlteringColumnNotFound(column_def.name.to_owned()))?;
+
+        let scan_key = Self::redis_generate_scankey(&self.namespace, table_name);
+        let key_iter: Vec<String> = self
+            .conn
+            .borrow_mut()
+            .scan_match(&scan_key)
+            .map(|iter| iter.collect::<Vec<String>>())
+            .map_err(|_| {
+                Error::StorageMsg(format!(
+                    "[RedisStorage] failed to execute SCAN: key={}",
+                    scan_key
+                ))
+            })?;
+
+        // every row is converted before anything is written, a failed conversion changes nothing
+        let mut rows = Vec::with_capacity(key_iter.len());
+        for key in key_iter {
+            let value = redis::cmd("GET")
+                .arg(&key)
+                .query::<String>(&mut self.conn.borrow_mut())
+                .map_err(|_| {
+                    Error::StorageMsg(format!("[RedisStorage] failed to execute GET: key={}", key))
+                })?;
+
+            let mut row: DataRow = serde_json::from_str(&value).map_err(|e| {
+                Error::StorageMsg(format!(
+                    "[RedisStorage] failed to deserialize value={} error={}",
+                    value, e
+                ))
+            })?;
+            match &mut row {
+                DataRow::Vec(values) => {
+                    if let Some(value) = values.get_mut(column_index) {
+                        *value = convert_column_value(value, column_def)?;
+                    }
+                }
+                DataRow::Map(_) => {
+                    return Err(Error::StorageMsg(
+                        "[RedisStorage] conflict - alter_column failed: schemaless row found"
+                            .to_owned(),
+                    ));
+                }
+            }
+
+            rows.push((key, row));
+        }
+
+        for (key, row) in rows {
+            let new_value = serde_json::to_string(&row).map_err(|_e| {
+                Error::StorageMsg(format!(
+                    "[RedisStorage] failed to serialize row={:?} error={}",
+                    row, _e
+                ))
+            })?;
+            let _: () = redis::cmd("SET")
+                .arg(&key)
+                .arg(new_value)
+                .query(&mut self.conn.borrow_mut())
+                .map_err(|_| {
+                    Error::StorageMsg(format!(
+                        "[RedisStorage] alter_column: failed to execute SET for row={:?}",
+                        row
+                    ))
+                })?;
+        }
+
+        column_defs[column_index] = column_def.clone();
+        self.redis_delete_schema(table_name)?;
+        self.redis_store_schema(&schema)?;
+
+        Ok(())
+    }
 }
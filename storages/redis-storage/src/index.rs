@@ -18,7 +18,9 @@ impl Index for RedisStorage {
         _table_name: &str,
         _index_name: &str,
         _asc: Option<bool>,
+        _prefix: &[Value],
         _cmp_value: Option<(&IndexOperator, Value)>,
+        _upper_cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
         Err(Error::StorageMsg(
             "[RedisStorage] index is not supported".to_owned(),
@@ -32,7 +34,7 @@ impl IndexMut for RedisStorage {
         &mut self,
         _table_name: &str,
         _index_name: &str,
-        _column: &OrderByExpr,
+        _columns: &[OrderByExpr],
     ) -> Result<()> {
         Err(Error::StorageMsg(
             "[RedisStorage] index is not supported".to_owned(),
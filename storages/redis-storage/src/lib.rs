@@ -228,6 +228,11 @@ impl CustomFunctionMut for RedisStorage {
     }
 }
 
+impl gluesql_core::store::View for RedisStorage {}
+impl gluesql_core::store::ViewMut for RedisStorage {}
+impl gluesql_core::store::Sequence for RedisStorage {}
+impl gluesql_core::store::SequenceMut for RedisStorage {}
+
 #[async_trait(?Send)]
 impl Store for RedisStorage {
     async fn fetch_all_schemas(&self) -> Result<Vec<Schema>> {
@@ -1,7 +1,11 @@
 use {
     super::SharedMemoryStorage,
     async_trait::async_trait,
-    gluesql_core::{ast::ColumnDef, error::Result, store::AlterTable},
+    gluesql_core::{
+        ast::{ColumnDef, Trigger},
+        error::Result,
+        store::AlterTable,
+    },
     std::sync::Arc,
 };
 
@@ -48,4 +52,25 @@ impl AlterTable for SharedMemoryStorage {
             .drop_column(table_name, column_name, if_exists)
             .await
     }
+
+    async fn alter_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.alter_column(table_name, column_def).await
+    }
+
+    async fn create_trigger(&mut self, table_name: &str, trigger: &Trigger) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.create_trigger(table_name, trigger).await
+    }
+
+    async fn drop_trigger(&mut self, table_name: &str, trigger_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.drop_trigger(table_name, trigger_name).await
+    }
 }
@@ -1,26 +1,43 @@
 use {
     super::SharedMemoryStorage,
     async_trait::async_trait,
+    futures::stream,
     gluesql_core::{
         ast::{IndexOperator, OrderByExpr},
         data::Value,
-        error::{Error, Result},
+        error::Result,
         store::{Index, IndexMut, RowIter},
     },
+    std::sync::Arc,
 };
 
 #[async_trait(?Send)]
 impl Index for SharedMemoryStorage {
     async fn scan_indexed_data(
         &self,
-        _table_name: &str,
-        _index_name: &str,
-        _asc: Option<bool>,
-        _cmp_value: Option<(&IndexOperator, Value)>,
+        table_name: &str,
+        index_name: &str,
+        asc: Option<bool>,
+        prefix: &[Value],
+        cmp_value: Option<(&IndexOperator, Value)>,
+        upper_cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+        let rows = self
+            .database
+            .read()
+            .await
+            .scan_indexed_data(
+                table_name,
+                index_name,
+                asc,
+                prefix,
+                cmp_value,
+                upper_cmp_value,
+            )?
+            .into_iter()
+            .map(Ok);
+
+        Ok(Box::pin(stream::iter(rows)))
     }
 }
 
@@ -28,18 +45,20 @@ impl Index for SharedMemoryStorage {
 impl IndexMut for SharedMemoryStorage {
     async fn create_index(
         &mut self,
-        _table_name: &str,
-        _index_name: &str,
-        _column: &OrderByExpr,
+        table_name: &str,
+        index_name: &str,
+        columns: &[OrderByExpr],
     ) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.create_index(table_name, index_name, columns).await
     }
 
-    async fn drop_index(&mut self, _table_name: &str, _index_name: &str) -> Result<()> {
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned(),
-        ))
+    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.drop_index(table_name, index_name).await
     }
 }
@@ -8,9 +8,12 @@ use {
     async_trait::async_trait,
     futures::stream,
     gluesql_core::{
-        data::{Key, Schema},
+        data::{Key, Schema, Sequence as StructSequence, View as StructView},
         error::Result,
-        store::{DataRow, Metadata, RowIter, Store, StoreMut},
+        store::{
+            DataRow, KeyRange, Metadata, RowIter, Sequence, SequenceMut, Store, StoreMut, View,
+            ViewMut,
+        },
     },
     gluesql_memory_storage::MemoryStorage,
     std::sync::Arc,
@@ -77,6 +80,23 @@ impl Store for SharedMemoryStorage {
 
         Ok(Box::pin(stream::iter(rows)))
     }
+
+    async fn scan_data_range(
+        &self,
+        table_name: &str,
+        range: KeyRange,
+        asc: bool,
+    ) -> Result<RowIter<'_>> {
+        let rows = self
+            .database
+            .read()
+            .await
+            .scan_data_range(table_name, range, asc)
+            .into_iter()
+            .map(Ok);
+
+        Ok(Box::pin(stream::iter(rows)))
+    }
 }
 
 #[async_trait(?Send)]
@@ -120,3 +140,71 @@ impl StoreMut for SharedMemoryStorage {
 impl Metadata for SharedMemoryStorage {}
 impl gluesql_core::store::CustomFunction for SharedMemoryStorage {}
 impl gluesql_core::store::CustomFunctionMut for SharedMemoryStorage {}
+
+#[async_trait(?Send)]
+impl View for SharedMemoryStorage {
+    async fn fetch_view(&self, view_name: &str) -> Result<Option<StructView>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_view(view_name).await
+    }
+
+    async fn fetch_all_views(&self) -> Result<Vec<StructView>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_all_views().await
+    }
+}
+
+#[async_trait(?Send)]
+impl ViewMut for SharedMemoryStorage {
+    async fn insert_view(&mut self, view: StructView) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_view(view).await
+    }
+
+    async fn delete_view(&mut self, view_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_view(view_name).await
+    }
+}
+
+#[async_trait(?Send)]
+impl Sequence for SharedMemoryStorage {
+    async fn fetch_sequence(&self, sequence_name: &str) -> Result<Option<StructSequence>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_sequence(sequence_name).await
+    }
+
+    async fn fetch_all_sequences(&self) -> Result<Vec<StructSequence>> {
+        let database = Arc::clone(&self.database);
+        let database = database.read().await;
+
+        database.fetch_all_sequences().await
+    }
+}
+
+#[async_trait(?Send)]
+impl SequenceMut for SharedMemoryStorage {
+    async fn insert_sequence(&mut self, sequence: StructSequence) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.insert_sequence(sequence).await
+    }
+
+    async fn delete_sequence(&mut self, sequence_name: &str) -> Result<()> {
+        let database = Arc::clone(&self.database);
+        let mut database = database.write().await;
+
+        database.delete_sequence(sequence_name).await
+    }
+}
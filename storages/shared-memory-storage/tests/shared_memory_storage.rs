@@ -25,6 +25,16 @@ generate_store_tests!(tokio::test, SharedMemoryTester);
 
 generate_alter_table_tests!(tokio::test, SharedMemoryTester);
 
+generate_view_tests!(tokio::test, SharedMemoryTester);
+
+generate_sequence_tests!(tokio::test, SharedMemoryTester);
+
+generate_trigger_tests!(tokio::test, SharedMemoryTester);
+
+generate_index_tests!(tokio::test, SharedMemoryTester);
+
+generate_alter_table_index_tests!(tokio::test, SharedMemoryTester);
+
 macro_rules! exec {
     ($glue: ident $sql: literal) => {
         $glue.execute($sql).await.unwrap();
@@ -40,8 +50,8 @@ macro_rules! test {
 #[tokio::test]
 async fn shared_memory_storage_index() {
     use gluesql_core::{
-        error::Error,
-        prelude::Glue,
+        error::IndexError,
+        prelude::{Glue, Payload, Value},
         store::{Index, Store},
     };
 
@@ -61,24 +71,35 @@ async fn shared_memory_storage_index() {
 
     assert_eq!(
         storage
-            .scan_indexed_data("Idx", "hello", None, None)
+            .scan_indexed_data("Idx", "hello", None, &[], None, None)
             .await
             .map(|_| ()),
-        Err(Error::StorageMsg(
-            "[Shared MemoryStorage] index is not supported".to_owned()
-        ))
+        Err(IndexError::TableNotFound("Idx".to_owned()).into())
     );
 
     let mut glue = Glue::new(storage);
 
     exec!(glue "CREATE TABLE Idx (id INTEGER);");
+    exec!(glue "INSERT INTO Idx VALUES (1), (2);");
     test!(
         glue "CREATE INDEX idx_id ON Idx (id);",
-        Err(Error::StorageMsg("[Shared MemoryStorage] index is not supported".to_owned()))
+        Ok(vec![Payload::CreateIndex])
+    );
+    exec!(glue "DELETE FROM Idx WHERE id = 1;");
+    test!(
+        glue "SELECT id FROM Idx WHERE id >= 1",
+        Ok(vec![Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: vec![vec![Value::I64(2)]],
+        }])
+    );
+    test!(
+        glue "DROP INDEX Idx.idx_id;",
+        Ok(vec![Payload::DropIndex])
     );
     test!(
         glue "DROP INDEX Idx.idx_id;",
-        Err(Error::StorageMsg("[Shared MemoryStorage] index is not supported".to_owned()))
+        Err(IndexError::IndexNameDoesNotExist("idx_id".to_owned()).into())
     );
 }
 
@@ -9,11 +9,11 @@ use {
     async_io::block_on,
     async_trait::async_trait,
     gluesql_core::{
-        ast::ColumnDef,
+        ast::{ColumnDef, Trigger},
         data::{schema::Schema, Value},
         error::{AlterTableError, Error, Result},
         executor::evaluate_stateless,
-        store::{AlterTable, DataRow},
+        store::{convert_column_value, AlterTable, DataRow, Store, StoreMut},
     },
     sled::transaction::ConflictableTransactionError,
     std::{iter::once, str},
@@ -49,9 +49,12 @@ impl AlterTable for SledStorage {
             let (old_snapshot, old_schema) = schema_snapshot.delete(txid);
             let Schema {
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
                 ..
             } = old_schema
@@ -61,9 +64,12 @@ impl AlterTable for SledStorage {
             let new_schema = Schema {
                 table_name: new_table_name.to_owned(),
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             };
 
@@ -161,9 +167,12 @@ impl AlterTable for SledStorage {
 
             let Schema {
                 column_defs,
+                mut primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment: schema_comment,
                 ..
             } = snapshot
@@ -195,6 +204,8 @@ impl AlterTable for SledStorage {
                 nullable,
                 default,
                 unique,
+                check,
+                identity,
                 comment,
                 ..
             } = column_defs[i].clone();
@@ -205,16 +216,25 @@ impl AlterTable for SledStorage {
                 nullable,
                 default,
                 unique,
+                check,
+                identity,
                 comment,
             };
             let column_defs = Vector::from(column_defs).update(i, column_def).into();
+            primary_key
+                .iter_mut()
+                .filter(|name| *name == old_column_name)
+                .for_each(|name| new_column_name.clone_into(name));
 
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs: Some(column_defs),
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment: schema_comment,
             };
             let (snapshot, _) = snapshot.update(txid, schema);
@@ -266,9 +286,12 @@ impl AlterTable for SledStorage {
             let Schema {
                 table_name,
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             } = schema_snapshot
                 .get(txid, None)
@@ -365,9 +388,12 @@ impl AlterTable for SledStorage {
             let schema = Schema {
                 table_name,
                 column_defs: Some(column_defs),
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
@@ -422,9 +448,12 @@ impl AlterTable for SledStorage {
             let Schema {
                 table_name,
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             } = schema_snapshot
                 .get(txid, None)
@@ -504,9 +533,12 @@ impl AlterTable for SledStorage {
             let schema = Schema {
                 table_name,
                 column_defs: Some(column_defs),
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             };
             let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
@@ -528,4 +560,162 @@ impl AlterTable for SledStorage {
 
         Ok(())
     }
+
+    async fn alter_column(&mut self, table_name: &str, column_def: &ColumnDef) -> Result<()> {
+        let prefix = format!("data/{}/", table_name);
+        let items = self
+            .tree
+            .scan_prefix(prefix.as_bytes())
+            .map(|item| item.map_err(err_into))
+            .collect::<Result<Vec<_>>>()?;
+
+        let state = &self.state;
+        let tx_timeout = self.tx_timeout;
+        let tx_result = self.tree.transaction(move |tree| {
+            let (txid, autocommit) = match lock::acquire(tree, state, tx_timeout)? {
+                LockAcquired::Success { txid, autocommit } => (txid, autocommit),
+                LockAcquired::RollbackAndRetry { lock_txid } => {
+                    return Ok(TxPayload::RollbackAndRetry(lock_txid));
+                }
+            };
+
+            let (schema_key, schema_snapshot) = fetch_schema(tree, table_name)?;
+            let schema_snapshot = schema_snapshot
+                .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let Schema {
+                table_name,
+                column_defs,
+                primary_key,
+                indexes,
+                engine,
+                foreign_keys,
+                checks,
+                triggers,
+                comment,
+            } = schema_snapshot
+                .get(txid, None)
+                .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let column_defs = column_defs
+                .ok_or_else(|| AlterTableError::SchemalessTableFound(table_name.to_owned()).into())
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            let column_index = column_defs
+                .iter()
+                .position(|ColumnDef { name, .. }| name == &column_def.name)
+                .ok_or_else(|| {
+                    AlterTableError::AlteringColumnNotFound(column_def.name.to_owned()).into()
+                })
+                .map_err(ConflictableTransactionError::Abort)?;
+
+            // migrate data
+            for (key, snapshot) in items.iter() {
+                let snapshot: Snapshot<DataRow> = bincode::deserialize(snapshot)
+                    .map_err(err_into)
+                    .map_err(ConflictableTransactionError::Abort)?;
+                let row = match snapshot.clone().extract(txid, None) {
+                    Some(row) => row,
+                    None => {
+                        continue;
+                    }
+                };
+
+                let values = match row {
+                    DataRow::Vec(values) => values,
+                    DataRow::Map(_) => {
+                        return Err(ConflictableTransactionError::Abort(Error::StorageMsg(
+                            "conflict - alter_column failed: schemaless row found".to_owned(),
+                        )));
+                    }
+                };
+
+                let row = values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, v)| match i == column_index {
+                        true => convert_column_value(&v, column_def),
+                        false => Ok(v),
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .map_err(ConflictableTransactionError::Abort)?
+                    .into();
+
+                let (snapshot, _) = snapshot.update(txid, row);
+                let snapshot = bincode::serialize(&snapshot)
+                    .map_err(err_into)
+                    .map_err(ConflictableTransactionError::Abort)?;
+
+                tree.insert(key, snapshot)?;
+
+                if !autocommit {
+                    let temp_key = key::temp_data(txid, key);
+
+                    tree.insert(temp_key, key)?;
+                }
+            }
+
+            // update schema
+            let column_defs = Vector::from(column_defs)
+                .update(column_index, column_def.clone())
+                .into();
+
+            let temp_key = key::temp_schema(txid, &table_name);
+
+            let schema = Schema {
+                table_name,
+                column_defs: Some(column_defs),
+                primary_key,
+                indexes,
+                engine,
+                foreign_keys,
+                checks,
+                triggers,
+                comment,
+            };
+            let (schema_snapshot, _) = schema_snapshot.update(txid, schema);
+            let schema_value = bincode::serialize(&schema_snapshot)
+                .map_err(err_into)
+                .map_err(ConflictableTransactionError::Abort)?;
+            tree.insert(schema_key.as_bytes(), schema_value)?;
+
+            if !autocommit {
+                tree.insert(temp_key, schema_key.as_bytes())?;
+            }
+
+            Ok(TxPayload::Success)
+        });
+
+        if self.check_retry(tx_result)? {
+            self.alter_column(table_name, column_def).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn create_trigger(&mut self, table_name: &str, trigger: &Trigger) -> Result<()> {
+        let mut schema = self
+            .fetch_schema(table_name)
+            .await?
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        schema.triggers.push(trigger.clone());
+
+        self.insert_schema(&schema).await
+    }
+
+    async fn drop_trigger(&mut self, table_name: &str, trigger_name: &str) -> Result<()> {
+        let mut schema = self
+            .fetch_schema(table_name)
+            .await?
+            .ok_or_else(|| AlterTableError::TableNotFound(table_name.to_owned()))?;
+
+        schema
+            .triggers
+            .retain(|Trigger { name, .. }| name != trigger_name);
+
+        self.insert_schema(&schema).await
+    }
 }
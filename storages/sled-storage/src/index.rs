@@ -1,7 +1,7 @@
 use {
     super::{
         err_into,
-        index_sync::{build_index_key, build_index_key_prefix},
+        index_sync::{build_index_key, build_index_key_prefix, encode_composite_values},
         lock, SledStorage, Snapshot, State,
     },
     async_trait::async_trait,
@@ -10,11 +10,14 @@ use {
         ast::IndexOperator,
         data::{Key, Value},
         error::{Error, IndexError, Result},
-        store::{DataRow, Index, RowIter},
+        store::{DataRow, Index, RowIter, Store},
     },
     iter_enum::{DoubleEndedIterator, Iterator},
     sled::IVec,
-    std::iter::{empty, once},
+    std::{
+        iter::{empty, once},
+        ops::Bound,
+    },
     utils::Vector,
 };
 
@@ -25,8 +28,22 @@ impl Index for SledStorage {
         table_name: &str,
         index_name: &str,
         asc: Option<bool>,
+        prefix: &[Value],
         cmp_value: Option<(&IndexOperator, Value)>,
+        upper_cmp_value: Option<(&IndexOperator, Value)>,
     ) -> Result<RowIter> {
+        let composite = self
+            .fetch_schema(table_name)
+            .await?
+            .and_then(|schema| {
+                schema
+                    .indexes
+                    .into_iter()
+                    .find(|index| index.name == index_name)
+            })
+            .map(|index| index.exprs.len() > 1)
+            .unwrap_or(false);
+
         let data_keys = {
             #[derive(Iterator, DoubleEndedIterator)]
             enum DataIds<I1, I2, I3, I4> {
@@ -38,30 +55,79 @@ impl Index for SledStorage {
 
             let map = |item: std::result::Result<_, _>| item.map(|(_, v)| v);
 
-            match cmp_value {
-                None => {
+            match (cmp_value, upper_cmp_value) {
+                (Some((lower_op, lower_value)), Some((upper_op, upper_value))) => {
+                    let build_key = |value: Value| -> Result<Vec<u8>> {
+                        match composite {
+                            true => Ok(build_index_key_prefix(table_name, index_name)
+                                .into_iter()
+                                .chain(encode_composite_values(prefix)?)
+                                .chain(encode_composite_values(&[value])?)
+                                .collect()),
+                            false => build_index_key(table_name, index_name, &[value]),
+                        }
+                    };
+                    let lower_key = build_key(lower_value)?;
+                    let upper_key = build_key(upper_value)?;
+
+                    // composite keys continue after the compared value, so the whole group of
+                    // keys sharing the value is skipped or kept using `incr`
+                    let lower = match (lower_op, composite) {
+                        (IndexOperator::Gt, true) => Bound::Included(incr(lower_key)),
+                        (IndexOperator::Gt, false) => Bound::Excluded(lower_key),
+                        _ => Bound::Included(lower_key),
+                    };
+                    let upper = match (upper_op, composite) {
+                        (IndexOperator::Lt, _) => Bound::Excluded(upper_key),
+                        (_, true) => Bound::Excluded(incr(upper_key)),
+                        (_, false) => Bound::Included(upper_key),
+                    };
+
+                    match is_empty_range(&lower, &upper) {
+                        true => DataIds::Empty(empty()),
+                        false => DataIds::Range(self.tree.range((lower, upper)).map(map)),
+                    }
+                }
+                (None, _) if prefix.is_empty() => {
                     let prefix = build_index_key_prefix(table_name, index_name);
 
                     DataIds::Full(self.tree.scan_prefix(prefix).map(map))
                 }
-                Some((op, value)) => {
-                    let incr = |key: Vec<u8>| {
-                        key.into_iter()
-                            .rev()
-                            .fold((false, Vector::new()), |(added, upper), v| {
-                                match (added, v) {
-                                    (true, _) => (added, upper.push(v)),
-                                    (false, u8::MAX) => (added, upper.push(v)),
-                                    (false, _) => (true, upper.push(v + 1)),
-                                }
-                            })
-                            .1
-                            .reverse()
-                            .into()
+                (None, _) => {
+                    let lower = build_index_key_prefix(table_name, index_name)
+                        .into_iter()
+                        .chain(encode_composite_values(prefix)?)
+                        .collect::<Vec<_>>();
+                    let upper = incr(lower.clone());
+
+                    DataIds::Range(self.tree.range(lower..upper).map(map))
+                }
+                (Some((op, value)), None) if composite => {
+                    let lower = build_index_key_prefix(table_name, index_name)
+                        .into_iter()
+                        .chain(encode_composite_values(prefix)?)
+                        .collect::<Vec<_>>();
+                    let upper = incr(lower.clone());
+                    let key = lower
+                        .iter()
+                        .copied()
+                        .chain(encode_composite_values(&[value])?)
+                        .collect::<Vec<_>>();
+
+                    let range = match op {
+                        IndexOperator::Eq => self.tree.range(key.clone()..incr(key)),
+                        IndexOperator::Gt => self.tree.range(incr(key)..upper),
+                        IndexOperator::GtEq => self.tree.range(key..upper),
+                        IndexOperator::Lt => self.tree.range(lower..key),
+                        IndexOperator::LtEq => self.tree.range(lower..incr(key)),
                     };
+
+                    DataIds::Range(range.map(map))
+                }
+                (Some((op, value)), None) => {
                     let lower = || build_index_key_prefix(table_name, index_name);
                     let upper = || incr(build_index_key_prefix(table_name, index_name));
-                    let key = build_index_key(table_name, index_name, value)?;
+                    let key = build_index_key(table_name, index_name, &[value])?;
 
                     match op {
                         IndexOperator::Eq => match self.tree.get(&key).transpose() {
@@ -155,3 +221,29 @@ impl Index for SledStorage {
         })
     }
 }
+
+fn is_empty_range(lower: &Bound<Vec<u8>>, upper: &Bound<Vec<u8>>) -> bool {
+    match (lower, upper) {
+        (Bound::Included(lower), Bound::Included(upper)) => lower > upper,
+        (
+            Bound::Included(lower) | Bound::Excluded(lower),
+            Bound::Included(upper) | Bound::Excluded(upper),
+        ) => lower >= upper,
+        _ => false,
+    }
+}
+
+pub(crate) fn incr(key: Vec<u8>) -> Vec<u8> {
+    key.into_iter()
+        .rev()
+        .fold((false, Vector::new()), |(added, upper), v| {
+            match (added, v) {
+                (true, _) => (added, upper.push(v)),
+                (false, u8::MAX) => (added, upper.push(v)),
+                (false, _) => (true, upper.push(v + 1)),
+            }
+        })
+        .1
+        .reverse()
+        .into()
+}
@@ -44,7 +44,7 @@ impl IndexMut for SledStorage {
         &mut self,
         table_name: &str,
         index_name: &str,
-        column: &OrderByExpr,
+        columns: &[OrderByExpr],
     ) -> Result<()> {
         let rows = self
             .scan_data(table_name)
@@ -62,7 +62,10 @@ impl IndexMut for SledStorage {
                 }
             };
 
-            let index_expr = &column.expr;
+            let index_exprs = columns
+                .iter()
+                .map(|OrderByExpr { expr, .. }| expr.clone())
+                .collect::<Vec<_>>();
 
             let (schema_key, schema_snapshot) = fetch_schema(tree, table_name)?;
             let schema_snapshot = schema_snapshot
@@ -72,9 +75,12 @@ impl IndexMut for SledStorage {
             let (schema_snapshot, schema) = schema_snapshot.delete(txid);
             let Schema {
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
                 ..
             } = schema
@@ -89,7 +95,7 @@ impl IndexMut for SledStorage {
 
             let index = SchemaIndex {
                 name: index_name.to_owned(),
-                expr: index_expr.clone(),
+                exprs: index_exprs,
                 order: SchemaIndexOrd::Both,
                 created: Utc::now().naive_utc(),
             };
@@ -102,9 +108,12 @@ impl IndexMut for SledStorage {
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             };
 
@@ -137,7 +146,7 @@ impl IndexMut for SledStorage {
         });
 
         if self.check_retry(tx_result)? {
-            self.create_index(table_name, index_name, column).await?;
+            self.create_index(table_name, index_name, columns).await?;
         }
 
         Ok(())
@@ -168,9 +177,12 @@ impl IndexMut for SledStorage {
             let (schema_snapshot, schema) = schema_snapshot.delete(txid);
             let Schema {
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
                 ..
             } = schema
@@ -193,9 +205,12 @@ impl IndexMut for SledStorage {
             let schema = Schema {
                 table_name: table_name.to_owned(),
                 column_defs,
+                primary_key,
                 indexes,
                 engine,
                 foreign_keys,
+                checks,
+                triggers,
                 comment,
             };
 
@@ -105,14 +105,14 @@ impl<'a> IndexSync<'a> {
     ) -> ConflictableTransactionResult<(), Error> {
         let SchemaIndex {
             name: index_name,
-            expr: index_expr,
+            exprs: index_exprs,
             ..
         } = index;
 
         let index_key = &evaluate_index_key(
             self.table_name,
             index_name,
-            index_expr,
+            index_exprs,
             self.columns.as_deref(),
             row,
         )
@@ -132,14 +132,14 @@ impl<'a> IndexSync<'a> {
         for index in self.indexes.iter() {
             let SchemaIndex {
                 name: index_name,
-                expr: index_expr,
+                exprs: index_exprs,
                 ..
             } = index;
 
             let old_index_key = &evaluate_index_key(
                 self.table_name,
                 index_name,
-                index_expr,
+                index_exprs,
                 self.columns.as_deref(),
                 old_row,
             )
@@ -148,7 +148,7 @@ impl<'a> IndexSync<'a> {
             let new_index_key = &evaluate_index_key(
                 self.table_name,
                 index_name,
-                index_expr,
+                index_exprs,
                 self.columns.as_deref(),
                 new_row,
             )
@@ -181,14 +181,14 @@ impl<'a> IndexSync<'a> {
     ) -> ConflictableTransactionResult<(), Error> {
         let SchemaIndex {
             name: index_name,
-            expr: index_expr,
+            exprs: index_exprs,
             ..
         } = index;
 
         let index_key = &evaluate_index_key(
             self.table_name,
             index_name,
-            index_expr,
+            index_exprs,
             self.columns.as_deref(),
             row,
         )
@@ -270,28 +270,61 @@ impl<'a> IndexSync<'a> {
 async fn evaluate_index_key(
     table_name: &str,
     index_name: &str,
-    index_expr: &Expr,
+    index_exprs: &[Expr],
     columns: Option<&[String]>,
     row: &DataRow,
 ) -> ConflictableTransactionResult<Vec<u8>, Error> {
-    let context = Some(row.as_context(columns));
-    let evaluated = evaluate_stateless(context, index_expr)
-        .await
-        .map_err(ConflictableTransactionError::Abort)?;
-    let value: Value = evaluated
-        .try_into()
-        .map_err(ConflictableTransactionError::Abort)?;
-
-    build_index_key(table_name, index_name, value).map_err(ConflictableTransactionError::Abort)
+    let mut values = Vec::with_capacity(index_exprs.len());
+
+    for index_expr in index_exprs {
+        let context = Some(row.as_context(columns));
+        let evaluated = evaluate_stateless(context, index_expr)
+            .await
+            .map_err(ConflictableTransactionError::Abort)?;
+        let value: Value = evaluated
+            .try_into()
+            .map_err(ConflictableTransactionError::Abort)?;
+
+        values.push(value);
+    }
+
+    build_index_key(table_name, index_name, &values).map_err(ConflictableTransactionError::Abort)
 }
 
 pub fn build_index_key_prefix(table_name: &str, index_name: &str) -> Vec<u8> {
     format!("index/{}/{}/", table_name, index_name).into_bytes()
 }
 
-pub fn build_index_key(table_name: &str, index_name: &str, value: Value) -> Result<Vec<u8>> {
+pub fn build_index_key(table_name: &str, index_name: &str, values: &[Value]) -> Result<Vec<u8>> {
+    let value_bytes = match values {
+        [value] => value.to_cmp_be_bytes()?,
+        _ => encode_composite_values(values)?,
+    };
+
     Ok(build_index_key_prefix(table_name, index_name)
         .into_iter()
-        .chain(value.to_cmp_be_bytes()?)
+        .chain(value_bytes)
         .collect::<Vec<_>>())
 }
+
+/// Encodes the values of a composite index key.
+///
+/// Each value has its `0x00` bytes escaped as `0x00 0xFF` and is terminated by `0x00 0x00`,
+/// so that no encoded value is a prefix of another one and the byte order of the encoded key
+/// follows the order of the value tuple.
+pub fn encode_composite_values(values: &[Value]) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    for value in values {
+        for byte in value.to_cmp_be_bytes()? {
+            match byte {
+                0x00 => bytes.extend([0x00, 0xFF]),
+                _ => bytes.push(byte),
+            }
+        }
+
+        bytes.extend([0x00, 0x00]);
+    }
+
+    Ok(bytes)
+}
@@ -3,6 +3,7 @@ use sled::IVec;
 const TEMP_DATA: &str = "temp_data/";
 const TEMP_SCHEMA: &str = "temp_schema/";
 const TEMP_INDEX: &str = "temp_index/";
+const TEMP_SEQUENCE: &str = "temp_sequence/";
 
 pub fn data_prefix(table_name: &str) -> String {
     format!("data/{table_name}/")
@@ -36,6 +37,10 @@ pub fn temp_index_prefix(txid: u64) -> IVec {
     IVec::from_iter(prefix!(txid, TEMP_INDEX))
 }
 
+pub fn temp_sequence_prefix(txid: u64) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_SEQUENCE))
+}
+
 pub fn temp_data(txid: u64, data_key: &IVec) -> IVec {
     IVec::from_iter(prefix!(txid, TEMP_DATA).chain(data_key.iter().copied()))
 }
@@ -51,3 +56,7 @@ pub fn temp_schema(txid: u64, table_name: &str) -> IVec {
 pub fn temp_index(txid: u64, index_key: &[u8]) -> IVec {
     IVec::from_iter(prefix!(txid, TEMP_INDEX).chain(index_key.iter().copied()))
 }
+
+pub fn temp_sequence(txid: u64, sequence_name: &str) -> IVec {
+    IVec::from_iter(prefix!(txid, TEMP_SEQUENCE).chain(sequence_name.as_bytes().iter().copied()))
+}
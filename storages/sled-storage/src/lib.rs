@@ -8,6 +8,7 @@ mod index_mut;
 mod index_sync;
 mod key;
 mod lock;
+mod sequence;
 mod snapshot;
 mod store;
 mod store_mut;
@@ -148,3 +149,5 @@ fn fetch_schema(
 impl Metadata for SledStorage {}
 impl gluesql_core::store::CustomFunction for SledStorage {}
 impl gluesql_core::store::CustomFunctionMut for SledStorage {}
+impl gluesql_core::store::View for SledStorage {}
+impl gluesql_core::store::ViewMut for SledStorage {}
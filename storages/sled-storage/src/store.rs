@@ -1,13 +1,14 @@
 use {
-    super::{err_into, key, lock, SledStorage, Snapshot, State},
+    super::{err_into, index::incr, key, lock, SledStorage, Snapshot, State},
     async_trait::async_trait,
     futures::stream::iter,
     gluesql_core::{
         data::{Key, Schema},
         error::{Error, Result},
-        store::{DataRow, RowIter, Store},
+        store::{DataRow, KeyRange, RowIter, Store},
     },
-    std::str,
+    sled::IVec,
+    std::{ops::Bound, str},
 };
 
 impl SledStorage {
@@ -124,4 +125,62 @@ impl Store for SledStorage {
 
         Ok(Box::pin(iter(result_set)))
     }
+
+    async fn scan_data_range(
+        &self,
+        table_name: &str,
+        range: KeyRange,
+        asc: bool,
+    ) -> Result<RowIter<'_>> {
+        let (txid, created_at) = match self.state {
+            State::Transaction {
+                txid, created_at, ..
+            } => (txid, created_at),
+            State::Idle => {
+                return Err(Error::StorageMsg(
+                    "conflict - scan_data_range failed, lock does not exist".to_owned(),
+                ));
+            }
+        };
+        let lock_txid = lock::fetch(&self.tree, txid, created_at, self.tx_timeout)?;
+
+        let prefix = key::data_prefix(table_name);
+        let prefix_len = prefix.len();
+        let data_key = |bound: Bound<Key>| -> Result<Bound<IVec>> {
+            Ok(match bound {
+                Bound::Included(key) => {
+                    Bound::Included(key::data(table_name, key.to_cmp_be_bytes()?))
+                }
+                Bound::Excluded(key) => {
+                    Bound::Excluded(key::data(table_name, key.to_cmp_be_bytes()?))
+                }
+                Bound::Unbounded => Bound::Unbounded,
+            })
+        };
+
+        let (lower, upper) = range;
+        let lower = match data_key(lower)? {
+            Bound::Unbounded => Bound::Included(IVec::from(prefix.as_bytes())),
+            lower => lower,
+        };
+        let upper = match data_key(upper)? {
+            Bound::Unbounded => Bound::Excluded(IVec::from(incr(prefix.into_bytes()))),
+            upper => upper,
+        };
+
+        let extract = move |item: sled::Result<(IVec, IVec)>| -> Result<Option<(Key, DataRow)>> {
+            let (key, value) = item.map_err(err_into)?;
+            let key = key.subslice(prefix_len, key.len() - prefix_len).to_vec();
+            let snapshot: Snapshot<DataRow> = bincode::deserialize(&value).map_err(err_into)?;
+            let row = snapshot.extract(txid, lock_txid);
+
+            Ok(row.map(|row| (Key::Bytea(key), row)))
+        };
+        let rows = self.tree.range((lower, upper));
+
+        Ok(match asc {
+            true => Box::pin(iter(rows.map(extract).filter_map(Result::transpose))),
+            false => Box::pin(iter(rows.rev().map(extract).filter_map(Result::transpose))),
+        })
+    }
 }
@@ -6,7 +6,7 @@ use {
     },
     async_trait::async_trait,
     gluesql_core::{
-        data::Schema,
+        data::{Schema, Sequence},
         error::{Error, Result},
         store::{DataRow, Transaction},
     },
@@ -169,12 +169,14 @@ impl SledStorage {
 
         let data_items = fetch_items(key::temp_data_prefix(txid))?;
         let schema_items = fetch_items(key::temp_schema_prefix(txid))?;
+        let sequence_items = fetch_items(key::temp_sequence_prefix(txid))?;
         let index_items = fetch_items(key::temp_index_prefix(txid))?;
 
         self.tree
             .transaction(move |tree| {
                 rollback_items::<DataRow>(tree, txid, &data_items)?;
                 rollback_items::<Schema>(tree, txid, &schema_items)?;
+                rollback_items::<Sequence>(tree, txid, &sequence_items)?;
 
                 for (temp_key, value_key) in index_items.iter() {
                     tree.remove(temp_key)?;
@@ -89,6 +89,8 @@ async fn sled_basic() {
 generate_store_tests!(tokio::test, SledTester);
 generate_index_tests!(tokio::test, SledTester);
 generate_transaction_tests!(tokio::test, SledTester);
+generate_sequence_tests!(tokio::test, SledTester);
+generate_trigger_tests!(tokio::test, SledTester);
 generate_alter_table_tests!(tokio::test, SledTester);
 generate_alter_table_index_tests!(tokio::test, SledTester);
 generate_transaction_alter_table_tests!(tokio::test, SledTester);
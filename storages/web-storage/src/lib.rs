@@ -213,3 +213,7 @@ impl Transaction for WebStorage {}
 impl Metadata for WebStorage {}
 impl CustomFunction for WebStorage {}
 impl CustomFunctionMut for WebStorage {}
+impl gluesql_core::store::View for WebStorage {}
+impl gluesql_core::store::ViewMut for WebStorage {}
+impl gluesql_core::store::Sequence for WebStorage {}
+impl gluesql_core::store::SequenceMut for WebStorage {}
@@ -2,8 +2,8 @@ use {
     crate::*,
     gluesql_core::{
         ast::*,
-        data::Value::*,
-        error::{AlterError, AlterTableError, EvaluateError, TranslateError},
+        data::{value::ConvertError, Value::*},
+        error::{AlterError, AlterTableError, EvaluateError, TranslateError, ValueError},
         executor::Referencing,
         prelude::Payload,
     },
@@ -64,6 +64,8 @@ test_case!(alter_table_add_drop, {
                 nullable: false,
                 default: None,
                 unique: None,
+                check: None,
+                identity: None,
                 comment: None,
             })
             .into()),
@@ -255,3 +257,129 @@ test_case!(alter_table_add_drop, {
         g.test(sql, expected).await;
     }
 });
+
+test_case!(alter_table_alter_column, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Foo (id INTEGER PRIMARY KEY, num TEXT, flag BOOLEAN NULL);
+        INSERT INTO Foo VALUES (1, '10', true), (2, '20', NULL);
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "set data type converts existing values",
+        "ALTER TABLE Foo ALTER COLUMN num SET DATA TYPE INTEGER, ALTER COLUMN flag SET DATA TYPE INTEGER;",
+        Ok(Payload::AlterTable),
+    )
+    .await;
+    g.test(
+        "SELECT id, num, flag FROM Foo;",
+        Ok(select_with_null!(
+            id     | num     | flag;
+            I64(1)   I64(10)   I64(1);
+            I64(2)   I64(20)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "set not null fails when existing value is null",
+        "ALTER TABLE Foo ALTER COLUMN flag SET NOT NULL;",
+        Err(ValueError::NullValueOnNotNullField.into()),
+    )
+    .await;
+    g.run("UPDATE Foo SET flag = 0 WHERE id = 2;").await;
+    g.named_test(
+        "set not null",
+        "ALTER TABLE Foo ALTER COLUMN flag SET NOT NULL;",
+        Ok(Payload::AlterTable),
+    )
+    .await;
+    g.test(
+        "INSERT INTO Foo VALUES (3, 30, NULL);",
+        Err(ValueError::NullValueOnNotNullField.into()),
+    )
+    .await;
+
+    g.named_test(
+        "drop not null and set default",
+        "ALTER TABLE Foo ALTER COLUMN flag DROP NOT NULL, ALTER COLUMN flag SET DEFAULT 100;",
+        Ok(Payload::AlterTable),
+    )
+    .await;
+    g.run("INSERT INTO Foo (id, num) VALUES (3, 30);").await;
+    g.named_test(
+        "drop default",
+        "ALTER TABLE Foo ALTER COLUMN flag DROP DEFAULT;",
+        Ok(Payload::AlterTable),
+    )
+    .await;
+    g.run("INSERT INTO Foo (id, num) VALUES (4, 40);").await;
+    g.test(
+        "SELECT id, flag FROM Foo WHERE id > 2;",
+        Ok(select_with_null!(
+            id     | flag;
+            I64(3)   I64(100);
+            I64(4)   Null
+        )),
+    )
+    .await;
+
+    g.run(
+        "
+        ALTER TABLE Foo ALTER COLUMN num SET DATA TYPE TEXT;
+        UPDATE Foo SET num = 'abc' WHERE id = 4;
+    ",
+    )
+    .await;
+    g.named_test(
+        "failed conversion keeps existing values",
+        "ALTER TABLE Foo ALTER COLUMN num SET DATA TYPE INTEGER;",
+        Err(ConvertError {
+            value: Str("abc".to_owned()),
+            data_type: DataType::Int,
+        }
+        .into()),
+    )
+    .await;
+    g.test(
+        "SELECT id, num FROM Foo;",
+        Ok(select!(
+            id  | num
+            I64 | Str;
+            1     "10".to_owned();
+            2     "20".to_owned();
+            3     "30".to_owned();
+            4     "abc".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "primary key column cannot change its data type",
+        "ALTER TABLE Foo ALTER COLUMN id SET DATA TYPE TEXT;",
+        Err(AlterError::CannotAlterPrimaryKeyColumn("id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "primary key column cannot be nullable",
+        "ALTER TABLE Foo ALTER COLUMN id DROP NOT NULL;",
+        Err(AlterError::CannotAlterPrimaryKeyColumn("id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "alter missing column",
+        "ALTER TABLE Foo ALTER COLUMN missing SET NOT NULL;",
+        Err(AlterTableError::AlteringColumnNotFound("missing".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "alter column of missing table",
+        "ALTER TABLE Missing ALTER COLUMN num DROP DEFAULT;",
+        Err(AlterError::TableNotFound("Missing".to_owned()).into()),
+    )
+    .await;
+});
@@ -62,8 +62,8 @@ test_case!(create_table, {
             Err(TranslateError::UnsupportedDataType("GLOBE".to_owned()).into()),
         ),
         (
-            "CREATE TABLE Gluery (id INTEGER CHECK (true));",
-            Err(TranslateError::UnsupportedColumnOption("CHECK (true)".to_owned()).into()),
+            "CREATE TABLE Gluery (id TEXT CHARACTER SET utf8);",
+            Err(TranslateError::UnsupportedColumnOption("CHARACTER SET utf8".to_owned()).into()),
         ),
         (
             "
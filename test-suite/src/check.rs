@@ -0,0 +1,135 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::ValidateError,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(check, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Product (
+            id INTEGER PRIMARY KEY,
+            price INTEGER CHECK (price > 0),
+            status TEXT,
+            discount INTEGER NULL,
+            CONSTRAINT valid_status CHECK (status IN ('active', 'sold_out')),
+            CHECK (discount < price)
+        );
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "rows satisfying every constraint are inserted",
+        "INSERT INTO Product VALUES (1, 100, 'active', 10), (2, 200, 'sold_out', NULL);",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+
+    g.named_test(
+        "column level constraint",
+        "INSERT INTO Product VALUES (3, 0, 'active', NULL);",
+        Err(ValidateError::CheckConstraintViolated(r#""price" > 0"#.to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "named table level constraint",
+        "INSERT INTO Product VALUES (3, 300, 'deleted', NULL);",
+        Err(ValidateError::CheckConstraintViolated("valid_status".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "unnamed table level constraint",
+        "INSERT INTO Product VALUES (3, 300, 'active', 400);",
+        Err(ValidateError::CheckConstraintViolated(r#""discount" < "price""#.to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "constraint evaluated to NULL passes",
+        "INSERT INTO Product VALUES (3, NULL, NULL, NULL);",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+
+    g.named_test(
+        "update violating constraint",
+        "UPDATE Product SET price = price - 150 WHERE id < 3;",
+        Err(ValidateError::CheckConstraintViolated(r#""price" > 0"#.to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "update checks the whole row",
+        "UPDATE Product SET price = 5 WHERE id = 1;",
+        Err(ValidateError::CheckConstraintViolated(r#""discount" < "price""#.to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "update satisfying constraints",
+        "UPDATE Product SET price = 50, status = 'sold_out' WHERE id = 1;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+
+    g.named_test(
+        "ON CONFLICT DO UPDATE is checked",
+        "INSERT INTO Product VALUES (2, 1, 'active', NULL) ON CONFLICT (id) DO UPDATE SET status = 'hidden';",
+        Err(ValidateError::CheckConstraintViolated("valid_status".to_owned()).into()),
+    )
+    .await;
+
+    g.test(
+        "SELECT id, price, status, discount FROM Product ORDER BY id;",
+        Ok(select_with_null!(
+            id     | price     | status                   | discount;
+            I64(1)   I64(50)     Str("sold_out".to_owned())   I64(10);
+            I64(2)   I64(200)    Str("sold_out".to_owned())   Null;
+            I64(3)   Null        Null                         Null
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE Memo (id INTEGER, memo TEXT NULL CHECK (memo IS NOT NULL));")
+        .await;
+    g.named_test(
+        "IS NOT NULL on a NULL column is not taken as NULL",
+        "INSERT INTO Memo VALUES (1, NULL);",
+        Err(ValidateError::CheckConstraintViolated(r#""memo" IS NOT NULL"#.to_owned()).into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE Note (id INTEGER, memo TEXT NULL CHECK (COALESCE(memo, '') <> ''));")
+        .await;
+    g.named_test(
+        "NULL column hidden by COALESCE is not taken as NULL",
+        "INSERT INTO Note VALUES (1, NULL);",
+        Err(
+            ValidateError::CheckConstraintViolated(r#"COALESCE("memo", '') <> ''"#.to_owned())
+                .into(),
+        ),
+    )
+    .await;
+
+    g.run("CREATE TABLE Pair (a INTEGER NULL, b INTEGER NULL, CHECK (a > 0 AND b > 0));")
+        .await;
+    g.named_test(
+        "NULL AND FALSE is FALSE",
+        "INSERT INTO Pair VALUES (NULL, -1);",
+        Err(ValidateError::CheckConstraintViolated(r#""a" > 0 AND "b" > 0"#.to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "NULL AND TRUE is NULL",
+        "INSERT INTO Pair VALUES (NULL, 1);",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+});
@@ -0,0 +1,178 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::SetOperator,
+        error::{FetchError, SelectError},
+        executor::MAX_RECURSION_DEPTH,
+        prelude::*,
+    },
+    Value::*,
+};
+
+test_case!(cte, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER, parent_id INTEGER, name TEXT);")
+        .await;
+    g.run(
+        "
+        INSERT INTO Item VALUES
+            (1, NULL, 'root'),
+            (2, 1, 'a'),
+            (3, 1, 'b'),
+            (4, 2, 'c'),
+            (5, 4, 'd');
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "WITH Leaf AS (SELECT id, name FROM Item WHERE id > 3) SELECT * FROM Leaf",
+            Ok(select!(
+                id  | name;
+                I64 | Str;
+                4     "c".to_owned();
+                5     "d".to_owned()
+            )),
+        ),
+        (
+            "WITH Sub (n, label) AS (SELECT id, name FROM Item WHERE id = 2) SELECT n, label FROM Sub",
+            Ok(select!(
+                n   | label;
+                I64 | Str;
+                2     "a".to_owned()
+            )),
+        ),
+        (
+            "
+            WITH
+                A AS (SELECT id FROM Item WHERE id < 4),
+                B AS (SELECT id FROM A WHERE id > 1)
+            SELECT id FROM B ORDER BY id DESC
+            ",
+            Ok(select!(id; I64; 3; 2)),
+        ),
+        (
+            "
+            WITH Parent AS (SELECT id, name FROM Item WHERE parent_id IS NULL)
+            SELECT Item.name, Parent.name AS parent
+            FROM Item JOIN Parent ON Item.parent_id = Parent.id
+            ORDER BY Item.id
+            ",
+            Ok(select!(
+                name              | parent;
+                Str               | Str;
+                "a".to_owned()      "root".to_owned();
+                "b".to_owned()      "root".to_owned()
+            )),
+        ),
+        (
+            "
+            WITH Target AS (SELECT id FROM Item WHERE name = 'c')
+            SELECT id FROM Item WHERE parent_id IN (SELECT id FROM Target)
+            ",
+            Ok(select!(id; I64; 5)),
+        ),
+        (
+            "WITH Item AS (VALUES (100)) SELECT * FROM Item",
+            Ok(select!(column1; I64; 100)),
+        ),
+        (
+            "WITH Sub AS (SELECT id FROM Item) SELECT COUNT(*) FROM (SELECT * FROM Sub) AS Derived",
+            Ok(select!("COUNT(*)"; I64; 5)),
+        ),
+        (
+            "
+            WITH RECURSIVE Counter (n) AS (
+                SELECT 1
+                UNION ALL
+                SELECT n + 1 FROM Counter WHERE n < 5
+            )
+            SELECT n FROM Counter
+            ",
+            Ok(select!(n; I64; 1; 2; 3; 4; 5)),
+        ),
+        (
+            "
+            WITH RECURSIVE Descendant (id, depth) AS (
+                SELECT id, 0 FROM Item WHERE id = 2
+                UNION ALL
+                SELECT Item.id, Descendant.depth + 1
+                FROM Item JOIN Descendant ON Item.parent_id = Descendant.id
+            )
+            SELECT id, depth FROM Descendant ORDER BY id
+            ",
+            Ok(select!(
+                id  | depth;
+                I64 | I64;
+                2     0;
+                4     1;
+                5     2
+            )),
+        ),
+        (
+            "
+            WITH RECURSIVE Cycle (n) AS (
+                SELECT 0
+                UNION
+                SELECT (n + 1) % 3 FROM Cycle
+            )
+            SELECT n FROM Cycle ORDER BY n
+            ",
+            Ok(select!(n; I64; 0; 1; 2)),
+        ),
+        (
+            "
+            WITH RECURSIVE Counter (n) AS (
+                SELECT 1
+                UNION ALL
+                SELECT n + 1 FROM Counter WHERE n < 3
+            )
+            SELECT a.n AS a, b.n AS b FROM Counter a JOIN Counter b ON a.n + b.n = 4
+            ORDER BY a
+            ",
+            Ok(select!(
+                a   | b;
+                I64 | I64;
+                1     3;
+                2     2;
+                3     1
+            )),
+        ),
+        (
+            "WITH Sub (a, b, c) AS (SELECT id, name FROM Item) SELECT * FROM Sub",
+            Err(FetchError::TooManyColumnAliases("Sub".to_owned(), 2, 3).into()),
+        ),
+        (
+            "
+            WITH RECURSIVE Wrong (n) AS (
+                SELECT 1
+                UNION ALL
+                SELECT n, n FROM Wrong WHERE n < 3
+            )
+            SELECT * FROM Wrong
+            ",
+            Err(SelectError::NumberOfColumnsDifferent(SetOperator::Union).into()),
+        ),
+        (
+            "
+            WITH RECURSIVE Endless (n) AS (
+                SELECT 1
+                UNION ALL
+                SELECT n + 1 FROM Endless
+            )
+            SELECT * FROM Endless
+            ",
+            Err(SelectError::RecursionDepthExceeded(
+                "Endless".to_owned(),
+                MAX_RECURSION_DEPTH,
+            )
+            .into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+});
@@ -69,3 +69,70 @@ test_case!(delete, {
     )
     .await;
 });
+
+test_case!(using, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Orders (id INTEGER PRIMARY KEY, item_id INTEGER);
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, discontinued BOOLEAN);
+        CREATE TABLE Stock (item_id INTEGER, quantity INTEGER);
+        INSERT INTO Orders VALUES (1, 10), (2, 20), (3, 30), (4, 20);
+        INSERT INTO Item VALUES (10, FALSE), (20, TRUE), (30, TRUE);
+        INSERT INTO Stock VALUES (20, 0), (30, 5);
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "delete rows matched by joined rows",
+        "
+        DELETE FROM Orders USING Item AS i
+        WHERE Orders.item_id = i.id AND i.discontinued = TRUE
+        RETURNING id
+        ",
+        Ok(select!(
+            id
+            I64;
+            2;
+            3;
+            4
+        )),
+    )
+    .await;
+    g.test("SELECT id FROM Orders", Ok(select!(id I64; 1)))
+        .await;
+
+    g.run("INSERT INTO Orders VALUES (2, 20), (3, 30);").await;
+    g.named_test(
+        "delete with USING relation joined with other tables",
+        "
+        DELETE FROM Orders
+        USING Item INNER JOIN Stock ON Item.id = Stock.item_id
+        WHERE Orders.item_id = Item.id AND Stock.quantity = 0
+        ",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id FROM Orders",
+        Ok(select!(
+            id
+            I64;
+            1;
+            3
+        )),
+    )
+    .await;
+
+    g.run("INSERT INTO Stock VALUES (30, 7);").await;
+    g.named_test(
+        "row matched by several joined rows is deleted once",
+        "DELETE FROM Orders USING Stock WHERE Orders.item_id = Stock.item_id RETURNING id",
+        Ok(select!(id I64; 3)),
+    )
+    .await;
+    g.test("SELECT id FROM Orders", Ok(select!(id I64; 1)))
+        .await;
+});
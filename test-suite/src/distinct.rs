@@ -0,0 +1,122 @@
+use {
+    crate::*,
+    gluesql_core::{error::TranslateError, prelude::*},
+    Value::*,
+};
+
+test_case!(distinct, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Item (id INTEGER, city TEXT, price INTEGER);")
+        .await;
+    g.run(
+        "
+        INSERT INTO Item VALUES
+            (1, 'Seoul', 10),
+            (2, 'Seoul', 10),
+            (3, 'Busan', 20),
+            (4, 'Seoul', 30),
+            (5, 'Busan', 20),
+            (6, NULL, 10),
+            (7, NULL, NULL);
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "SELECT DISTINCT city FROM Item WHERE city IS NOT NULL ORDER BY city",
+            Ok(select!(
+                city;
+                Str;
+                "Busan".to_owned();
+                "Seoul".to_owned()
+            )),
+        ),
+        (
+            "SELECT DISTINCT city, price FROM Item WHERE city IS NOT NULL ORDER BY city, price",
+            Ok(select!(
+                city                | price;
+                Str                 | I64;
+                "Busan".to_owned()    20;
+                "Seoul".to_owned()    10;
+                "Seoul".to_owned()    30
+            )),
+        ),
+        (
+            "SELECT DISTINCT price FROM Item ORDER BY price DESC LIMIT 2",
+            Ok(select_with_null!(
+                price;
+                Null;
+                I64(30)
+            )),
+        ),
+        (
+            "SELECT DISTINCT price FROM Item WHERE price IS NOT NULL ORDER BY price LIMIT 1 OFFSET 1",
+            Ok(select!(price; I64; 20)),
+        ),
+        (
+            "SELECT COUNT(DISTINCT city) AS cities, COUNT(city) AS total FROM Item",
+            Ok(select!(
+                cities | total;
+                I64    | I64;
+                2        5
+            )),
+        ),
+        (
+            "SELECT COUNT(DISTINCT price) AS prices, SUM(DISTINCT price) AS sum FROM Item WHERE price IS NOT NULL",
+            Ok(select!(
+                prices | sum;
+                I64    | I64;
+                3        60
+            )),
+        ),
+        (
+            "
+            SELECT city, COUNT(DISTINCT price) AS prices, SUM(DISTINCT price) AS sum
+            FROM Item
+            WHERE city IS NOT NULL
+            GROUP BY city
+            ORDER BY city
+            ",
+            Ok(select!(
+                city               | prices | sum;
+                Str                | I64    | I64;
+                "Busan".to_owned()   1        20;
+                "Seoul".to_owned()   2        40
+            )),
+        ),
+        (
+            "SELECT DISTINCT COUNT(DISTINCT price) AS prices FROM Item GROUP BY city ORDER BY prices",
+            Ok(select!(
+                prices;
+                I64;
+                1;
+                2
+            )),
+        ),
+        (
+            "SELECT id, SUM(DISTINCT price) OVER (ORDER BY id) AS sum FROM Item WHERE id < 5 ORDER BY id",
+            Ok(select!(
+                id  | sum;
+                I64 | I64;
+                1     10;
+                2     10;
+                3     30;
+                4     60
+            )),
+        ),
+        (
+            "SELECT DISTINCT ON (city) city FROM Item",
+            Err(TranslateError::SelectDistinctOnNotSupported("city".to_owned()).into()),
+        ),
+        (
+            "SELECT ABS(DISTINCT price) FROM Item",
+            Err(TranslateError::DistinctFunctionArgNotSupported("ABS".to_owned()).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+});
@@ -0,0 +1,165 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::TranslateError,
+        executor::{ExplainNode, Payload},
+        prelude::Value::*,
+    },
+};
+
+/// Flattens the plan tree into `operator: detail` lines indented by depth.
+fn lines(node: &ExplainNode) -> Vec<String> {
+    fn push(lines: &mut Vec<String>, node: &ExplainNode, depth: usize) {
+        let line = match &node.detail {
+            Some(detail) => format!("{}{}: {detail}", "  ".repeat(depth), node.operator),
+            None => format!("{}{}", "  ".repeat(depth), node.operator),
+        };
+
+        lines.push(line);
+        for child in &node.children {
+            push(lines, child, depth + 1);
+        }
+    }
+
+    let mut lines = Vec::new();
+    push(&mut lines, node, 0);
+    lines
+}
+
+/// Flattens the plan tree into operators and their measured rows.
+fn rows(node: &ExplainNode) -> Vec<(String, Option<usize>)> {
+    let mut rows = vec![(
+        node.operator.to_owned(),
+        node.analyzed.as_ref().map(|analyzed| analyzed.rows),
+    )];
+    rows.extend(node.children.iter().flat_map(rows));
+    rows
+}
+
+test_case!(explain, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Player (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+    g.run("CREATE TABLE Item (id INTEGER, player_id INTEGER, amount INTEGER);")
+        .await;
+    g.run("INSERT INTO Player VALUES (1, 'Taehoon'), (2, 'Mike'), (3, 'Jorno');")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 1, 10), (2, 1, 20), (3, 2, 30), (4, 3, 40), (5, 3, 50);")
+        .await;
+
+    macro_rules! explain {
+        ($sql: literal) => {
+            match g.run($sql).await {
+                Payload::Explain(node) => node,
+                payload => panic!("unexpected payload of {}: {payload:?}", $sql),
+            }
+        };
+    }
+
+    let actual = lines(&explain!("EXPLAIN SELECT name FROM Player WHERE id = 2;"));
+    let expected = vec![
+        r#"Project: "name""#.to_owned(),
+        "  PrimaryKeyScan: Player (key = 2)".to_owned(),
+    ];
+    assert_eq!(actual, expected, "primary key scan");
+
+    let actual = lines(&explain!(
+        "EXPLAIN SELECT name FROM Player WHERE id > 1 ORDER BY id DESC;"
+    ));
+    let expected = vec![
+        r#"Project: "name""#.to_owned(),
+        "  PrimaryKeyRangeScan: Player (key > 1, DESC)".to_owned(),
+    ];
+    assert_eq!(actual, expected, "primary key range scan");
+
+    let actual = lines(&explain!(
+        "EXPLAIN SELECT id FROM Item WHERE amount > 25 ORDER BY id DESC LIMIT 2;"
+    ));
+    let expected = vec![
+        "Limit: LIMIT 2".to_owned(),
+        r#"  Sort: "id" DESC"#.to_owned(),
+        r#"    Project: "id""#.to_owned(),
+        r#"      SeqScan: Item (filter = "amount" > 25, columns = ("id"))"#.to_owned(),
+    ];
+    assert_eq!(
+        actual, expected,
+        "sequential scan with pushed down filter, sort and limit"
+    );
+
+    let node = explain!(
+        "EXPLAIN SELECT Player.name, SUM(Item.amount) FROM Player
+        JOIN Item ON Item.player_id = Player.id
+        GROUP BY Player.name;"
+    );
+    let actual = node
+        .children
+        .iter()
+        .flat_map(|node| &node.children)
+        .map(|node| node.operator.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(actual, vec!["HashJoin"], "hash join under aggregate");
+    assert_eq!(node.operator, "Project");
+    assert_eq!(node.children[0].operator, "Aggregate");
+    assert_eq!(
+        node.children[0].detail.as_deref(),
+        Some(r#"GROUP BY "Player"."name""#)
+    );
+
+    let actual = lines(&explain!("EXPLAIN DELETE FROM Item WHERE amount < 20;"));
+    let expected = vec![
+        "Delete: Item".to_owned(),
+        r#"  Filter: "amount" < 20"#.to_owned(),
+        "    SeqScan: Item".to_owned(),
+    ];
+    assert_eq!(actual, expected, "explain delete");
+
+    let actual = lines(&explain!(
+        "EXPLAIN DELETE FROM Item USING Player
+        WHERE Item.player_id = Player.id AND Player.name = 'Mike';"
+    ));
+    let expected = vec![
+        "Delete: Item".to_owned(),
+        r#"  HashJoin: INNER ON "Player"."name" = 'Mike', hash key "Item"."player_id" = "Player"."id""#.to_owned(),
+        "    SeqScan: Player".to_owned(),
+        "    SeqScan: Item".to_owned(),
+    ];
+    assert_eq!(actual, expected, "explain delete using");
+    g.count("SELECT * FROM Item;", 5).await;
+
+    let node = explain!(
+        "EXPLAIN ANALYZE SELECT Player.name FROM Player
+        JOIN Item ON Item.player_id = Player.id
+        WHERE Item.amount > 15;"
+    );
+    let expected = vec![
+        ("Project".to_owned(), Some(4)),
+        ("Filter".to_owned(), Some(4)),
+        ("HashJoin".to_owned(), Some(5)),
+        ("SeqScan".to_owned(), Some(3)),
+        ("SeqScan".to_owned(), None),
+    ];
+    assert_eq!(rows(&node), expected, "analyze select");
+
+    let node = explain!("EXPLAIN ANALYZE UPDATE Item SET amount = amount + 1 WHERE id > 3;");
+    let expected = vec![
+        ("Update".to_owned(), Some(2)),
+        ("Filter".to_owned(), None),
+        ("SeqScan".to_owned(), None),
+    ];
+    assert_eq!(rows(&node), expected, "analyze update");
+    g.test(
+        "SELECT amount FROM Item WHERE id = 5;",
+        Ok(select!(amount I64; 51)),
+    )
+    .await;
+
+    g.test(
+        "EXPLAIN CREATE TABLE Foo (id INTEGER);",
+        Err(TranslateError::UnsupportedExplainStatement(
+            "CREATE TABLE Foo (id INTEGER)".to_owned(),
+        )
+        .into()),
+    )
+    .await;
+});
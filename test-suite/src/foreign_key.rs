@@ -5,7 +5,7 @@ use {
             DataType::{Int, Text},
             ForeignKey, ReferentialAction,
         },
-        error::{DeleteError, InsertError, TranslateError, UpdateError},
+        error::{DeleteError, InsertError, UpdateError},
         executor::{AlterError, Referencing},
         prelude::Payload,
     },
@@ -87,42 +87,6 @@ test_case!(foreign_key, {
     )
     .await;
 
-    g.named_test(
-        "Unsupported foreign key option: CASCADE",
-        "CREATE TABLE ReferencingTable (
-            id INT,
-            name TEXT,
-            referenced_id INT,
-            FOREIGN KEY (referenced_id) REFERENCES ReferencedTableWithPK (id) ON DELETE CASCADE
-        );",
-        Err(TranslateError::UnsupportedConstraint("CASCADE".to_owned()).into()),
-    )
-    .await;
-
-    g.named_test(
-        "Unsupported foreign key option: SET DEFAULT",
-        "CREATE TABLE ReferencingTable (
-            id INT,
-            name TEXT,
-            referenced_id INT,
-            FOREIGN KEY (referenced_id) REFERENCES ReferencedTableWithPK (id) ON DELETE SET DEFAULT
-        );",
-        Err(TranslateError::UnsupportedConstraint("SET DEFAULT".to_owned()).into()),
-    )
-    .await;
-
-    g.named_test(
-        "Unsupported foreign key option: SET NULL",
-        "CREATE TABLE ReferencingTable (
-            id INT,
-            name TEXT,
-            referenced_id INT,
-            FOREIGN KEY (referenced_id) REFERENCES ReferencedTableWithPK (id) ON DELETE SET NULL
-        );",
-        Err(TranslateError::UnsupportedConstraint("SET NULL".to_owned()).into()),
-    )
-    .await;
-
     g.named_test(
         "Referencing column not found",
         "CREATE TABLE ReferencingTable (
@@ -148,7 +112,7 @@ test_case!(foreign_key, {
     .await;
 
     g.named_test(
-        "Creating table with foreign key should be succeeded if referenced table has primary key. NO ACTION(=RESTRICT) is default",
+        "Creating table with foreign key should be succeeded if referenced table has primary key. NO ACTION is default",
         "CREATE TABLE ReferencingTable (
             id INT,
             name TEXT,
@@ -284,7 +248,7 @@ test_case!(foreign_key, {
                         referenced_table_name: "ReferencedTableWithPK".to_owned(),
                         referenced_column_name: "id".to_owned(),
                         on_delete: ReferentialAction::NoAction,
-                        on_update: ReferentialAction::NoAction,
+                        on_update: ReferentialAction::Restrict,
                     },
                 },
                 Referencing {
@@ -330,3 +294,150 @@ test_case!(foreign_key, {
     )
     .await;
 });
+
+test_case!(referential_action, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Team (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+    g.run(
+        "CREATE TABLE Player (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            team_id INTEGER,
+            FOREIGN KEY (team_id) REFERENCES Team (id) ON DELETE CASCADE ON UPDATE CASCADE
+        );",
+    )
+    .await;
+    g.run(
+        "CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            player_id INTEGER,
+            FOREIGN KEY (player_id) REFERENCES Player (id) ON DELETE SET NULL ON UPDATE CASCADE
+        );",
+    )
+    .await;
+    g.run(
+        "CREATE TABLE Sponsor (
+            id INTEGER PRIMARY KEY,
+            team_id INTEGER DEFAULT 1,
+            FOREIGN KEY (team_id) REFERENCES Team (id) ON DELETE SET DEFAULT ON UPDATE SET NULL
+        );",
+    )
+    .await;
+    g.run(
+        "CREATE TABLE Coach (
+            id INTEGER PRIMARY KEY,
+            team_id INTEGER,
+            FOREIGN KEY (team_id) REFERENCES Team (id) ON DELETE RESTRICT ON UPDATE RESTRICT
+        );",
+    )
+    .await;
+
+    g.run("INSERT INTO Team VALUES (1, 'Lions'), (2, 'Tigers'), (3, 'Bears');")
+        .await;
+    g.run("INSERT INTO Player VALUES (1, 'Kim', 2), (2, 'Lee', 2), (3, 'Park', 1);")
+        .await;
+    g.run("INSERT INTO Item VALUES (1, 1), (2, 2), (3, 3);")
+        .await;
+    g.run("INSERT INTO Sponsor VALUES (1, 2), (2, 1);").await;
+    g.run("INSERT INTO Coach VALUES (1, 3);").await;
+
+    g.named_test(
+        "ON DELETE RESTRICT fails while a referencing row exists",
+        "DELETE FROM Team WHERE id = 3;",
+        Err(DeleteError::ReferencingColumnExists("Coach.team_id".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "ON DELETE CASCADE, SET NULL and SET DEFAULT",
+        "DELETE FROM Team WHERE id = 2;",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.named_test(
+        "referencing players are deleted by CASCADE",
+        "SELECT id, name FROM Player;",
+        Ok(select!(id | name I64 | Str; 3 "Park".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "items of the deleted players are set to NULL",
+        "SELECT id, player_id FROM Item;",
+        Ok(select_with_null!(
+            id     | player_id;
+            I64(1)   Null;
+            I64(2)   Null;
+            I64(3)   I64(3)
+        )),
+    )
+    .await;
+    g.named_test(
+        "sponsor of the deleted team is set to the default",
+        "SELECT id, team_id FROM Sponsor;",
+        Ok(select!(id | team_id I64 | I64; 1 1; 2 1)),
+    )
+    .await;
+
+    g.named_test(
+        "ON UPDATE RESTRICT fails while a referencing row exists",
+        "UPDATE Team SET id = 30 WHERE id = 3;",
+        Err(UpdateError::ReferencingColumnExists("Coach.team_id".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "ON UPDATE CASCADE and SET NULL",
+        "UPDATE Team SET id = 10 WHERE id = 1;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.named_test(
+        "referencing players follow the updated key",
+        "SELECT id, team_id FROM Player;",
+        Ok(select!(id | team_id I64 | I64; 3 10)),
+    )
+    .await;
+    g.named_test(
+        "sponsors of the updated team are set to NULL",
+        "SELECT id, team_id FROM Sponsor;",
+        Ok(select_with_null!(
+            id     | team_id;
+            I64(1)   Null;
+            I64(2)   Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "ON UPDATE CASCADE of a cascaded table",
+        "UPDATE Player SET id = 30 WHERE id = 3;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, player_id FROM Item WHERE id = 3;",
+        Ok(select!(id | player_id I64 | I64; 3 30)),
+    )
+    .await;
+
+    g.run("UPDATE Sponsor SET team_id = 10 WHERE id = 1;").await;
+    g.named_test(
+        "SET DEFAULT fails when the default value is not referenced",
+        "DELETE FROM Team WHERE id = 10;",
+        Err(UpdateError::CannotFindReferencedValue {
+            table_name: "Team".to_owned(),
+            column_name: "id".to_owned(),
+            referenced_value: "1".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "failed referential action leaves every table untouched",
+        "SELECT id, team_id FROM Player;",
+        Ok(select!(id | team_id I64 | I64; 30 10)),
+    )
+    .await;
+});
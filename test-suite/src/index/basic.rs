@@ -317,12 +317,6 @@ test_case!(basic, {
     )
     .await;
 
-    g.test(
-        "CREATE INDEX idx_com ON Test (id, num)",
-        Err(TranslateError::CompositeIndexNotSupported.into()),
-    )
-    .await;
-
     g.test(
         "DROP INDEX Test.idx_id, Test.idx_id2",
         Err(TranslateError::TooManyParamsInDropIndex.into()),
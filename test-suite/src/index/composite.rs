@@ -0,0 +1,219 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::IndexOperator::*,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(composite, {
+    let g = get_tester!();
+
+    g.run(
+        "
+CREATE TABLE Event (
+    tenant_id INTEGER,
+    created_at INTEGER,
+    note TEXT
+)",
+    )
+    .await;
+
+    g.run(
+        "
+        INSERT INTO Event
+            (tenant_id, created_at, note)
+        VALUES
+            (1, 10, 'a'),
+            (1, 20, 'ab'),
+            (1, 30, 'b'),
+            (2, 10, 'a'),
+            (2, 20, 'c'),
+            (3, 30, 'ab');
+    ",
+    )
+    .await;
+
+    g.test(
+        "CREATE INDEX idx_tenant ON Event (tenant_id, created_at)",
+        Ok(Payload::CreateIndex),
+    )
+    .await;
+    g.test(
+        "CREATE INDEX idx_note ON Event (note, tenant_id)",
+        Ok(Payload::CreateIndex),
+    )
+    .await;
+
+    g.test(
+        "SHOW INDEXES FROM Event",
+        Ok(select!(
+            TABLE_NAME         | INDEX_NAME              | ORDER             | EXPRESSION                       | UNIQUENESS;
+            Str                | Str                     | Str               | Str                              | Bool;
+            "Event".to_owned()   "idx_tenant".to_owned()   "BOTH".to_owned()   "tenant_id, created_at".to_owned()   false;
+            "Event".to_owned()   "idx_note".to_owned()     "BOTH".to_owned()   "note, tenant_id".to_owned()         false
+        )),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at > 15",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           20           "ab".to_owned();
+            1           30           "b".to_owned()
+        )),
+        idx!(idx_tenant, ["1"], Gt, "15"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE created_at <= 20 AND 2 = tenant_id",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            2           10           "a".to_owned();
+            2           20           "c".to_owned()
+        )),
+        idx!(idx_tenant, ["2"], LtEq, "20"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at = 30",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           30           "b".to_owned()
+        )),
+        idx!(idx_tenant, ["1"], Eq, "30"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at < 30 AND note = 'a'",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           10           "a".to_owned()
+        )),
+        idx!(idx_tenant, ["1"], Lt, "30"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at > 10 AND created_at <= 30",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           20           "ab".to_owned();
+            1           30           "b".to_owned()
+        )),
+        idx!(idx_tenant, ["1"], Gt, "10", LtEq, "30"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE created_at BETWEEN 5 AND 15 AND tenant_id = 2",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            2           10           "a".to_owned()
+        )),
+        idx!(idx_tenant, ["2"], GtEq, "5", LtEq, "15"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE note = 'a' AND tenant_id >= 1",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           10           "a".to_owned();
+            2           10           "a".to_owned()
+        )),
+        idx!(idx_note, ["'a'"], GtEq, "1"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE note = 'a'",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           10           "a".to_owned();
+            2           10           "a".to_owned()
+        )),
+        idx!(idx_note, Eq, "'a'"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id > 1",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            2           10           "a".to_owned();
+            2           20           "c".to_owned();
+            3           30           "ab".to_owned()
+        )),
+        idx!(idx_tenant, Gt, "1"),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event WHERE created_at > 20",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           30           "b".to_owned();
+            3           30           "ab".to_owned()
+        )),
+        idx!(),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT * FROM Event ORDER BY tenant_id DESC",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            3           30           "ab".to_owned();
+            2           20           "c".to_owned();
+            2           10           "a".to_owned();
+            1           30           "b".to_owned();
+            1           20           "ab".to_owned();
+            1           10           "a".to_owned()
+        )),
+        idx!(idx_tenant, DESC),
+    )
+    .await;
+
+    g.run("UPDATE Event SET created_at = 40 WHERE note = 'c'")
+        .await;
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id = 2 AND created_at > 30",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            2           40           "c".to_owned()
+        )),
+        idx!(idx_tenant, ["2"], Gt, "30"),
+    )
+    .await;
+
+    g.run("DELETE FROM Event WHERE tenant_id = 1 AND created_at = 20")
+        .await;
+    g.test_idx(
+        "SELECT * FROM Event WHERE tenant_id = 1 AND created_at >= 0",
+        Ok(select!(
+            tenant_id | created_at | note;
+            I64       | I64        | Str;
+            1           10           "a".to_owned();
+            1           30           "b".to_owned()
+        )),
+        idx!(idx_tenant, ["1"], GtEq, "0"),
+    )
+    .await;
+});
@@ -1,19 +1,23 @@
 mod and;
 mod basic;
+mod composite;
 mod expr;
 mod nested;
 mod null;
 mod order_by;
+mod range;
 mod showindexes;
 mod value;
 
 pub use {
     and::and,
     basic::basic,
+    composite::composite,
     expr::expr,
     nested::nested,
     null::null,
     order_by::{order_by, order_by_multi},
+    range::range,
     showindexes::showindexes,
     value::value,
 };
@@ -0,0 +1,138 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::IndexOperator::*,
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(range, {
+    let g = get_tester!();
+
+    g.run(
+        "
+CREATE TABLE Post (
+    id INTEGER,
+    created_at DATE NULL
+)",
+    )
+    .await;
+
+    g.run(
+        "
+        INSERT INTO Post
+            (id, created_at)
+        VALUES
+            (1, '2019-12-31'),
+            (2, '2020-01-01'),
+            (3, '2020-06-15'),
+            (4, NULL),
+            (5, '2020-12-31'),
+            (6, '2021-01-01');
+    ",
+    )
+    .await;
+
+    g.test(
+        "CREATE INDEX idx_created_at ON Post (created_at)",
+        Ok(Payload::CreateIndex),
+    )
+    .await;
+
+    g.test_idx(
+        "
+        SELECT id FROM Post
+        WHERE created_at >= DATE '2020-01-01' AND created_at < DATE '2021-01-01'
+        ",
+        Ok(select!(id I64; 2; 3; 5)),
+        idx!(
+            idx_created_at,
+            GtEq,
+            "DATE '2020-01-01'",
+            Lt,
+            "DATE '2021-01-01'"
+        ),
+    )
+    .await;
+
+    g.test_idx(
+        "
+        SELECT id FROM Post
+        WHERE DATE '2021-01-01' >= created_at AND id <> 3 AND created_at > DATE '2020-01-01'
+        ",
+        Ok(select!(id I64; 5; 6)),
+        idx!(
+            idx_created_at,
+            Gt,
+            "DATE '2020-01-01'",
+            LtEq,
+            "DATE '2021-01-01'"
+        ),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Post WHERE created_at BETWEEN DATE '2019-01-01' AND DATE '2020-01-01'",
+        Ok(select!(id I64; 1; 2)),
+        idx!(
+            idx_created_at,
+            GtEq,
+            "DATE '2019-01-01'",
+            LtEq,
+            "DATE '2020-01-01'"
+        ),
+    )
+    .await;
+
+    g.test_idx(
+        "
+        SELECT id FROM Post
+        WHERE created_at >= DATE '2020-06-15' AND created_at <= DATE '2020-06-15'
+        ",
+        Ok(select!(id I64; 3)),
+        idx!(
+            idx_created_at,
+            GtEq,
+            "DATE '2020-06-15'",
+            LtEq,
+            "DATE '2020-06-15'"
+        ),
+    )
+    .await;
+
+    g.test_idx(
+        "
+        SELECT id FROM Post
+        WHERE created_at > DATE '2020-06-15' AND created_at < DATE '2020-06-15'
+        ",
+        Ok(select!(id I64)),
+        idx!(
+            idx_created_at,
+            Gt,
+            "DATE '2020-06-15'",
+            Lt,
+            "DATE '2020-06-15'"
+        ),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Post WHERE created_at BETWEEN DATE '2021-01-01' AND DATE '2020-01-01'",
+        Ok(select!(id I64)),
+        idx!(
+            idx_created_at,
+            GtEq,
+            "DATE '2021-01-01'",
+            LtEq,
+            "DATE '2020-01-01'"
+        ),
+    )
+    .await;
+
+    g.test_idx(
+        "SELECT id FROM Post WHERE created_at NOT BETWEEN DATE '2020-01-01' AND DATE '2020-12-31'",
+        Ok(select!(id I64; 1; 6)),
+        idx!(),
+    )
+    .await;
+});
@@ -251,9 +251,9 @@ test_case!(inline_view, {
             Err(TranslateError::TooManyTables.into()),
         ),
         (
-            // unsupported select distinct
-            "SELECT DISTINCT id FROM OuterTable",
-            Err(TranslateError::SelectDistinctNotSupported.into()),
+            // unsupported select distinct on
+            "SELECT DISTINCT ON (id) id FROM OuterTable",
+            Err(TranslateError::SelectDistinctOnNotSupported("id".to_owned()).into()),
         ),
         (
             // inline view subquery + join with inline view
@@ -303,3 +303,145 @@ test_case!(project, {
         g.test(sql, Err(error)).await;
     }
 });
+
+test_case!(outer, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Account (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+    g.run("CREATE TABLE Ledger (id INTEGER, account_id INTEGER, amount INTEGER);")
+        .await;
+    g.run("INSERT INTO Account VALUES (1, 'a'), (2, 'b'), (3, 'c');")
+        .await;
+    g.run(
+        "
+        INSERT INTO Ledger VALUES
+            (10, 1, 100),
+            (11, 1, 50),
+            (12, 3, 70),
+            (13, 4, 30),
+            (14, NULL, 5);
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "
+            SELECT Account.id AS a, Ledger.id AS l
+            FROM Account
+            RIGHT JOIN Ledger ON Account.id = Ledger.account_id
+            ORDER BY l
+            ",
+            select_with_null!(
+                a      | l;
+                I64(1)   I64(10);
+                I64(1)   I64(11);
+                I64(3)   I64(12);
+                Null     I64(13);
+                Null     I64(14)
+            ),
+        ),
+        (
+            "
+            SELECT Account.id AS a, Ledger.id AS l
+            FROM Account
+            RIGHT OUTER JOIN Ledger ON Account.id = Ledger.account_id AND Ledger.amount > 60
+            ORDER BY l
+            ",
+            select_with_null!(
+                a      | l;
+                I64(1)   I64(10);
+                Null     I64(11);
+                I64(3)   I64(12);
+                Null     I64(13);
+                Null     I64(14)
+            ),
+        ),
+        (
+            "
+            SELECT Account.id AS a, Ledger.id AS l
+            FROM Account
+            FULL JOIN Ledger ON Account.id = Ledger.account_id
+            ORDER BY a, l
+            ",
+            select_with_null!(
+                a      | l;
+                I64(1)   I64(10);
+                I64(1)   I64(11);
+                I64(2)   Null;
+                I64(3)   I64(12);
+                Null     I64(13);
+                Null     I64(14)
+            ),
+        ),
+        (
+            "
+            SELECT Account.id AS a, Ledger.id AS l
+            FROM Account
+            FULL OUTER JOIN Ledger ON Account.id > Ledger.account_id + 1
+            ORDER BY a, l
+            ",
+            select_with_null!(
+                a      | l;
+                I64(1)   Null;
+                I64(2)   Null;
+                I64(3)   I64(10);
+                I64(3)   I64(11);
+                Null     I64(12);
+                Null     I64(13);
+                Null     I64(14)
+            ),
+        ),
+        (
+            "
+            SELECT Account.id AS a, Ledger.id AS l
+            FROM Account
+            FULL JOIN Ledger ON Account.id = Ledger.account_id
+            WHERE Account.id IS NULL OR Ledger.id IS NULL
+            ORDER BY a, l
+            ",
+            select_with_null!(
+                a      | l;
+                I64(2)   Null;
+                Null     I64(13);
+                Null     I64(14)
+            ),
+        ),
+        (
+            "
+            SELECT Account.id AS a, Ledger.id AS l
+            FROM Account
+            RIGHT JOIN Ledger ON Account.id = Ledger.account_id
+            WHERE Account.id = 1
+            ORDER BY l
+            ",
+            select!(
+                a   | l;
+                I64 | I64;
+                1     10;
+                1     11
+            ),
+        ),
+        (
+            "
+            SELECT Ledger.id AS l, a1.name AS n1, a2.name AS n2
+            FROM Ledger
+            LEFT JOIN Account a1 ON a1.id = Ledger.account_id
+            RIGHT JOIN Account a2 ON a2.id = a1.id + 1
+            ORDER BY n2, l
+            ",
+            select_with_null!(
+                l        | n1                  | n2;
+                Null       Null                  Str("a".to_owned());
+                I64(10)    Str("a".to_owned())   Str("b".to_owned());
+                I64(11)    Str("a".to_owned())   Str("b".to_owned());
+                Null       Null                  Str("c".to_owned())
+            ),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, Ok(expected)).await;
+    }
+});
@@ -10,14 +10,18 @@ pub mod bitwise_and;
 pub mod bitwise_shift_left;
 pub mod bitwise_shift_right;
 pub mod case;
+pub mod check;
 pub mod column_alias;
 pub mod concat;
+pub mod cte;
 pub mod custom_function;
 pub mod data_type;
 pub mod default;
 pub mod delete;
 pub mod dictionary;
 pub mod dictionary_index;
+pub mod distinct;
+pub mod explain;
 pub mod filter;
 pub mod foreign_key;
 pub mod function;
@@ -31,21 +35,31 @@ pub mod metadata;
 pub mod migrate;
 pub mod nested_select;
 pub mod nullable;
+pub mod on_conflict;
 pub mod order_by;
 pub mod ordering;
+pub mod prepared;
 pub mod primary_key;
 pub mod project;
+pub mod pushdown;
+pub mod returning;
 pub mod schemaless;
+pub mod sequence;
 pub mod series;
+pub mod set_operation;
 pub mod show_columns;
 pub mod store;
+pub mod stream;
 pub mod synthesize;
 pub mod transaction;
+pub mod trigger;
 pub mod type_match;
 pub mod unary_operator;
 pub mod update;
 pub mod validate;
 pub mod values;
+pub mod view;
+pub mod window;
 
 pub mod tester;
 
@@ -73,8 +87,16 @@ macro_rules! generate_store_tests {
             };
         }
         glue!(update, update::update);
+        glue!(update_from, update::from);
         glue!(insert, insert::insert);
+        glue!(on_conflict, on_conflict::on_conflict);
+        glue!(returning, returning::returning);
+        glue!(prepared, prepared::prepared);
+        glue!(explain, explain::explain);
+        glue!(check, check::check);
+        glue!(stream, stream::stream);
         glue!(delete, delete::delete);
+        glue!(delete_using, delete::using);
         glue!(basic, basic::basic);
         glue!(array, array::array);
         glue!(bitwise_and, bitwise_and::bitwise_and);
@@ -103,8 +125,13 @@ macro_rules! generate_store_tests {
         glue!(limit, limit::limit);
         glue!(like_ilike, like_ilike::like_ilike);
         glue!(filter, filter::filter);
+        glue!(pushdown, pushdown::pushdown);
         glue!(inline_view, inline_view::inline_view);
         glue!(values, values::values);
+        glue!(set_operation, set_operation::set_operation);
+        glue!(cte, cte::cte);
+        glue!(window, window::window);
+        glue!(distinct, distinct::distinct);
         glue!(unary_operator, unary_operator::unary_operator);
         glue!(function_upper_lower, function::upper_lower::upper_lower);
         glue!(function_initcap, function::initcap::initcap);
@@ -165,10 +192,18 @@ macro_rules! generate_store_tests {
         glue!(function_values, function::values::values);
         glue!(join, join::join);
         glue!(join_project, join::project);
+        glue!(join_outer, join::outer);
         glue!(migrate, migrate::migrate);
         glue!(nested_select, nested_select::nested_select);
         glue!(primary_key, primary_key::primary_key);
+        glue!(primary_key_composite, primary_key::composite);
+        glue!(primary_key_composite_order, primary_key::composite_order);
+        glue!(primary_key_range, primary_key::range);
         glue!(foreign_key, foreign_key::foreign_key);
+        glue!(
+            foreign_key_referential_action,
+            foreign_key::referential_action
+        );
         glue!(series, series::series);
         glue!(nullable, nullable::nullable);
         glue!(nullable_text, nullable::nullable_text);
@@ -318,6 +353,7 @@ macro_rules! generate_alter_table_tests {
 
         glue!(alter_table_rename, alter::alter_table_rename);
         glue!(alter_table_add_drop, alter::alter_table_add_drop);
+        glue!(alter_table_alter_column, alter::alter_table_alter_column);
     };
 }
 
@@ -334,6 +370,47 @@ macro_rules! generate_custom_function_tests {
     };
 }
 
+#[macro_export]
+macro_rules! generate_view_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(view, view::view);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_sequence_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(sequence, sequence::sequence);
+        glue!(sequence_identity, sequence::identity);
+    };
+}
+
+#[macro_export]
+macro_rules! generate_trigger_tests {
+    ($test: meta, $storage: ident) => {
+        macro_rules! glue {
+            ($title: ident, $func: path) => {
+                declare_test_fn!($test, $storage, $title, $func);
+            };
+        }
+
+        glue!(trigger, trigger::trigger);
+        glue!(trigger_referential_action, trigger::referential_action);
+    };
+}
+
 #[macro_export]
 macro_rules! generate_index_tests {
     ($test: meta, $storage: ident) => {
@@ -345,12 +422,14 @@ macro_rules! generate_index_tests {
 
         glue!(index_basic, index::basic);
         glue!(index_and, index::and);
+        glue!(index_composite, index::composite);
         glue!(index_nested, index::nested);
         glue!(index_null, index::null);
         glue!(index_expr, index::expr);
         glue!(index_value, index::value);
         glue!(index_order_by, index::order_by);
         glue!(index_order_by_multi, index::order_by_multi);
+        glue!(index_range, index::range);
         glue!(showindexes, index::showindexes);
         glue!(dictionary_index, dictionary_index::ditionary_index);
     };
@@ -372,6 +451,8 @@ macro_rules! generate_transaction_tests {
         );
         glue!(transaction_dictionary, transaction::dictionary);
         glue!(transaction_ast_builder, transaction::ast_builder);
+        glue!(transaction_sequence, transaction::sequence);
+        glue!(transaction_trigger, transaction::trigger);
     };
 }
 
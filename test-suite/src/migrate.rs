@@ -56,11 +56,8 @@ test_case!(migrate, {
             TranslateError::UnsupportedBinaryOperator("^".to_owned()).into(),
         ),
         (
-            "SELECT * FROM Test UNION SELECT * FROM Test;",
-            TranslateError::UnsupportedQuerySetExpr(
-                "SELECT * FROM Test UNION SELECT * FROM Test".to_owned(),
-            )
-            .into(),
+            "SELECT * FROM Test UNION BY NAME SELECT * FROM Test;",
+            TranslateError::UnsupportedSetQuantifier("BY NAME".to_owned()).into(),
         ),
         (
             "SELECT * FROM Test WHERE noname = 1;",
@@ -0,0 +1,130 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{InsertError, UpdateError, ValidateError},
+        prelude::{Key, Payload, Value::*},
+    },
+};
+
+test_case!(on_conflict, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            code TEXT UNIQUE,
+            stock INTEGER
+        );
+    ",
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (1, 'apple', 'A', 10), (2, 'banana', 'B', 20);")
+        .await;
+
+    g.named_test(
+        "DO NOTHING skips conflicting rows",
+        "INSERT INTO Item VALUES (1, 'avocado', 'C', 5), (3, 'cherry', 'D', 30) ON CONFLICT DO NOTHING;",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, code, stock FROM Item",
+        Ok(select!(
+            id  | name                | code            | stock
+            I64 | Str                 | Str             | I64;
+            1     "apple".to_owned()    "A".to_owned()    10;
+            2     "banana".to_owned()   "B".to_owned()    20;
+            3     "cherry".to_owned()   "D".to_owned()    30
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DO UPDATE with excluded row",
+        "
+        INSERT INTO Item VALUES (2, 'blueberry', 'B', 5), (4, 'durian', 'E', 40)
+        ON CONFLICT (id) DO UPDATE SET stock = stock + excluded.stock, name = excluded.name;
+        ",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, stock FROM Item WHERE id = 2 OR id = 4",
+        Ok(select!(
+            id  | name                   | stock
+            I64 | Str                    | I64;
+            2     "blueberry".to_owned()   25;
+            4     "durian".to_owned()      40
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DO UPDATE on a unique column conflict target",
+        "INSERT INTO Item VALUES (5, 'apricot', 'A', 1) ON CONFLICT (code) DO UPDATE SET stock = 0;",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, stock FROM Item WHERE code = 'A'",
+        Ok(select!(
+            id  | name               | stock
+            I64 | Str                | I64;
+            1     "apple".to_owned()   0
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DO UPDATE skips rows not matching WHERE",
+        "
+        INSERT INTO Item VALUES (1, 'apple', 'A', 7), (3, 'cherry', 'D', 7)
+        ON CONFLICT (id) DO UPDATE SET stock = excluded.stock WHERE Item.stock = 0;
+        ",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT id, stock FROM Item WHERE id = 1 OR id = 3",
+        Ok(select!(
+            id  | stock
+            I64 | I64;
+            1     7;
+            3     30
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "conflict target without unique constraint",
+        "INSERT INTO Item VALUES (1, 'apple', 'A', 1) ON CONFLICT (name) DO NOTHING;",
+        Err(InsertError::ConflictTargetNotUnique("name".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "same row cannot be updated twice",
+        "
+        INSERT INTO Item VALUES (1, 'apple', 'A', 1), (1, 'apple', 'A', 2)
+        ON CONFLICT (id) DO UPDATE SET stock = excluded.stock;
+        ",
+        Err(InsertError::ConflictRowAffectedTwice(Key::I64(1)).into()),
+    )
+    .await;
+    g.named_test(
+        "primary key cannot be updated",
+        "INSERT INTO Item VALUES (1, 'apple', 'A', 1) ON CONFLICT (id) DO UPDATE SET id = 10;",
+        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "conflict on another unique constraint than the target",
+        "INSERT INTO Item VALUES (9, 'apple', 'A', 1) ON CONFLICT (id) DO NOTHING;",
+        Err(
+            ValidateError::DuplicateEntryOnUniqueField(Str("A".to_owned()), "code".to_owned())
+                .into(),
+        ),
+    )
+    .await;
+});
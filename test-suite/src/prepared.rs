@@ -0,0 +1,166 @@
+use {
+    crate::*,
+    gluesql_core::{
+        data::Interval as I,
+        error::{EvaluateError, ExecuteError},
+        executor::Payload,
+        prelude::Value::{self, *},
+    },
+    rust_decimal::Decimal as D,
+};
+
+test_case!(prepared, {
+    let glue = get_glue!();
+
+    glue.execute("CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);")
+        .await
+        .expect("create table");
+
+    let insert = glue
+        .prepare("INSERT INTO Item VALUES ($1, $2, $3);")
+        .await
+        .expect("prepare insert");
+    for (id, name, price) in [
+        (1, "Apple", 300),
+        (2, "It's a pear", 500),
+        (3, "Melon", 900),
+    ] {
+        let params = [I64(id), Str(name.to_owned()), I64(price)];
+        let actual = glue.execute_with_params(&insert, &params).await;
+        assert_eq!(actual, Ok(Payload::Insert(1)), "insert {name}");
+    }
+
+    let select = glue
+        .prepare("SELECT id, name FROM Item WHERE price > ? AND price < ? ORDER BY id;")
+        .await
+        .expect("prepare select");
+    let actual = glue
+        .execute_with_params(&select, &[I64(200), I64(600)])
+        .await;
+    let expected = Ok(select!(
+        id  | name
+        I64 | Str;
+        1     "Apple".to_owned();
+        2     "It's a pear".to_owned()
+    ));
+    assert_eq!(actual, expected, "select with params");
+
+    let actual = glue
+        .execute_with_params(&select, &[I64(400), I64(1000)])
+        .await;
+    let expected = Ok(select!(
+        id  | name
+        I64 | Str;
+        2     "It's a pear".to_owned();
+        3     "Melon".to_owned()
+    ));
+    assert_eq!(actual, expected, "reuse prepared statement");
+
+    let update = glue
+        .prepare("UPDATE Item SET price = price + $2 WHERE id = $1;")
+        .await
+        .expect("prepare update");
+    let actual = glue.execute_with_params(&update, &[I64(3), I64(100)]).await;
+    assert_eq!(actual, Ok(Payload::Update(1)), "update with params");
+
+    let lookup = glue
+        .prepare("SELECT price FROM Item WHERE id = $1;")
+        .await
+        .expect("prepare primary key lookup");
+    let actual = glue.execute_with_params(&lookup, &[I64(3)]).await;
+    let expected = Ok(select!(price I64; 1000));
+    assert_eq!(actual, expected, "primary key lookup with param");
+
+    let actual = glue.execute_with_params(&lookup, &[Null]).await;
+    let expected = Ok(Payload::Select {
+        labels: vec!["price".to_owned()],
+        rows: Vec::<Vec<Value>>::new(),
+    });
+    assert_eq!(actual, expected, "bind null");
+
+    let actual = glue.execute_with_params(&lookup, &[]).await;
+    let expected = Err(EvaluateError::UnboundParameter(1).into());
+    assert_eq!(actual, expected, "missing param");
+
+    let limit = glue
+        .prepare("SELECT id FROM Item ORDER BY id LIMIT $1 OFFSET $2;")
+        .await
+        .expect("prepare limit");
+    let actual = glue.execute_with_params(&limit, &[I64(1), I64(1)]).await;
+    let expected = Ok(select!(id I64; 2));
+    assert_eq!(actual, expected, "limit and offset with params");
+
+    glue.execute(
+        "CREATE TABLE Typed (id INT8, amount DECIMAL, elapsed INTERVAL, uid UUID, tags LIST);",
+    )
+    .await
+    .expect("create typed table");
+
+    let params = [
+        I64(1),
+        Decimal(D::new(12345, 3)),
+        Interval(I::hours(35)),
+        Uuid(0x936DA01F9ABD4D9D80C702AF85C822A8),
+        Value::parse_json_list(r#"[1, "a", [true]]"#).unwrap(),
+    ];
+    let insert = glue
+        .prepare("INSERT INTO Typed VALUES ($1, $2, $3, $4, $5);")
+        .await
+        .expect("prepare typed insert");
+    let actual = glue.execute_with_params(&insert, &params).await;
+    assert_eq!(actual, Ok(Payload::Insert(1)), "insert typed params");
+
+    let select = glue
+        .prepare(
+            "SELECT id, amount, elapsed, uid, tags FROM Typed
+            WHERE amount = $2 AND elapsed = $3 AND uid = $4 AND tags = $5 AND id = $1;",
+        )
+        .await
+        .expect("prepare typed select");
+    let actual = glue.execute_with_params(&select, &params).await;
+    let mut values = params.to_vec();
+    values[0] = I8(1);
+    let expected = Ok(Payload::Select {
+        labels: ["id", "amount", "elapsed", "uid", "tags"]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect(),
+        rows: vec![values],
+    });
+    assert_eq!(actual, expected, "round trip of typed params");
+
+    glue.execute(
+        "
+        CREATE TABLE Small (id INT8 PRIMARY KEY, name TEXT);
+        INSERT INTO Small VALUES (1, 'a'), (2, 'b'), (3, 'c'), (4, 'd');
+        ",
+    )
+    .await
+    .expect("create small table");
+
+    let select = glue
+        .prepare("SELECT id FROM Small WHERE id > $1 AND id <= $2;")
+        .await
+        .expect("prepare key range");
+    let actual = glue.execute_with_params(&select, &[I32(1), I32(3)]).await;
+    let expected = Ok(select!(id I8; 2; 3));
+    assert_eq!(actual, expected, "key range with I32 params");
+
+    let select = glue
+        .prepare("SELECT id FROM Small WHERE id = $1;")
+        .await
+        .expect("prepare key lookup");
+    let actual = glue.execute_with_params(&select, &[I32(2)]).await;
+    let expected = Ok(select!(id I8; 2));
+    assert_eq!(actual, expected, "key lookup with an I32 param");
+
+    let actual = glue.execute("SELECT $1 FROM Item;").await;
+    let expected = Err(EvaluateError::UnboundParameter(1).into());
+    assert_eq!(actual, expected, "execute without params");
+
+    let actual = glue
+        .prepare("SELECT * FROM Item; SELECT * FROM Item;")
+        .await;
+    let expected = Err(ExecuteError::PrepareRequiresSingleStatement(2).into());
+    assert_eq!(actual, expected, "prepare multiple statements");
+});
@@ -2,7 +2,7 @@ use {
     crate::*,
     gluesql_core::{
         data::Value::*,
-        error::{UpdateError, ValidateError, ValueError},
+        error::{ValidateError, ValueError},
         prelude::{Key, Payload},
     },
 };
@@ -147,9 +147,275 @@ test_case!(primary_key, {
     .await;
 
     g.named_test(
-        "UPDATE is not allowed for PRIMARY KEY applied column",
-        "UPDATE Allegro SET id = 100 WHERE id = 1",
-        Err(UpdateError::UpdateOnPrimaryKeyNotSupported("id".to_owned()).into()),
+        "UPDATE on PRIMARY KEY fails when the new key already exists",
+        "UPDATE Allegro SET id = 2 WHERE id = 1",
+        Err(ValidateError::DuplicateEntryOnPrimaryKeyField(Key::I64(2)).into()),
+    )
+    .await;
+    g.named_test(
+        "UPDATE on PRIMARY KEY can move a row to the key another updated row leaves",
+        "UPDATE Allegro SET id = id + 1 WHERE id > 1",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, name FROM Allegro",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "hello".to_owned();
+            3     "foo".to_owned();
+            4     "world".to_owned()
+        )),
+    )
+    .await;
+});
+
+test_case!(composite, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Enrollment (
+            student_id INTEGER,
+            course TEXT,
+            grade TEXT,
+            PRIMARY KEY (student_id, course)
+        );
+    ",
+    )
+    .await;
+    g.test(
+        "
+        INSERT INTO Enrollment VALUES
+            (2, 'math', 'B'),
+            (1, 'physics', 'A'),
+            (1, 'math', 'C'),
+            (2, 'art', 'A');
+        ",
+        Ok(Payload::Insert(4)),
+    )
+    .await;
+
+    g.named_test(
+        "rows are ordered by the tuple of primary key columns",
+        "SELECT student_id, course, grade FROM Enrollment",
+        Ok(select!(
+            student_id | course             | grade
+            I64        | Str                | Str;
+            1            "math".to_owned()    "C".to_owned();
+            1            "physics".to_owned() "A".to_owned();
+            2            "art".to_owned()     "A".to_owned();
+            2            "math".to_owned()    "B".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "point lookup when every primary key column is bound",
+        "SELECT grade FROM Enrollment WHERE course = 'math' AND student_id = 2",
+        Ok(select!(grade Str; "B".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "point lookup with remaining selection",
+        "SELECT grade FROM Enrollment WHERE student_id = 1 AND course = 'math' AND grade = 'A'",
+        Ok(Payload::Select {
+            labels: vec!["grade".to_owned()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+    g.named_test(
+        "partially bound primary key falls back to scan",
+        "SELECT course FROM Enrollment WHERE student_id = 1",
+        Ok(select!(course Str; "math".to_owned(); "physics".to_owned())),
+    )
+    .await;
+
+    g.named_test(
+        "each primary key column alone is not unique",
+        "INSERT INTO Enrollment VALUES (3, 'math', 'A');",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.named_test(
+        "tuple of primary key columns is unique",
+        "INSERT INTO Enrollment VALUES (1, 'math', 'A');",
+        Err(
+            ValidateError::DuplicateEntryOnPrimaryKeyField(Key::List(vec![
+                Key::I64(1),
+                Key::Str("math".to_owned()),
+            ]))
+            .into(),
+        ),
+    )
+    .await;
+    g.named_test(
+        "PRIMARY KEY constraint includes NOT NULL constraint",
+        "INSERT INTO Enrollment VALUES (4, NULL, 'A');",
+        Err(ValueError::NullValueOnNotNullField.into()),
+    )
+    .await;
+
+    g.run("DELETE FROM Enrollment WHERE student_id = 2 AND course = 'art'")
+        .await;
+    g.test(
+        "SELECT student_id, course FROM Enrollment WHERE student_id = 2",
+        Ok(select!(
+            student_id | course
+            I64        | Str;
+            2            "math".to_owned()
+        )),
+    )
+    .await;
+});
+
+test_case!(composite_order, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Grade (
+            student_id INTEGER,
+            course TEXT,
+            grade TEXT,
+            PRIMARY KEY (course, student_id)
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Grade VALUES
+            (1, 'physics', 'A'),
+            (2, 'math', 'B'),
+            (1, 'math', 'C');
+        ",
+    )
+    .await;
+
+    g.named_test(
+        "rows are ordered by the primary key columns in the order of the constraint",
+        "SELECT student_id, course FROM Grade",
+        Ok(select!(
+            student_id | course
+            I64        | Str;
+            1            "math".to_owned();
+            2            "math".to_owned();
+            1            "physics".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "point lookup builds the key in the order of the constraint",
+        "SELECT grade FROM Grade WHERE student_id = 2 AND course = 'math'",
+        Ok(select!(grade Str; "B".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "duplicate key in the order of the constraint",
+        "INSERT INTO Grade VALUES (1, 'math', 'A');",
+        Err(
+            ValidateError::DuplicateEntryOnPrimaryKeyField(Key::List(vec![
+                Key::Str("math".to_owned()),
+                Key::I64(1),
+            ]))
+            .into(),
+        ),
+    )
+    .await;
+    g.named_test(
+        "schema keeps the order of the constraint",
+        "SELECT EXPRESSION FROM GLUE_INDEXES WHERE TABLE_NAME = 'Grade'",
+        Ok(select!(EXPRESSION Str; "course, student_id".to_owned())),
+    )
+    .await;
+});
+
+test_case!(range, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Account (
+            id INTEGER PRIMARY KEY,
+            name TEXT
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Account VALUES
+            (5, 'e'), (-2, 'neg'), (1, 'a'), (3, 'c'), (4, 'd'), (2, 'b');
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "lower and upper bounds",
+        "SELECT id FROM Account WHERE id > 1 AND 4 >= id ORDER BY id",
+        Ok(select!(id I64; 2; 3; 4)),
+    )
+    .await;
+    g.named_test(
+        "BETWEEN with negative bound and remaining conditions",
+        "SELECT id FROM Account WHERE id BETWEEN -5 AND 3 AND name <> 'b' ORDER BY id",
+        Ok(select!(id I64; -2; 1; 3)),
+    )
+    .await;
+    g.named_test(
+        "keyset pagination in key order",
+        "SELECT id, name FROM Account WHERE id > 2 ORDER BY id LIMIT 2",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            3     "c".to_owned();
+            4     "d".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "keyset pagination in descending key order",
+        "SELECT id FROM Account WHERE id < 4 ORDER BY id DESC LIMIT 3",
+        Ok(select!(id I64; 3; 2; 1)),
+    )
+    .await;
+    g.named_test(
+        "empty range",
+        "SELECT id FROM Account WHERE id BETWEEN 4 AND 2",
+        Ok(select!(id I64)),
+    )
+    .await;
+    g.named_test(
+        "exclusive bounds on the same key",
+        "SELECT id FROM Account WHERE id >= 3 AND id < 3",
+        Ok(select!(id I64)),
+    )
+    .await;
+
+    g.run("DELETE FROM Account WHERE id >= 4").await;
+    g.named_test(
+        "range scan after delete",
+        "SELECT id FROM Account WHERE id >= 2 ORDER BY id DESC",
+        Ok(select!(id I64; 3; 2)),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Word (
+            word TEXT PRIMARY KEY,
+            len INTEGER
+        );
+        INSERT INTO Word VALUES ('banana', 6), ('apple', 5), ('cherry', 6), ('date', 4);
+    ",
+    )
+    .await;
+    g.named_test(
+        "text key range",
+        "SELECT word FROM Word WHERE word >= 'b' AND word < 'd' ORDER BY word",
+        Ok(select!(word Str; "banana".to_owned(); "cherry".to_owned())),
     )
     .await;
 });
@@ -0,0 +1,101 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(pushdown, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER,
+            name TEXT,
+            price FLOAT,
+            memo TEXT NULL
+        );
+    ",
+    )
+    .await;
+    g.run(
+        "
+        INSERT INTO Item VALUES
+            (1, 'apple', 1.5, 'red'),
+            (2, 'banana', 2, NULL),
+            (3, 'cherry', 3.25, 'red'),
+            (4, 'durian', 4, 'smelly'),
+            (5, 'elder', NULL, NULL);
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "pushed down and residual conditions",
+        "SELECT id FROM Item WHERE price > 1.5 AND name LIKE '%e%' ORDER BY id",
+        Ok(select!(id I64; 3)),
+    )
+    .await;
+    g.named_test(
+        "column on the right side",
+        "SELECT id FROM Item WHERE 2 >= price ORDER BY id",
+        Ok(select!(id I64; 1; 2)),
+    )
+    .await;
+    g.named_test(
+        "NULL never passes comparisons",
+        "SELECT id FROM Item WHERE price <> 2 ORDER BY id",
+        Ok(select!(id I64; 1; 3; 4)),
+    )
+    .await;
+    g.named_test(
+        "IS NULL and IS NOT NULL",
+        "SELECT id FROM Item WHERE memo IS NULL AND price IS NOT NULL",
+        Ok(select!(id I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "IN and NOT IN",
+        "SELECT id FROM Item WHERE memo IN ('red', 'smelly') AND id NOT IN (1, 4) ORDER BY id",
+        Ok(select!(id I64; 3)),
+    )
+    .await;
+    g.named_test(
+        "BETWEEN with alias",
+        "SELECT i.name FROM Item i WHERE i.id BETWEEN 2 AND 4 AND i.price < 4 ORDER BY i.id",
+        Ok(select!(name Str; "banana".to_owned(); "cherry".to_owned())),
+    )
+    .await;
+    g.named_test(
+        "projection keeps the columns used by ORDER BY and aggregates",
+        "SELECT memo, SUM(price) AS total FROM Item WHERE id < 5 GROUP BY memo ORDER BY total",
+        Ok(select_with_null!(
+            memo                   | total;
+            Null                     F64(2.0);
+            Str("smelly".to_owned()) F64(4.0);
+            Str("red".to_owned())    F64(4.75)
+        )),
+    )
+    .await;
+    g.named_test(
+        "pushdown in correlated subquery",
+        "
+        SELECT id FROM Item o
+        WHERE EXISTS (SELECT * FROM Item WHERE id = 4 AND memo = o.memo)
+        ",
+        Ok(select!(id I64; 4)),
+    )
+    .await;
+    g.named_test(
+        "pushdown in UNION",
+        "SELECT id FROM Item WHERE id = 1 UNION SELECT id FROM Item WHERE name = 'elder'",
+        Ok(select!(id I64; 1; 5)),
+    )
+    .await;
+    g.named_test(
+        "pushed down conditions do not change DML",
+        "DELETE FROM Item WHERE price IS NULL",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.count("SELECT * FROM Item WHERE id > 0", 4).await;
+});
@@ -0,0 +1,109 @@
+use {
+    crate::*,
+    gluesql_core::prelude::{Payload, Value::*},
+};
+
+test_case!(returning, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (
+            id INTEGER PRIMARY KEY,
+            name TEXT,
+            stock INTEGER
+        );
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "INSERT RETURNING *",
+        "INSERT INTO Item VALUES (1, 'apple', 10), (2, 'banana', 20) RETURNING *;",
+        Ok(select!(
+            id  | name                | stock
+            I64 | Str                 | I64;
+            1     "apple".to_owned()    10;
+            2     "banana".to_owned()   20
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "INSERT RETURNING expressions with aliases",
+        "INSERT INTO Item (id, name) VALUES (3, 'cherry') RETURNING id, UPPER(name) AS label, stock;",
+        Ok(select_with_null!(
+            id     | label                | stock;
+            I64(3)   Str("CHERRY".to_owned()) Null
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "INSERT ON CONFLICT DO UPDATE RETURNING returns inserted and updated rows",
+        "
+        INSERT INTO Item VALUES (1, 'avocado', 5), (4, 'durian', 40)
+        ON CONFLICT (id) DO UPDATE SET stock = stock + excluded.stock
+        RETURNING id, name, stock;
+        ",
+        Ok(select!(
+            id  | name                | stock
+            I64 | Str                 | I64;
+            4     "durian".to_owned()   40;
+            1     "apple".to_owned()    15
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "UPDATE RETURNING",
+        "UPDATE Item SET stock = stock * 2 WHERE id <= 2 RETURNING id, stock AS doubled;",
+        Ok(select!(
+            id  | doubled
+            I64 | I64;
+            1     30;
+            2     40
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "UPDATE without RETURNING keeps the affected row count",
+        "UPDATE Item SET stock = 0 WHERE id = 3;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+
+    g.named_test(
+        "DELETE RETURNING qualified wildcard",
+        "DELETE FROM Item WHERE id > 2 RETURNING Item.*;",
+        Ok(select!(
+            id  | name                | stock
+            I64 | Str                 | I64;
+            3     "cherry".to_owned()   0;
+            4     "durian".to_owned()   40
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "DELETE RETURNING with no affected rows",
+        "DELETE FROM Item WHERE id = 100 RETURNING id;",
+        Ok(Payload::Select {
+            labels: vec!["id".to_owned()],
+            rows: Vec::new(),
+        }),
+    )
+    .await;
+
+    g.test(
+        "SELECT id, name, stock FROM Item",
+        Ok(select!(
+            id  | name                | stock
+            I64 | Str                 | I64;
+            1     "apple".to_owned()    30;
+            2     "banana".to_owned()   40
+        )),
+    )
+    .await;
+});
@@ -0,0 +1,286 @@
+use {
+    crate::*,
+    gluesql_core::{
+        ast::DataType,
+        error::{AlterError, InsertError, SequenceError, TranslateError},
+        prelude::{Payload, Value::*},
+    },
+};
+
+test_case!(sequence, {
+    let g = get_tester!();
+
+    g.named_test(
+        "create sequence",
+        "CREATE SEQUENCE Counter;",
+        Ok(Payload::CreateSequence),
+    )
+    .await;
+    g.named_test(
+        "create sequence with options",
+        "CREATE SEQUENCE Countdown INCREMENT BY -10 START WITH 100;",
+        Ok(Payload::CreateSequence),
+    )
+    .await;
+    g.named_test(
+        "create existing sequence",
+        "CREATE SEQUENCE Counter;",
+        Err(AlterError::SequenceAlreadyExists("Counter".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "create existing sequence with IF NOT EXISTS",
+        "CREATE SEQUENCE IF NOT EXISTS Counter START WITH 50;",
+        Ok(Payload::CreateSequence),
+    )
+    .await;
+    g.named_test(
+        "zero increment",
+        "CREATE SEQUENCE Still INCREMENT BY 0;",
+        Err(AlterError::ZeroSequenceIncrement("Still".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "unsupported sequence option",
+        "CREATE SEQUENCE Cycle MAXVALUE 10;",
+        Err(TranslateError::UnsupportedSequenceOption("MAXVALUE 10".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "CURRVAL before NEXTVAL",
+        "SELECT CURRVAL('Counter') AS n;",
+        Err(SequenceError::CurrentValueNotDefined("Counter".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "NEXTVAL in SELECT without FROM",
+        "SELECT NEXTVAL('Counter') AS n, NEXTVAL('Countdown') AS m;",
+        Ok(select!(
+            n   | m
+            I64 | I64;
+            1     100
+        )),
+    )
+    .await;
+    g.named_test(
+        "NEXTVAL and CURRVAL are resolved from left to right",
+        "SELECT CURRVAL('Counter') AS a, NEXTVAL('Counter') AS b, CURRVAL('Counter') AS c;",
+        Ok(select!(
+            a   | b   | c
+            I64 | I64 | I64;
+            1     2     2
+        )),
+    )
+    .await;
+    g.named_test(
+        "NEXTVAL of missing sequence",
+        "SELECT NEXTVAL('Missing') AS n;",
+        Err(SequenceError::SequenceNotFound("Missing".to_owned()).into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE Item (id INTEGER, name TEXT);").await;
+    g.named_test(
+        "NEXTVAL in INSERT VALUES",
+        "INSERT INTO Item VALUES (NEXTVAL('Counter'), 'a'), (NEXTVAL('Counter') * 10, 'b');",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (CURRVAL('Countdown'), 'c');")
+        .await;
+    g.named_test(
+        "NEXTVAL evaluated per row is not supported",
+        "SELECT NEXTVAL('Counter') AS n FROM Item;",
+        Err(SequenceError::NextvalNotSupported.into()),
+    )
+    .await;
+    g.test(
+        "SELECT id, name, CURRVAL('Counter') AS c FROM Item ORDER BY id;",
+        Ok(select!(
+            id  | name             | c
+            I64 | Str              | I64;
+            3     "a".to_owned()     4;
+            40    "b".to_owned()     4;
+            100   "c".to_owned()     4
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE Ticket (id INTEGER DEFAULT NEXTVAL('Countdown'), memo TEXT);")
+        .await;
+    g.named_test(
+        "NEXTVAL as column default",
+        "INSERT INTO Ticket (memo) VALUES ('a'), ('b');",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, memo FROM Ticket;",
+        Ok(select!(
+            id  | memo
+            I64 | Str;
+            90    "a".to_owned();
+            80    "b".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "SETVAL sets the current value",
+        "SELECT SETVAL('Counter', 20) AS n;",
+        Ok(select!(n I64; 20)),
+    )
+    .await;
+    g.named_test(
+        "NEXTVAL continues from SETVAL",
+        "SELECT NEXTVAL('Counter') AS n;",
+        Ok(select!(n I64; 21)),
+    )
+    .await;
+    g.named_test(
+        "SETVAL of missing sequence",
+        "SELECT SETVAL('Missing', 1) AS n;",
+        Err(SequenceError::SequenceNotFound("Missing".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "drop sequence",
+        "DROP SEQUENCE Counter, Countdown;",
+        Ok(Payload::DropSequence),
+    )
+    .await;
+    g.named_test(
+        "drop missing sequence",
+        "DROP SEQUENCE Counter;",
+        Err(AlterError::SequenceNotFound("Counter".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "drop missing sequence with IF EXISTS",
+        "DROP SEQUENCE IF EXISTS Counter;",
+        Ok(Payload::DropSequence),
+    )
+    .await;
+});
+
+test_case!(identity, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Account (
+            id SERIAL PRIMARY KEY,
+            code INTEGER GENERATED ALWAYS AS IDENTITY (INCREMENT BY 5 START WITH 100),
+            name TEXT
+        );
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "identity columns are generated when omitted",
+        "INSERT INTO Account (name) VALUES ('a'), ('b');",
+        Ok(Payload::Insert(2)),
+    )
+    .await;
+    g.named_test(
+        "SERIAL accepts an explicit value",
+        "INSERT INTO Account (id, name) VALUES (10, 'c');",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.named_test(
+        "GENERATED ALWAYS rejects an explicit value",
+        "INSERT INTO Account (code, name) VALUES (1, 'd');",
+        Err(InsertError::IdentityColumnAlwaysGenerated("code".to_owned()).into()),
+    )
+    .await;
+    g.run("INSERT INTO Account (name) VALUES ('e');").await;
+    g.test(
+        "SELECT id, code, name FROM Account ORDER BY id;",
+        Ok(select!(
+            id  | code | name
+            I64 | I64  | Str;
+            1     100    "a".to_owned();
+            2     105    "b".to_owned();
+            3     115    "e".to_owned();
+            10    110    "c".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "identity sequence is named after the table and column",
+        "SELECT CURRVAL('Account_code_seq') AS code;",
+        Ok(select!(code I64; 115)),
+    )
+    .await;
+
+    g.named_test(
+        "identity column requires an integer type",
+        "CREATE TABLE Wrong (id TEXT GENERATED BY DEFAULT AS IDENTITY);",
+        Err(
+            AlterError::UnsupportedDataTypeForIdentityColumn("id".to_owned(), DataType::Text)
+                .into(),
+        ),
+    )
+    .await;
+    g.named_test(
+        "identity column cannot be added to a table",
+        "ALTER TABLE Account ADD COLUMN num SERIAL;",
+        Err(AlterError::AddIdentityColumnNotSupported("num".to_owned()).into()),
+    )
+    .await;
+
+    g.run("CREATE TABLE Legacy (id INTEGER, name TEXT);").await;
+    g.run("INSERT INTO Legacy VALUES (1, 'a'), (2, 'b');").await;
+    g.named_test(
+        "identity can be added to an existing column",
+        "ALTER TABLE Legacy ALTER COLUMN id ADD GENERATED ALWAYS AS IDENTITY (START WITH 3);",
+        Ok(Payload::AlterTable),
+    )
+    .await;
+    g.named_test(
+        "added identity column generates values",
+        "INSERT INTO Legacy (name) VALUES ('c') RETURNING id;",
+        Ok(select!(id I64; 3)),
+    )
+    .await;
+    g.named_test(
+        "added identity column rejects an explicit value",
+        "INSERT INTO Legacy VALUES (4, 'd');",
+        Err(InsertError::IdentityColumnAlwaysGenerated("id".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "identity cannot be added twice",
+        "ALTER TABLE Legacy ALTER COLUMN id ADD GENERATED BY DEFAULT AS IDENTITY;",
+        Err(AlterError::IdentityColumnAlreadyExists("id".to_owned()).into()),
+    )
+    .await;
+
+    g.run("ALTER TABLE Account RENAME TO Member;").await;
+    g.named_test(
+        "identity sequence follows the renamed table",
+        "INSERT INTO Member (name) VALUES ('f') RETURNING id, code;",
+        Ok(select!(
+            id  | code
+            I64 | I64;
+            4     120
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "drop table drops its identity sequences",
+        "DROP TABLE Member;",
+        Ok(Payload::DropTable(1)),
+    )
+    .await;
+    g.test(
+        "SELECT NEXTVAL('Member_id_seq') AS id;",
+        Err(SequenceError::SequenceNotFound("Member_id_seq".to_owned()).into()),
+    )
+    .await;
+});
@@ -0,0 +1,128 @@
+use {
+    crate::*,
+    gluesql_core::{ast::SetOperator, error::SelectError, prelude::*},
+    Value::*,
+};
+
+test_case!(set_operation, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Foo (id INTEGER, name TEXT);").await;
+    g.run("CREATE TABLE Bar (id INTEGER, name TEXT);").await;
+    g.run("INSERT INTO Foo VALUES (1, 'a'), (2, 'b'), (2, 'b'), (3, 'c');")
+        .await;
+    g.run("INSERT INTO Bar VALUES (2, 'b'), (3, 'c'), (3, 'c'), (4, 'd');")
+        .await;
+
+    let test_cases = [
+        (
+            "SELECT id FROM Foo UNION SELECT id FROM Bar ORDER BY id",
+            Ok(select!(id; I64; 1; 2; 3; 4)),
+        ),
+        (
+            "SELECT id FROM Foo UNION ALL SELECT id FROM Bar ORDER BY id",
+            Ok(select!(id; I64; 1; 2; 2; 2; 3; 3; 3; 4)),
+        ),
+        (
+            "SELECT id, name FROM Foo INTERSECT SELECT id, name FROM Bar ORDER BY id",
+            Ok(select!(
+                id  | name;
+                I64 | Str;
+                2     "b".to_owned();
+                3     "c".to_owned()
+            )),
+        ),
+        (
+            "SELECT id FROM Bar INTERSECT ALL SELECT id FROM Foo ORDER BY id",
+            Ok(select!(id; I64; 2; 3)),
+        ),
+        (
+            "SELECT id FROM Foo EXCEPT SELECT id FROM Bar",
+            Ok(select!(id; I64; 1)),
+        ),
+        (
+            "SELECT id FROM Foo EXCEPT ALL SELECT id FROM Bar ORDER BY id",
+            Ok(select!(id; I64; 1; 2)),
+        ),
+        (
+            "SELECT id AS n FROM Foo UNION SELECT id FROM Bar ORDER BY n DESC LIMIT 2 OFFSET 1",
+            Ok(select!(n; I64; 3; 2)),
+        ),
+        (
+            "SELECT id FROM Foo UNION SELECT id FROM Bar EXCEPT VALUES (1), (4) ORDER BY id",
+            Ok(select!(id; I64; 2; 3)),
+        ),
+        (
+            "SELECT id FROM Foo UNION SELECT id FROM Bar INTERSECT SELECT 4 ORDER BY id",
+            Ok(select!(id; I64; 1; 2; 3; 4)),
+        ),
+        (
+            "SELECT id FROM Foo EXCEPT (SELECT id FROM Bar UNION SELECT 1)",
+            Ok(select!(id)),
+        ),
+        (
+            "SELECT * FROM (SELECT id FROM Foo INTERSECT SELECT id FROM Bar) AS Sub ORDER BY id",
+            Ok(select!(id; I64; 2; 3)),
+        ),
+        (
+            "SELECT id FROM Foo WHERE id IN (SELECT 1 UNION SELECT 3) ORDER BY id",
+            Ok(select!(id; I64; 1; 3)),
+        ),
+        (
+            "SELECT id, name FROM Foo UNION SELECT id FROM Bar",
+            Err(SelectError::NumberOfColumnsDifferent(SetOperator::Union).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+
+    g.run("CREATE TABLE Baz AS SELECT id, name FROM Foo UNION SELECT id, name FROM Bar;")
+        .await;
+    g.named_test(
+        "CTAS and INSERT accept set operations",
+        "SELECT COUNT(*) FROM Baz",
+        Ok(select!("COUNT(*)"; I64; 4)),
+    )
+    .await;
+
+    g.run("INSERT INTO Baz SELECT id, name FROM Foo EXCEPT SELECT id, name FROM Bar;")
+        .await;
+    g.test(
+        "SELECT id, name FROM Baz WHERE id = 1",
+        Ok(select!(
+            id  | name;
+            I64 | Str;
+            1     "a".to_owned();
+            1     "a".to_owned()
+        )),
+    )
+    .await;
+
+    g.run("CREATE TABLE ListA (items LIST);").await;
+    g.run("CREATE TABLE ListB (items LIST);").await;
+    g.run(r#"INSERT INTO ListA VALUES ('[1, 2]'), ('[1, 2]'), ('[{"a": [3]}]');"#)
+        .await;
+    g.run(r#"INSERT INTO ListB VALUES ('[1, 2]'), ('[2, 1]'), ('[{"a": [3]}]');"#)
+        .await;
+
+    let l = |s: &str| Value::parse_json_list(s).unwrap();
+    g.named_test(
+        "UNION deduplicates LIST values",
+        "SELECT items FROM ListA UNION SELECT items FROM ListB",
+        Ok(select_with_null!(
+            items;
+            l("[1, 2]");
+            l(r#"[{"a": [3]}]"#);
+            l("[2, 1]")
+        )),
+    )
+    .await;
+    g.named_test(
+        "EXCEPT compares LIST values",
+        "SELECT items FROM ListB EXCEPT SELECT items FROM ListA",
+        Ok(select_with_null!(items; l("[2, 1]"))),
+    )
+    .await;
+});
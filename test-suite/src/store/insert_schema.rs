@@ -14,15 +14,20 @@ test_case!(insert_schema, {
         nullable: false,
         default: Some(Expr::Literal(AstLiteral::Number(11.into()))),
         unique: None,
+        check: None,
+        identity: None,
         comment: Some("default value is lucky eleven".to_owned()),
     }]);
 
     let mut schema = Schema {
         table_name: "MutableTable".to_owned(),
         column_defs,
+        primary_key: Vec::new(),
         indexes: Vec::new(),
         engine: None,
         foreign_keys: Vec::new(),
+        checks: Vec::new(),
+        triggers: Vec::new(),
         comment: Some("this is comment for table".to_owned()),
     };
 
@@ -36,6 +41,8 @@ test_case!(insert_schema, {
             nullable: false,
             default: None,
             unique: None,
+            check: None,
+            identity: None,
             comment: Some("this is comment for name column".to_owned()),
         });
 
@@ -54,9 +61,12 @@ test_case!(insert_schema, {
     let schema = Schema {
         table_name: "SchemalessTable".to_owned(),
         column_defs: None,
+        primary_key: Vec::new(),
         indexes: Vec::new(),
         engine: None,
         foreign_keys: Vec::new(),
+        checks: Vec::new(),
+        triggers: Vec::new(),
         comment: Some("this is comment for schemaless table".to_owned()),
     };
     storage.insert_schema(&schema).await.unwrap();
@@ -0,0 +1,120 @@
+use {
+    crate::*,
+    futures::stream::TryStreamExt,
+    gluesql_core::{
+        error::{FetchError, ValueError},
+        executor::Payload,
+        prelude::{PayloadStream, Value::*},
+    },
+};
+
+test_case!(stream, {
+    let glue = get_glue!();
+
+    let create = glue
+        .plan("CREATE TABLE Item (id INTEGER, name TEXT);")
+        .await
+        .expect("plan create table");
+    let actual = glue
+        .execute_stream(&create[0])
+        .await
+        .expect("create table")
+        .collect()
+        .await;
+    assert_eq!(actual, Ok(Payload::Create), "non-query statement");
+
+    let statements = glue
+        .plan(
+            "
+            INSERT INTO Item VALUES (1, 'Apple'), (2, 'Pear'), (3, 'Melon');
+            SELECT id, name FROM Item WHERE id > 1;
+            ",
+        )
+        .await
+        .expect("plan");
+
+    let actual = match glue.execute_stream(&statements[0]).await {
+        Ok(PayloadStream::Payload(payload)) => Ok(payload),
+        _ => Err("insert should not be streamed"),
+    };
+    assert_eq!(actual, Ok(Payload::Insert(3)), "insert");
+
+    let (labels, mut rows) = match glue.execute_stream(&statements[1]).await {
+        Ok(PayloadStream::Select { labels, rows }) => (labels, rows),
+        _ => panic!("select should be streamed"),
+    };
+    assert_eq!(labels, vec!["id".to_owned(), "name".to_owned()]);
+    assert_eq!(
+        rows.try_next().await,
+        Ok(Some(vec![I64(2), Str("Pear".to_owned())])),
+        "first row"
+    );
+    assert_eq!(
+        rows.try_next().await,
+        Ok(Some(vec![I64(3), Str("Melon".to_owned())])),
+        "second row"
+    );
+    assert_eq!(rows.try_next().await, Ok(None), "end of rows");
+    drop(rows);
+
+    let actual = glue
+        .execute_stream(&statements[1])
+        .await
+        .expect("select")
+        .collect()
+        .await;
+    let expected = Ok(select!(
+        id  | name
+        I64 | Str;
+        2     "Pear".to_owned();
+        3     "Melon".to_owned()
+    ));
+    assert_eq!(actual, expected, "collect streamed rows");
+
+    glue.finish_stream().await.expect("finish stream");
+
+    let actual = glue.execute("DELETE FROM Item WHERE id = 1;").await;
+    assert_eq!(actual, Ok(vec![Payload::Delete(1)]), "execute after stream");
+
+    let statements = glue
+        .plan(
+            "
+            SELECT * FROM Missing;
+            SELECT id / (id - 3) AS ratio FROM Item;
+            ",
+        )
+        .await
+        .expect("plan failing queries");
+
+    let actual = glue
+        .execute_stream(&statements[0])
+        .await
+        .err()
+        .expect("missing table");
+    assert_eq!(
+        actual,
+        FetchError::TableNotFound("Missing".to_owned()).into(),
+        "failed query"
+    );
+
+    let mut rows = match glue.execute_stream(&statements[1]).await {
+        Ok(PayloadStream::Select { rows, .. }) => rows,
+        _ => panic!("select should be streamed"),
+    };
+    assert_eq!(
+        rows.try_next().await,
+        Ok(Some(vec![I64(-2)])),
+        "row before error"
+    );
+    assert_eq!(
+        rows.try_next().await,
+        Err(ValueError::DivisorShouldNotBeZero.into()),
+        "failed row"
+    );
+    assert_eq!(rows.try_next().await, Ok(None), "stream ends after error");
+    drop(rows);
+
+    let actual = glue.execute("SELECT id FROM Item;").await;
+    let expected = Ok(vec![select!(id I64; 2; 3)]);
+    assert_eq!(actual, expected, "execute after failed stream");
+});
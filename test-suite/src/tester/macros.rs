@@ -14,6 +14,7 @@ macro_rules! idx {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: None,
+            prefix: vec![],
             cmp_expr: Some((
                 $op,
                 gluesql_core::translate::translate_expr(
@@ -21,27 +22,108 @@ macro_rules! idx {
                 )
                 .unwrap(),
             )),
+            upper_cmp_expr: None,
+        }]
+    };
+    ($name: path, $op: path, $sql_expr: literal, $upper_op: path, $upper_sql_expr: literal) => {
+        vec![gluesql_core::ast::IndexItem::NonClustered {
+            name: stringify_label!($name).to_owned(),
+            asc: None,
+            prefix: vec![],
+            cmp_expr: Some((
+                $op,
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($sql_expr).unwrap(),
+                )
+                .unwrap(),
+            )),
+            upper_cmp_expr: Some((
+                $upper_op,
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($upper_sql_expr).unwrap(),
+                )
+                .unwrap(),
+            )),
+        }]
+    };
+    ($name: path, [$($prefix: literal),+], $op: path, $sql_expr: literal) => {
+        vec![gluesql_core::ast::IndexItem::NonClustered {
+            name: stringify_label!($name).to_owned(),
+            asc: None,
+            prefix: vec![$(
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($prefix).unwrap(),
+                )
+                .unwrap()
+            ),+],
+            cmp_expr: Some((
+                $op,
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($sql_expr).unwrap(),
+                )
+                .unwrap(),
+            )),
+            upper_cmp_expr: None,
+        }]
+    };
+    (
+        $name: path,
+        [$($prefix: literal),+],
+        $op: path,
+        $sql_expr: literal,
+        $upper_op: path,
+        $upper_sql_expr: literal
+    ) => {
+        vec![gluesql_core::ast::IndexItem::NonClustered {
+            name: stringify_label!($name).to_owned(),
+            asc: None,
+            prefix: vec![$(
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($prefix).unwrap(),
+                )
+                .unwrap()
+            ),+],
+            cmp_expr: Some((
+                $op,
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($sql_expr).unwrap(),
+                )
+                .unwrap(),
+            )),
+            upper_cmp_expr: Some((
+                $upper_op,
+                gluesql_core::translate::translate_expr(
+                    &gluesql_core::parse_sql::parse_expr($upper_sql_expr).unwrap(),
+                )
+                .unwrap(),
+            )),
         }]
     };
     ($name: path) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: None,
+            prefix: vec![],
             cmp_expr: None,
+            upper_cmp_expr: None,
         }]
     };
     ($name: path, ASC) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: Some(true),
+            prefix: vec![],
             cmp_expr: None,
+            upper_cmp_expr: None,
         }]
     };
     ($name: path, DESC) => {
         vec![gluesql_core::ast::IndexItem::NonClustered {
             name: stringify_label!($name).to_owned(),
             asc: Some(false),
+            prefix: vec![],
             cmp_expr: None,
+            upper_cmp_expr: None,
         }]
     };
 }
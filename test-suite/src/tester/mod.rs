@@ -84,7 +84,12 @@ fn find_indexes(statement: &Statement) -> Vec<&IndexItem> {
             .map(find_expr_indexes)
             .unwrap_or_default();
 
+        // pushed down filters are planned as a full scan, not as an index
         let table_indexes = match &select.from.relation {
+            TableFactor::Table {
+                index: Some(IndexItem::Scan { .. }),
+                ..
+            } => vec![],
             TableFactor::Table {
                 index: Some(index), ..
             } => vec![index],
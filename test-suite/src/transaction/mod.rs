@@ -3,8 +3,11 @@ mod ast_builder;
 mod basic;
 mod dictionary;
 mod index;
+mod sequence;
 mod table;
+mod trigger;
 
 pub use {
-    alter_table::*, ast_builder::*, basic::basic, dictionary::dictionary, index::*, table::*,
+    alter_table::*, ast_builder::*, basic::basic, dictionary::dictionary, index::*,
+    sequence::sequence, table::*, trigger::trigger,
 };
@@ -0,0 +1,62 @@
+use {
+    crate::*,
+    gluesql_core::{error::SequenceError, prelude::Value::*},
+};
+
+test_case!(sequence, {
+    let g = get_tester!();
+
+    g.run("CREATE SEQUENCE Counter;").await;
+    g.test("SELECT NEXTVAL('Counter') AS n;", Ok(select!(n I64; 1)))
+        .await;
+
+    g.run("BEGIN;").await;
+    g.test("SELECT NEXTVAL('Counter') AS n;", Ok(select!(n I64; 2)))
+        .await;
+    g.run("ROLLBACK;").await;
+    g.named_test(
+        "rolled back NEXTVAL is given again",
+        "SELECT NEXTVAL('Counter') AS n;",
+        Ok(select!(n I64; 2)),
+    )
+    .await;
+
+    g.run("BEGIN;").await;
+    g.run("CREATE SEQUENCE Temp;").await;
+    g.run("DROP SEQUENCE Counter;").await;
+    g.test(
+        "SELECT NEXTVAL('Counter') AS n;",
+        Err(SequenceError::SequenceNotFound("Counter".to_owned()).into()),
+    )
+    .await;
+    g.run("ROLLBACK;").await;
+    g.named_test(
+        "created sequence is rolled back",
+        "SELECT NEXTVAL('Temp') AS n;",
+        Err(SequenceError::SequenceNotFound("Temp".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "dropped sequence is rolled back",
+        "SELECT CURRVAL('Counter') AS n;",
+        Ok(select!(n I64; 2)),
+    )
+    .await;
+
+    g.run("CREATE TABLE Item (id SERIAL, name TEXT);").await;
+    g.run("BEGIN;").await;
+    g.run("INSERT INTO Item (name) VALUES ('a'), ('b');").await;
+    g.run("COMMIT;").await;
+    g.run("INSERT INTO Item (name) VALUES ('c');").await;
+    g.test(
+        "SELECT id, name FROM Item;",
+        Ok(select!(
+            id  | name
+            I64 | Str;
+            1     "a".to_owned();
+            2     "b".to_owned();
+            3     "c".to_owned()
+        )),
+    )
+    .await;
+});
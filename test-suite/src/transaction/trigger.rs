@@ -0,0 +1,54 @@
+use {
+    crate::*,
+    gluesql_core::{error::AlterError, prelude::Value::*},
+};
+
+test_case!(trigger, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE TABLE Counter (id INTEGER PRIMARY KEY, total INTEGER);
+        INSERT INTO Counter VALUES (1, 0);
+        CREATE TRIGGER count_insert AFTER INSERT ON Item FOR EACH ROW
+        UPDATE Counter SET total = total + 1 WHERE id = 1;
+    ",
+    )
+    .await;
+
+    g.run("BEGIN;").await;
+    g.run("INSERT INTO Item VALUES (1, 'a'), (2, 'b');").await;
+    g.test("SELECT total FROM Counter;", Ok(select!(total I64; 2)))
+        .await;
+    g.run("ROLLBACK;").await;
+    g.named_test(
+        "changes made by trigger are rolled back",
+        "SELECT total FROM Counter;",
+        Ok(select!(total I64; 0)),
+    )
+    .await;
+
+    g.run("BEGIN;").await;
+    g.run("DROP TRIGGER count_insert ON Item;").await;
+    g.run("CREATE TRIGGER count_delete AFTER DELETE ON Item FOR EACH ROW DELETE FROM Counter;")
+        .await;
+    g.run("ROLLBACK;").await;
+    g.named_test(
+        "created trigger is rolled back",
+        "DROP TRIGGER count_delete ON Item;",
+        Err(AlterError::TriggerNotFound {
+            table_name: "Item".to_owned(),
+            trigger_name: "count_delete".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (3, 'c');").await;
+    g.named_test(
+        "dropped trigger is rolled back",
+        "SELECT total FROM Counter;",
+        Ok(select!(total I64; 1)),
+    )
+    .await;
+});
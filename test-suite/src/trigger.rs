@@ -0,0 +1,263 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, TranslateError, TriggerError},
+        executor::MAX_TRIGGER_DEPTH,
+        prelude::{
+            Payload,
+            Value::{self, *},
+        },
+    },
+    rust_decimal::Decimal as D,
+};
+
+test_case!(trigger, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, name TEXT, price INTEGER);
+        CREATE TABLE Audit (action TEXT, item_id INTEGER, price INTEGER);
+        CREATE TABLE Counter (id INTEGER PRIMARY KEY, total INTEGER);
+        INSERT INTO Counter VALUES (1, 0);
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "create trigger with a single statement body",
+        "
+        CREATE TRIGGER count_insert AFTER INSERT ON Item FOR EACH ROW
+        UPDATE Counter SET total = total + 1 WHERE id = 1;
+        ",
+        Ok(Payload::CreateTrigger),
+    )
+    .await;
+    g.named_test(
+        "create trigger with BEGIN ... END body",
+        "
+        CREATE TRIGGER audit_delete BEFORE DELETE ON Item FOR EACH ROW
+        BEGIN
+            INSERT INTO Audit VALUES ('delete', OLD.id, OLD.price);
+            UPDATE Counter SET total = total - 1 WHERE id = 1;
+        END;
+        ",
+        Ok(Payload::CreateTrigger),
+    )
+    .await;
+    g.named_test(
+        "create trigger with WHEN condition",
+        "
+        CREATE TRIGGER audit_price AFTER UPDATE ON Item FOR EACH ROW
+        WHEN (NEW.price <> OLD.price)
+        INSERT INTO Audit VALUES ('price', OLD.id, NEW.price);
+        ",
+        Ok(Payload::CreateTrigger),
+    )
+    .await;
+    g.named_test(
+        "create existing trigger",
+        "CREATE TRIGGER count_insert AFTER DELETE ON Item FOR EACH ROW DELETE FROM Audit;",
+        Err(AlterError::TriggerAlreadyExists {
+            table_name: "Item".to_owned(),
+            trigger_name: "count_insert".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "create trigger on missing table",
+        "CREATE TRIGGER foo AFTER INSERT ON Missing FOR EACH ROW DELETE FROM Audit;",
+        Err(AlterError::TableNotFound("Missing".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "only INSERT, UPDATE and DELETE are allowed in trigger body",
+        "CREATE TRIGGER foo AFTER INSERT ON Item FOR EACH ROW DROP TABLE Audit;",
+        Err(TranslateError::UnsupportedTriggerStatement("DROP TABLE Audit".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "insert fires trigger for each row",
+        "INSERT INTO Item VALUES (1, 'apple', 10), (2, 'banana', 20), (3, 'cherry', 30);",
+        Ok(Payload::Insert(3)),
+    )
+    .await;
+    g.test("SELECT total FROM Counter;", Ok(select!(total I64; 3)))
+        .await;
+
+    g.named_test(
+        "update fires trigger only when the condition holds",
+        "UPDATE Item SET price = price + 5 WHERE id < 3;",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+    g.run("UPDATE Item SET name = 'cherries' WHERE id = 3;")
+        .await;
+
+    g.named_test(
+        "delete fires trigger with OLD row",
+        "DELETE FROM Item WHERE id = 2;",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.test(
+        "SELECT action, item_id, price FROM Audit ORDER BY action, item_id;",
+        Ok(select!(
+            action               | item_id | price
+            Str                  | I64     | I64;
+            "delete".to_owned()    2         25;
+            "price".to_owned()     1         15;
+            "price".to_owned()     2         25
+        )),
+    )
+    .await;
+    g.test("SELECT total FROM Counter;", Ok(select!(total I64; 2)))
+        .await;
+
+    g.named_test(
+        "drop trigger",
+        "DROP TRIGGER count_insert ON Item;",
+        Ok(Payload::DropTrigger),
+    )
+    .await;
+    g.run("INSERT INTO Item VALUES (4, 'durian', 40);").await;
+    g.named_test(
+        "dropped trigger is not fired",
+        "SELECT total FROM Counter;",
+        Ok(select!(total I64; 2)),
+    )
+    .await;
+    g.named_test(
+        "drop missing trigger",
+        "DROP TRIGGER count_insert ON Item;",
+        Err(AlterError::TriggerNotFound {
+            table_name: "Item".to_owned(),
+            trigger_name: "count_insert".to_owned(),
+        }
+        .into()),
+    )
+    .await;
+    g.named_test(
+        "drop missing trigger with IF EXISTS",
+        "DROP TRIGGER IF EXISTS count_insert ON Item;",
+        Ok(Payload::DropTrigger),
+    )
+    .await;
+
+    g.run(
+        r#"
+        CREATE TABLE Wallet (id INTEGER, balance DECIMAL, tags LIST);
+        CREATE TABLE WalletLog (balance DECIMAL, tags LIST);
+        CREATE TRIGGER log_wallet AFTER INSERT ON Wallet FOR EACH ROW
+        INSERT INTO WalletLog VALUES (NEW.balance, NEW.tags);
+        INSERT INTO Wallet VALUES (1, 12.345, '[1, "a", [true]]');
+    "#,
+    )
+    .await;
+    g.named_test(
+        "trigger reads DECIMAL and LIST values of NEW as they are",
+        "SELECT balance, tags FROM WalletLog;",
+        Ok(select_with_null!(
+            balance                   | tags;
+            Decimal(D::new(12345, 3))   Value::parse_json_list(r#"[1, "a", [true]]"#).unwrap()
+        )),
+    )
+    .await;
+
+    g.run(
+        "CREATE TRIGGER wrong_column AFTER INSERT ON Counter FOR EACH ROW DELETE FROM Audit WHERE item_id = NEW.item_id;",
+    )
+    .await;
+    g.named_test(
+        "failed trigger fails the statement",
+        "INSERT INTO Counter VALUES (2, 0);",
+        Err(TriggerError::RowColumnNotFound("NEW.item_id".to_owned()).into()),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Countdown (n INTEGER);
+        CREATE TRIGGER countdown AFTER INSERT ON Countdown FOR EACH ROW
+        WHEN (NEW.n > 0)
+        INSERT INTO Countdown VALUES (NEW.n - 1);
+    ",
+    )
+    .await;
+    g.named_test(
+        "trigger fires itself until its condition fails",
+        "INSERT INTO Countdown VALUES (3);",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.test(
+        "SELECT n FROM Countdown ORDER BY n;",
+        Ok(select!(n I64; 0; 1; 2; 3)),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Chain (n INTEGER);
+        CREATE TRIGGER chain AFTER INSERT ON Chain FOR EACH ROW
+        INSERT INTO Chain VALUES (NEW.n + 1);
+    ",
+    )
+    .await;
+    g.named_test(
+        "self-recursive trigger fails past the maximum nesting depth",
+        "INSERT INTO Chain VALUES (1);",
+        Err(TriggerError::NestingDepthExceeded("chain".to_owned(), MAX_TRIGGER_DEPTH).into()),
+    )
+    .await;
+});
+
+test_case!(referential_action, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Team (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE TABLE Player (
+            id INTEGER PRIMARY KEY,
+            team_id INTEGER NULL,
+            FOREIGN KEY (team_id) REFERENCES Team (id) ON DELETE CASCADE ON UPDATE SET NULL
+        );
+        CREATE TABLE PlayerLog (action TEXT, player_id INTEGER, team_id INTEGER NULL);
+        CREATE TRIGGER log_delete BEFORE DELETE ON Player FOR EACH ROW
+        INSERT INTO PlayerLog VALUES ('delete', OLD.id, OLD.team_id);
+        CREATE TRIGGER log_update AFTER UPDATE ON Player FOR EACH ROW
+        INSERT INTO PlayerLog VALUES ('update', OLD.id, NEW.team_id);
+        INSERT INTO Team VALUES (1, 'a'), (2, 'b');
+        INSERT INTO Player VALUES (1, 1), (2, 1), (3, 2);
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "ON UPDATE SET NULL fires UPDATE triggers of the referencing table",
+        "UPDATE Team SET id = 3 WHERE id = 2;",
+        Ok(Payload::Update(1)),
+    )
+    .await;
+    g.named_test(
+        "ON DELETE CASCADE fires DELETE triggers of the referencing table",
+        "DELETE FROM Team WHERE id = 1;",
+        Ok(Payload::Delete(1)),
+    )
+    .await;
+    g.test(
+        "SELECT action, player_id, team_id FROM PlayerLog ORDER BY action, player_id;",
+        Ok(select_with_null!(
+            action                   | player_id | team_id;
+            Str("delete".to_owned())   I64(1)      I64(1);
+            Str("delete".to_owned())   I64(2)      I64(1);
+            Str("update".to_owned())   I64(3)      Null
+        )),
+    )
+    .await;
+    g.test("SELECT id FROM Player;", Ok(select!(id I64; 3)))
+        .await;
+});
@@ -140,3 +140,122 @@ test_case!(update, {
         g.test(sql, expected).await;
     }
 });
+
+test_case!(from, {
+    let g = get_tester!();
+
+    g.run(
+        "
+        CREATE TABLE Orders (id INTEGER PRIMARY KEY, item_id INTEGER, status TEXT);
+        CREATE TABLE Staging (order_id INTEGER, status TEXT);
+        CREATE TABLE Item (id INTEGER PRIMARY KEY, discontinued BOOLEAN);
+        INSERT INTO Orders VALUES (1, 10, 'open'), (2, 20, 'open'), (3, 30, 'open');
+        INSERT INTO Staging VALUES (1, 'shipped'), (3, 'cancelled'), (4, 'shipped');
+        INSERT INTO Item VALUES (10, FALSE), (20, TRUE), (30, TRUE);
+    ",
+    )
+    .await;
+
+    g.named_test(
+        "update with values of joined rows",
+        "UPDATE Orders SET status = s.status FROM Staging AS s WHERE Orders.id = s.order_id",
+        Ok(Payload::Update(2)),
+    )
+    .await;
+    g.test(
+        "SELECT id, status FROM Orders",
+        Ok(select!(
+            id  | status
+            I64 | Str;
+            1     "shipped".to_owned();
+            2     "open".to_owned();
+            3     "cancelled".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "update with FROM relation joined with other tables",
+        "
+        UPDATE Orders SET status = 'stopped' || '-' || s.status
+        FROM Staging s INNER JOIN Item ON Item.discontinued = TRUE
+        WHERE Orders.id = s.order_id AND Orders.item_id = Item.id
+        RETURNING id, status
+        ",
+        Ok(select!(
+            id  | status
+            I64 | Str;
+            3     "stopped-cancelled".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "unqualified column is resolved by the updated table first",
+        "UPDATE Orders SET status = 'matched' FROM Item WHERE item_id = id",
+        Ok(Payload::Update(0)),
+    )
+    .await;
+
+    g.run(
+        "
+        CREATE TABLE Candidate (id INTEGER PRIMARY KEY, order_id INTEGER, status TEXT);
+        INSERT INTO Candidate VALUES (1, 2, 'first'), (2, 2, 'second'), (3, 3, 'third');
+    ",
+    )
+    .await;
+    g.named_test(
+        "first joined row wins when several rows match",
+        "
+        UPDATE Orders SET status = c.status
+        FROM (SELECT * FROM Candidate ORDER BY id) AS c
+        WHERE Orders.id = c.order_id
+        RETURNING id, status
+        ",
+        Ok(select!(
+            id  | status
+            I64 | Str;
+            2     "first".to_owned();
+            3     "third".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "order of FROM rows decides the matching row",
+        "
+        UPDATE Orders SET status = c.status
+        FROM (SELECT * FROM Candidate ORDER BY id DESC) AS c
+        WHERE Orders.id = c.order_id
+        RETURNING id, status
+        ",
+        Ok(select!(
+            id  | status
+            I64 | Str;
+            2     "second".to_owned();
+            3     "third".to_owned()
+        )),
+    )
+    .await;
+    g.named_test(
+        "first joined row wins with hash join",
+        "
+        UPDATE Orders SET status = Candidate.status
+        FROM Candidate
+        WHERE Orders.id = Candidate.order_id AND Orders.id = 2
+        RETURNING id, status
+        ",
+        Ok(select!(
+            id  | status
+            I64 | Str;
+            2     "first".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "row without any joined row is not updated",
+        "UPDATE Orders SET status = 'none' FROM Staging WHERE Staging.order_id = 100",
+        Ok(Payload::Update(0)),
+    )
+    .await;
+});
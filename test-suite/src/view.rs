@@ -0,0 +1,159 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{AlterError, FetchError, PlanError},
+        prelude::{Payload, PayloadVariable, Value::*},
+    },
+};
+
+test_case!(view, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Player (id INTEGER PRIMARY KEY, name TEXT, team_id INTEGER);")
+        .await;
+    g.run("CREATE TABLE Team (id INTEGER PRIMARY KEY, name TEXT);")
+        .await;
+    g.run("INSERT INTO Player VALUES (1, 'Taehoon', 1), (2, 'Mike', 2), (3, 'Jorno', 1);")
+        .await;
+    g.run("INSERT INTO Team VALUES (1, 'Red'), (2, 'Blue');")
+        .await;
+
+    g.named_test(
+        "create view of a joined query",
+        "
+        CREATE VIEW Roster AS
+        SELECT Player.id, Player.name, Team.name AS team
+        FROM Player
+        JOIN Team ON Team.id = Player.team_id;
+        ",
+        Ok(Payload::CreateView),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Roster WHERE team = 'Red'",
+        Ok(select!(
+            id  | name                 | team
+            I64 | Str                  | Str;
+            1     "Taehoon".to_owned()   "Red".to_owned();
+            3     "Jorno".to_owned()     "Red".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "view with column names referencing another view",
+        "CREATE VIEW RedPlayer (player) AS SELECT name FROM Roster WHERE team = 'Red';",
+        Ok(Payload::CreateView),
+    )
+    .await;
+    g.test(
+        "SELECT R.player FROM RedPlayer R WHERE R.player IN (SELECT name FROM Roster WHERE id > 1)",
+        Ok(select!(
+            player
+            Str;
+            "Jorno".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "view reflects the changes of its tables",
+        "INSERT INTO Player VALUES (4, 'Ana', 1);",
+        Ok(Payload::Insert(1)),
+    )
+    .await;
+    g.count("SELECT * FROM RedPlayer", 3).await;
+
+    g.named_test(
+        "create existing view",
+        "CREATE VIEW Roster AS SELECT * FROM Player;",
+        Err(AlterError::ViewAlreadyExists("Roster".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "create view with table name",
+        "CREATE VIEW Team AS SELECT * FROM Player;",
+        Err(AlterError::TableAlreadyExists("Team".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "create table with view name",
+        "CREATE TABLE Roster (id INTEGER);",
+        Err(AlterError::ViewAlreadyExists("Roster".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "replace view which references itself",
+        "CREATE OR REPLACE VIEW Roster AS SELECT * FROM RedPlayer;",
+        Err(PlanError::ViewReferencesItself("Roster".to_owned()).into()),
+    )
+    .await;
+
+    g.named_test(
+        "create or replace view",
+        "CREATE OR REPLACE VIEW Roster AS SELECT id, name, 'None' AS team FROM Player WHERE id = 2;",
+        Ok(Payload::CreateView),
+    )
+    .await;
+    g.test(
+        "SELECT * FROM Roster",
+        Ok(select!(
+            id  | name              | team
+            I64 | Str               | Str;
+            2     "Mike".to_owned()   "None".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test(
+        "views are listed in SHOW TABLES",
+        "SHOW TABLES",
+        Ok(Payload::ShowVariable(PayloadVariable::Tables(vec![
+            "Player".to_owned(),
+            "RedPlayer".to_owned(),
+            "Roster".to_owned(),
+            "Team".to_owned(),
+        ]))),
+    )
+    .await;
+    g.named_test(
+        "views are listed in GLUE_OBJECTS",
+        "SELECT OBJECT_NAME, OBJECT_TYPE FROM GLUE_OBJECTS WHERE OBJECT_TYPE = 'VIEW'",
+        Ok(select!(
+            OBJECT_NAME               | OBJECT_TYPE
+            Str                       | Str;
+            "RedPlayer".to_owned()      "VIEW".to_owned();
+            "Roster".to_owned()         "VIEW".to_owned()
+        )),
+    )
+    .await;
+
+    g.named_test("drop view", "DROP VIEW RedPlayer;", Ok(Payload::DropView))
+        .await;
+    g.named_test(
+        "select dropped view",
+        "SELECT * FROM RedPlayer",
+        Err(FetchError::TableNotFound("RedPlayer".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "drop missing view",
+        "DROP VIEW RedPlayer;",
+        Err(AlterError::ViewNotFound("RedPlayer".to_owned()).into()),
+    )
+    .await;
+    g.named_test(
+        "drop missing view if exists",
+        "DROP VIEW IF EXISTS RedPlayer, Roster;",
+        Ok(Payload::DropView),
+    )
+    .await;
+    g.test(
+        "SHOW TABLES",
+        Ok(Payload::ShowVariable(PayloadVariable::Tables(vec![
+            "Player".to_owned(),
+            "Team".to_owned(),
+        ]))),
+    )
+    .await;
+});
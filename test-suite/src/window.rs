@@ -0,0 +1,151 @@
+use {
+    crate::*,
+    gluesql_core::{
+        error::{EvaluateError, TranslateError, WindowError},
+        prelude::*,
+    },
+    Value::*,
+};
+
+test_case!(window, {
+    let g = get_tester!();
+
+    g.run("CREATE TABLE Sale (id INTEGER, region TEXT, amount INTEGER);")
+        .await;
+    g.run(
+        "
+        INSERT INTO Sale VALUES
+            (1, 'east', 10),
+            (2, 'east', 30),
+            (3, 'east', 30),
+            (4, 'west', 20),
+            (5, 'west', 5),
+            (6, 'east', 40);
+    ",
+    )
+    .await;
+
+    let test_cases = [
+        (
+            "SELECT id, ROW_NUMBER() OVER (ORDER BY id DESC) AS rn FROM Sale ORDER BY id",
+            Ok(select!(
+                id  | rn;
+                I64 | I64;
+                1     6;
+                2     5;
+                3     4;
+                4     3;
+                5     2;
+                6     1
+            )),
+        ),
+        (
+            "
+            SELECT
+                id,
+                RANK() OVER (PARTITION BY region ORDER BY amount DESC) AS rank,
+                DENSE_RANK() OVER (PARTITION BY region ORDER BY amount DESC) AS dense
+            FROM Sale
+            ORDER BY id
+            ",
+            Ok(select!(
+                id  | rank | dense;
+                I64 | I64  | I64;
+                1     4      3;
+                2     2      2;
+                3     2      2;
+                4     1      1;
+                5     2      2;
+                6     1      1
+            )),
+        ),
+        (
+            "
+            SELECT
+                id,
+                LAG(amount) OVER (PARTITION BY region ORDER BY id) AS prev,
+                LEAD(amount, 2, 0) OVER (PARTITION BY region ORDER BY id) AS next
+            FROM Sale
+            ORDER BY id
+            ",
+            Ok(select_with_null!(
+                id     | prev    | next;
+                I64(1)   Null      I64(30);
+                I64(2)   I64(10)   I64(40);
+                I64(3)   I64(30)   I64(0);
+                I64(4)   Null      I64(0);
+                I64(5)   I64(20)   I64(0);
+                I64(6)   I64(30)   I64(0)
+            )),
+        ),
+        (
+            "
+            SELECT
+                id,
+                SUM(amount) OVER (PARTITION BY region ORDER BY id) AS running,
+                SUM(amount) OVER (PARTITION BY region) AS total,
+                COUNT(*) OVER () AS cnt
+            FROM Sale
+            ORDER BY id
+            ",
+            Ok(select!(
+                id  | running | total | cnt;
+                I64 | I64     | I64   | I64;
+                1     10        110     6;
+                2     40        110     6;
+                3     70        110     6;
+                4     20        25      6;
+                5     25        25      6;
+                6     110       110     6
+            )),
+        ),
+        (
+            "SELECT id, SUM(amount) OVER (ORDER BY amount) AS s FROM Sale WHERE region = 'east' ORDER BY id",
+            Ok(select!(
+                id  | s;
+                I64 | I64;
+                1     10;
+                2     70;
+                3     70;
+                6     110
+            )),
+        ),
+        (
+            "
+            SELECT region, SUM(amount) AS total, RANK() OVER (ORDER BY SUM(amount) DESC) AS rank
+            FROM Sale
+            GROUP BY region
+            ORDER BY rank
+            ",
+            Ok(select!(
+                region            | total | rank;
+                Str               | I64   | I64;
+                "east".to_owned()   110     1;
+                "west".to_owned()   25      2
+            )),
+        ),
+        (
+            "SELECT id FROM Sale ORDER BY ROW_NUMBER() OVER (ORDER BY amount DESC, id) LIMIT 2",
+            Ok(select!(id; I64; 6; 2)),
+        ),
+        (
+            "SELECT id FROM Sale WHERE ROW_NUMBER() OVER (ORDER BY id) = 1",
+            Err(EvaluateError::WindowFunctionNotAllowed(
+                r#"ROW_NUMBER() OVER (ORDER BY "id")"#.to_owned(),
+            )
+            .into()),
+        ),
+        (
+            "SELECT LAG(id, -1) OVER (ORDER BY id) FROM Sale",
+            Err(WindowError::InvalidOffset(r#"LAG("id", -1)"#.to_owned()).into()),
+        ),
+        (
+            "SELECT UPPER(region) OVER () FROM Sale",
+            Err(TranslateError::UnsupportedWindowFunction("UPPER".to_owned()).into()),
+        ),
+    ];
+
+    for (sql, expected) in test_cases {
+        g.test(sql, expected).await;
+    }
+});